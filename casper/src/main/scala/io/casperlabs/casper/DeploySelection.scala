@@ -53,8 +53,8 @@ object DeploySelection {
   )
 
   trait DeploySelection[F[_]] extends Select[F] {
-    // prestate hash, block time, protocol version, stream of deploys.
-    type A = (ByteString, Long, ProtocolVersion, fs2.Stream[F, Deploy])
+    // prestate hash, block time, block main rank, protocol version, stream of deploys.
+    type A = (ByteString, Long, Long, ProtocolVersion, fs2.Stream[F, Deploy])
     type B = DeploySelectionResult
   }
 
@@ -106,7 +106,7 @@ object DeploySelection {
     val underlying = create[F](sizeLimitBytes)
     new DeploySelection[F] {
       override def select(
-          in: (DeployHash, Long, ProtocolVersion, fs2.Stream[F, Deploy])
+          in: (DeployHash, Long, Long, ProtocolVersion, fs2.Stream[F, Deploy])
       ): F[DeploySelectionResult] =
         Metrics[F].timer("deploySelection")(underlying.select(in))
     }
@@ -133,9 +133,9 @@ object DeploySelection {
         state.size > 0.9 * sizeLimitBytes
 
       override def select(
-          in: (DeployHash, Long, ProtocolVersion, fs2.Stream[F, Deploy])
+          in: (DeployHash, Long, Long, ProtocolVersion, fs2.Stream[F, Deploy])
       ): F[DeploySelectionResult] = {
-        val (prestate, blocktime, protocolVersion, deploys) = in
+        val (prestate, blocktime, rank, protocolVersion, deploys) = in
         def go(
             state: IntermediateState,
             chunks: fs2.Stream[F, Deploy]
@@ -148,6 +148,7 @@ object DeploySelection {
               val newState = eeExecuteDeploys[F](
                 prestate,
                 blocktime,
+                rank,
                 batch,
                 protocolVersion
               )(ExecutionEngineService[F].exec _) map { results =>
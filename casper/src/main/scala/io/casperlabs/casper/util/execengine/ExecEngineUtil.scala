@@ -62,6 +62,7 @@ object ExecEngineUtil {
   type EEExecFun[F[_]] = (
       ByteString, // Prestate hash
       Long,       // Block time
+      Long,       // Block main rank
       Seq[DeployItem],
       state.ProtocolVersion
   ) => F[Either[Throwable, Seq[DeployResult]]]
@@ -104,13 +105,16 @@ object ExecEngineUtil {
       stage: Int,
       prestate: ByteString,
       blocktime: Long,
+      rank: Long,
       protocolVersion: state.ProtocolVersion,
       deploys: CommutingDeploys
   )(
       eeExec: EEExecFun[F],
       eeCommit: EECommitFun[F]
   ): F[Either[List[PreconditionFailure], DeploysCheckpoint]] =
-    eeExecuteDeploys[F](prestate, blocktime, deploys.getDeploys.toList, protocolVersion)(eeExec)
+    eeExecuteDeploys[F](prestate, blocktime, rank, deploys.getDeploys.toList, protocolVersion)(
+      eeExec
+    )
       .flatMap { results =>
         val (failures, deployEffects) = ProcessedDeployResult.split(results)
         if (failures.nonEmpty)
@@ -142,6 +146,7 @@ object ExecEngineUtil {
   protected[execengine] def execCommitSeqDeploys[F[_]: MonadThrowable: Log: Metrics: DeployStorage: DeployBuffer: ExecutionEngineService](
       prestateHash: ByteString,
       blocktime: Long,
+      rank: Long,
       protocolVersion: state.ProtocolVersion,
       deploys: NonEmptyList[Deploy]
   )(eeExec: EEExecFun[F], eeCommit: EECommitFun[F]): F[DeploysCheckpoint] = {
@@ -170,6 +175,7 @@ object ExecEngineUtil {
                     stage,
                     state.postStateHash,
                     blocktime,
+                    rank,
                     protocolVersion,
                     CommutingDeploys(deploy)
                   )(
@@ -201,6 +207,31 @@ object ExecEngineUtil {
     )
   }
 
+  /** Runs the scheduled calls which are due at `blocktime` on top of `prestate` and commits their
+    * effects, in order, so that the block's deploys can be executed on top of them.
+    *
+    * @return The post-state hash to execute the block's deploys on, and the effects committed,
+    *         which belong to the block's first stage.
+    */
+  def commitScheduledCalls[F[_]: MonadThrowable: ExecutionEngineService](
+      prestate: StateHash,
+      blocktime: Long,
+      rank: Long,
+      protocolVersion: state.ProtocolVersion
+  ): F[(StateHash, Seq[TransformEntry])] =
+    for {
+      results <- ExecutionEngineService[F]
+                  .execScheduledCalls(prestate, blocktime, rank, protocolVersion)
+                  .rethrow
+      transforms = results.flatMap(_.getExecutionResult.getEffects.transformMap)
+      postStateHash <- if (transforms.isEmpty) prestate.pure[F]
+                      else
+                        ExecutionEngineService[F]
+                          .commit(prestate, transforms, protocolVersion)
+                          .rethrow
+                          .map(_.postStateHash)
+    } yield (postStateHash, transforms)
+
   /** Given a set of chosen parents create a "deploy checkpoint".
     */
   def computeDeploysCheckpoint[F[_]: MonadThrowable: DeployStorage: DeployBuffer: Log: ExecutionEngineService: DeploySelection: Metrics](
@@ -213,20 +244,27 @@ object ExecEngineUtil {
   ): F[DeploysCheckpoint] = Metrics[F].timer("computeDeploysCheckpoint") {
     for {
       preStateHash <- computePrestate[F](merged, mainRank, upgrades).timer("computePrestate")
+      (scheduledStateHash, scheduledEffects) <- commitScheduledCalls[F](
+                                                 preStateHash,
+                                                 blocktime,
+                                                 mainRank,
+                                                 protocolVersion
+                                               ).timer("commitScheduledCalls")
       DeploySelectionResult(commuting, conflicting, preconditionFailures) <- DeploySelection[F]
                                                                               .select(
                                                                                 (
-                                                                                  preStateHash,
+                                                                                  scheduledStateHash,
                                                                                   blocktime,
+                                                                                  mainRank,
                                                                                   protocolVersion,
                                                                                   deployStream
                                                                                 )
                                                                               )
       _                             <- handleInvalidDeploys[F](preconditionFailures)
       (deploysForBlock, transforms) = ExecEngineUtil.unzipEffectsAndDeploys(commuting).unzip
-      stageEffects                  = Map(0 -> transforms.flatten)
+      stageEffects                  = Map(0 -> (scheduledEffects ++ transforms.flatten))
       parResult <- ExecutionEngineService[F]
-                    .commit(preStateHash, stageEffects(0), protocolVersion)
+                    .commit(scheduledStateHash, transforms.flatten, protocolVersion)
                     .rethrow
       result <- NonEmptyList
                  .fromList(conflicting)
@@ -245,6 +283,7 @@ object ExecEngineUtil {
                      execCommitSeqDeploys[F](
                        parResult.postStateHash,
                        blocktime,
+                       mainRank,
                        protocolVersion,
                        nelDeploys
                      )(
@@ -294,6 +333,7 @@ object ExecEngineUtil {
   def eeExecuteDeploys[F[_]: MonadThrowable](
       prestate: StateHash,
       blocktime: Long,
+      rank: Long,
       deploys: Seq[Deploy],
       protocolVersion: state.ProtocolVersion
   )(eeExec: EEExecFun[F]): F[List[ProcessedDeployResult]] = {
@@ -313,7 +353,7 @@ object ExecEngineUtil {
       }
 
     for {
-      results <- eeExec(prestate, blocktime, eeDeploys, protocolVersion).rethrow
+      results <- eeExec(prestate, blocktime, rank, eeDeploys, protocolVersion).rethrow
       _ <- MonadThrowable[F]
             .raiseError[List[ProcessedDeployResult]](
               SmartContractEngineError(
@@ -397,6 +437,7 @@ object ExecEngineUtil {
   ): F[BlockEffects] = {
     val deploysGrouped = ProtoUtil.deploys(block)
     val blocktime      = block.getHeader.timestamp
+    val rank           = block.getHeader.mainRank
 
     if (block.isGenesisLike) {
       // The new Genesis definition is that there's a chain spec that everyone's supposed to
@@ -414,13 +455,20 @@ object ExecEngineUtil {
     } else {
       for {
         protocolVersion <- CasperLabsProtocol[F].protocolFromBlock(block)
-        effectsRef      <- Ref[F].of(Map.empty[Int, Seq[TransformEntry]])
-        _ <- deploysGrouped.toList.sortBy(_._1).foldLeftM(prestate) {
+        (scheduledStateHash, scheduledEffects) <- commitScheduledCalls[F](
+                                                   prestate,
+                                                   blocktime,
+                                                   rank,
+                                                   protocolVersion
+                                                 )
+        // The scheduled calls' effects precede those of the deploys in the first stage.
+        effectsRef <- Ref[F].of(Map(0 -> scheduledEffects).filter(_._2.nonEmpty))
+        _ <- deploysGrouped.toList.sortBy(_._1).foldLeftM(scheduledStateHash) {
               case (preStateHash, (stage, deploys)) =>
                 val eeCommitCaptureEffects: EECommitFun[F] =
                   (preState, transforms, protocol) =>
                     for {
-                      _ <- effectsRef.update(_.updated(stage, transforms))
+                      _ <- effectsRef.update(_ |+| Map(stage -> transforms))
                       commitResult <- ExecutionEngineService[F].commit(
                                        preState,
                                        transforms,
@@ -432,6 +480,7 @@ object ExecEngineUtil {
                   stage,
                   preStateHash,
                   blocktime,
+                  rank,
                   protocolVersion,
                   CommutingDeploys(deploys.map(_.deploy.get))
                 )(
@@ -58,6 +58,7 @@ class DeploySelectionTest
 
   val prestate        = ByteString.EMPTY
   val blocktime       = 0L
+  val rank            = 0L
   val protocolVersion = ProtocolVersion(1)
 
   val smallBlockSizeBytes = 5 * 1024
@@ -110,7 +111,7 @@ class DeploySelectionTest
 
       val test = for {
         selected <- deploySelection
-                     .select((prestate, blocktime, protocolVersion, countedStream.stream))
+                     .select((prestate, blocktime, rank, protocolVersion, countedStream.stream))
                      .map(_.commuting.map(_.deploy))
         _ <- Task.delay(assert(scaleWithChunkSize(countedStream.getCount()) == expectedPulls))
       } yield selected should contain theSameElementsAs expected
@@ -136,7 +137,7 @@ class DeploySelectionTest
       DeploySelection.create[Task](smallBlockSizeBytes)
 
     val test = deploySelection
-      .select((prestate, blocktime, protocolVersion, stream))
+      .select((prestate, blocktime, rank, protocolVersion, stream))
       .map(results => {
         assert(results.commuting.map(_.deploy) == cappedEffects)
       })
@@ -164,7 +165,7 @@ class DeploySelectionTest
     val expectedConflicting = cappedEffects.zipWithIndex.filter(_._2 % 2 == 0).map(_._1).tail
 
     val test = deploySelection
-      .select((prestate, blocktime, protocolVersion, stream))
+      .select((prestate, blocktime, rank, protocolVersion, stream))
       .map {
         case DeploySelectionResult(commutingRes, conflictingRes, _) =>
           conflictingRes should contain theSameElementsAs expectedConflicting
@@ -196,7 +197,7 @@ class DeploySelectionTest
       val stream = fs2.Stream.fromIterator(cappedDeploys.toIterator)
 
       val test = deploySelection
-        .select((prestate, blocktime, protocolVersion, stream))
+        .select((prestate, blocktime, rank, protocolVersion, stream))
         .map(_.commuting.map(_.deploy))
         .map(_ should contain theSameElementsAs cappedDeploys)
 
@@ -222,7 +223,7 @@ class DeploySelectionTest
       DeploySelection.create[Task](smallBlockSizeBytes)
 
     val test = deploySelection
-      .select((prestate, blocktime, protocolVersion, stream))
+      .select((prestate, blocktime, rank, protocolVersion, stream))
       .map {
         case DeploySelectionResult(chosenDeploys, _, invalidDeploys) => {
           // Assert that all invalid deploys are a subset of the input set of invalid deploys.
@@ -265,6 +265,7 @@ object HashSetCasperTestNode {
       override def exec(
           prestate: ByteString,
           blocktime: Long,
+          rank: Long,
           deploys: Seq[ipc.DeployItem],
           protocolVersion: ProtocolVersion
       ): F[Either[Throwable, Seq[DeployResult]]] =
@@ -285,6 +286,14 @@ object HashSetCasperTestNode {
           .asRight[Throwable]
           .pure[F]
 
+      override def execScheduledCalls(
+          prestate: ByteString,
+          blocktime: Long,
+          rank: Long,
+          protocolVersion: ProtocolVersion
+      ): F[Either[Throwable, Seq[DeployResult]]] =
+        Seq.empty[DeployResult].asRight[Throwable].pure[F]
+
       override def runGenesis(
           genesisConfig: ipc.ChainSpec.GenesisConfig
       ): F[Either[Throwable, GenesisResult]] =
@@ -750,6 +750,7 @@ class ExecEngineUtilTest
   abstract class SequentialExecFixture(
       initPrestate: ByteString = ByteString.copyFromUtf8("initPrestateHash"),
       blockTime: Long = 1L,
+      rank: Long = 1L,
       protocolVersion: state.ProtocolVersion = state.ProtocolVersion(1)
   ) {
     implicit val mockDS: DeployStorage[Task] = MockDeployStorage.unsafeCreate[Task]()
@@ -767,7 +768,7 @@ class ExecEngineUtilTest
 
     def testF[R](f: DeploysCheckpoint => Task[R]): R =
       ExecEngineUtil
-        .execCommitSeqDeploys[Task](initPrestate, blockTime, protocolVersion, deploys)(
+        .execCommitSeqDeploys[Task](initPrestate, blockTime, rank, protocolVersion, deploys)(
           eeExec,
           eeCommit
         )
@@ -828,7 +829,7 @@ class ExecEngineUtilTest
 
   it should "send one deploy at a time to the ExecutionEngine" in new SequentialExecFixture {
     override val eeExec: EEExecFun[Task] =
-      (_, _, deploys, _) => {
+      (_, _, _, deploys, _) => {
         assert(deploys.size == 1)
         preconditionFailure
       }
@@ -848,7 +849,7 @@ class ExecEngineUtilTest
         (initPrestate :: deploys.map(_.deployHash)).zipWithIndex.toList.map(_.swap).toMap
 
       override val eeExec: EEExecFun[Task] =
-        (_, _, deploys, _) => deployResults(Seq(deployEffects(deploys.head.deployHash)))
+        (_, _, _, deploys, _) => deployResults(Seq(deployEffects(deploys.head.deployHash)))
 
       val commitCounter = AtomicInt(0)
       override val eeCommit: EECommitFun[Task] =
@@ -865,7 +866,7 @@ class ExecEngineUtilTest
   }
 
   it should "mark deploys as invalid if they fail execution with PreconditionFailure" in new SequentialExecFixture {
-    override val eeExec: EEExecFun[Task] = (_, _, _, _) => preconditionFailure
+    override val eeExec: EEExecFun[Task] = (_, _, _, _, _) => preconditionFailure
     override val eeCommit: EECommitFun[Task] =
       (_, _, _) => commitResult(ByteString.EMPTY, Seq.empty)
 
@@ -885,7 +886,7 @@ class ExecEngineUtilTest
     )
 
     override val eeExec: EEExecFun[Task] =
-      (_, _, deploys, _) => deployResults(Seq(deployEffects(deploys.head.deployHash)))
+      (_, _, _, deploys, _) => deployResults(Seq(deployEffects(deploys.head.deployHash)))
 
     val commitCounter = AtomicLong(0)
     override val eeCommit: EECommitFun[Task] = (_, _, _) => {
@@ -78,7 +78,7 @@ object ExecutionEngineServiceStub {
     } yield ProtoUtil.postStateHash(b)).attempt
   }
 
-  def mock[F[_]](
+  def mock[F[_]: Applicative](
       runGenesisWithChainSpecFunc: (
           ChainSpec.GenesisConfig
       ) => F[Either[Throwable, GenesisResult]],
@@ -115,10 +115,18 @@ object ExecutionEngineServiceStub {
     override def exec(
         prestate: ByteString,
         blocktime: Long,
+        rank: Long,
         deploys: Seq[DeployItem],
         protocolVersion: ProtocolVersion
     ): F[Either[Throwable, Seq[DeployResult]]] =
       execFunc(prestate, blocktime, deploys, protocolVersion)
+    override def execScheduledCalls(
+        prestate: ByteString,
+        blocktime: Long,
+        rank: Long,
+        protocolVersion: ProtocolVersion
+    ): F[Either[Throwable, Seq[DeployResult]]] =
+      Seq.empty[DeployResult].asRight[Throwable].pure[F]
     override def commit(
         prestate: ByteString,
         effects: Seq[TransformEntry],
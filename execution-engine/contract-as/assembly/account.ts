@@ -1,5 +1,7 @@
 import * as externals from "./externals";
 import {arrayToTyped} from "./utils";
+import {toBytesMap, toBytesPair, toBytesU8} from "./bytesrepr";
+import {Pair} from "./pair";
 import {UREF_SERIALIZED_LENGTH} from "./constants";
 import {URef} from "./uref";
 import {PublicKey, PUBLIC_KEY_ED25519_ID} from "./key";
@@ -47,6 +49,26 @@ export enum SetThresholdFailure {
     InsufficientTotalWeight = 4,
 }
 
+export enum RecoveryFailure {
+    Ok = 0,
+    // Unable to change account recovery due to insufficient permissions
+    PermissionDenied = 1,
+    // Account has no recovery keys
+    NotConfigured = 2,
+    // Unable to set recovery keys because maximum amount of keys is reached
+    MaxKeysLimit = 3,
+    // Account recovery has already been requested
+    AlreadyRequested = 4,
+    // Account recovery has not been requested
+    NotRequested = 5,
+    // Account recovery delay has not elapsed yet
+    DelayNotElapsed = 6,
+    // Account recovery delay has already elapsed
+    DelayElapsed = 7,
+    // Unable to set recovery keys because their threshold can't be met
+    InvalidThreshold = 8,
+}
+
 export enum ActionType {
     // Required by deploy execution.
     Deployment = 0,
@@ -77,6 +99,33 @@ export function removeAssociatedKey(publicKey: PublicKey): RemoveKeyFailure {
     return <RemoveKeyFailure>ret;
 }
 
+export function setRecovery(keys: Array<Pair<PublicKey, u8>>, threshold: u8, delay: u64): RecoveryFailure {
+    let pairs = new Array<u8[]>(0);
+    for (let i = 0; i < keys.length; i++) {
+        pairs.push(toBytesPair(keys[i].first.toBytes(), toBytesU8(keys[i].second)));
+    }
+    const keysBytes = toBytesMap(pairs);
+    const ret = externals.set_recovery(keysBytes.dataStart, keysBytes.length, threshold, delay);
+    return <RecoveryFailure>ret;
+}
+
+export function requestRecovery(account: PublicKey): RecoveryFailure {
+    const publicKeyBytes = account.toBytes();
+    const ret = externals.request_recovery(publicKeyBytes.dataStart, publicKeyBytes.length);
+    return <RecoveryFailure>ret;
+}
+
+export function cancelRecovery(): RecoveryFailure {
+    const ret = externals.cancel_recovery();
+    return <RecoveryFailure>ret;
+}
+
+export function recoverAccount(account: PublicKey): RecoveryFailure {
+    const publicKeyBytes = account.toBytes();
+    const ret = externals.recover_account(publicKeyBytes.dataStart, publicKeyBytes.length);
+    return <RecoveryFailure>ret;
+}
+
 export function getMainPurse(): URef | null {
     let data = new Uint8Array(UREF_SERIALIZED_LENGTH);
     data.fill(0);
@@ -27,6 +27,11 @@ export enum CLTypeTag {
     Tuple2 = 19,
     Tuple3 = 20,
     Any = 21,
+    Struct = 22,
+    Enum = 23,
+    I256 = 24,
+    Decimal = 25,
+    ByteArray = 26,
 }
 
 export class CLValue {
@@ -39,6 +39,8 @@ export const enum ErrorCode {
     BufferTooSmall = 32,
     HostBufferEmpty = 33,
     HostBufferFull = 34,
+    AllocLayout = 35,
+    Reentrancy = 36,
 }
 
 export const enum PosErrorCode {
@@ -22,6 +22,10 @@ export declare function load_named_keys(total_keys: usize, result_size: usize):
 export declare function get_arg(index: u32, dest_ptr: usize, dest_size: u32): i32;
 @external("env", "get_arg_size")
 export declare function get_arg_size(index: u32, dest_size: u32): i32;
+@external("env", "get_named_arg")
+export declare function get_named_arg(name_ptr: usize, name_size: usize, dest_ptr: usize, dest_size: u32): i32;
+@external("env", "get_named_arg_size")
+export declare function get_named_arg_size(name_ptr: usize, name_size: usize, dest_size: u32): i32;
 @external("env", "ret")
 export declare function ret(value_ptr: usize, value_size: usize): void;
 @external("env", "call_contract")
@@ -87,6 +91,30 @@ export declare function transfer_from_purse_to_purse(
 export declare function get_balance(purse_ptr: usize, purse_size: usize, result_size: u32): i32;
 @external("env", "get_phase")
 export declare function get_phase(dest_ptr: usize): void;
+@external("env", "set_non_reentrant")
+export declare function set_non_reentrant(): void;
+@external("env", "get_call_stack")
+export declare function get_call_stack(output_size: usize): i32;
+@external("env", "set_recovery")
+export declare function set_recovery(keys_ptr: usize, keys_size: usize, threshold: u8, delay: u64): i32;
+@external("env", "request_recovery")
+export declare function request_recovery(public_key_ptr: usize, public_key_size: usize): i32;
+@external("env", "cancel_recovery")
+export declare function cancel_recovery(): i32;
+@external("env", "recover_account")
+export declare function recover_account(public_key_ptr: usize, public_key_size: usize): i32;
+@external("env", "schedule_call")
+export declare function schedule_call(
+    contract_ptr: usize,
+    contract_size: u32,
+    args_ptr: usize,
+    args_size: u32,
+    due: u64,
+    purse_ptr: usize,
+    purse_size: u32,
+    amount_ptr: usize,
+    amount_size: u32,
+): i32;
 @external("env", "upgrade_contract_at_uref")
 export declare function upgrade_contract_at_uref(
     name_ptr: usize,
@@ -5,6 +5,7 @@ import {CLValue} from "./clvalue";
 import {Key, PublicKey} from "./key";
 import {toBytesString,
         toBytesVecT,
+        fromBytesArray,
         fromBytesMap,
         fromBytesString} from "./bytesrepr";
 import {U512} from "./bignum";
@@ -50,6 +51,33 @@ export function getArg(i: u32): Uint8Array | null {
   return data;
 }
 
+export function getNamedArgSize(name: String): U32 | null {
+  let nameBytes = toBytesString(name);
+  let size = new Array<u32>(1);
+  size[0] = 0;
+
+  let ret = externals.get_named_arg_size(nameBytes.dataStart, nameBytes.length, size.dataStart);
+  if (ret > 0) {
+    return null;
+  }
+  return changetype<U32>(size[0]);
+}
+
+export function getNamedArg(name: String): Uint8Array | null {
+  let arg_size = getNamedArgSize(name);
+  if (arg_size === null) {
+    return null;
+  }
+  let arg_size_u32 = changetype<u32>(arg_size);
+  let nameBytes = toBytesString(name);
+  let data = new Uint8Array(arg_size_u32);
+  let ret = externals.get_named_arg(nameBytes.dataStart, nameBytes.length, data.dataStart, arg_size_u32);
+  if (ret > 0) {
+    return null;
+  }
+  return data;
+}
+
 export function readHostBuffer(count: u32): Uint8Array | null {
   let result = new Uint8Array(count);
   let resultSize = new Uint32Array(1);
@@ -128,6 +156,26 @@ export function callContract(key: Key, args: CLValue[]): Uint8Array | null {
   }
 }
 
+export function scheduleCall(key: Key, args: CLValue[], due: u64, purse: URef, amount: U512): Error | null {
+  let keyBytes = key.toBytes();
+  let argBytes = toBytesVecT(args);
+  let purseBytes = purse.toBytes();
+  let amountBytes = amount.toBytes();
+
+  let ret = externals.schedule_call(
+      <usize>keyBytes.dataStart,
+      keyBytes.length,
+      argBytes.dataStart,
+      argBytes.length,
+      due,
+      purseBytes.dataStart,
+      purseBytes.length,
+      amountBytes.dataStart,
+      amountBytes.length,
+  );
+  return Error.fromResult(ret);
+}
+
 export function putKey(name: String, key: Key): void {
   var nameBytes = toBytesString(name);
   var keyBytes = key.toBytes();
@@ -201,6 +249,27 @@ export function getCaller(): PublicKey {
   return publicKeyResult.value;
 }
 
+export function getCallStack(): Array<Key> {
+  let outputSize = new Uint32Array(1);
+  let ret = externals.get_call_stack(outputSize.dataStart);
+  const error = Error.fromResult(ret);
+  if (error != null) {
+    error.revert();
+    return <Array<Key>>unreachable();
+  }
+  const callStackBytes = readHostBuffer(outputSize[0]);
+  if (callStackBytes === null) {
+    Error.fromErrorCode(ErrorCode.Deserialize).revert();
+    return <Array<Key>>unreachable();
+  }
+  const callStackResult = fromBytesArray<Key>(callStackBytes, Key.fromBytes);
+  if (callStackResult.hasError()) {
+    Error.fromErrorCode(ErrorCode.Deserialize).revert();
+    return <Array<Key>>unreachable();
+  }
+  return callStackResult.value;
+}
+
 export enum Phase {
   System = 0,
   Payment = 1,
@@ -215,6 +284,10 @@ export function getPhase(): Phase {
   return <Phase>phase;
 }
 
+export function setNonReentrant(): void {
+  externals.set_non_reentrant();
+}
+
 export function removeKey(name: String): void{
   var nameBytes = toBytesString(name);
   externals.remove_key(nameBytes.dataStart, nameBytes.length);
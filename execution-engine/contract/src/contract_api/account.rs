@@ -1,12 +1,12 @@
 //! Functions for managing accounts.
 
-use alloc::vec::Vec;
+use alloc::{collections::BTreeMap, vec::Vec};
 use core::convert::TryFrom;
 
 use casperlabs_types::{
     account::{
-        ActionType, AddKeyFailure, PublicKey, RemoveKeyFailure, SetThresholdFailure,
-        UpdateKeyFailure, Weight,
+        ActionType, AddKeyFailure, PublicKey, RecoveryFailure, RemoveKeyFailure,
+        SetThresholdFailure, UpdateKeyFailure, Weight,
     },
     bytesrepr, URef, UREF_SERIALIZED_LENGTH,
 };
@@ -80,3 +80,51 @@ pub fn update_associated_key(
         Err(UpdateKeyFailure::try_from(result).unwrap_or_revert())
     }
 }
+
+/// Designates the given [`PublicKey`]s, with associated [`Weight`]s, as the account's recovery
+/// keys.
+///
+/// Each recovery key can then approve a recovery of the account with [`request_recovery`].  Once
+/// the weight of the approving keys meets `threshold`, the recovery is requested, and until `delay`
+/// milliseconds have passed, the account's own keys can cancel it with [`cancel_recovery`].  After
+/// that, the recovery keys can take over key management of the account with [`recover_account`].
+/// Passing no keys opts the account out of recovery.
+pub fn set_recovery(
+    keys: BTreeMap<PublicKey, Weight>,
+    threshold: Weight,
+    delay: u64,
+) -> Result<(), RecoveryFailure> {
+    let (keys_ptr, keys_size, _bytes) = to_ptr(keys);
+    let threshold = threshold.value().into();
+    let result = unsafe { ext_ffi::set_recovery(keys_ptr, keys_size, threshold, delay) };
+    recovery_result(result)
+}
+
+/// Approves recovery of the given account, using the current account as one of its recovery keys.
+pub fn request_recovery(account: PublicKey) -> Result<(), RecoveryFailure> {
+    let (public_key_ptr, public_key_size, _bytes) = to_ptr(account);
+    let result = unsafe { ext_ffi::request_recovery(public_key_ptr, public_key_size) };
+    recovery_result(result)
+}
+
+/// Cancels a pending recovery request of the current account.
+pub fn cancel_recovery() -> Result<(), RecoveryFailure> {
+    let result = unsafe { ext_ffi::cancel_recovery() };
+    recovery_result(result)
+}
+
+/// Replaces the associated keys of the given account with its recovery keys, once the delay of its
+/// pending recovery request has elapsed.  The current account must be one of the recovery keys.
+pub fn recover_account(account: PublicKey) -> Result<(), RecoveryFailure> {
+    let (public_key_ptr, public_key_size, _bytes) = to_ptr(account);
+    let result = unsafe { ext_ffi::recover_account(public_key_ptr, public_key_size) };
+    recovery_result(result)
+}
+
+fn recovery_result(result: i32) -> Result<(), RecoveryFailure> {
+    if result == 0 {
+        Ok(())
+    } else {
+        Err(RecoveryFailure::try_from(result).unwrap_or_revert())
+    }
+}
@@ -11,7 +11,7 @@ use casperlabs_types::{
     api_error,
     bytesrepr::{self, FromBytes},
     ApiError, BlockTime, CLTyped, CLValue, ContractRef, Key, Phase, URef,
-    BLOCKTIME_SERIALIZED_LENGTH, PHASE_SERIALIZED_LENGTH,
+    BLOCKTIME_SERIALIZED_LENGTH, PHASE_SERIALIZED_LENGTH, U512,
 };
 
 use crate::{args_parser::ArgsParser, contract_api, ext_ffi, unwrap_or_revert::UnwrapOrRevert};
@@ -43,6 +43,10 @@ pub fn revert<T: Into<ApiError>>(error: T) -> ! {
 /// If the stored contract calls [`ret`], then that value is returned from `call_contract`.  If the
 /// stored contract calls [`revert`], then execution stops and `call_contract` doesn't return.
 /// Otherwise `call_contract` returns `()`.
+///
+/// Execution is aborted if the call would exceed the maximum depth of nested contract calls set for
+/// the current protocol version, and reverted with [`ApiError::Reentrancy`] if the stored contract
+/// is already executing and has called [`set_non_reentrant`].
 #[allow(clippy::ptr_arg)]
 pub fn call_contract<A: ArgsParser, T: CLTyped + FromBytes>(c_ptr: ContractRef, args: A) -> T {
     let contract_key: Key = c_ptr.into();
@@ -82,6 +86,56 @@ pub fn call_contract<A: ArgsParser, T: CLTyped + FromBytes>(c_ptr: ContractRef,
     bytesrepr::deserialize(serialized_result).unwrap_or_revert()
 }
 
+/// Declares the currently-executing stored contract non-reentrant.
+///
+/// Until the contract returns, any attempt to call it again, whether directly or via other
+/// contracts, reverts with [`ApiError::Reentrancy`].  Has no effect when called from session or
+/// payment code.
+pub fn set_non_reentrant() {
+    unsafe { ext_ffi::set_non_reentrant() }
+}
+
+/// Schedules a call to the given stored contract, passing the given arguments to it, to run at the
+/// start of the first block whose block time is at least `due`.
+///
+/// `amount` motes are moved from `purse` into escrow to pay for the call, and whatever isn't spent
+/// is refunded to `purse`.  Returns [`ApiError::InvalidArgument`] if there's no stored contract
+/// under `c_ptr` or `amount` is below the minimum escrow of 10,000,000 motes, or
+/// [`ApiError::Transfer`] if `amount` can't be taken from `purse`.
+///
+/// When called from session code, the call runs as session code of the current account.  A stored
+/// contract can schedule calls to itself, which run in its own context, with its named keys, and
+/// execution is aborted if it tries to schedule a call to any other contract.
+pub fn schedule_call<A: ArgsParser>(
+    c_ptr: ContractRef,
+    args: A,
+    due: BlockTime,
+    purse: URef,
+    amount: U512,
+) -> Result<(), ApiError> {
+    let contract_key: Key = c_ptr.into();
+    let (key_ptr, key_size, _bytes1) = contract_api::to_ptr(contract_key);
+    let (args_ptr, args_size, _bytes2) = ArgsParser::parse(args)
+        .map(contract_api::to_ptr)
+        .unwrap_or_revert();
+    let (purse_ptr, purse_size, _bytes3) = contract_api::to_ptr(purse);
+    let (amount_ptr, amount_size, _bytes4) = contract_api::to_ptr(amount);
+    let ret = unsafe {
+        ext_ffi::schedule_call(
+            key_ptr,
+            key_size,
+            args_ptr,
+            args_size,
+            due.into(),
+            purse_ptr,
+            purse_size,
+            amount_ptr,
+            amount_size,
+        )
+    };
+    api_error::result_from(ret)
+}
+
 /// Takes the name of a (non-mangled) `extern "C"` function to store as a contract under the given
 /// [`URef`] which should already reference a stored contract.
 ///
@@ -130,6 +184,39 @@ pub fn get_arg<T: FromBytes>(i: u32) -> Option<Result<T, bytesrepr::Error>> {
     Some(bytesrepr::deserialize(arg_bytes))
 }
 
+fn get_named_arg_size(name: &str) -> Option<usize> {
+    let (name_ptr, name_size, _bytes) = contract_api::to_ptr(name);
+    let mut arg_size: usize = 0;
+    let ret =
+        unsafe { ext_ffi::get_named_arg_size(name_ptr, name_size, &mut arg_size as *mut usize) };
+    match api_error::result_from(ret) {
+        Ok(_) => Some(arg_size),
+        Err(ApiError::MissingArgument) => None,
+        Err(e) => revert(e),
+    }
+}
+
+/// Returns the argument named `name` passed to the host for the current module invocation.
+///
+/// Named arguments are only available to deploys which provide them in named form; they are not
+/// passed on to contracts invoked via [`call_contract`].
+pub fn get_named_arg<T: FromBytes>(name: &str) -> Option<Result<T, bytesrepr::Error>> {
+    let arg_size = get_named_arg_size(name)?;
+
+    let arg_bytes = {
+        let res = {
+            let (name_ptr, name_size, _bytes) = contract_api::to_ptr(name);
+            let data_ptr = contract_api::alloc_bytes(arg_size);
+            let ret = unsafe { ext_ffi::get_named_arg(name_ptr, name_size, data_ptr, arg_size) };
+            let data = unsafe { Vec::from_raw_parts(data_ptr, arg_size, arg_size) };
+            api_error::result_from(ret).map(|_| data)
+        };
+        // Assumed to be safe as `get_named_arg_size` checks the argument already
+        res.unwrap_or_revert()
+    };
+    Some(bytesrepr::deserialize(arg_bytes))
+}
+
 /// Returns the caller of the current context, i.e. the [`PublicKey`] of the account which made the
 /// deploy request.
 pub fn get_caller() -> PublicKey {
@@ -143,6 +230,34 @@ pub fn get_caller() -> PublicKey {
     bytesrepr::deserialize(buf).unwrap_or_revert()
 }
 
+/// Returns the call stack of the current context, i.e. [`Key::Account`] of the account which made
+/// the deploy request, followed by the keys of the stored contracts currently being executed,
+/// outermost first.
+///
+/// When called from session or payment code, the returned stack holds only the account's key.
+/// When called from a stored contract, the last entry is the key of that contract.
+pub fn get_call_stack() -> Vec<Key> {
+    let output_size = {
+        let mut output_size = MaybeUninit::uninit();
+        let ret = unsafe { ext_ffi::get_call_stack(output_size.as_mut_ptr()) };
+        api_error::result_from(ret).unwrap_or_revert();
+        unsafe { output_size.assume_init() }
+    };
+    let buf = read_host_buffer(output_size).unwrap_or_revert();
+    bytesrepr::deserialize(buf).unwrap_or_revert()
+}
+
+/// Returns the key of whatever called the current context: either the key of the calling stored
+/// contract, or [`Key::Account`] of the deploying account if the current stored contract was
+/// called directly by session or payment code.
+///
+/// When called from session or payment code, returns [`Key::Account`] of the deploying account.
+pub fn get_immediate_caller() -> Key {
+    let mut call_stack = get_call_stack();
+    let index = call_stack.len().saturating_sub(2);
+    call_stack.swap_remove(index)
+}
+
 /// Returns the current [`BlockTime`].
 pub fn get_blocktime() -> BlockTime {
     let dest_ptr = contract_api::alloc_bytes(BLOCKTIME_SERIALIZED_LENGTH);
@@ -32,6 +32,13 @@ extern "C" {
     pub fn load_named_keys(total_keys: *mut usize, result_size: *mut usize) -> i32;
     pub fn get_arg(index: usize, dest_ptr: *mut u8, dest_size: usize) -> i32;
     pub fn get_arg_size(index: usize, dest_size: *mut usize) -> i32;
+    pub fn get_named_arg(
+        name_ptr: *const u8,
+        name_size: usize,
+        dest_ptr: *mut u8,
+        dest_size: usize,
+    ) -> i32;
+    pub fn get_named_arg_size(name_ptr: *const u8, name_size: usize, dest_size: *mut usize) -> i32;
     pub fn ret(value_ptr: *const u8, value_size: usize) -> !;
     pub fn call_contract(
         key_ptr: *const u8,
@@ -104,4 +111,21 @@ extern "C" {
     ) -> i32;
     pub fn get_main_purse(dest_ptr: *mut u8);
     pub fn read_host_buffer(dest_ptr: *mut u8, dest_size: usize, bytes_written: *mut usize) -> i32;
+    pub fn set_non_reentrant();
+    pub fn get_call_stack(output_size: *mut usize) -> i32;
+    pub fn set_recovery(keys_ptr: *const u8, keys_size: usize, threshold: i32, delay: u64) -> i32;
+    pub fn request_recovery(public_key_ptr: *const u8, public_key_size: usize) -> i32;
+    pub fn cancel_recovery() -> i32;
+    pub fn recover_account(public_key_ptr: *const u8, public_key_size: usize) -> i32;
+    pub fn schedule_call(
+        contract_ptr: *const u8,
+        contract_size: usize,
+        args_ptr: *const u8,
+        args_size: usize,
+        due: u64,
+        purse_ptr: *const u8,
+        purse_size: usize,
+        amount_ptr: *const u8,
+        amount_size: usize,
+    ) -> i32;
 }
@@ -20,6 +20,10 @@ const METHOD_MINT: &str = "mint";
 const METHOD_CREATE: &str = "create";
 const METHOD_BALANCE: &str = "balance";
 const METHOD_TRANSFER: &str = "transfer";
+const METHOD_TOTAL_SUPPLY: &str = "total_supply";
+const METHOD_AUTHORITY: &str = "authority";
+const METHOD_MINT_TOKENS: &str = "mint_tokens";
+const METHOD_BURN: &str = "burn";
 
 pub struct MintContract;
 
@@ -31,6 +35,10 @@ impl RuntimeProvider for MintContract {
     fn put_key(&mut self, name: &str, key: Key) {
         runtime::put_key(name, key)
     }
+
+    fn get_key(&self, name: &str) -> Option<Key> {
+        runtime::get_key(name)
+    }
 }
 
 impl StorageProvider for MintContract {
@@ -113,6 +121,48 @@ pub fn delegate() {
             let ret = CLValue::from_t(result).unwrap_or_revert();
             runtime::ret(ret);
         }
+        // Type: `fn total_supply() -> U512`
+        METHOD_TOTAL_SUPPLY => {
+            let total_supply: U512 = mint_contract.total_supply().unwrap_or_revert();
+            let ret = CLValue::from_t(total_supply).unwrap_or_revert();
+            runtime::ret(ret)
+        }
+        // Type: `fn authority() -> Result<URef, Error>`
+        METHOD_AUTHORITY => {
+            let result: Result<URef, Error> = mint_contract.authority();
+            let ret = CLValue::from_t(result).unwrap_or_revert();
+            runtime::ret(ret)
+        }
+        // Type: `fn mint_tokens(authority: URef, purse: URef, amount: U512) -> Result<(), Error>`
+        METHOD_MINT_TOKENS => {
+            let authority: URef = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let purse: URef = runtime::get_arg(2)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let amount: U512 = runtime::get_arg(3)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let result: Result<(), Error> = mint_contract.mint_tokens(authority, purse, amount);
+            let ret = CLValue::from_t(result).unwrap_or_revert();
+            runtime::ret(ret)
+        }
+        // Type: `fn burn(authority: URef, purse: URef, amount: U512) -> Result<(), Error>`
+        METHOD_BURN => {
+            let authority: URef = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let purse: URef = runtime::get_arg(2)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let amount: U512 = runtime::get_arg(3)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let result: Result<(), Error> = mint_contract.burn(authority, purse, amount);
+            let ret = CLValue::from_t(result).unwrap_or_revert();
+            runtime::ret(ret)
+        }
 
         _ => panic!("Unknown method name!"),
     }
@@ -8,22 +8,33 @@ use contract::{
     contract_api::{runtime, storage},
     unwrap_or_revert::UnwrapOrRevert,
 };
-use proof_of_stake::Stakes;
+use proof_of_stake::{DelegationQueue, Delegations, EraRewards, Jail, PosParams, Queue, Stakes};
 use types::{
     account::PublicKey, system_contract_errors::mint, AccessRights, ApiError, CLValue, ContractRef,
     Key, URef, U512,
 };
 
-const PLACEHOLDER_KEY: Key = Key::Hash([0u8; 32]);
 const POS_BONDING_PURSE: &str = "pos_bonding_purse";
 const POS_PAYMENT_PURSE: &str = "pos_payment_purse";
 const POS_REWARDS_PURSE: &str = "pos_rewards_purse";
+const POS_TREASURY_PURSE: &str = "pos_treasury_purse";
+const POS_MINT_AUTHORITY: &str = "mint_authority";
+const POS_STAKES: &str = "pos_stakes";
+const POS_BONDING_QUEUE: &str = "pos_bonding_queue";
+const POS_UNBONDING_QUEUE: &str = "pos_unbonding_queue";
+const POS_PARAMS: &str = "pos_params";
+const POS_DELEGATIONS: &str = "pos_delegations";
+const POS_DELEGATING_QUEUE: &str = "pos_delegating_queue";
+const POS_UNDELEGATING_QUEUE: &str = "pos_undelegating_queue";
+const POS_ERA_REWARDS: &str = "pos_era_rewards";
+const POS_JAIL: &str = "pos_jail";
 const POS_FUNCTION_NAME: &str = "pos_ext";
 
 #[repr(u32)]
 enum Args {
     MintURef = 0,
     GenesisValidators = 1,
+    PosParams = 2,
 }
 
 #[no_mangle]
@@ -43,31 +54,53 @@ pub extern "C" fn call() {
             .unwrap_or_revert_with(ApiError::MissingArgument)
             .unwrap_or_revert_with(ApiError::InvalidArgument);
 
-    let stakes = Stakes::new(genesis_validators);
-
-    // Add genesis validators to PoS contract object.
-    // For now, we are storing validators in `named_keys` map of the PoS contract
-    // in the form: key: "v_{validator_pk}_{validator_stake}", value: doesn't
-    // matter.
-    let mut named_keys: BTreeMap<String, Key> =
-        stakes.strings().map(|key| (key, PLACEHOLDER_KEY)).collect();
+    let pos_params: PosParams = runtime::get_arg(Args::PosParams as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
 
+    let stakes = Stakes::new(genesis_validators);
     let total_bonds: U512 = stakes.total_bonds();
 
     let bonding_purse = mint_purse(&mint, total_bonds);
     let payment_purse = mint_purse(&mint, U512::zero());
     let rewards_purse = mint_purse(&mint, U512::zero());
-
-    // Include PoS purses in its named_keys
-    [
+    let treasury_purse = mint_purse(&mint, U512::zero());
+    let mint_authority: Result<URef, mint::Error> =
+        runtime::call_contract(mint.clone(), ("authority",));
+
+    // Store the genesis validators' stakes, the empty bonding and delegation queues, the
+    // delegations, era rewards and jail, and the parameters under their own urefs.
+    let stakes_uref = storage::new_uref(stakes);
+    let bonding_queue_uref = storage::new_uref(Queue::default());
+    let unbonding_queue_uref = storage::new_uref(Queue::default());
+    let params_uref = storage::new_uref(pos_params);
+    let delegations_uref = storage::new_uref(Delegations::default());
+    let delegating_queue_uref = storage::new_uref(DelegationQueue::default());
+    let undelegating_queue_uref = storage::new_uref(DelegationQueue::default());
+    let era_rewards_uref = storage::new_uref(Option::<EraRewards>::None);
+    let jail_uref = storage::new_uref(Jail::default());
+
+    // Include PoS purses, the mint authority, the staking state and the parameters in its
+    // named_keys
+    let named_keys: BTreeMap<String, Key> = [
         (POS_BONDING_PURSE, bonding_purse),
         (POS_PAYMENT_PURSE, payment_purse),
         (POS_REWARDS_PURSE, rewards_purse),
+        (POS_TREASURY_PURSE, treasury_purse),
+        (POS_MINT_AUTHORITY, mint_authority.unwrap_or_revert()),
+        (POS_STAKES, stakes_uref),
+        (POS_BONDING_QUEUE, bonding_queue_uref),
+        (POS_UNBONDING_QUEUE, unbonding_queue_uref),
+        (POS_PARAMS, params_uref),
+        (POS_DELEGATIONS, delegations_uref),
+        (POS_DELEGATING_QUEUE, delegating_queue_uref),
+        (POS_UNDELEGATING_QUEUE, undelegating_queue_uref),
+        (POS_ERA_REWARDS, era_rewards_uref),
+        (POS_JAIL, jail_uref),
     ]
     .iter()
-    .for_each(|(name, uref)| {
-        named_keys.insert(String::from(*name), Key::URef(*uref));
-    });
+    .map(|(name, uref)| (String::from(*name), Key::URef(*uref)))
+    .collect();
 
     let uref: URef = storage::store_function(POS_FUNCTION_NAME, named_keys)
         .into_uref()
@@ -2,35 +2,97 @@
 
 extern crate alloc;
 
-use alloc::{
-    collections::{BTreeMap, BTreeSet},
-    string::String,
-};
+use alloc::{collections::BTreeMap, string::String};
 
 use contract::{
     contract_api::{runtime, storage, system},
     unwrap_or_revert::UnwrapOrRevert,
 };
 use proof_of_stake::{
-    MintProvider, ProofOfStake, Queue, QueueProvider, RuntimeProvider, Stakes, StakesProvider,
+    DelegationQueue, Delegations, DelegationsProvider, EraRewards, Jail, JailProvider,
+    MintProvider, ParamsProvider, PosParams, ProofOfStake, Queue, QueueProvider, RewardsProvider,
+    RuntimeProvider, Slash, Stakes, StakesProvider,
 };
 use types::{
-    account::PublicKey, system_contract_errors::pos::Error, ApiError, BlockTime, CLValue, Key,
-    Phase, TransferResult, URef, U512,
+    account::PublicKey,
+    bytesrepr::{FromBytes, ToBytes},
+    system_contract_errors::{mint, pos::Error},
+    ApiError, BlockTime, CLTyped, CLValue, Key, Phase, TransferResult, URef, U512,
 };
 
 const METHOD_BOND: &str = "bond";
 const METHOD_UNBOND: &str = "unbond";
+const METHOD_DELEGATE: &str = "delegate";
+const METHOD_UNDELEGATE: &str = "undelegate";
 const METHOD_GET_PAYMENT_PURSE: &str = "get_payment_purse";
 const METHOD_SET_REFUND_PURSE: &str = "set_refund_purse";
 const METHOD_GET_REFUND_PURSE: &str = "get_refund_purse";
 const METHOD_FINALIZE_PAYMENT: &str = "finalize_payment";
+const METHOD_DISTRIBUTE_REWARDS: &str = "distribute_rewards";
+const METHOD_GET_ERA_REWARDS: &str = "get_era_rewards";
+const METHOD_GET_PARAMS: &str = "get_params";
+const METHOD_SLASH: &str = "slash";
+const METHOD_STEP: &str = "step";
+
+const MINT_METHOD_TOTAL_SUPPLY: &str = "total_supply";
+const MINT_METHOD_MINT_TOKENS: &str = "mint_tokens";
 
-const BONDING_KEY: u8 = 1;
-const UNBONDING_KEY: u8 = 2;
+const STAKES_KEY: &str = "pos_stakes";
+const BONDING_QUEUE_KEY: &str = "pos_bonding_queue";
+const UNBONDING_QUEUE_KEY: &str = "pos_unbonding_queue";
+const PARAMS_KEY: &str = "pos_params";
+const DELEGATIONS_KEY: &str = "pos_delegations";
+const DELEGATING_QUEUE_KEY: &str = "pos_delegating_queue";
+const UNDELEGATING_QUEUE_KEY: &str = "pos_undelegating_queue";
+const ERA_REWARDS_KEY: &str = "pos_era_rewards";
+const JAIL_KEY: &str = "pos_jail";
 
 pub struct ProofOfStakeContract;
 
+impl DelegationsProvider for ProofOfStakeContract {
+    /// Reads the delegated stakes from the contract's delegations uref.
+    fn read_delegations(&mut self) -> Result<Delegations, Error> {
+        Ok(read_value(DELEGATIONS_KEY)?.unwrap_or_default())
+    }
+
+    /// Writes the delegated stakes to the contract's delegations uref.
+    fn write_delegations(&mut self, delegations: Delegations) -> Result<(), Error> {
+        write_value(DELEGATIONS_KEY, delegations)
+    }
+
+    /// Reads delegation queue from the contract's delegation queue uref.
+    fn read_delegating(&mut self) -> Result<DelegationQueue, Error> {
+        Ok(read_value(DELEGATING_QUEUE_KEY)?.unwrap_or_default())
+    }
+
+    /// Reads undelegation queue from the contract's undelegation queue uref.
+    fn read_undelegating(&mut self) -> Result<DelegationQueue, Error> {
+        Ok(read_value(UNDELEGATING_QUEUE_KEY)?.unwrap_or_default())
+    }
+
+    /// Writes delegation queue to the contract's delegation queue uref.
+    fn write_delegating(&mut self, queue: DelegationQueue) -> Result<(), Error> {
+        write_value(DELEGATING_QUEUE_KEY, queue)
+    }
+
+    /// Writes undelegation queue to the contract's undelegation queue uref.
+    fn write_undelegating(&mut self, queue: DelegationQueue) -> Result<(), Error> {
+        write_value(UNDELEGATING_QUEUE_KEY, queue)
+    }
+}
+
+impl JailProvider for ProofOfStakeContract {
+    /// Reads the jailed validators from the contract's jail uref.
+    fn read_jail(&mut self) -> Result<Jail, Error> {
+        Ok(read_value(JAIL_KEY)?.unwrap_or_default())
+    }
+
+    /// Writes the jailed validators to the contract's jail uref.
+    fn write_jail(&mut self, jail: Jail) -> Result<(), Error> {
+        write_value(JAIL_KEY, jail)
+    }
+}
+
 impl MintProvider for ProofOfStakeContract {
     fn transfer_purse_to_account(
         &mut self,
@@ -53,31 +115,80 @@ impl MintProvider for ProofOfStakeContract {
     fn balance(&mut self, purse: URef) -> Option<U512> {
         system::get_balance(purse)
     }
+
+    fn total_supply(&mut self) -> U512 {
+        runtime::call_contract(system::get_mint(), (MINT_METHOD_TOTAL_SUPPLY,))
+    }
+
+    fn mint_tokens(&mut self, authority: URef, purse: URef, amount: U512) -> Result<(), ()> {
+        let result: Result<(), mint::Error> = runtime::call_contract(
+            system::get_mint(),
+            (MINT_METHOD_MINT_TOKENS, authority, purse, amount),
+        );
+        result.map_err(|_| ())
+    }
+}
+
+/// Returns the `URef` stored under the given name in the contract's named keys.
+fn get_uref(name: &str) -> Result<URef, Error> {
+    match runtime::get_key(name) {
+        Some(Key::URef(uref)) => Ok(uref),
+        Some(_) => Err(Error::StateKeyUnexpectedType),
+        None => Err(Error::StateKeyNotFound),
+    }
+}
+
+/// Reads the value stored under the `URef` with the given name, or `None` if nothing has been
+/// written there yet.
+fn read_value<T: CLTyped + FromBytes>(name: &str) -> Result<Option<T>, Error> {
+    storage::read(get_uref(name)?).map_err(|_| Error::StateSerializationFailed)
+}
+
+/// Writes `value` under the `URef` with the given name.
+fn write_value<T: CLTyped + ToBytes>(name: &str, value: T) -> Result<(), Error> {
+    storage::write(get_uref(name)?, value);
+    Ok(())
+}
+
+impl ParamsProvider for ProofOfStakeContract {
+    /// Reads the parameters from the contract's params uref, falling back to the defaults if they
+    /// have never been set.
+    fn read_params(&mut self) -> Result<PosParams, Error> {
+        Ok(read_value(PARAMS_KEY)?.unwrap_or_default())
+    }
 }
 
 impl QueueProvider for ProofOfStakeContract {
-    /// Reads bonding queue from the local state of the contract.
-    fn read_bonding(&mut self) -> Queue {
-        storage::read_local(&BONDING_KEY)
-            .unwrap_or_default()
-            .unwrap_or_default()
+    /// Reads bonding queue from the contract's bonding queue uref.
+    fn read_bonding(&mut self) -> Result<Queue, Error> {
+        Ok(read_value(BONDING_QUEUE_KEY)?.unwrap_or_default())
     }
 
-    /// Reads unbonding queue from the local state of the contract.
-    fn read_unbonding(&mut self) -> Queue {
-        storage::read_local(&UNBONDING_KEY)
-            .unwrap_or_default()
-            .unwrap_or_default()
+    /// Reads unbonding queue from the contract's unbonding queue uref.
+    fn read_unbonding(&mut self) -> Result<Queue, Error> {
+        Ok(read_value(UNBONDING_QUEUE_KEY)?.unwrap_or_default())
     }
 
-    /// Writes bonding queue to the local state of the contract.
-    fn write_bonding(&mut self, queue: Queue) {
-        storage::write_local(BONDING_KEY, queue);
+    /// Writes bonding queue to the contract's bonding queue uref.
+    fn write_bonding(&mut self, queue: Queue) -> Result<(), Error> {
+        write_value(BONDING_QUEUE_KEY, queue)
+    }
+
+    /// Writes unbonding queue to the contract's unbonding queue uref.
+    fn write_unbonding(&mut self, queue: Queue) -> Result<(), Error> {
+        write_value(UNBONDING_QUEUE_KEY, queue)
+    }
+}
+
+impl RewardsProvider for ProofOfStakeContract {
+    /// Reads the last era's rewards from the contract's era rewards uref.
+    fn read_era_rewards(&mut self) -> Result<Option<EraRewards>, Error> {
+        Ok(read_value(ERA_REWARDS_KEY)?.unwrap_or_default())
     }
 
-    /// Writes unbonding queue to the local state of the contract.
-    fn write_unbonding(&mut self, queue: Queue) {
-        storage::write_local(UNBONDING_KEY, queue);
+    /// Writes the last era's rewards to the contract's era rewards uref.
+    fn write_era_rewards(&mut self, era_rewards: EraRewards) -> Result<(), Error> {
+        write_value(ERA_REWARDS_KEY, Some(era_rewards))
     }
 }
 
@@ -108,51 +219,18 @@ impl RuntimeProvider for ProofOfStakeContract {
 }
 
 impl StakesProvider for ProofOfStakeContract {
-    /// Reads the current stakes from the contract's known urefs.
+    /// Reads the current stakes from the contract's stakes uref.
     fn read(&self) -> Result<Stakes, Error> {
-        let mut stakes = BTreeMap::new();
-        for (name, _) in runtime::list_named_keys() {
-            let mut split_name = name.split('_');
-            if Some("v") != split_name.next() {
-                continue;
-            }
-            let hex_key = split_name
-                .next()
-                .ok_or(Error::StakesKeyDeserializationFailed)?;
-            if hex_key.len() != 64 {
-                return Err(Error::StakesKeyDeserializationFailed);
-            }
-            let mut key_bytes = [0u8; 32];
-            let _bytes_written = base16::decode_slice(hex_key, &mut key_bytes)
-                .map_err(|_| Error::StakesKeyDeserializationFailed)?;
-            debug_assert!(_bytes_written == key_bytes.len());
-            let pub_key = PublicKey::ed25519_from(key_bytes);
-            let balance = split_name
-                .next()
-                .and_then(|b| U512::from_dec_str(b).ok())
-                .ok_or(Error::StakesDeserializationFailed)?;
-            stakes.insert(pub_key, balance);
-        }
-        if stakes.is_empty() {
-            return Err(Error::StakesNotFound);
-        }
-        Ok(Stakes(stakes))
-    }
-
-    /// Writes the current stakes to the contract's known urefs.
-    fn write(&mut self, stakes: &Stakes) {
-        // Encode the stakes as a set of uref names.
-        let mut new_urefs: BTreeSet<String> = stakes.strings().collect();
-        // Remove and add urefs to update the contract's known urefs accordingly.
-        for (name, _) in runtime::list_named_keys() {
-            if name.starts_with("v_") && !new_urefs.remove(&name) {
-                runtime::remove_key(&name);
-            }
-        }
-        for name in new_urefs {
-            runtime::put_key(&name, Key::Hash([0; 32]));
+        match read_value::<Stakes>(STAKES_KEY)? {
+            Some(stakes) if !stakes.0.is_empty() => Ok(stakes),
+            _ => Err(Error::StakesNotFound),
         }
     }
+
+    /// Writes the current stakes to the contract's stakes uref.
+    fn write(&mut self, stakes: &Stakes) -> Result<(), Error> {
+        write_value(STAKES_KEY, stakes.clone())
+    }
 }
 
 impl ProofOfStake for ProofOfStakeContract {}
@@ -188,6 +266,35 @@ pub fn delegate() {
                 .unbond(validator, maybe_amount)
                 .unwrap_or_revert();
         }
+        // Type of this method: `fn delegate(validator: PublicKey, amount: U512, purse: URef)`
+        METHOD_DELEGATE => {
+            let delegator = runtime::get_caller();
+            let validator: PublicKey = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let amount: U512 = runtime::get_arg(2)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let source_purse: URef = runtime::get_arg(3)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            pos_contract
+                .delegate(delegator, validator, amount, source_purse)
+                .unwrap_or_revert();
+        }
+        // Type of this method: `fn undelegate(validator: PublicKey, amount: Option<U512>)`
+        METHOD_UNDELEGATE => {
+            let delegator = runtime::get_caller();
+            let validator: PublicKey = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let maybe_amount = runtime::get_arg(2)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            pos_contract
+                .undelegate(delegator, validator, maybe_amount)
+                .unwrap_or_revert();
+        }
         // Type of this method: `fn get_payment_purse() -> URef`
         METHOD_GET_PAYMENT_PURSE => {
             let rights_controlled_purse = pos_contract.get_payment_purse().unwrap_or_revert();
@@ -224,6 +331,45 @@ pub fn delegate() {
                 .finalize_payment(amount_spent, account)
                 .unwrap_or_revert();
         }
+        // Type of this method:
+        // `fn distribute_rewards(era_id: u64, inflation_rate: u64, commission_rate: u64)`
+        METHOD_DISTRIBUTE_REWARDS => {
+            let era_id: u64 = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let inflation_rate: u64 = runtime::get_arg(2)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let commission_rate: u64 = runtime::get_arg(3)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            pos_contract
+                .distribute_rewards(era_id, inflation_rate, commission_rate)
+                .unwrap_or_revert();
+        }
+        // Type of this method: `fn get_era_rewards() -> Option<EraRewards>`
+        METHOD_GET_ERA_REWARDS => {
+            let maybe_era_rewards = pos_contract.get_era_rewards().unwrap_or_revert();
+            let return_value = CLValue::from_t(maybe_era_rewards).unwrap_or_revert();
+            runtime::ret(return_value);
+        }
+        // Type of this method: `fn get_params() -> PosParams`
+        METHOD_GET_PARAMS => {
+            let params = pos_contract.get_params().unwrap_or_revert();
+            let return_value = CLValue::from_t(params).unwrap_or_revert();
+            runtime::ret(return_value);
+        }
+        // Type of this method: `fn slash(slashes: BTreeMap<PublicKey, Slash>)`
+        METHOD_SLASH => {
+            let slashes: BTreeMap<PublicKey, Slash> = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            pos_contract.slash(slashes).unwrap_or_revert();
+        }
+        // Type of this method: `fn step()`
+        METHOD_STEP => {
+            pos_contract.step().unwrap_or_revert();
+        }
         _ => {}
     }
 }
@@ -0,0 +1,69 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{collections::BTreeMap, string::String};
+
+use contract::{
+    contract_api::{account, runtime},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{
+    account::{PublicKey, Weight},
+    ApiError,
+};
+
+const SET_RECOVERY: &str = "set_recovery";
+const REQUEST_RECOVERY: &str = "request_recovery";
+const CANCEL_RECOVERY: &str = "cancel_recovery";
+const RECOVER_ACCOUNT: &str = "recover_account";
+
+const RECOVERY_KEY_WEIGHT: u8 = 1;
+
+enum Args {
+    MethodName = 0,
+    Account = 1,
+    Delay = 2,
+    Threshold = 3,
+    OtherRecoveryKey = 4,
+}
+
+fn get_account() -> PublicKey {
+    runtime::get_arg(Args::Account as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument)
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let method_name: String = runtime::get_arg(Args::MethodName as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    let result = match method_name.as_str() {
+        SET_RECOVERY => {
+            let delay: u64 = runtime::get_arg(Args::Delay as u32)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let threshold: u8 = runtime::get_arg(Args::Threshold as u32)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let mut keys = BTreeMap::new();
+            keys.insert(get_account(), Weight::new(RECOVERY_KEY_WEIGHT));
+            if let Some(other_recovery_key) = runtime::get_arg(Args::OtherRecoveryKey as u32) {
+                let other_recovery_key: PublicKey =
+                    other_recovery_key.unwrap_or_revert_with(ApiError::InvalidArgument);
+                keys.insert(other_recovery_key, Weight::new(RECOVERY_KEY_WEIGHT));
+            }
+            account::set_recovery(keys, Weight::new(threshold), delay)
+        }
+        REQUEST_RECOVERY => account::request_recovery(get_account()),
+        CANCEL_RECOVERY => account::cancel_recovery(),
+        RECOVER_ACCOUNT => account::recover_account(get_account()),
+        _ => runtime::revert(ApiError::InvalidArgument),
+    };
+
+    if let Err(failure) = result {
+        runtime::revert(ApiError::User(failure as u16))
+    }
+}
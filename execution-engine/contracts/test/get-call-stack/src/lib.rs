@@ -0,0 +1,79 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{bytesrepr::FromBytes, ApiError, CLValue, Key};
+
+const GET_CALL_STACK_EXT: &str = "get_call_stack_ext";
+
+#[repr(u16)]
+enum Error {
+    UnexpectedSessionCallStack = 0,
+    UnexpectedImmediateCaller,
+}
+
+impl From<Error> for ApiError {
+    fn from(error: Error) -> Self {
+        ApiError::User(error as u16)
+    }
+}
+
+fn get_arg<T: FromBytes>(i: u32) -> T {
+    runtime::get_arg(i)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument)
+}
+
+fn call_self(self_key: Key, depth: u32) -> Vec<Key> {
+    let contract_ref = self_key
+        .to_contract_ref()
+        .unwrap_or_revert_with(ApiError::UnexpectedKeyVariant);
+    runtime::call_contract(contract_ref, (self_key, depth))
+}
+
+/// Calls itself a further `depth` times and returns the call stack seen by the innermost call.
+#[no_mangle]
+pub extern "C" fn get_call_stack_ext() {
+    let self_key: Key = get_arg(0);
+    let depth: u32 = get_arg(1);
+
+    let call_stack = runtime::get_call_stack();
+    let expected_caller = if call_stack.len() > 2 {
+        self_key
+    } else {
+        Key::Account(runtime::get_caller())
+    };
+    if runtime::get_immediate_caller() != expected_caller {
+        runtime::revert(Error::UnexpectedImmediateCaller);
+    }
+
+    let call_stack = if depth > 0 {
+        call_self(self_key, depth - 1)
+    } else {
+        call_stack
+    };
+    let return_value = CLValue::from_t(call_stack).unwrap_or_revert();
+    runtime::ret(return_value)
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let depth: u32 = get_arg(0);
+
+    let account_key = Key::Account(runtime::get_caller());
+    if runtime::get_call_stack() != [account_key] || runtime::get_immediate_caller() != account_key
+    {
+        runtime::revert(Error::UnexpectedSessionCallStack);
+    }
+
+    let contract_ref = storage::store_function_at_hash(GET_CALL_STACK_EXT, BTreeMap::new());
+    let self_key: Key = contract_ref.into();
+    let call_stack = call_self(self_key, depth);
+    runtime::put_key(GET_CALL_STACK_EXT, storage::new_uref(call_stack).into());
+}
@@ -0,0 +1,39 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::String;
+
+use contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use types::{ApiError, U512};
+
+const ARG_GREETING: &str = "greeting";
+const ARG_AMOUNT: &str = "amount";
+
+#[repr(u16)]
+enum Error {
+    MissingGreeting = 0,
+    MissingAmount,
+    InvalidGreeting,
+    InvalidAmount,
+    MissingPositionalGreeting,
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let greeting: String = runtime::get_named_arg(ARG_GREETING)
+        .unwrap_or_revert_with(ApiError::User(Error::MissingGreeting as u16))
+        .unwrap_or_revert_with(ApiError::User(Error::InvalidGreeting as u16));
+    assert_eq!(greeting, "Hello, world!");
+
+    let amount: U512 = runtime::get_named_arg(ARG_AMOUNT)
+        .unwrap_or_revert_with(ApiError::User(Error::MissingAmount as u16))
+        .unwrap_or_revert_with(ApiError::User(Error::InvalidAmount as u16));
+    assert_eq!(amount, U512::from(42));
+
+    // Named args are also available by position when no positional args are provided.
+    let positional_greeting: String = runtime::get_arg(0)
+        .unwrap_or_revert_with(ApiError::User(Error::MissingPositionalGreeting as u16))
+        .unwrap_or_revert_with(ApiError::User(Error::InvalidGreeting as u16));
+    assert_eq!(positional_greeting, greeting);
+}
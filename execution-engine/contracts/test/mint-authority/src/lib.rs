@@ -0,0 +1,47 @@
+#![no_std]
+
+use contract::{
+    contract_api::{runtime, storage, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{bytesrepr::FromBytes, system_contract_errors::mint, ApiError, URef, U512};
+
+const PURSE_KEY: &str = "purse";
+
+fn get_arg<T: FromBytes>(i: u32) -> T {
+    runtime::get_arg(i)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument)
+}
+
+/// Mints `mint_amount` tokens into a new purse and then burns `burn_amount` of them.
+///
+/// If `use_own_authority` is set, a URef created by this contract is passed to the mint in place
+/// of the mint authority.
+#[no_mangle]
+pub extern "C" fn call() {
+    let mint_amount: U512 = get_arg(0);
+    let burn_amount: U512 = get_arg(1);
+    let use_own_authority: bool = get_arg(2);
+
+    let mint = system::get_mint();
+    let authority: URef = if use_own_authority {
+        storage::new_uref(())
+    } else {
+        let result: Result<URef, mint::Error> =
+            runtime::call_contract(mint.clone(), ("authority",));
+        result.unwrap_or_revert()
+    };
+
+    let purse = system::create_purse();
+
+    let result: Result<(), mint::Error> =
+        runtime::call_contract(mint.clone(), ("mint_tokens", authority, purse, mint_amount));
+    result.unwrap_or_revert();
+
+    let result: Result<(), mint::Error> =
+        runtime::call_contract(mint, ("burn", authority, purse, burn_amount));
+    result.unwrap_or_revert();
+
+    runtime::put_key(PURSE_KEY, purse.into());
+}
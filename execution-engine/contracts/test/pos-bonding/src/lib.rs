@@ -24,13 +24,25 @@ fn unbond(pos: &ContractRef, amount: Option<U512>) {
     runtime::call_contract::<_, ()>(pos.clone(), (POS_UNBOND, amount));
 }
 
+fn delegate(pos: &ContractRef, validator: PublicKey, amount: &U512, source: URef) {
+    runtime::call_contract::<_, ()>(pos.clone(), (POS_DELEGATE, validator, *amount, source));
+}
+
+fn undelegate(pos: &ContractRef, validator: PublicKey, amount: Option<U512>) {
+    runtime::call_contract::<_, ()>(pos.clone(), (POS_UNDELEGATE, validator, amount));
+}
+
 const POS_BOND: &str = "bond";
 const POS_UNBOND: &str = "unbond";
+const POS_DELEGATE: &str = "delegate";
+const POS_UNDELEGATE: &str = "undelegate";
 
 const TEST_BOND: &str = "bond";
 const TEST_BOND_FROM_MAIN_PURSE: &str = "bond-from-main-purse";
 const TEST_SEED_NEW_ACCOUNT: &str = "seed_new_account";
 const TEST_UNBOND: &str = "unbond";
+const TEST_DELEGATE: &str = "delegate";
+const TEST_UNDELEGATE: &str = "undelegate";
 
 #[no_mangle]
 pub extern "C" fn call() {
@@ -71,6 +83,27 @@ pub extern "C" fn call() {
             .unwrap_or_revert_with(ApiError::MissingArgument)
             .unwrap_or_revert_with(ApiError::InvalidArgument);
         unbond(&pos_pointer, maybe_amount);
+    } else if command == TEST_DELEGATE {
+        let validator: PublicKey = runtime::get_arg(1)
+            .unwrap_or_revert_with(ApiError::MissingArgument)
+            .unwrap_or_revert_with(ApiError::InvalidArgument);
+        let amount: U512 = runtime::get_arg(2)
+            .unwrap_or_revert_with(ApiError::MissingArgument)
+            .unwrap_or_revert_with(ApiError::InvalidArgument);
+        let p1 = system::create_purse();
+
+        system::transfer_from_purse_to_purse(account::get_main_purse(), p1, amount)
+            .unwrap_or_revert();
+
+        delegate(&pos_pointer, validator, &amount, p1);
+    } else if command == TEST_UNDELEGATE {
+        let validator: PublicKey = runtime::get_arg(1)
+            .unwrap_or_revert_with(ApiError::MissingArgument)
+            .unwrap_or_revert_with(ApiError::InvalidArgument);
+        let maybe_amount: Option<U512> = runtime::get_arg(2)
+            .unwrap_or_revert_with(ApiError::MissingArgument)
+            .unwrap_or_revert_with(ApiError::InvalidArgument);
+        undelegate(&pos_pointer, validator, maybe_amount);
     } else {
         runtime::revert(ApiError::User(Error::UnknownCommand as u16));
     }
@@ -0,0 +1,15 @@
+#![no_std]
+
+use contract::contract_api::{runtime, storage, system};
+use proof_of_stake::PosParams;
+
+const POS_GET_PARAMS: &str = "get_params";
+const POS_PARAMS_KEY: &str = "pos_params";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let pos_pointer = system::get_proof_of_stake();
+    let pos_params: PosParams = runtime::call_contract(pos_pointer, (POS_GET_PARAMS,));
+    let uref = storage::new_uref(pos_params);
+    runtime::put_key(POS_PARAMS_KEY, uref.into());
+}
@@ -0,0 +1,48 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::collections::BTreeMap;
+
+use contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{bytesrepr::FromBytes, ApiError, Key};
+
+const RECURSE_EXT: &str = "recurse_ext";
+
+fn get_arg<T: FromBytes>(i: u32) -> T {
+    runtime::get_arg(i)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument)
+}
+
+/// Calls itself a further `depth` times, first declaring itself non-reentrant if requested.
+#[no_mangle]
+pub extern "C" fn recurse_ext() {
+    let self_key: Key = get_arg(0);
+    let depth: u32 = get_arg(1);
+    let non_reentrant: bool = get_arg(2);
+
+    if non_reentrant {
+        runtime::set_non_reentrant();
+    }
+
+    if depth > 0 {
+        let contract_ref = self_key
+            .to_contract_ref()
+            .unwrap_or_revert_with(ApiError::UnexpectedKeyVariant);
+        runtime::call_contract::<_, ()>(contract_ref, (self_key, depth - 1, non_reentrant));
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let depth: u32 = get_arg(0);
+    let non_reentrant: bool = get_arg(1);
+
+    let contract_ref = storage::store_function_at_hash(RECURSE_EXT, BTreeMap::new());
+    let self_key: Key = contract_ref.clone().into();
+    runtime::call_contract::<_, ()>(contract_ref, (self_key, depth, non_reentrant));
+}
@@ -0,0 +1,119 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{collections::BTreeMap, string::String};
+
+use contract::{
+    contract_api::{account, runtime, storage, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{bytesrepr::FromBytes, ApiError, BlockTime, ContractRef, Key, URef, U512};
+
+const INSTALL: &str = "install";
+const SCHEDULE: &str = "schedule";
+const SCHEDULE_FROM_CONTRACT: &str = "schedule_from_contract";
+const PUT_BLOCKTIME: &str = "put_blocktime";
+const FAIL: &str = "fail";
+const SCHEDULER: &str = "scheduler";
+const SCHEDULED_CONTRACT_KEY: &str = "scheduled_contract";
+const SCHEDULER_CONTRACT_KEY: &str = "scheduler_contract";
+const FAILING_CONTRACT_KEY: &str = "failing_contract";
+const SCHEDULED_CALL_RAN_KEY: &str = "scheduled_call_ran";
+
+enum Args {
+    MethodName = 0,
+    Contract = 1,
+    Due = 2,
+    Amount = 3,
+}
+
+enum SchedulerArgs {
+    Contract = 0,
+    Due = 1,
+    Purse = 2,
+    Amount = 3,
+}
+
+fn get_arg<T: FromBytes>(i: u32) -> T {
+    runtime::get_arg(i)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument)
+}
+
+#[no_mangle]
+pub extern "C" fn put_blocktime() {
+    let blocktime: u64 = runtime::get_blocktime().into();
+    let blocktime_uref = storage::new_uref(blocktime);
+    runtime::put_key(SCHEDULED_CALL_RAN_KEY, blocktime_uref.into());
+}
+
+#[no_mangle]
+pub extern "C" fn fail() {
+    runtime::revert(ApiError::User(0))
+}
+
+/// Schedules a call to `contract`, paid from `purse`, when called with arguments.  Otherwise, as
+/// when its own scheduled call runs, puts the block time under its named keys.
+#[no_mangle]
+pub extern "C" fn scheduler() {
+    let contract: Key = match runtime::get_arg(SchedulerArgs::Contract as u32) {
+        Some(contract) => contract.unwrap_or_revert_with(ApiError::InvalidArgument),
+        None => return put_blocktime(),
+    };
+    let contract: ContractRef = contract
+        .to_contract_ref()
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    let due: u64 = get_arg(SchedulerArgs::Due as u32);
+    let purse: URef = get_arg(SchedulerArgs::Purse as u32);
+    let amount: U512 = get_arg(SchedulerArgs::Amount as u32);
+    runtime::schedule_call(contract, (), BlockTime::new(due), purse, amount).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let method_name: String = runtime::get_arg(Args::MethodName as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    match method_name.as_str() {
+        INSTALL => {
+            let contract = storage::store_function_at_hash(PUT_BLOCKTIME, BTreeMap::new());
+            runtime::put_key(SCHEDULED_CONTRACT_KEY, contract.into());
+            let scheduler = storage::store_function_at_hash(SCHEDULER, BTreeMap::new());
+            runtime::put_key(SCHEDULER_CONTRACT_KEY, scheduler.into());
+            let failing = storage::store_function_at_hash(FAIL, BTreeMap::new());
+            runtime::put_key(FAILING_CONTRACT_KEY, failing.into());
+        }
+        SCHEDULE => {
+            let contract: Key = get_arg(Args::Contract as u32);
+            let contract: ContractRef = contract
+                .to_contract_ref()
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let due: u64 = get_arg(Args::Due as u32);
+            let amount: U512 = get_arg(Args::Amount as u32);
+            runtime::schedule_call(
+                contract,
+                (),
+                BlockTime::new(due),
+                account::get_main_purse(),
+                amount,
+            )
+            .unwrap_or_revert();
+        }
+        SCHEDULE_FROM_CONTRACT => {
+            let contract: Key = get_arg(Args::Contract as u32);
+            let due: u64 = get_arg(Args::Due as u32);
+            let amount: U512 = get_arg(Args::Amount as u32);
+            let scheduler = runtime::get_key(SCHEDULER_CONTRACT_KEY)
+                .unwrap_or_revert_with(ApiError::GetKey)
+                .to_contract_ref()
+                .unwrap_or_revert_with(ApiError::UnexpectedKeyVariant);
+            let purse = system::create_purse();
+            system::transfer_from_purse_to_purse(account::get_main_purse(), purse, amount)
+                .unwrap_or_revert();
+            runtime::call_contract::<_, ()>(scheduler, (contract, due, purse, amount));
+        }
+        _ => runtime::revert(ApiError::InvalidArgument),
+    }
+}
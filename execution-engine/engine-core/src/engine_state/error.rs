@@ -1,9 +1,9 @@
 use failure::Fail;
 
 use engine_shared::newtypes::Blake2bHash;
-use types::{bytesrepr, system_contract_errors::mint};
+use types::{account::PublicKey, bytesrepr, system_contract_errors::mint};
 
-use crate::execution;
+use crate::{engine_state::migration::MigrationError, execution};
 use types::ProtocolVersion;
 
 #[derive(Fail, Debug)]
@@ -38,6 +38,14 @@ pub enum Error {
     Serialization(bytesrepr::Error),
     #[fail(display = "Mint error: {}", _0)]
     Mint(mint::Error),
+    #[fail(display = "Genesis account can't meet its action thresholds: {}", _0)]
+    InvalidGenesisAccountKeys(PublicKey),
+    #[fail(display = "Invalid migration: {}", _0)]
+    InvalidMigration(MigrationError),
+    #[fail(display = "Protocol version {} isn't active at this block", _0)]
+    InactiveProtocolVersion(ProtocolVersion),
+    #[fail(display = "Block rank is required to check an activation point by rank")]
+    MissingRank,
 }
 
 impl From<engine_wasm_prep::PreprocessingError> for Error {
@@ -70,6 +78,12 @@ impl From<bytesrepr::Error> for Error {
     }
 }
 
+impl From<MigrationError> for Error {
+    fn from(error: MigrationError) -> Self {
+        Error::InvalidMigration(error)
+    }
+}
+
 impl From<mint::Error> for Error {
     fn from(error: mint::Error) -> Self {
         Error::Mint(error)
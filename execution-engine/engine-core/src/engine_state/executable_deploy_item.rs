@@ -1,30 +1,60 @@
+use types::NamedArg;
+
+/// Code to be executed for a deploy, along with its arguments.
+///
+/// `args` holds the ABI-encoded positional arguments, while `named_args` holds the arguments
+/// identified by name.  A deploy may provide either form or both; where only named arguments are
+/// given, their values are also made available by position in the order provided.
 #[derive(Clone, PartialEq, Eq)]
 pub enum ExecutableDeployItem {
     ModuleBytes {
         module_bytes: Vec<u8>,
         args: Vec<u8>,
+        named_args: Vec<NamedArg>,
     },
     StoredContractByHash {
         hash: Vec<u8>,
         args: Vec<u8>,
+        named_args: Vec<NamedArg>,
     },
     StoredContractByName {
         name: String,
         args: Vec<u8>,
+        named_args: Vec<NamedArg>,
     },
     StoredContractByURef {
         uref: Vec<u8>,
         args: Vec<u8>,
+        named_args: Vec<NamedArg>,
     },
 }
 
 impl ExecutableDeployItem {
-    pub fn take_args(self) -> Vec<u8> {
+    /// Consumes `self`, returning the positional and named arguments.
+    pub fn take_args(self) -> (Vec<u8>, Vec<NamedArg>) {
+        match self {
+            ExecutableDeployItem::ModuleBytes {
+                args, named_args, ..
+            }
+            | ExecutableDeployItem::StoredContractByHash {
+                args, named_args, ..
+            }
+            | ExecutableDeployItem::StoredContractByName {
+                args, named_args, ..
+            }
+            | ExecutableDeployItem::StoredContractByURef {
+                args, named_args, ..
+            } => (args, named_args),
+        }
+    }
+
+    /// Returns a mutable reference to the named arguments.
+    pub fn named_args_mut(&mut self) -> &mut Vec<NamedArg> {
         match self {
-            ExecutableDeployItem::ModuleBytes { args, .. } => args,
-            ExecutableDeployItem::StoredContractByHash { args, .. } => args,
-            ExecutableDeployItem::StoredContractByName { args, .. } => args,
-            ExecutableDeployItem::StoredContractByURef { args, .. } => args,
+            ExecutableDeployItem::ModuleBytes { named_args, .. }
+            | ExecutableDeployItem::StoredContractByHash { named_args, .. }
+            | ExecutableDeployItem::StoredContractByName { named_args, .. }
+            | ExecutableDeployItem::StoredContractByURef { named_args, .. } => named_args,
         }
     }
 }
@@ -8,22 +8,33 @@ use super::{deploy_item::DeployItem, execution_result::ExecutionResult};
 pub struct ExecuteRequest {
     pub parent_state_hash: Blake2bHash,
     pub block_time: u64,
+    /// The rank, i.e. height, of the block being executed, if sent.  It is required once a
+    /// protocol version activates by rank.
+    pub rank: Option<u64>,
     pub deploys: Vec<Result<DeployItem, ExecutionResult>>,
+    /// Must be the version active at this block, or the default to let the engine pick it.
     pub protocol_version: ProtocolVersion,
+    /// Whether to run the scheduled calls which are due, ahead of the deploys.  Each block should
+    /// run them in a single request, so that none of them runs twice.
+    pub run_scheduled_calls: bool,
 }
 
 impl ExecuteRequest {
     pub fn new(
         parent_state_hash: Blake2bHash,
         block_time: u64,
+        rank: Option<u64>,
         deploys: Vec<Result<DeployItem, ExecutionResult>>,
         protocol_version: ProtocolVersion,
+        run_scheduled_calls: bool,
     ) -> Self {
         Self {
             parent_state_hash,
             block_time,
+            rank,
             deploys,
             protocol_version,
+            run_scheduled_calls,
         }
     }
 
@@ -37,8 +48,20 @@ impl Default for ExecuteRequest {
         Self {
             parent_state_hash: [0u8; 32].into(),
             block_time: 0,
+            rank: None,
             deploys: vec![],
             protocol_version: Default::default(),
+            run_scheduled_calls: false,
         }
     }
 }
+
+/// The results of an [`ExecuteRequest`], each holding only the effects of its own call or deploy.
+///
+/// If requested, the scheduled calls run in order on top of the request's parent state, and the
+/// deploys run on top of all of them.  Committing the results therefore takes the effects of the
+/// scheduled calls in order, followed by those of the deploys.
+pub struct ExecuteResults {
+    pub scheduled_call_results: Vec<ExecutionResult>,
+    pub deploy_results: Vec<ExecutionResult>,
+}
@@ -6,17 +6,37 @@ use rand::{
     Rng,
 };
 
-use engine_shared::{motes::Motes, newtypes::Blake2bHash, TypeMismatch};
+use engine_shared::{
+    account::{ActionThresholds, AssociatedKeys},
+    motes::Motes,
+    newtypes::Blake2bHash,
+    TypeMismatch,
+};
 use engine_storage::global_state::CommitResult;
 use engine_wasm_prep::wasm_costs::WasmCosts;
-use types::{account::PublicKey, bytesrepr, Key, ProtocolVersion, U512};
+use proof_of_stake::PosParams;
+use types::{
+    account::{PublicKey, Weight},
+    bytesrepr, Key, ProtocolVersion, U512,
+};
 
 use crate::engine_state::execution_effect::ExecutionEffect;
 
-pub const PLACEHOLDER_KEY: Key = Key::Hash([0u8; 32]);
 pub const POS_BONDING_PURSE: &str = "pos_bonding_purse";
 pub const POS_PAYMENT_PURSE: &str = "pos_payment_purse";
+pub const POS_REFUND_PURSE: &str = "pos_refund_purse";
 pub const POS_REWARDS_PURSE: &str = "pos_rewards_purse";
+pub const POS_TREASURY_PURSE: &str = "pos_treasury_purse";
+pub const POS_MINT_AUTHORITY: &str = "mint_authority";
+pub const POS_STAKES: &str = "pos_stakes";
+pub const POS_BONDING_QUEUE: &str = "pos_bonding_queue";
+pub const POS_UNBONDING_QUEUE: &str = "pos_unbonding_queue";
+pub const POS_PARAMS: &str = "pos_params";
+pub const POS_DELEGATIONS: &str = "pos_delegations";
+pub const POS_DELEGATING_QUEUE: &str = "pos_delegating_queue";
+pub const POS_UNDELEGATING_QUEUE: &str = "pos_undelegating_queue";
+pub const POS_ERA_REWARDS: &str = "pos_era_rewards";
+pub const POS_JAIL: &str = "pos_jail";
 
 pub enum GenesisResult {
     RootNotFound,
@@ -61,22 +81,39 @@ impl GenesisResult {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GenesisAccount {
     public_key: PublicKey,
     balance: Motes,
     bonded_amount: Motes,
+    associated_keys: AssociatedKeys,
+    action_thresholds: ActionThresholds,
 }
 
 impl GenesisAccount {
+    /// Creates a genesis account controlled by its own key alone, as created by
+    /// [`Account::create`](engine_shared::account::Account::create).
     pub fn new(public_key: PublicKey, balance: Motes, bonded_amount: Motes) -> Self {
         GenesisAccount {
             public_key,
             balance,
             bonded_amount,
+            associated_keys: AssociatedKeys::new(public_key, Weight::new(1)),
+            action_thresholds: ActionThresholds::default(),
         }
     }
 
+    /// Returns `self` with the given keys and thresholds in place of the default ones.
+    pub fn with_associated_keys(
+        mut self,
+        associated_keys: AssociatedKeys,
+        action_thresholds: ActionThresholds,
+    ) -> Self {
+        self.associated_keys = associated_keys;
+        self.action_thresholds = action_thresholds;
+        self
+    }
+
     pub fn public_key(&self) -> PublicKey {
         self.public_key
     }
@@ -88,6 +125,14 @@ impl GenesisAccount {
     pub fn bonded_amount(&self) -> Motes {
         self.bonded_amount
     }
+
+    pub fn associated_keys(&self) -> &AssociatedKeys {
+        &self.associated_keys
+    }
+
+    pub fn action_thresholds(&self) -> &ActionThresholds {
+        &self.action_thresholds
+    }
 }
 
 impl Distribution<GenesisAccount> for Standard {
@@ -101,10 +146,61 @@ impl Distribution<GenesisAccount> for Standard {
         rng.fill_bytes(u512_array.as_mut());
         let bonded_amount = Motes::new(U512::from(u512_array.as_ref()));
 
+        let mut associated_keys = AssociatedKeys::new(public_key, Weight::new(rng.gen()));
+        let other_key = PublicKey::ed25519_from(rng.gen());
+        let _ = associated_keys.add_key(other_key, Weight::new(rng.gen()));
+        let (weight_1, weight_2): (u8, u8) = (rng.gen(), rng.gen());
+        let action_thresholds = ActionThresholds::new(
+            Weight::new(weight_1.min(weight_2)),
+            Weight::new(weight_1.max(weight_2)),
+        )
+        .expect("should create thresholds");
+
         GenesisAccount {
             public_key,
             balance,
             bonded_amount,
+            associated_keys,
+            action_thresholds,
+        }
+    }
+}
+
+/// A contract installer run as the system account at genesis, after all genesis accounts have
+/// been created.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GenesisInstaller {
+    installer_bytes: Vec<u8>,
+    args: Vec<u8>,
+}
+
+impl GenesisInstaller {
+    pub fn new(installer_bytes: Vec<u8>, args: Vec<u8>) -> Self {
+        GenesisInstaller {
+            installer_bytes,
+            args,
+        }
+    }
+
+    pub fn installer_bytes(&self) -> &[u8] {
+        self.installer_bytes.as_slice()
+    }
+
+    /// The serialized positional arguments passed to the installer.
+    pub fn args(&self) -> &[u8] {
+        self.args.as_slice()
+    }
+}
+
+impl Distribution<GenesisInstaller> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> GenesisInstaller {
+        let mut count = rng.gen_range(1000, 10_000);
+        let installer_bytes = iter::repeat(()).map(|_| rng.gen()).take(count).collect();
+        count = rng.gen_range(0, 100);
+        let args = iter::repeat(()).map(|_| rng.gen()).take(count).collect();
+        GenesisInstaller {
+            installer_bytes,
+            args,
         }
     }
 }
@@ -118,9 +214,13 @@ pub struct GenesisConfig {
     proof_of_stake_installer_bytes: Vec<u8>,
     accounts: Vec<GenesisAccount>,
     wasm_costs: WasmCosts,
+    max_call_depth: u32,
+    pos_params: PosParams,
+    installers: Vec<GenesisInstaller>,
 }
 
 impl GenesisConfig {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         name: String,
         timestamp: u64,
@@ -129,6 +229,8 @@ impl GenesisConfig {
         proof_of_stake_installer_bytes: Vec<u8>,
         accounts: Vec<GenesisAccount>,
         wasm_costs: WasmCosts,
+        max_call_depth: u32,
+        pos_params: PosParams,
     ) -> Self {
         GenesisConfig {
             name,
@@ -138,6 +240,9 @@ impl GenesisConfig {
             proof_of_stake_installer_bytes,
             accounts,
             wasm_costs,
+            max_call_depth,
+            pos_params,
+            installers: Vec::new(),
         }
     }
 
@@ -165,6 +270,14 @@ impl GenesisConfig {
         self.wasm_costs
     }
 
+    pub fn max_call_depth(&self) -> u32 {
+        self.max_call_depth
+    }
+
+    pub fn pos_params(&self) -> PosParams {
+        self.pos_params
+    }
+
     pub fn get_bonded_validators(&self) -> impl Iterator<Item = (PublicKey, Motes)> + '_ {
         let zero = Motes::zero();
         self.accounts.iter().filter_map(move |genesis_account| {
@@ -186,6 +299,15 @@ impl GenesisConfig {
     pub fn push_account(&mut self, account: GenesisAccount) {
         self.accounts.push(account);
     }
+
+    /// The installers to run after the system contracts and genesis accounts are set up, in order.
+    pub fn installers(&self) -> &[GenesisInstaller] {
+        self.installers.as_slice()
+    }
+
+    pub fn push_installer(&mut self, installer: GenesisInstaller) {
+        self.installers.push(installer);
+    }
 }
 
 impl Distribution<GenesisConfig> for Standard {
@@ -223,6 +345,31 @@ impl Distribution<GenesisConfig> for Standard {
             opcodes_div: rng.gen(),
         };
 
+        let max_call_depth = rng.gen_range(1, 1000);
+
+        let mut u512_array = [0u8; 64];
+        let mut gen_u512 = || {
+            rng.fill_bytes(u512_array.as_mut());
+            U512::from(u512_array.as_ref())
+        };
+        let max_spread = gen_u512();
+        let max_increase = gen_u512();
+        let max_decrease = gen_u512();
+        let pos_params = PosParams::new(
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+            max_spread,
+            max_increase,
+            max_decrease,
+            rng.gen(),
+            rng.gen(),
+        );
+
+        count = rng.gen_range(0, 3);
+        let installers = iter::repeat(()).map(|_| rng.gen()).take(count).collect();
+
         GenesisConfig {
             name,
             timestamp,
@@ -231,6 +378,9 @@ impl Distribution<GenesisConfig> for Standard {
             proof_of_stake_installer_bytes,
             accounts,
             wasm_costs,
+            max_call_depth,
+            pos_params,
+            installers,
         }
     }
 }
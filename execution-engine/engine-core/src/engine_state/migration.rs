@@ -0,0 +1,473 @@
+use std::collections::BTreeMap;
+
+use failure::Fail;
+
+use engine_shared::{
+    account::{Account, ActionThresholds},
+    newtypes::CorrelationId,
+    stored_value::StoredValue,
+};
+use engine_storage::global_state::StateReader;
+use types::{
+    account::{ActionType, PublicKey, SetThresholdFailure},
+    AccessRights, CLType, CLValue, Key, URef,
+};
+
+use crate::{
+    engine_state::Error,
+    execution::{self, AddressGenerator},
+    tracking_copy::TrackingCopy,
+};
+
+/// A change to global state applied natively by the engine during an upgrade, as an alternative to
+/// running an upgrade installer for simple data fixes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Migration {
+    /// Overwrites the value under `key`.  The key must already hold a value of the same type.
+    WriteValue { key: Key, value: CLValue },
+    /// Moves the named key `from_name` of the account or contract under `from` to the account or
+    /// contract under `to`, where it is stored as `to_name`.
+    MoveNamedKey {
+        from: Key,
+        from_name: String,
+        to: Key,
+        to_name: String,
+    },
+    /// Replaces the action thresholds of an existing account.
+    SetActionThresholds {
+        account: PublicKey,
+        action_thresholds: ActionThresholds,
+    },
+    /// Stores `value` under a new `URef`, which is added as the named key `name` of the account or
+    /// contract under `key`, e.g. to seed the mint's `total_supply` on a chain which predates it.
+    PutNamedValue {
+        key: Key,
+        name: String,
+        value: CLValue,
+    },
+}
+
+#[derive(Fail, Debug, Clone, PartialEq, Eq)]
+pub enum MigrationError {
+    #[fail(display = "Key not found: {}", _0)]
+    KeyNotFound(Key),
+    #[fail(
+        display = "Value under {} has type {:?}, expected {:?}",
+        key, found, expected
+    )]
+    TypeMismatch {
+        key: Key,
+        expected: CLType,
+        found: CLType,
+    },
+    #[fail(display = "Key {} doesn't hold an account or a contract", _0)]
+    NoNamedKeys(Key),
+    #[fail(display = "Named key {} not found under {}", name, key)]
+    NamedKeyNotFound { key: Key, name: String },
+    #[fail(display = "Named key {} already exists under {}", name, key)]
+    NamedKeyExists { key: Key, name: String },
+    #[fail(display = "Account {} can't meet the new action thresholds", _0)]
+    InvalidActionThresholds(PublicKey),
+}
+
+impl Migration {
+    /// Validates the migration against the state seen by `tracking_copy` and applies it.  Returns
+    /// [`Error::InvalidMigration`] if the migration doesn't fit the current state, in which case
+    /// nothing has been written.
+    pub(crate) fn apply<R>(
+        &self,
+        correlation_id: CorrelationId,
+        tracking_copy: &mut TrackingCopy<R>,
+        address_generator: &mut AddressGenerator,
+    ) -> Result<(), Error>
+    where
+        R: StateReader<Key, StoredValue>,
+        R::Error: Into<execution::Error>,
+    {
+        match self {
+            Migration::WriteValue { key, value } => {
+                let found = match read(correlation_id, tracking_copy, key)? {
+                    StoredValue::CLValue(existing) => existing.cl_type().clone(),
+                    _ => return Err(MigrationError::KeyNotFound(*key).into()),
+                };
+                if found != *value.cl_type() {
+                    return Err(MigrationError::TypeMismatch {
+                        key: *key,
+                        expected: found,
+                        found: value.cl_type().clone(),
+                    }
+                    .into());
+                }
+                tracking_copy.write(*key, StoredValue::CLValue(value.clone()));
+            }
+            Migration::MoveNamedKey {
+                from,
+                from_name,
+                to,
+                to_name,
+            } => {
+                let mut source = read(correlation_id, tracking_copy, from)?;
+                let mut target = if from == to {
+                    None
+                } else {
+                    Some(read(correlation_id, tracking_copy, to)?)
+                };
+                let named_key = named_keys_mut(&mut source, from)?
+                    .remove(from_name)
+                    .ok_or_else(|| MigrationError::NamedKeyNotFound {
+                        key: *from,
+                        name: from_name.clone(),
+                    })?;
+                let target_named_keys = match target.as_mut() {
+                    Some(target) => named_keys_mut(target, to)?,
+                    None => named_keys_mut(&mut source, to)?,
+                };
+                if target_named_keys.contains_key(to_name) {
+                    return Err(MigrationError::NamedKeyExists {
+                        key: *to,
+                        name: to_name.clone(),
+                    }
+                    .into());
+                }
+                target_named_keys.insert(to_name.clone(), named_key);
+
+                tracking_copy.write(*from, source);
+                if let Some(target) = target {
+                    tracking_copy.write(*to, target);
+                }
+            }
+            Migration::SetActionThresholds {
+                account,
+                action_thresholds,
+            } => {
+                let key = Key::Account(*account);
+                let mut existing = match read(correlation_id, tracking_copy, &key)? {
+                    StoredValue::Account(existing) => existing,
+                    _ => return Err(MigrationError::KeyNotFound(key).into()),
+                };
+                set_action_thresholds(&mut existing, action_thresholds)
+                    .map_err(|_| MigrationError::InvalidActionThresholds(*account))?;
+                tracking_copy.write(key, StoredValue::Account(existing));
+            }
+            Migration::PutNamedValue { key, name, value } => {
+                let mut target = read(correlation_id, tracking_copy, key)?;
+                let named_keys = named_keys_mut(&mut target, key)?;
+                if named_keys.contains_key(name) {
+                    return Err(MigrationError::NamedKeyExists {
+                        key: *key,
+                        name: name.clone(),
+                    }
+                    .into());
+                }
+                let uref = URef::new(
+                    address_generator.create_address(),
+                    AccessRights::READ_ADD_WRITE,
+                );
+                named_keys.insert(name.clone(), Key::URef(uref));
+
+                tracking_copy.write(Key::URef(uref), StoredValue::CLValue(value.clone()));
+                tracking_copy.write(*key, target);
+            }
+        }
+        Ok(())
+    }
+}
+
+fn read<R>(
+    correlation_id: CorrelationId,
+    tracking_copy: &mut TrackingCopy<R>,
+    key: &Key,
+) -> Result<StoredValue, Error>
+where
+    R: StateReader<Key, StoredValue>,
+    R::Error: Into<execution::Error>,
+{
+    tracking_copy
+        .read(correlation_id, key)
+        .map_err(|error| Error::Exec(error.into()))?
+        .ok_or_else(|| MigrationError::KeyNotFound(*key).into())
+}
+
+fn named_keys_mut<'a>(
+    stored_value: &'a mut StoredValue,
+    key: &Key,
+) -> Result<&'a mut BTreeMap<String, Key>, MigrationError> {
+    match stored_value {
+        StoredValue::Account(account) => Ok(account.named_keys_mut()),
+        StoredValue::Contract(contract) => Ok(contract.named_keys_mut()),
+        _ => Err(MigrationError::NoNamedKeys(*key)),
+    }
+}
+
+/// Sets both thresholds of `account`, in whichever order keeps the deployment threshold at or below
+/// the key management threshold in between.
+fn set_action_thresholds(
+    account: &mut Account,
+    action_thresholds: &ActionThresholds,
+) -> Result<(), SetThresholdFailure> {
+    let deployment = *action_thresholds.deployment();
+    let key_management = *action_thresholds.key_management();
+    if deployment > *account.action_thresholds().key_management() {
+        account.set_action_threshold(ActionType::KeyManagement, key_management)?;
+        account.set_action_threshold(ActionType::Deployment, deployment)
+    } else {
+        account.set_action_threshold(ActionType::Deployment, deployment)?;
+        account.set_action_threshold(ActionType::KeyManagement, key_management)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use engine_shared::{account::AssociatedKeys, contract::Contract};
+    use engine_storage::global_state::{
+        in_memory::{InMemoryGlobalState, InMemoryGlobalStateView},
+        StateProvider,
+    };
+    use types::{
+        account::{PublicKey, Weight},
+        Phase, ProtocolVersion,
+    };
+
+    use super::*;
+
+    const ACCOUNT: PublicKey = PublicKey::ed25519_from([1; 32]);
+    const CONTRACT_KEY: Key = Key::Hash([2; 32]);
+    const VALUE_KEY: Key = Key::Hash([3; 32]);
+    const NAME: &str = "name";
+
+    fn account() -> Account {
+        let named_keys = vec![(NAME.to_string(), VALUE_KEY)].into_iter().collect();
+        let mut associated_keys = AssociatedKeys::new(ACCOUNT, Weight::new(1));
+        associated_keys
+            .add_key(PublicKey::ed25519_from([4; 32]), Weight::new(2))
+            .expect("should add key");
+        Account::new(
+            ACCOUNT,
+            named_keys,
+            URef::new([5; 32], AccessRights::READ_ADD_WRITE),
+            associated_keys,
+            ActionThresholds::default(),
+        )
+    }
+
+    fn apply(migration: Migration) -> Result<TrackingCopy<InMemoryGlobalStateView>, Error> {
+        let correlation_id = CorrelationId::new();
+        let contract = {
+            let named_keys = vec![(NAME.to_string(), Key::Hash([6; 32]))]
+                .into_iter()
+                .collect();
+            Contract::new(vec![], named_keys, ProtocolVersion::V1_0_0)
+        };
+        let (state, root_hash) = InMemoryGlobalState::from_pairs(
+            correlation_id,
+            &[
+                (Key::Account(ACCOUNT), StoredValue::Account(account())),
+                (CONTRACT_KEY, StoredValue::Contract(contract)),
+                (
+                    VALUE_KEY,
+                    StoredValue::CLValue(CLValue::from_t(1u64).unwrap()),
+                ),
+            ],
+        )
+        .expect("should create state");
+        let reader = state
+            .checkout(root_hash)
+            .expect("should checkout")
+            .expect("should have reader");
+        let mut tracking_copy = TrackingCopy::new(reader);
+        let mut address_generator = AddressGenerator::new(&[0; 32], Phase::System);
+        migration.apply(correlation_id, &mut tracking_copy, &mut address_generator)?;
+        Ok(tracking_copy)
+    }
+
+    fn expect_invalid(migration: Migration) -> MigrationError {
+        match apply(migration) {
+            Err(Error::InvalidMigration(error)) => error,
+            Err(error) => panic!("unexpected error: {}", error),
+            Ok(_) => panic!("migration should be invalid"),
+        }
+    }
+
+    fn read<R>(tracking_copy: &mut TrackingCopy<R>, key: Key) -> StoredValue
+    where
+        R: StateReader<Key, StoredValue>,
+        R::Error: Into<execution::Error>,
+    {
+        super::read(CorrelationId::new(), tracking_copy, &key).expect("should read")
+    }
+
+    #[test]
+    fn should_write_value_of_same_type() {
+        let value = CLValue::from_t(2u64).unwrap();
+        let mut tracking_copy = apply(Migration::WriteValue {
+            key: VALUE_KEY,
+            value: value.clone(),
+        })
+        .expect("should apply");
+        assert_eq!(
+            read(&mut tracking_copy, VALUE_KEY),
+            StoredValue::CLValue(value)
+        );
+    }
+
+    #[test]
+    fn should_not_write_value_of_other_type() {
+        let error = expect_invalid(Migration::WriteValue {
+            key: VALUE_KEY,
+            value: CLValue::from_t(2u32).unwrap(),
+        });
+        assert_eq!(
+            error,
+            MigrationError::TypeMismatch {
+                key: VALUE_KEY,
+                expected: CLType::U64,
+                found: CLType::U32,
+            }
+        );
+
+        let error = expect_invalid(Migration::WriteValue {
+            key: Key::Hash([9; 32]),
+            value: CLValue::from_t(2u64).unwrap(),
+        });
+        assert_eq!(error, MigrationError::KeyNotFound(Key::Hash([9; 32])));
+    }
+
+    #[test]
+    fn should_move_named_key() {
+        let mut tracking_copy = apply(Migration::MoveNamedKey {
+            from: Key::Account(ACCOUNT),
+            from_name: NAME.to_string(),
+            to: CONTRACT_KEY,
+            to_name: "moved".to_string(),
+        })
+        .expect("should apply");
+
+        match read(&mut tracking_copy, Key::Account(ACCOUNT)) {
+            StoredValue::Account(account) => assert!(account.named_keys().is_empty()),
+            other => panic!("unexpected value: {:?}", other),
+        }
+        match read(&mut tracking_copy, CONTRACT_KEY) {
+            StoredValue::Contract(contract) => {
+                assert_eq!(contract.named_keys().get("moved"), Some(&VALUE_KEY))
+            }
+            other => panic!("unexpected value: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn should_rename_named_key() {
+        let mut tracking_copy = apply(Migration::MoveNamedKey {
+            from: Key::Account(ACCOUNT),
+            from_name: NAME.to_string(),
+            to: Key::Account(ACCOUNT),
+            to_name: "renamed".to_string(),
+        })
+        .expect("should apply");
+
+        match read(&mut tracking_copy, Key::Account(ACCOUNT)) {
+            StoredValue::Account(account) => {
+                assert_eq!(account.named_keys().len(), 1);
+                assert_eq!(account.named_keys().get("renamed"), Some(&VALUE_KEY));
+            }
+            other => panic!("unexpected value: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn should_not_move_named_key_over_existing_one() {
+        let error = expect_invalid(Migration::MoveNamedKey {
+            from: Key::Account(ACCOUNT),
+            from_name: NAME.to_string(),
+            to: CONTRACT_KEY,
+            to_name: NAME.to_string(),
+        });
+        assert_eq!(
+            error,
+            MigrationError::NamedKeyExists {
+                key: CONTRACT_KEY,
+                name: NAME.to_string(),
+            }
+        );
+
+        let error = expect_invalid(Migration::MoveNamedKey {
+            from: Key::Account(ACCOUNT),
+            from_name: "missing".to_string(),
+            to: CONTRACT_KEY,
+            to_name: NAME.to_string(),
+        });
+        assert_eq!(
+            error,
+            MigrationError::NamedKeyNotFound {
+                key: Key::Account(ACCOUNT),
+                name: "missing".to_string(),
+            }
+        );
+
+        let error = expect_invalid(Migration::MoveNamedKey {
+            from: Key::Account(ACCOUNT),
+            from_name: NAME.to_string(),
+            to: VALUE_KEY,
+            to_name: NAME.to_string(),
+        });
+        assert_eq!(error, MigrationError::NoNamedKeys(VALUE_KEY));
+    }
+
+    #[test]
+    fn should_set_action_thresholds() {
+        let action_thresholds = ActionThresholds::new(Weight::new(2), Weight::new(3)).unwrap();
+        let mut tracking_copy = apply(Migration::SetActionThresholds {
+            account: ACCOUNT,
+            action_thresholds: action_thresholds.clone(),
+        })
+        .expect("should apply");
+
+        match read(&mut tracking_copy, Key::Account(ACCOUNT)) {
+            StoredValue::Account(account) => {
+                assert_eq!(*account.action_thresholds(), action_thresholds)
+            }
+            other => panic!("unexpected value: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn should_put_named_value() {
+        let value = CLValue::from_t(2u64).unwrap();
+        let mut tracking_copy = apply(Migration::PutNamedValue {
+            key: CONTRACT_KEY,
+            name: "seeded".to_string(),
+            value: value.clone(),
+        })
+        .expect("should apply");
+
+        let uref = match read(&mut tracking_copy, CONTRACT_KEY) {
+            StoredValue::Contract(contract) => contract.named_keys()["seeded"],
+            other => panic!("unexpected value: {:?}", other),
+        };
+        assert_eq!(read(&mut tracking_copy, uref), StoredValue::CLValue(value));
+    }
+
+    #[test]
+    fn should_not_put_named_value_over_existing_one() {
+        let error = expect_invalid(Migration::PutNamedValue {
+            key: CONTRACT_KEY,
+            name: NAME.to_string(),
+            value: CLValue::from_t(2u64).unwrap(),
+        });
+        assert_eq!(
+            error,
+            MigrationError::NamedKeyExists {
+                key: CONTRACT_KEY,
+                name: NAME.to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn should_not_set_action_thresholds_above_total_weight() {
+        let error = expect_invalid(Migration::SetActionThresholds {
+            account: ACCOUNT,
+            action_thresholds: ActionThresholds::new(Weight::new(1), Weight::new(4)).unwrap(),
+        });
+        assert_eq!(error, MigrationError::InvalidActionThresholds(ACCOUNT));
+    }
+}
@@ -6,15 +6,21 @@ pub mod execute_request;
 pub mod execution_effect;
 pub mod execution_result;
 pub mod genesis;
+pub mod migration;
 pub mod op;
 pub mod query;
+pub mod rewards;
+pub mod scheduled_call;
+pub mod slash;
 pub mod system_contract_cache;
+pub mod unbond_payout;
 pub mod upgrade;
 pub mod utils;
 
 use std::{
     cell::RefCell,
     collections::{BTreeMap, BTreeSet, HashMap},
+    iter,
     rc::Rc,
 };
 
@@ -34,14 +40,17 @@ use engine_shared::{
     wasm,
 };
 use engine_storage::{
-    global_state::{CommitResult, StateProvider, StateReader},
-    protocol_data::ProtocolData,
+    global_state::{CommitResult, StateProvider, StateReader, StorageStats},
+    protocol_data::{ActivationPoint, ProtocolData},
 };
 use engine_wasm_prep::{wasm_costs::WasmCosts, Preprocessor};
-use proof_of_stake::Stakes;
+use proof_of_stake::{DelegationQueue, Delegations, EraRewards, Jail, PosParams, Queue, Stakes};
 use types::{
-    account::PublicKey, bytesrepr::ToBytes, system_contract_errors::mint, AccessRights, BlockTime,
-    Key, Phase, ProtocolVersion, URef, KEY_HASH_LENGTH, U512, UREF_ADDR_LENGTH,
+    account::PublicKey,
+    bytesrepr::{self, ToBytes},
+    system_contract_errors::mint,
+    AccessRights, BlockTime, CLValue, Key, Phase, ProtocolVersion, URef, KEY_HASH_LENGTH, U512,
+    UREF_ADDR_LENGTH,
 };
 
 use self::{
@@ -49,9 +58,12 @@ use self::{
     executable_deploy_item::ExecutableDeployItem,
     execution_result::{ExecutionResult, ForcedTransferResult},
     genesis::{
-        GenesisAccount, GenesisConfig, GenesisResult, PLACEHOLDER_KEY, POS_PAYMENT_PURSE,
-        POS_REWARDS_PURSE,
+        GenesisAccount, GenesisConfig, GenesisResult, POS_BONDING_QUEUE, POS_DELEGATING_QUEUE,
+        POS_DELEGATIONS, POS_ERA_REWARDS, POS_JAIL, POS_MINT_AUTHORITY, POS_PARAMS,
+        POS_PAYMENT_PURSE, POS_REWARDS_PURSE, POS_STAKES, POS_TREASURY_PURSE, POS_UNBONDING_QUEUE,
+        POS_UNDELEGATING_QUEUE,
     },
+    scheduled_call::{CallContext, DueCall},
     system_contract_cache::SystemContractCache,
 };
 pub use self::{
@@ -61,9 +73,12 @@ pub use self::{
 use crate::{
     engine_state::{
         error::Error::MissingSystemContract,
-        execute_request::ExecuteRequest,
+        execute_request::{ExecuteRequest, ExecuteResults},
         genesis::POS_BONDING_PURSE,
         query::{QueryRequest, QueryResult},
+        rewards::DistributeRewardsRequest,
+        slash::SlashRequest,
+        unbond_payout::UnbondPayoutRequest,
         upgrade::{UpgradeConfig, UpgradeResult},
     },
     execution::{self, AddressGenerator, Executor, MINT_NAME, POS_NAME},
@@ -81,7 +96,7 @@ pub const SYSTEM_ACCOUNT_ADDR: PublicKey = PublicKey::ed25519_from([0u8; 32]);
 const GENESIS_INITIAL_BLOCKTIME: u64 = 0;
 const MINT_METHOD_NAME: &str = "mint";
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct EngineState<S> {
     config: EngineConfig,
     system_contract_cache: SystemContractCache,
@@ -127,11 +142,38 @@ where
         }
     }
 
+    /// Returns every known protocol version along with its [`WasmCosts`].
+    pub fn get_all_wasm_costs(&self) -> Result<Vec<(ProtocolVersion, WasmCosts)>, Error> {
+        match self.state.get_all_protocol_data() {
+            Ok(all_protocol_data) => Ok(all_protocol_data
+                .into_iter()
+                .map(|(protocol_version, protocol_data)| {
+                    (protocol_version, *protocol_data.wasm_costs())
+                })
+                .collect()),
+            Err(error) => Err(Error::Exec(error.into())),
+        }
+    }
+
+    pub fn storage_stats(&self) -> Result<Option<StorageStats>, Error> {
+        self.state
+            .storage_stats()
+            .map_err(|error| Error::Exec(error.into()))
+    }
+
     pub fn commit_genesis(
         &self,
         correlation_id: CorrelationId,
         genesis_config: GenesisConfig,
     ) -> Result<GenesisResult, Error> {
+        // Every genesis account has to be usable by its associated keys
+        if let Some(account) = genesis_config.accounts().iter().find(|account| {
+            account.associated_keys().total_keys_weight()
+                < *account.action_thresholds().key_management()
+        }) {
+            return Err(Error::InvalidGenesisAccountKeys(account.public_key()));
+        }
+
         // Preliminaries
         let executor = Executor::new(self.config);
         let blocktime = BlockTime::new(GENESIS_INITIAL_BLOCKTIME);
@@ -247,8 +289,8 @@ where
             }
         };
 
-        // Spec #7: Execute pos installer wasm code, passing the initially bonded validators as an
-        // argument
+        // Spec #7: Execute pos installer wasm code, passing the initially bonded validators and the
+        // proof-of-stake parameters as arguments
         let proof_of_stake_reference: URef = {
             let proof_of_stake_installer_bytes = genesis_config.proof_of_stake_installer_bytes();
 
@@ -258,6 +300,7 @@ where
                 .get_bonded_validators()
                 .map(|(k, v)| (k, v.value()))
                 .collect();
+            let pos_params = genesis_config.pos_params();
 
             let tracking_copy = Rc::clone(&tracking_copy);
             let address_generator = Rc::clone(&address_generator);
@@ -271,6 +314,7 @@ where
                 mint_reference,
                 // This is used as unknown key
                 URef::new([0; 32], AccessRights::READ),
+                genesis_config.max_call_depth(),
             );
 
             if self.config.turbo() && proof_of_stake_installer_bytes.is_empty() {
@@ -295,7 +339,7 @@ where
                     blocktime,
                     install_deploy_hash,
                     gas_limit,
-                    address_generator,
+                    Rc::clone(&address_generator),
                     protocol_version,
                     correlation_id,
                     Rc::clone(&tracking_copy),
@@ -332,25 +376,72 @@ where
                     .expect("should convert")
                     .expect("should convert");
                 let rewards_purse: URef = runtime
+                    .call_contract(mint_reference.into(), zero_args.clone())?
+                    .into_t::<Result<URef, mint::Error>>()
+                    .expect("should convert")
+                    .expect("should convert");
+                let treasury_purse: URef = runtime
                     .call_contract(mint_reference.into(), zero_args)?
                     .into_t::<Result<URef, mint::Error>>()
                     .expect("should convert")
                     .expect("should convert");
+                let authority_args = {
+                    let args = ("authority",);
+                    ArgsParser::parse(args)
+                        .expect("args should convert to `Vec<CLValue>`")
+                        .into_bytes()
+                        .expect("args should serialize")
+                };
+                let mint_authority: URef = runtime
+                    .call_contract(mint_reference.into(), authority_args)?
+                    .into_t::<Result<URef, mint::Error>>()
+                    .expect("should convert")
+                    .expect("should convert");
 
-                let named_keys = {
-                    let mut tmp: BTreeMap<String, Key> =
-                        stakes.strings().map(|key| (key, PLACEHOLDER_KEY)).collect();
-                    [
-                        (POS_BONDING_PURSE, bonding_purse),
-                        (POS_PAYMENT_PURSE, payment_purse),
-                        (POS_REWARDS_PURSE, rewards_purse),
-                    ]
-                    .iter()
-                    .for_each(|(name, uref)| {
-                        tmp.insert(String::from(*name), Key::URef(*uref));
-                    });
-                    tmp
+                // Stores the value under a new uref, as `storage::new_uref` would in the installer.
+                let new_uref = |value: CLValue| {
+                    let addr = address_generator.borrow_mut().create_address();
+                    let uref = URef::new(addr, AccessRights::READ_ADD_WRITE);
+                    tracking_copy
+                        .borrow_mut()
+                        .write(Key::URef(uref), StoredValue::CLValue(value));
+                    uref
                 };
+                let stakes_uref = new_uref(CLValue::from_t(stakes).expect("should convert"));
+                let bonding_queue_uref =
+                    new_uref(CLValue::from_t(Queue::default()).expect("should convert"));
+                let unbonding_queue_uref =
+                    new_uref(CLValue::from_t(Queue::default()).expect("should convert"));
+                let params_uref = new_uref(CLValue::from_t(pos_params).expect("should convert"));
+                let delegations_uref =
+                    new_uref(CLValue::from_t(Delegations::default()).expect("should convert"));
+                let delegating_queue_uref =
+                    new_uref(CLValue::from_t(DelegationQueue::default()).expect("should convert"));
+                let undelegating_queue_uref =
+                    new_uref(CLValue::from_t(DelegationQueue::default()).expect("should convert"));
+                let era_rewards_uref =
+                    new_uref(CLValue::from_t(Option::<EraRewards>::None).expect("should convert"));
+                let jail_uref = new_uref(CLValue::from_t(Jail::default()).expect("should convert"));
+
+                let named_keys: BTreeMap<String, Key> = [
+                    (POS_BONDING_PURSE, bonding_purse),
+                    (POS_PAYMENT_PURSE, payment_purse),
+                    (POS_REWARDS_PURSE, rewards_purse),
+                    (POS_TREASURY_PURSE, treasury_purse),
+                    (POS_MINT_AUTHORITY, mint_authority),
+                    (POS_STAKES, stakes_uref),
+                    (POS_BONDING_QUEUE, bonding_queue_uref),
+                    (POS_UNBONDING_QUEUE, unbonding_queue_uref),
+                    (POS_PARAMS, params_uref),
+                    (POS_DELEGATIONS, delegations_uref),
+                    (POS_DELEGATING_QUEUE, delegating_queue_uref),
+                    (POS_UNDELEGATING_QUEUE, undelegating_queue_uref),
+                    (POS_ERA_REWARDS, era_rewards_uref),
+                    (POS_JAIL, jail_uref),
+                ]
+                .iter()
+                .map(|(name, uref)| (String::from(*name), Key::URef(*uref)))
+                .collect();
                 let contract = {
                     let bytes = parity_wasm::serialize(do_nothing).expect("failed to serialize");
                     Contract::new(bytes, named_keys, protocol_version)
@@ -363,7 +454,7 @@ where
                 let proof_of_stake_installer_module =
                     preprocessor.preprocess(proof_of_stake_installer_bytes)?;
                 let args = {
-                    let args = (mint_reference, bonded_validators);
+                    let args = (mint_reference, bonded_validators, pos_params);
                     ArgsParser::parse(args)
                         .expect("args should convert to `Vec<CLValue>`")
                         .into_bytes()
@@ -394,7 +485,12 @@ where
         };
 
         // Spec #2: Associate given CostTable with given ProtocolVersion.
-        let protocol_data = ProtocolData::new(wasm_costs, mint_reference, proof_of_stake_reference);
+        let protocol_data = ProtocolData::new(
+            wasm_costs,
+            mint_reference,
+            proof_of_stake_reference,
+            genesis_config.max_call_depth(),
+        );
 
         self.state
             .put_protocol_data(protocol_version, &protocol_data)
@@ -470,7 +566,12 @@ where
                 };
                 let tracking_copy_exec = Rc::clone(&tracking_copy);
                 let tracking_copy_write = Rc::clone(&tracking_copy);
-                let mut named_keys_exec = BTreeMap::new();
+                // The mint tracks the total supply under its named keys, so each purse creation
+                // has to see the keys written by the previous ones.
+                let mut named_keys_exec = tracking_copy
+                    .borrow_mut()
+                    .get_contract(correlation_id, Key::URef(mint_reference))?
+                    .take_named_keys();
                 let base_key = Key::URef(mint_reference);
                 let authorization_keys: BTreeSet<PublicKey> = BTreeSet::new();
                 let account_public_key = account.public_key();
@@ -536,10 +637,12 @@ where
                 let key = Key::Account(account_public_key);
                 let value = {
                     let main_purse = mint_result?;
-                    StoredValue::Account(Account::create(
+                    StoredValue::Account(Account::new(
                         account_public_key,
                         named_keys,
                         main_purse,
+                        account.associated_keys().clone(),
+                        account.action_thresholds().clone(),
                     ))
                 };
 
@@ -547,6 +650,48 @@ where
             }
         }
 
+        // Run the additional installers as the system account, so that any named keys they put
+        // end up under the system account
+        for (index, installer) in genesis_config.installers().iter().enumerate() {
+            let module = preprocessor.preprocess(installer.installer_bytes())?;
+            let system_account = tracking_copy
+                .borrow_mut()
+                .get_account(correlation_id, SYSTEM_ACCOUNT_ADDR)?;
+            let mut named_keys = system_account.named_keys().clone();
+            let authorization_keys: BTreeSet<PublicKey> = iter::once(SYSTEM_ACCOUNT_ADDR).collect();
+            // Each installer gets its own deploy hash, so that installing the same contract twice
+            // doesn't create colliding addresses
+            let installer_deploy_hash = {
+                let mut bytes = install_deploy_hash.value().to_vec();
+                bytes.extend_from_slice(&(index as u64).to_le_bytes());
+                Blake2bHash::new(&bytes)
+            };
+            let address_generator = {
+                let generator = AddressGenerator::new(&installer_deploy_hash.value(), phase);
+                Rc::new(RefCell::new(generator))
+            };
+            let system_contract_cache = SystemContractCache::clone(&self.system_contract_cache);
+
+            executor.exec_system::<_, ()>(
+                module,
+                installer.args().to_vec(),
+                &mut named_keys,
+                initial_base_key,
+                &system_account,
+                authorization_keys,
+                blocktime,
+                installer_deploy_hash.value(),
+                gas_limit,
+                address_generator,
+                protocol_version,
+                correlation_id,
+                Rc::clone(&tracking_copy),
+                phase,
+                protocol_data,
+                system_contract_cache,
+            )?;
+        }
+
         // Spec #15: Commit the transforms.
         let effects = tracking_copy.borrow().effect();
 
@@ -593,7 +738,13 @@ where
             }
         };
 
-        // 3.1.1.1.1.3 activation point is not currently used by EE; skipping
+        // 3.1.1.1.1.3 the new version can't be scheduled before the current one, nor by a
+        // different kind of activation point; without one it applies from the first block on
+        let new_activation_point = upgrade_config.activation_point().unwrap_or_default();
+        if !new_activation_point.can_follow(&current_protocol_data.activation_point()) {
+            return Err(Error::InvalidUpgradeConfig);
+        }
+
         // 3.1.1.1.1.4 upgrade point protocol version validation
         let new_protocol_version = upgrade_config.new_protocol_version();
 
@@ -610,16 +761,23 @@ where
             None => *current_protocol_data.wasm_costs(),
         };
 
-        // 3.1.2.2 persist wasm CostTable
+        let new_max_call_depth = upgrade_config
+            .max_call_depth()
+            .unwrap_or_else(|| current_protocol_data.max_call_depth());
+
+        // 3.1.2.2 resolve wasm CostTable; it is persisted once the upgrade has been applied
         let new_protocol_data = ProtocolData::new(
             new_wasm_costs,
             current_protocol_data.mint(),
             current_protocol_data.proof_of_stake(),
-        );
+            new_max_call_depth,
+        )
+        .with_activation_point(new_activation_point);
 
-        self.state
-            .put_protocol_data(new_protocol_version, &new_protocol_data)
-            .map_err(Into::into)?;
+        let address_generator = {
+            let generator = AddressGenerator::new(&pre_state_hash.value(), Phase::System);
+            Rc::new(RefCell::new(generator))
+        };
 
         // 3.1.1.1.1.5 upgrade installer is optional except on major version upgrades
         match upgrade_config.upgrade_installer_bytes() {
@@ -680,10 +838,7 @@ where
                 // upgrade has no gas limit; approximating with MAX
                 let gas_limit = Gas::new(std::u64::MAX.into());
                 let phase = Phase::System;
-                let address_generator = {
-                    let generator = AddressGenerator::new(&pre_state_hash.value(), phase);
-                    Rc::new(RefCell::new(generator))
-                };
+                let address_generator = Rc::clone(&address_generator);
                 let state = Rc::clone(&tracking_copy);
                 let system_contract_cache = SystemContractCache::clone(&self.system_contract_cache);
 
@@ -710,6 +865,50 @@ where
             }
         }
 
+        migrate_proof_of_stake_storage(
+            correlation_id,
+            &mut tracking_copy.borrow_mut(),
+            &mut address_generator.borrow_mut(),
+            new_protocol_data.proof_of_stake(),
+        )?;
+
+        migrate_proof_of_stake_delegation_storage(
+            correlation_id,
+            &mut tracking_copy.borrow_mut(),
+            &mut address_generator.borrow_mut(),
+            new_protocol_data.proof_of_stake(),
+        )?;
+
+        update_proof_of_stake_params(
+            correlation_id,
+            &mut tracking_copy.borrow_mut(),
+            &mut address_generator.borrow_mut(),
+            new_protocol_data.proof_of_stake(),
+            upgrade_config.pos_params(),
+        )?;
+
+        create_proof_of_stake_treasury_purse(
+            correlation_id,
+            &mut tracking_copy.borrow_mut(),
+            &mut address_generator.borrow_mut(),
+            new_protocol_data.mint(),
+            new_protocol_data.proof_of_stake(),
+        )?;
+
+        for (index, migration) in upgrade_config.migrations().iter().enumerate() {
+            match migration.apply(
+                correlation_id,
+                &mut tracking_copy.borrow_mut(),
+                &mut address_generator.borrow_mut(),
+            ) {
+                Ok(()) => {}
+                Err(Error::InvalidMigration(error)) => {
+                    return Ok(UpgradeResult::InvalidMigration { index, error });
+                }
+                Err(error) => return Err(error),
+            }
+        }
+
         let effects = tracking_copy.borrow().effect();
 
         // commit
@@ -722,8 +921,214 @@ where
             )
             .map_err(Into::into)?;
 
+        // persist the new protocol data only once the upgrade has been committed, so that a failed
+        // upgrade leaves the new protocol version unregistered
+        if let CommitResult::Success { .. } = commit_result {
+            self.state
+                .put_protocol_data(new_protocol_version, &new_protocol_data)
+                .map_err(Into::into)?;
+        }
+
         // return result and effects
-        Ok(UpgradeResult::from_commit_result(commit_result, effects))
+        Ok(UpgradeResult::from_commit_result(
+            commit_result,
+            effects,
+            upgrade_config.migrations().len(),
+        ))
+    }
+
+    /// Runs the proof of stake contract's `distribute_rewards` method as the system account and
+    /// commits its effects on top of the request's parent state.
+    pub fn commit_distribute_rewards(
+        &self,
+        correlation_id: CorrelationId,
+        distribute_rewards_request: DistributeRewardsRequest,
+    ) -> Result<CommitResult, Error>
+    where
+        Error: From<S::Error>,
+    {
+        let args = {
+            let args = (
+                "distribute_rewards",
+                distribute_rewards_request.era_id(),
+                distribute_rewards_request.inflation_rate(),
+                distribute_rewards_request.commission_rate(),
+            );
+            ArgsParser::parse(args)
+                .expect("args should convert to `Vec<CLValue>`")
+                .into_bytes()
+                .expect("args should serialize")
+        };
+
+        // seeds the address generator w/ the era id
+        let deploy_hash = {
+            let bytes = distribute_rewards_request.era_id().to_le_bytes();
+            Blake2bHash::new(&bytes).value()
+        };
+
+        self.commit_proof_of_stake_call(
+            correlation_id,
+            distribute_rewards_request.parent_state_hash(),
+            distribute_rewards_request.protocol_version(),
+            args,
+            BlockTime::default(),
+            deploy_hash,
+        )
+    }
+
+    /// Runs the proof of stake contract's `slash` method as the system account and commits its
+    /// effects on top of the request's parent state.
+    pub fn commit_slash(
+        &self,
+        correlation_id: CorrelationId,
+        slash_request: SlashRequest,
+    ) -> Result<CommitResult, Error>
+    where
+        Error: From<S::Error>,
+    {
+        let args = {
+            let args = ("slash", slash_request.slashes().clone());
+            ArgsParser::parse(args)
+                .expect("args should convert to `Vec<CLValue>`")
+                .into_bytes()
+                .expect("args should serialize")
+        };
+
+        // seeds the address generator w/ the serialized slashes
+        let deploy_hash = Blake2bHash::new(&args).value();
+
+        self.commit_proof_of_stake_call(
+            correlation_id,
+            slash_request.parent_state_hash(),
+            slash_request.protocol_version(),
+            args,
+            slash_request.block_time(),
+            deploy_hash,
+        )
+    }
+
+    /// Runs the proof of stake contract's `step` method as the system account, paying out the
+    /// stake released by unbonding requests which are due, and commits its effects on top of the
+    /// request's parent state.
+    pub fn commit_unbond_payout(
+        &self,
+        correlation_id: CorrelationId,
+        unbond_payout_request: UnbondPayoutRequest,
+    ) -> Result<CommitResult, Error>
+    where
+        Error: From<S::Error>,
+    {
+        let args = {
+            let args = ("step",);
+            ArgsParser::parse(args)
+                .expect("args should convert to `Vec<CLValue>`")
+                .into_bytes()
+                .expect("args should serialize")
+        };
+
+        // seeds the address generator w/ the block time
+        let deploy_hash = {
+            let block_time: u64 = unbond_payout_request.block_time().into();
+            Blake2bHash::new(&block_time.to_le_bytes()).value()
+        };
+
+        self.commit_proof_of_stake_call(
+            correlation_id,
+            unbond_payout_request.parent_state_hash(),
+            unbond_payout_request.protocol_version(),
+            args,
+            unbond_payout_request.block_time(),
+            deploy_hash,
+        )
+    }
+
+    /// Calls the proof of stake contract with the given serialized `args` as the system account
+    /// and commits the effects on top of `pre_state_hash`.
+    fn commit_proof_of_stake_call(
+        &self,
+        correlation_id: CorrelationId,
+        pre_state_hash: Blake2bHash,
+        protocol_version: ProtocolVersion,
+        args: Vec<u8>,
+        blocktime: BlockTime,
+        deploy_hash: [u8; 32],
+    ) -> Result<CommitResult, Error>
+    where
+        Error: From<S::Error>,
+    {
+        let tracking_copy = match self.tracking_copy(pre_state_hash)? {
+            Some(tracking_copy) => Rc::new(RefCell::new(tracking_copy)),
+            None => return Ok(CommitResult::RootNotFound),
+        };
+
+        let protocol_data = match self.state.get_protocol_data(protocol_version)? {
+            Some(protocol_data) => protocol_data,
+            None => return Err(Error::InvalidProtocolVersion(protocol_version)),
+        };
+
+        let proof_of_stake_reference = protocol_data.proof_of_stake();
+        let proof_of_stake_contract = tracking_copy
+            .borrow_mut()
+            .get_contract(correlation_id, Key::URef(proof_of_stake_reference))
+            .map_err(|error| Error::Exec(error.into()))?;
+
+        let proof_of_stake_module = match self.system_contract_cache.get(&proof_of_stake_reference)
+        {
+            Some(module) => module,
+            None => {
+                let module = engine_wasm_prep::deserialize(proof_of_stake_contract.bytes())?;
+                self.system_contract_cache
+                    .insert(proof_of_stake_reference, module.clone());
+                module
+            }
+        };
+
+        let system_account = Account::new(
+            SYSTEM_ACCOUNT_ADDR,
+            Default::default(),
+            URef::new(Default::default(), AccessRights::READ_ADD_WRITE),
+            Default::default(),
+            Default::default(),
+        );
+        let authorization_keys = {
+            let mut ret = BTreeSet::new();
+            ret.insert(SYSTEM_ACCOUNT_ADDR);
+            ret
+        };
+
+        let mut proof_of_stake_keys = proof_of_stake_contract.named_keys().to_owned();
+        let base_key = Key::from(proof_of_stake_reference);
+        let gas_limit = Gas::new(U512::from(std::u64::MAX));
+        let system_contract_cache = SystemContractCache::clone(&self.system_contract_cache);
+        let executor = Executor::new(self.config);
+
+        let execution_result = executor.exec_finalize(
+            proof_of_stake_module,
+            args,
+            &mut proof_of_stake_keys,
+            base_key,
+            &system_account,
+            authorization_keys,
+            blocktime,
+            deploy_hash,
+            gas_limit,
+            protocol_version,
+            correlation_id,
+            tracking_copy,
+            Phase::System,
+            protocol_data,
+            system_contract_cache,
+        );
+
+        match execution_result {
+            ExecutionResult::Success { effect, .. } => self.apply_effect(
+                correlation_id,
+                protocol_version,
+                pre_state_hash,
+                effect.transforms,
+            ),
+            ExecutionResult::Failure { error, .. } => Err(Error::Exec(error)),
+        }
     }
 
     pub fn tracking_copy(
@@ -754,68 +1159,435 @@ where
             .into())
     }
 
+    /// Returns the newest protocol version, along with its data, whose activation point has been
+    /// reached by a block with the given rank and block time.
+    ///
+    /// Without a rank, only activation points by block time and the genesis point can be checked,
+    /// so any other activation point by rank is an error.
+    pub fn active_protocol_data(
+        &self,
+        rank: Option<u64>,
+        block_time: BlockTime,
+    ) -> Result<Option<(ProtocolVersion, ProtocolData)>, Error> {
+        let all_protocol_data = self
+            .state
+            .get_all_protocol_data()
+            .map_err(|error| Error::Exec(error.into()))?;
+        let mut active = None;
+        for (protocol_version, protocol_data) in all_protocol_data {
+            let is_reached = match (protocol_data.activation_point(), rank) {
+                (ActivationPoint::Rank(0), None) => true,
+                (ActivationPoint::Rank(_), None) => return Err(Error::MissingRank),
+                (activation_point, rank) => {
+                    activation_point.is_reached(rank.unwrap_or_default(), block_time)
+                }
+            };
+            let is_newer = match active {
+                Some((active_protocol_version, _)) => protocol_version > active_protocol_version,
+                None => true,
+            };
+            if is_reached && is_newer {
+                active = Some((protocol_version, protocol_data));
+            }
+        }
+        Ok(active)
+    }
+
+    /// Resolves the protocol version an execute request runs under.  An unset (i.e. default)
+    /// version resolves to the active one; any other version must be the active one.
+    fn resolve_protocol_data(
+        &self,
+        requested_protocol_version: ProtocolVersion,
+        rank: Option<u64>,
+        block_time: BlockTime,
+    ) -> Result<(ProtocolVersion, ProtocolData), Error> {
+        match self.active_protocol_data(rank, block_time)? {
+            Some((active_protocol_version, protocol_data))
+                if requested_protocol_version == ProtocolVersion::default()
+                    || requested_protocol_version == active_protocol_version =>
+            {
+                Ok((active_protocol_version, protocol_data))
+            }
+            _ => Err(Error::InactiveProtocolVersion(requested_protocol_version)),
+        }
+    }
+
+    /// Runs the scheduled calls which are due at the request's block time, if it asks for them,
+    /// followed by the request's deploys, on top of the request's parent state.
+    ///
+    /// Nothing is committed: see [`ExecuteResults`] for the order in which the results' effects
+    /// apply.
     pub fn run_execute(
         &self,
         correlation_id: CorrelationId,
         mut exec_request: ExecuteRequest,
-    ) -> Result<Vec<ExecutionResult>, RootNotFound> {
-        // TODO: do not unwrap
-        let wasm_costs = self
-            .wasm_costs(exec_request.protocol_version)
-            .unwrap()
-            .unwrap();
+    ) -> Result<ExecuteResults, RootNotFound> {
         let executor = Executor::new(self.config);
-        let preprocessor = Preprocessor::new(wasm_costs);
+        let prestate_hash = exec_request.parent_state_hash;
+        let block_time = BlockTime::new(exec_request.block_time);
+        let requested_protocol_version = exec_request.protocol_version;
+        let rank = exec_request.rank;
+        let prepare = || -> Result<Option<_>, Error> {
+            let (protocol_version, protocol_data) =
+                self.resolve_protocol_data(requested_protocol_version, rank, block_time)?;
+            let tracking_copy = self.tracking_copy(prestate_hash)?;
+            Ok(tracking_copy.map(|tracking_copy| (protocol_version, protocol_data, tracking_copy)))
+        };
+
+        let (protocol_version, protocol_data, mut tracking_copy) = match prepare() {
+            Ok(Some(prepared)) => prepared,
+            Ok(None) => return Err(RootNotFound::new(prestate_hash)),
+            // Errors aren't `Clone`, so each result gets its own by preparing again.
+            Err(error) => {
+                let prepare_error = || {
+                    let error = prepare().err().unwrap_or_else(|| {
+                        Error::InactiveProtocolVersion(requested_protocol_version)
+                    });
+                    ExecutionResult::precondition_failure(error)
+                };
+                let deploy_results = exec_request
+                    .take_deploys()
+                    .into_iter()
+                    .map(|deploy_item| match deploy_item {
+                        Ok(_) => prepare_error(),
+                        Err(exec_result) => exec_result,
+                    })
+                    .collect();
+                let scheduled_call_results = if exec_request.run_scheduled_calls {
+                    vec![ExecutionResult::precondition_failure(error)]
+                } else {
+                    Vec::new()
+                };
+                return Ok(ExecuteResults {
+                    scheduled_call_results,
+                    deploy_results,
+                });
+            }
+        };
+        let preprocessor = Preprocessor::new(*protocol_data.wasm_costs());
 
-        let mut results = Vec::new();
+        let scheduled_call_results = if exec_request.run_scheduled_calls {
+            self.run_scheduled_calls(
+                correlation_id,
+                &executor,
+                &preprocessor,
+                protocol_version,
+                protocol_data,
+                &mut tracking_copy,
+                block_time,
+            )
+        } else {
+            Vec::new()
+        };
 
-        for deploy_item in exec_request.take_deploys() {
-            let result = match deploy_item {
+        let deploy_results = exec_request
+            .take_deploys()
+            .into_iter()
+            .map(|deploy_item| match deploy_item {
                 Ok(deploy_item) => self.deploy(
                     correlation_id,
                     &executor,
                     &preprocessor,
-                    exec_request.protocol_version,
-                    exec_request.parent_state_hash,
-                    BlockTime::new(exec_request.block_time),
+                    protocol_version,
+                    &tracking_copy,
+                    block_time,
                     deploy_item,
                 ),
-                Err(exec_result) => Ok(exec_result), /* this will get pushed into the results vec
-                                                      * below */
-            };
-            match result {
-                Ok(result) => results.push(result),
+                Err(exec_result) => exec_result,
+            })
+            .collect();
+
+        Ok(ExecuteResults {
+            scheduled_call_results,
+            deploy_results,
+        })
+    }
+
+    /// Runs the scheduled calls which are due at `blocktime`, in the order they became due, and
+    /// replays the effects of each into `tracking_copy`, so that whatever runs next builds on them.
+    ///
+    /// A call whose effects can't be replayed is dropped in their place.  A schedule which can't be
+    /// read, or a drop which can't be replayed either, is reported as failed, and the calls left
+    /// stay on the schedule.
+    #[allow(clippy::too_many_arguments)]
+    fn run_scheduled_calls(
+        &self,
+        correlation_id: CorrelationId,
+        executor: &Executor,
+        preprocessor: &Preprocessor,
+        protocol_version: ProtocolVersion,
+        protocol_data: ProtocolData,
+        tracking_copy: &mut TrackingCopy<<S as StateProvider>::Reader>,
+        blocktime: BlockTime,
+    ) -> Vec<ExecutionResult> {
+        let due_calls = match scheduled_call::due_calls(correlation_id, tracking_copy, blocktime) {
+            Ok(due_calls) => due_calls,
+            Err(error) => return vec![ExecutionResult::precondition_failure(error)],
+        };
+
+        let mut results = Vec::with_capacity(due_calls.len());
+        for due_call in due_calls {
+            // The calls before this one may have scheduled further calls, so the system account is
+            // read again each time.
+            let system_account =
+                match tracking_copy.get_account(correlation_id, SYSTEM_ACCOUNT_ADDR) {
+                    Ok(system_account) => system_account,
+                    Err(error) => {
+                        results.push(ExecutionResult::precondition_failure(error.into()));
+                        break;
+                    }
+                };
+
+            let result = self.run_scheduled_call(
+                correlation_id,
+                executor,
+                preprocessor,
+                protocol_version,
+                protocol_data,
+                tracking_copy,
+                &system_account,
+                blocktime,
+                &due_call,
+            );
+            let result = match scheduled_call::replay_transforms(
+                correlation_id,
+                tracking_copy,
+                &result.effect().transforms,
+            ) {
+                Ok(()) => result,
                 Err(error) => {
-                    return Err(error);
+                    let dropped = scheduled_call::drop_call(
+                        correlation_id,
+                        tracking_copy,
+                        protocol_data.mint(),
+                        &system_account,
+                        &due_call.name,
+                        &due_call.call,
+                        error,
+                    );
+                    if let Err(error) = scheduled_call::replay_transforms(
+                        correlation_id,
+                        tracking_copy,
+                        &dropped.effect().transforms,
+                    ) {
+                        results.push(ExecutionResult::precondition_failure(error));
+                        break;
+                    }
+                    dropped
                 }
             };
+            results.push(result);
         }
-
-        Ok(results)
+        results
     }
 
-    pub fn get_module(
+    /// Runs a due scheduled call on top of `base_tracking_copy`, in which `system_account` is the
+    /// system account.
+    ///
+    /// The call is taken off the schedule and its escrowed motes take the place of payment code.
+    /// It then runs in its context as the session of a deploy would, and is finalized likewise.
+    #[allow(clippy::too_many_arguments)]
+    fn run_scheduled_call(
         &self,
-        tracking_copy: Rc<RefCell<TrackingCopy<<S as StateProvider>::Reader>>>,
-        deploy_item: &ExecutableDeployItem,
-        account: &Account,
         correlation_id: CorrelationId,
+        executor: &Executor,
         preprocessor: &Preprocessor,
-        protocol_version: &ProtocolVersion,
-    ) -> Result<Module, error::Error> {
-        let stored_contract_key = match deploy_item {
-            ExecutableDeployItem::ModuleBytes { module_bytes, .. } => {
-                let module = preprocessor.preprocess(&module_bytes)?;
-                return Ok(module);
+        protocol_version: ProtocolVersion,
+        protocol_data: ProtocolData,
+        base_tracking_copy: &TrackingCopy<<S as StateProvider>::Reader>,
+        system_account: &Account,
+        blocktime: BlockTime,
+        due_call: &DueCall,
+    ) -> ExecutionResult {
+        let DueCall { name, hash, call } = due_call;
+        let account_addr = call.account();
+        let escrow_purse = call.escrow_purse();
+        let refund_purse = call.refund_purse();
+
+        // Once off the schedule, a call which can't be paid for, can't run on behalf of its account
+        // or can't be finalized is dropped, so that it isn't run again.
+        let dropped = |error: Error| {
+            scheduled_call::drop_call(
+                correlation_id,
+                base_tracking_copy,
+                protocol_data.mint(),
+                system_account,
+                name,
+                call,
+                error,
+            )
+        };
+
+        let tracking_copy = Rc::new(RefCell::new(base_tracking_copy.fork()));
+        scheduled_call::unschedule(&mut tracking_copy.borrow_mut(), system_account, name);
+
+        // The tracking copy is borrowed again for the session, so each step's borrow has to end
+        // before its result is matched.
+        let account_result = tracking_copy
+            .borrow_mut()
+            .get_account(correlation_id, account_addr);
+        let account = match account_result {
+            Ok(account) => account,
+            Err(error) => return dropped(error.into()),
+        };
+
+        // A call in the context of a contract runs with the contract's named keys.
+        let context_result = match call.context() {
+            CallContext::Session => Ok((Key::Account(account_addr), account.named_keys().clone())),
+            CallContext::Contract => {
+                let contract_key = call.contract();
+                tracking_copy
+                    .borrow_mut()
+                    .get_contract(correlation_id, contract_key)
+                    .map(|contract| (contract_key, contract.take_named_keys()))
             }
-            ExecutableDeployItem::StoredContractByHash { hash, .. } => {
-                let hash_len = hash.len();
-                if hash_len != KEY_HASH_LENGTH {
-                    return Err(error::Error::InvalidHashLength {
-                        expected: KEY_HASH_LENGTH,
-                        actual: hash_len,
-                    });
-                }
+        };
+        let (base_key, named_keys) = match context_result {
+            Ok(context) => context,
+            Err(error) => return dropped(error.into()),
+        };
+
+        // The escrow takes the place of payment code: it's moved into the payment purse, and the
+        // purse it was taken from is set as the refund purse for finalization.
+        let contract_result = tracking_copy
+            .borrow_mut()
+            .get_contract(correlation_id, Key::URef(protocol_data.proof_of_stake()));
+        let payment_purse = match contract_result {
+            Ok(contract) => match contract.named_keys().get(POS_PAYMENT_PURSE) {
+                Some(key) => *key,
+                None => return dropped(Error::Deploy),
+            },
+            Err(error) => return dropped(error.into()),
+        };
+        let payment_result = scheduled_call::pay_from_escrow(
+            correlation_id,
+            &mut tracking_copy.borrow_mut(),
+            protocol_data.mint(),
+            protocol_data.proof_of_stake(),
+            payment_purse,
+            escrow_purse,
+            refund_purse,
+        );
+        let payment_amount = match payment_result {
+            Ok(payment_amount) => payment_amount,
+            Err(error) => return dropped(error),
+        };
+
+        let mut execution_result_builder = execution_result::ExecutionResultBuilder::new();
+        execution_result_builder.set_payment_execution_result(ExecutionResult::Success {
+            effect: tracking_copy.borrow().effect(),
+            cost: Gas::default(),
+        });
+
+        let post_payment_tc = tracking_copy.borrow();
+        let session_tc = Rc::new(RefCell::new(post_payment_tc.fork()));
+
+        // Anything that goes wrong from here on fails the session, and the escrowed motes which
+        // weren't spent are refunded on finalization.
+        let session_result = match call.clone().into_session() {
+            Some(session) => match self.get_module(
+                Rc::clone(&session_tc),
+                &session,
+                &account,
+                correlation_id,
+                preprocessor,
+                &protocol_version,
+            ) {
+                Ok(session_module) => {
+                    let session_gas_limit =
+                        Gas::from_motes(payment_amount, CONV_RATE).unwrap_or_default();
+                    let system_contract_cache =
+                        SystemContractCache::clone(&self.system_contract_cache);
+                    let (args, named_args) = session.take_args();
+                    executor.exec_with_named_keys(
+                        session_module,
+                        args,
+                        named_args,
+                        named_keys,
+                        base_key,
+                        &account,
+                        BTreeSet::new(),
+                        blocktime,
+                        *hash,
+                        session_gas_limit,
+                        protocol_version,
+                        correlation_id,
+                        Rc::clone(&session_tc),
+                        Phase::Session,
+                        protocol_data,
+                        system_contract_cache,
+                    )
+                }
+                Err(error) => ExecutionResult::precondition_failure(error),
+            },
+            None => ExecutionResult::precondition_failure(Error::Deploy),
+        };
+
+        let post_session_rc = if session_result.is_failure() {
+            Rc::new(RefCell::new(post_payment_tc.fork()))
+        } else {
+            session_tc
+        };
+        execution_result_builder.set_session_execution_result(session_result);
+
+        let finalize_result = {
+            let post_session_tc = post_session_rc.borrow();
+            let finalization_tc = Rc::new(RefCell::new(post_session_tc.fork()));
+
+            match self.finalize_payment(
+                correlation_id,
+                executor,
+                protocol_version,
+                protocol_data,
+                execution_result_builder.total_cost(),
+                account_addr,
+                BTreeSet::new(),
+                blocktime,
+                *hash,
+                finalization_tc,
+            ) {
+                // The builder would report a failed finalization without any effects.
+                Ok(finalize_result) if finalize_result.is_failure() => {
+                    return dropped(Error::Finalization)
+                }
+                Ok(finalize_result) => finalize_result,
+                Err(error) => return dropped(error),
+            }
+        };
+        execution_result_builder.set_finalize_execution_result(finalize_result);
+
+        // We panic here to indicate that the builder was not used properly.
+        execution_result_builder
+            .build(&base_tracking_copy, correlation_id)
+            .expect("ExecutionResultBuilder not initialized properly")
+    }
+
+    pub fn get_module<R>(
+        &self,
+        tracking_copy: Rc<RefCell<TrackingCopy<R>>>,
+        deploy_item: &ExecutableDeployItem,
+        account: &Account,
+        correlation_id: CorrelationId,
+        preprocessor: &Preprocessor,
+        protocol_version: &ProtocolVersion,
+    ) -> Result<Module, error::Error>
+    where
+        R: StateReader<Key, StoredValue>,
+        R::Error: Into<execution::Error>,
+    {
+        let stored_contract_key = match deploy_item {
+            ExecutableDeployItem::ModuleBytes { module_bytes, .. } => {
+                let module = preprocessor.preprocess(&module_bytes)?;
+                return Ok(module);
+            }
+            ExecutableDeployItem::StoredContractByHash { hash, .. } => {
+                let hash_len = hash.len();
+                if hash_len != KEY_HASH_LENGTH {
+                    return Err(error::Error::InvalidHashLength {
+                        expected: KEY_HASH_LENGTH,
+                        actual: hash_len,
+                    });
+                }
                 let mut arr = [0u8; KEY_HASH_LENGTH];
                 arr.copy_from_slice(&hash);
                 Key::Hash(arr)
@@ -899,10 +1671,10 @@ where
         executor: &Executor,
         preprocessor: &Preprocessor,
         protocol_version: ProtocolVersion,
-        prestate_hash: Blake2bHash,
+        base_tracking_copy: &TrackingCopy<<S as StateProvider>::Reader>,
         blocktime: BlockTime,
         deploy_item: DeployItem,
-    ) -> Result<ExecutionResult, RootNotFound> {
+    ) -> ExecutionResult {
         // spec: https://casperlabs.atlassian.net/wiki/spaces/EN/pages/123404576/Payment+code+execution+specification
 
         let session = deploy_item.session;
@@ -912,22 +1684,13 @@ where
         let deploy_hash = deploy_item.deploy_hash;
 
         // Create tracking copy (which functions as a deploy context)
-        // validation_spec_2: prestate_hash check
-        let tracking_copy = match self.tracking_copy(prestate_hash) {
-            Err(error) => return Ok(ExecutionResult::precondition_failure(error)),
-            Ok(None) => return Err(RootNotFound::new(prestate_hash)),
-            Ok(Some(tracking_copy)) => Rc::new(RefCell::new(tracking_copy)),
-        };
+        let tracking_copy = Rc::new(RefCell::new(base_tracking_copy.fork()));
 
         // Get addr bytes from `address` (which is actually a Key)
         // validation_spec_3: account validity
         let account_addr = match address.into_account() {
             Some(account_addr) => account_addr,
-            None => {
-                return Ok(ExecutionResult::precondition_failure(
-                    error::Error::Authorization,
-                ))
-            }
+            None => return ExecutionResult::precondition_failure(error::Error::Authorization),
         };
 
         // Get account from tracking copy
@@ -938,27 +1701,25 @@ where
         {
             Ok(account) => account,
             Err(_) => {
-                return Ok(ExecutionResult::precondition_failure(
-                    error::Error::Authorization,
-                ));
+                return ExecutionResult::precondition_failure(error::Error::Authorization);
             }
         };
 
         // Authorize using provided authorization keys
         // validation_spec_3: account validity
         if !account.can_authorize(&authorization_keys) {
-            return Ok(ExecutionResult::precondition_failure(
+            return ExecutionResult::precondition_failure(
                 crate::engine_state::error::Error::Authorization,
-            ));
+            );
         }
 
         // Check total key weight against deploy threshold
         // validation_spec_4: deploy validity
         if !account.can_deploy_with(&authorization_keys) {
-            return Ok(ExecutionResult::precondition_failure(
+            return ExecutionResult::precondition_failure(
                 // TODO?:this doesn't happen in execution any longer, should error variant be moved
                 execution::Error::DeploymentAuthorizationFailure.into(),
-            ));
+            );
         }
 
         // Create session code `A` from provided session bytes
@@ -973,7 +1734,7 @@ where
         ) {
             Ok(module) => module,
             Err(error) => {
-                return Ok(ExecutionResult::precondition_failure(error));
+                return ExecutionResult::precondition_failure(error);
             }
         };
 
@@ -982,12 +1743,10 @@ where
             Ok(Some(protocol_data)) => protocol_data,
             Ok(None) => {
                 let error = Error::InvalidProtocolVersion(protocol_version);
-                return Ok(ExecutionResult::precondition_failure(error));
+                return ExecutionResult::precondition_failure(error);
             }
             Err(error) => {
-                return Ok(ExecutionResult::precondition_failure(Error::Exec(
-                    error.into(),
-                )));
+                return ExecutionResult::precondition_failure(Error::Exec(error.into()));
             }
         };
 
@@ -1006,13 +1765,13 @@ where
                 .get_contract(correlation_id, Key::URef(mint_reference))
             {
                 Ok(contract) => contract,
-                Err(error) => return Ok(ExecutionResult::precondition_failure(error.into())),
+                Err(error) => return ExecutionResult::precondition_failure(error.into()),
             };
 
             if !self.system_contract_cache.has(&mint_reference) {
                 let module = match engine_wasm_prep::deserialize(mint_contract.bytes()) {
                     Ok(module) => module,
-                    Err(error) => return Ok(ExecutionResult::precondition_failure(error.into())),
+                    Err(error) => return ExecutionResult::precondition_failure(error.into()),
                 };
                 self.system_contract_cache.insert(mint_reference, module);
             }
@@ -1033,7 +1792,7 @@ where
         {
             Ok(contract) => contract,
             Err(error) => {
-                return Ok(ExecutionResult::precondition_failure(error.into()));
+                return ExecutionResult::precondition_failure(error.into());
             }
         };
 
@@ -1046,7 +1805,7 @@ where
                 match proof_of_stake_contract.named_keys().get(POS_REWARDS_PURSE) {
                     Some(key) => *key,
                     None => {
-                        return Ok(ExecutionResult::precondition_failure(Error::Deploy));
+                        return ExecutionResult::precondition_failure(Error::Deploy);
                     }
                 };
 
@@ -1057,7 +1816,7 @@ where
             ) {
                 Ok(key) => key,
                 Err(error) => {
-                    return Ok(ExecutionResult::precondition_failure(error.into()));
+                    return ExecutionResult::precondition_failure(error.into());
                 }
             }
         };
@@ -1073,7 +1832,7 @@ where
             ) {
                 Ok(key) => key,
                 Err(error) => {
-                    return Ok(ExecutionResult::precondition_failure(error.into()));
+                    return ExecutionResult::precondition_failure(error.into());
                 }
             }
         };
@@ -1085,27 +1844,15 @@ where
             .get_purse_balance(correlation_id, account_main_purse_balance_key)
         {
             Ok(balance) => balance,
-            Err(error) => return Ok(ExecutionResult::precondition_failure(error.into())),
+            Err(error) => return ExecutionResult::precondition_failure(error.into()),
         };
 
         // Enforce minimum main purse balance validation
         // validation_spec_5: account main purse minimum balance
         if account_main_purse_balance < max_payment_cost {
-            return Ok(ExecutionResult::precondition_failure(
-                Error::InsufficientPayment,
-            ));
+            return ExecutionResult::precondition_failure(Error::InsufficientPayment);
         }
 
-        // Finalization is executed by system account (currently genesis account)
-        // payment_code_spec_5: system executes finalization
-        let system_account = Account::new(
-            SYSTEM_ACCOUNT_ADDR,
-            Default::default(),
-            URef::new(Default::default(), AccessRights::READ_ADD_WRITE),
-            Default::default(),
-            Default::default(),
-        );
-
         // [`ExecutionResultBuilder`] handles merging of multiple execution results
         let mut execution_result_builder = execution_result::ExecutionResultBuilder::new();
 
@@ -1127,15 +1874,17 @@ where
             ) {
                 Ok(module) => module,
                 Err(error) => {
-                    return Ok(ExecutionResult::precondition_failure(error));
+                    return ExecutionResult::precondition_failure(error);
                 }
             };
             let system_contract_cache = SystemContractCache::clone(&self.system_contract_cache);
 
             // payment_code_spec_2: execute payment code
+            let (args, named_args) = payment.take_args();
             executor.exec(
                 payment_module,
-                payment.take_args(),
+                args,
+                named_args,
                 address,
                 &account,
                 authorization_keys.clone(),
@@ -1161,7 +1910,7 @@ where
             let payment_purse: Key =
                 match proof_of_stake_contract.named_keys().get(POS_PAYMENT_PURSE) {
                     Some(key) => *key,
-                    None => return Ok(ExecutionResult::precondition_failure(Error::Deploy)),
+                    None => return ExecutionResult::precondition_failure(Error::Deploy),
                 };
 
             let purse_balance_key = match tracking_copy.borrow_mut().get_purse_balance_key(
@@ -1171,7 +1920,7 @@ where
             ) {
                 Ok(key) => key,
                 Err(error) => {
-                    return Ok(ExecutionResult::precondition_failure(error.into()));
+                    return ExecutionResult::precondition_failure(error.into());
                 }
             };
 
@@ -1181,7 +1930,7 @@ where
             {
                 Ok(balance) => balance,
                 Err(error) => {
-                    return Ok(ExecutionResult::precondition_failure(error.into()));
+                    return ExecutionResult::precondition_failure(error.into());
                 }
             }
         };
@@ -1191,13 +1940,13 @@ where
                 ForcedTransferResult::InsufficientPayment => Error::InsufficientPayment,
                 ForcedTransferResult::PaymentFailure => payment_result.take_error().unwrap(),
             };
-            return Ok(ExecutionResult::new_payment_code_error(
+            return ExecutionResult::new_payment_code_error(
                 error,
                 max_payment_cost,
                 account_main_purse_balance,
                 account_main_purse_balance_key,
                 rewards_purse_balance_key,
-            ));
+            );
         }
 
         execution_result_builder.set_payment_execution_result(payment_result);
@@ -1216,9 +1965,11 @@ where
                 - payment_result_cost;
             let system_contract_cache = SystemContractCache::clone(&self.system_contract_cache);
 
+            let (args, named_args) = session.take_args();
             executor.exec(
                 session_module,
-                session.take_args(),
+                args,
+                named_args,
                 address,
                 &account,
                 authorization_keys.clone(),
@@ -1251,67 +2002,21 @@ where
             let post_session_tc = post_session_rc.borrow();
             let finalization_tc = Rc::new(RefCell::new(post_session_tc.fork()));
 
-            // validation_spec_1: valid wasm bytes
-            let proof_of_stake_module =
-                match self.system_contract_cache.get(&proof_of_stake_reference) {
-                    Some(module) => module,
-                    None => {
-                        let module =
-                            match engine_wasm_prep::deserialize(proof_of_stake_contract.bytes()) {
-                                Ok(module) => module,
-                                Err(error) => {
-                                    return Ok(ExecutionResult::precondition_failure(error.into()))
-                                }
-                            };
-                        self.system_contract_cache
-                            .insert(proof_of_stake_reference, module.clone());
-                        module
-                    }
-                };
-
-            let proof_of_stake_args = {
-                //((gas spent during payment code execution) + (gas spent during session code execution)) * conv_rate
-                let finalize_cost_motes: Motes = Motes::from_gas(execution_result_builder.total_cost(), CONV_RATE).expect("motes overflow");
-                let args = ("finalize_payment", finalize_cost_motes.value(), account_addr);
-                ArgsParser::parse(args)
-                    .expect("args should convert to `Vec<CLValue>`")
-                    .into_bytes()
-                    .expect("args should serialize")
-            };
-
-            // The PoS keys may have changed because of effects during payment and/or
-            // session, so we need to look them up again from the tracking copy
-            let proof_of_stake_contract = match finalization_tc
-                .borrow_mut()
-                .get_contract(correlation_id, Key::URef(proof_of_stake_reference))
-            {
-                Ok(info) => info,
-                Err(error) => return Ok(ExecutionResult::precondition_failure(error.into())),
-            };
-
-            let mut proof_of_stake_keys = proof_of_stake_contract.named_keys().to_owned();
-
-            let base_key = Key::from(proof_of_stake_reference);
-            let gas_limit = Gas::new(U512::from(std::u64::MAX));
-            let system_contract_cache = SystemContractCache::clone(&self.system_contract_cache);
-
-            executor.exec_finalize(
-                proof_of_stake_module,
-                proof_of_stake_args,
-                &mut proof_of_stake_keys,
-                base_key,
-                &system_account,
+            match self.finalize_payment(
+                correlation_id,
+                executor,
+                protocol_version,
+                protocol_data,
+                execution_result_builder.total_cost(),
+                account_addr,
                 authorization_keys,
                 blocktime,
                 deploy_hash,
-                gas_limit,
-                protocol_version,
-                correlation_id,
                 finalization_tc,
-                Phase::FinalizePayment,
-                protocol_data,
-                system_contract_cache,
-            )
+            ) {
+                Ok(finalize_result) => finalize_result,
+                Err(error) => return ExecutionResult::precondition_failure(error),
+            }
         };
 
         execution_result_builder.set_finalize_execution_result(finalize_result);
@@ -1324,7 +2029,93 @@ where
         // NOTE: payment_code_spec_5_a is enforced in execution_result_builder.build()
         // payment_code_spec_6: return properly combined set of transforms and
         // appropriate error
-        Ok(ret)
+        ret
+    }
+
+    /// Runs the proof of stake contract's `finalize_payment` method as the system account, charging
+    /// `cost` to the payment purse and refunding the rest of it to `account_addr`.
+    #[allow(clippy::too_many_arguments)]
+    fn finalize_payment<R>(
+        &self,
+        correlation_id: CorrelationId,
+        executor: &Executor,
+        protocol_version: ProtocolVersion,
+        protocol_data: ProtocolData,
+        cost: Gas,
+        account_addr: PublicKey,
+        authorization_keys: BTreeSet<PublicKey>,
+        blocktime: BlockTime,
+        deploy_hash: [u8; 32],
+        finalization_tc: Rc<RefCell<TrackingCopy<R>>>,
+    ) -> Result<ExecutionResult, Error>
+    where
+        R: StateReader<Key, StoredValue>,
+        R::Error: Into<execution::Error>,
+    {
+        let proof_of_stake_reference = protocol_data.proof_of_stake();
+
+        // The PoS keys may have changed because of effects during payment and/or
+        // session, so we need to look them up again from the tracking copy
+        let proof_of_stake_contract = finalization_tc
+            .borrow_mut()
+            .get_contract(correlation_id, Key::URef(proof_of_stake_reference))?;
+
+        // validation_spec_1: valid wasm bytes
+        let proof_of_stake_module = match self.system_contract_cache.get(&proof_of_stake_reference)
+        {
+            Some(module) => module,
+            None => {
+                let module = engine_wasm_prep::deserialize(proof_of_stake_contract.bytes())?;
+                self.system_contract_cache
+                    .insert(proof_of_stake_reference, module.clone());
+                module
+            }
+        };
+
+        let proof_of_stake_args = {
+            //((gas spent during payment code execution) + (gas spent during session code execution)) * conv_rate
+            let finalize_cost_motes: Motes =
+                Motes::from_gas(cost, CONV_RATE).expect("motes overflow");
+            let args = ("finalize_payment", finalize_cost_motes.value(), account_addr);
+            ArgsParser::parse(args)
+                .expect("args should convert to `Vec<CLValue>`")
+                .into_bytes()
+                .expect("args should serialize")
+        };
+
+        // Finalization is executed by system account (currently genesis account)
+        // payment_code_spec_5: system executes finalization
+        let system_account = Account::new(
+            SYSTEM_ACCOUNT_ADDR,
+            Default::default(),
+            URef::new(Default::default(), AccessRights::READ_ADD_WRITE),
+            Default::default(),
+            Default::default(),
+        );
+
+        let mut proof_of_stake_keys = proof_of_stake_contract.named_keys().to_owned();
+
+        let base_key = Key::from(proof_of_stake_reference);
+        let gas_limit = Gas::new(U512::from(std::u64::MAX));
+        let system_contract_cache = SystemContractCache::clone(&self.system_contract_cache);
+
+        Ok(executor.exec_finalize(
+            proof_of_stake_module,
+            proof_of_stake_args,
+            &mut proof_of_stake_keys,
+            base_key,
+            &system_account,
+            authorization_keys,
+            blocktime,
+            deploy_hash,
+            gas_limit,
+            protocol_version,
+            correlation_id,
+            finalization_tc,
+            Phase::FinalizePayment,
+            protocol_data,
+            system_contract_cache,
+        ))
     }
 
     pub fn apply_effect(
@@ -1383,12 +2174,694 @@ where
             _ => return Err(MissingSystemContract("proof of stake".to_string())),
         };
 
-        let bonded_validators = contract
+        let stakes_key = match contract.named_keys().get(POS_STAKES) {
+            Some(key) => *key,
+            None => {
+                // Proof of stake contracts which have not been migrated yet still encode the
+                // stakes in their named keys.
+                let bonded_validators = contract
+                    .named_keys()
+                    .keys()
+                    .filter_map(|entry| utils::pos_validator_key_name_to_tuple(entry))
+                    .collect::<HashMap<PublicKey, U512>>();
+                return Ok(bonded_validators);
+            }
+        };
+
+        let stakes: Stakes = match reader.read(correlation_id, &stakes_key.normalize())? {
+            Some(StoredValue::CLValue(cl_value)) => {
+                cl_value.into_t().map_err(execution::Error::from)?
+            }
+            _ => return Err(Error::Exec(execution::Error::KeyNotFound(stakes_key))),
+        };
+
+        Ok(stakes.0.into_iter().collect())
+    }
+
+    /// Returns the proof of stake contract's parameters at `root_hash`, or `None` if the root hash
+    /// doesn't exist.  Contracts installed before the parameters were configurable use the
+    /// defaults.
+    pub fn get_pos_params(
+        &self,
+        correlation_id: CorrelationId,
+        protocol_version: ProtocolVersion,
+        root_hash: Blake2bHash,
+    ) -> Result<Option<PosParams>, Error>
+    where
+        Error: From<S::Error>,
+    {
+        let protocol_data = match self.state.get_protocol_data(protocol_version)? {
+            Some(protocol_data) => protocol_data,
+            None => return Err(Error::InvalidProtocolVersion(protocol_version)),
+        };
+
+        let proof_of_stake = Key::URef(protocol_data.proof_of_stake()).normalize();
+
+        let reader = match self.state.checkout(root_hash)? {
+            Some(reader) => reader,
+            None => return Ok(None),
+        };
+
+        let contract = match reader.read(correlation_id, &proof_of_stake)? {
+            Some(StoredValue::Contract(contract)) => contract,
+            _ => return Err(MissingSystemContract("proof of stake".to_string())),
+        };
+
+        let params_key = match contract.named_keys().get(POS_PARAMS) {
+            Some(key) => *key,
+            None => return Ok(Some(PosParams::default())),
+        };
+
+        match reader.read(correlation_id, &params_key.normalize())? {
+            Some(StoredValue::CLValue(cl_value)) => {
+                let params = cl_value.into_t().map_err(execution::Error::from)?;
+                Ok(Some(params))
+            }
+            _ => Err(Error::Exec(execution::Error::KeyNotFound(params_key))),
+        }
+    }
+}
+
+/// Moves the stakes and the bonding and unbonding queues of the proof of stake contract from their
+/// legacy encodings, i.e. `v_{public_key}_{stake}` named keys and local state entries, to typed
+/// values under their own `URef`s.  Does nothing if the contract has already been migrated.
+///
+/// The legacy local state entries are left in place, but are not read anymore.
+fn migrate_proof_of_stake_storage<R>(
+    correlation_id: CorrelationId,
+    tracking_copy: &mut TrackingCopy<R>,
+    address_generator: &mut AddressGenerator,
+    proof_of_stake_reference: URef,
+) -> Result<(), Error>
+where
+    R: StateReader<Key, StoredValue>,
+    R::Error: Into<execution::Error>,
+{
+    const LEGACY_BONDING_KEY: u8 = 1;
+    const LEGACY_UNBONDING_KEY: u8 = 2;
+
+    let proof_of_stake_key = Key::URef(proof_of_stake_reference);
+    let mut contract = tracking_copy.get_contract(correlation_id, proof_of_stake_key)?;
+    if contract.named_keys().contains_key(POS_STAKES) {
+        return Ok(());
+    }
+
+    let legacy_stakes: Vec<(String, PublicKey, U512)> = contract
+        .named_keys()
+        .keys()
+        .filter_map(|name| {
+            utils::pos_validator_key_name_to_tuple(name)
+                .map(|(public_key, stake)| (name.clone(), public_key, stake))
+        })
+        .collect();
+    let mut stakes = BTreeMap::new();
+    for (name, public_key, stake) in legacy_stakes {
+        contract.named_keys_mut().remove(&name);
+        stakes.insert(public_key, stake);
+    }
+
+    let mut values = vec![(
+        POS_STAKES,
+        CLValue::from_t(Stakes::new(stakes)).map_err(execution::Error::from)?,
+    )];
+    for (name, legacy_key) in &[
+        (POS_BONDING_QUEUE, LEGACY_BONDING_KEY),
+        (POS_UNBONDING_QUEUE, LEGACY_UNBONDING_KEY),
+    ] {
+        let key = Key::local(
+            proof_of_stake_reference.addr(),
+            &(*legacy_key).into_bytes()?,
+        );
+        let queue = match tracking_copy
+            .read(correlation_id, &key)
+            .map_err(|error| Error::Exec(error.into()))?
+        {
+            Some(StoredValue::CLValue(cl_value)) => cl_value,
+            _ => CLValue::from_t(Queue::default()).map_err(execution::Error::from)?,
+        };
+        values.push((*name, queue));
+    }
+
+    for (name, value) in values {
+        let uref = URef::new(
+            address_generator.create_address(),
+            AccessRights::READ_ADD_WRITE,
+        );
+        tracking_copy.write(Key::URef(uref), StoredValue::CLValue(value));
+        contract
+            .named_keys_mut()
+            .insert(name.to_string(), Key::URef(uref));
+    }
+    tracking_copy.write(proof_of_stake_key, StoredValue::Contract(contract));
+
+    Ok(())
+}
+
+/// Moves the delegations, the delegation and undelegation queues, the last era's rewards and the
+/// jail of the proof of stake contract from its local state to typed values under their own
+/// `URef`s, creating empty ones where there is nothing to move.  Values which already have a
+/// `URef` are left untouched.
+///
+/// The legacy local state entries are left in place, but are not read anymore.
+fn migrate_proof_of_stake_delegation_storage<R>(
+    correlation_id: CorrelationId,
+    tracking_copy: &mut TrackingCopy<R>,
+    address_generator: &mut AddressGenerator,
+    proof_of_stake_reference: URef,
+) -> Result<(), Error>
+where
+    R: StateReader<Key, StoredValue>,
+    R::Error: Into<execution::Error>,
+{
+    const LEGACY_ERA_REWARDS_KEY: u8 = 3;
+    const LEGACY_DELEGATIONS_KEY: u8 = 4;
+    const LEGACY_DELEGATING_KEY: u8 = 5;
+    const LEGACY_UNDELEGATING_KEY: u8 = 6;
+    const LEGACY_JAIL_KEY: u8 = 7;
+
+    let proof_of_stake_key = Key::URef(proof_of_stake_reference);
+    let mut contract = tracking_copy.get_contract(correlation_id, proof_of_stake_key)?;
+    let mut read_legacy = |legacy_key: u8| -> Result<Option<Vec<u8>>, Error> {
+        let key = Key::local(proof_of_stake_reference.addr(), &legacy_key.into_bytes()?);
+        match tracking_copy
+            .read(correlation_id, &key)
+            .map_err(|error| Error::Exec(error.into()))?
+        {
+            Some(StoredValue::CLValue(cl_value)) => Ok(Some(cl_value.destructure().1)),
+            _ => Ok(None),
+        }
+    };
+
+    // The legacy values were recorded as `CLType::Any`, so they are decoded from their bytes and
+    // stored again with their proper types.
+    let delegations: Delegations = match read_legacy(LEGACY_DELEGATIONS_KEY)? {
+        Some(bytes) => bytesrepr::deserialize(bytes)?,
+        None => Delegations::default(),
+    };
+    let delegating_queue: DelegationQueue = match read_legacy(LEGACY_DELEGATING_KEY)? {
+        Some(bytes) => bytesrepr::deserialize(bytes)?,
+        None => DelegationQueue::default(),
+    };
+    let undelegating_queue: DelegationQueue = match read_legacy(LEGACY_UNDELEGATING_KEY)? {
+        Some(bytes) => bytesrepr::deserialize(bytes)?,
+        None => DelegationQueue::default(),
+    };
+    // The legacy local state held the era rewards themselves rather than an `Option`.
+    let era_rewards: Option<EraRewards> = match read_legacy(LEGACY_ERA_REWARDS_KEY)? {
+        Some(bytes) => Some(bytesrepr::deserialize(bytes)?),
+        None => None,
+    };
+    let jail: Jail = match read_legacy(LEGACY_JAIL_KEY)? {
+        Some(bytes) => bytesrepr::deserialize(bytes)?,
+        None => Jail::default(),
+    };
+
+    let values = vec![
+        (POS_DELEGATIONS, CLValue::from_t(delegations)),
+        (POS_DELEGATING_QUEUE, CLValue::from_t(delegating_queue)),
+        (POS_UNDELEGATING_QUEUE, CLValue::from_t(undelegating_queue)),
+        (POS_ERA_REWARDS, CLValue::from_t(era_rewards)),
+        (POS_JAIL, CLValue::from_t(jail)),
+    ];
+
+    let mut migrated = false;
+    for (name, value) in values {
+        if contract.named_keys().contains_key(name) {
+            continue;
+        }
+        let value = value.map_err(execution::Error::from)?;
+        let uref = URef::new(
+            address_generator.create_address(),
+            AccessRights::READ_ADD_WRITE,
+        );
+        tracking_copy.write(Key::URef(uref), StoredValue::CLValue(value));
+        contract
+            .named_keys_mut()
+            .insert(name.to_string(), Key::URef(uref));
+        migrated = true;
+    }
+    if migrated {
+        tracking_copy.write(proof_of_stake_key, StoredValue::Contract(contract));
+    }
+
+    Ok(())
+}
+
+/// Writes `maybe_params` to the proof of stake contract's parameters `URef`.  If the contract
+/// predates configurable parameters, the `URef` is created, holding the defaults unless new
+/// parameters are given.  Does nothing if the contract has parameters and none are given.
+fn update_proof_of_stake_params<R>(
+    correlation_id: CorrelationId,
+    tracking_copy: &mut TrackingCopy<R>,
+    address_generator: &mut AddressGenerator,
+    proof_of_stake_reference: URef,
+    maybe_params: Option<PosParams>,
+) -> Result<(), Error>
+where
+    R: StateReader<Key, StoredValue>,
+    R::Error: Into<execution::Error>,
+{
+    let proof_of_stake_key = Key::URef(proof_of_stake_reference);
+    let mut contract = tracking_copy.get_contract(correlation_id, proof_of_stake_key)?;
+    let existing_key = contract.named_keys().get(POS_PARAMS).cloned();
+    let params = match (existing_key, maybe_params) {
+        (Some(_), None) => return Ok(()),
+        (_, Some(params)) => params,
+        (None, None) => PosParams::default(),
+    };
+    let value = StoredValue::CLValue(CLValue::from_t(params).map_err(execution::Error::from)?);
+
+    match existing_key {
+        Some(key) => tracking_copy.write(key, value),
+        None => {
+            let uref = URef::new(
+                address_generator.create_address(),
+                AccessRights::READ_ADD_WRITE,
+            );
+            tracking_copy.write(Key::URef(uref), value);
+            contract
+                .named_keys_mut()
+                .insert(POS_PARAMS.to_string(), Key::URef(uref));
+            tracking_copy.write(proof_of_stake_key, StoredValue::Contract(contract));
+        }
+    }
+
+    Ok(())
+}
+
+/// Creates the proof of stake contract's treasury purse if the contract predates it.  The purse is
+/// created empty, the way the mint creates purses.  Does nothing if the contract has the purse.
+fn create_proof_of_stake_treasury_purse<R>(
+    correlation_id: CorrelationId,
+    tracking_copy: &mut TrackingCopy<R>,
+    address_generator: &mut AddressGenerator,
+    mint_reference: URef,
+    proof_of_stake_reference: URef,
+) -> Result<(), Error>
+where
+    R: StateReader<Key, StoredValue>,
+    R::Error: Into<execution::Error>,
+{
+    let proof_of_stake_key = Key::URef(proof_of_stake_reference);
+    let mut proof_of_stake = tracking_copy.get_contract(correlation_id, proof_of_stake_key)?;
+    if proof_of_stake.named_keys().contains_key(POS_TREASURY_PURSE) {
+        return Ok(());
+    }
+    let mint_key = Key::URef(mint_reference);
+    let mut mint = tracking_copy.get_contract(correlation_id, mint_key)?;
+
+    let balance_uref = URef::new(
+        address_generator.create_address(),
+        AccessRights::READ_ADD_WRITE,
+    );
+    let balance = CLValue::from_t(U512::zero()).map_err(execution::Error::from)?;
+    tracking_copy.write(Key::URef(balance_uref), StoredValue::CLValue(balance));
+
+    let purse = URef::new(
+        address_generator.create_address(),
+        AccessRights::READ_ADD_WRITE,
+    );
+    let unit = CLValue::from_t(()).map_err(execution::Error::from)?;
+    tracking_copy.write(Key::URef(purse), StoredValue::CLValue(unit));
+
+    let balance_key = Key::local(mint_reference.addr(), &purse.addr().into_bytes()?);
+    let balance_uref_value =
+        CLValue::from_t(Key::URef(balance_uref)).map_err(execution::Error::from)?;
+    tracking_copy.write(balance_key, StoredValue::CLValue(balance_uref_value));
+    mint.named_keys_mut().insert(
+        purse.remove_access_rights().as_string(),
+        Key::URef(balance_uref),
+    );
+    tracking_copy.write(mint_key, StoredValue::Contract(mint));
+
+    proof_of_stake
+        .named_keys_mut()
+        .insert(POS_TREASURY_PURSE.to_string(), Key::URef(purse));
+    tracking_copy.write(proof_of_stake_key, StoredValue::Contract(proof_of_stake));
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use engine_shared::{contract::Contract, newtypes::CorrelationId, stored_value::StoredValue};
+    use engine_storage::global_state::{
+        in_memory::InMemoryGlobalState, StateProvider, StateReader,
+    };
+    use proof_of_stake::{
+        DelegationQueue, Delegations, EraRewards, Jail, PosParams, Queue, Stakes,
+    };
+    use types::{
+        account::PublicKey,
+        bytesrepr::{FromBytes, ToBytes},
+        AccessRights, BlockTime, CLType, CLTyped, CLValue, Key, Phase, ProtocolVersion, URef, U512,
+    };
+
+    use super::{
+        create_proof_of_stake_treasury_purse,
+        genesis::{
+            POS_BONDING_PURSE, POS_BONDING_QUEUE, POS_DELEGATING_QUEUE, POS_DELEGATIONS,
+            POS_ERA_REWARDS, POS_JAIL, POS_PARAMS, POS_STAKES, POS_TREASURY_PURSE,
+            POS_UNBONDING_QUEUE, POS_UNDELEGATING_QUEUE,
+        },
+        migrate_proof_of_stake_delegation_storage, migrate_proof_of_stake_storage,
+        update_proof_of_stake_params,
+    };
+    use crate::{
+        execution::{self, AddressGenerator},
+        tracking_copy::{TrackingCopy, TrackingCopyExt},
+    };
+
+    const MINT_ADDR: [u8; 32] = [2; 32];
+    const POS_ADDR: [u8; 32] = [1; 32];
+    const VALIDATOR: [u8; 32] = [42; 32];
+
+    fn read_named_value<R, T>(
+        tracking_copy: &mut TrackingCopy<R>,
+        contract: &Contract,
+        name: &str,
+    ) -> T
+    where
+        R: StateReader<Key, StoredValue>,
+        T: CLTyped + FromBytes,
+    {
+        let key = contract.named_keys()[name];
+        match tracking_copy.read(CorrelationId::new(), &key) {
+            Ok(Some(StoredValue::CLValue(cl_value))) => cl_value.into_t().expect("should convert"),
+            _ => panic!("should have value under {}", name),
+        }
+    }
+
+    fn read_params<R>(tracking_copy: &mut TrackingCopy<R>, pos_uref: URef) -> (Key, PosParams)
+    where
+        R: StateReader<Key, StoredValue>,
+        R::Error: Into<execution::Error>,
+    {
+        let contract = tracking_copy
+            .get_contract(CorrelationId::new(), Key::URef(pos_uref))
+            .expect("should have contract");
+        let params = read_named_value(tracking_copy, &contract, POS_PARAMS);
+        (contract.named_keys()[POS_PARAMS], params)
+    }
+
+    #[test]
+    fn should_migrate_legacy_proof_of_stake_storage() {
+        let correlation_id = CorrelationId::new();
+        let pos_uref = URef::new(POS_ADDR, AccessRights::READ_ADD_WRITE);
+        let validator = PublicKey::ed25519_from(VALIDATOR);
+        let bonding_purse = URef::new([2; 32], AccessRights::READ_ADD_WRITE);
+
+        let contract = {
+            let legacy_stake_name =
+                format!("v_{}_{}", base16::encode_lower(&VALIDATOR), U512::from(100));
+            let named_keys = vec![
+                (legacy_stake_name, Key::Hash([0; 32])),
+                (POS_BONDING_PURSE.to_string(), Key::URef(bonding_purse)),
+            ]
+            .into_iter()
+            .collect();
+            Contract::new(vec![], named_keys, ProtocolVersion::V1_0_0)
+        };
+        let mut bonding_queue = Queue::default();
+        bonding_queue
+            .push(validator, U512::from(10), BlockTime::new(1))
+            .expect("should push");
+        let legacy_bonding_key = Key::local(POS_ADDR, &1u8.into_bytes().expect("should serialize"));
+
+        let (state, root_hash) = InMemoryGlobalState::from_pairs(
+            correlation_id,
+            &[
+                (Key::URef(pos_uref), StoredValue::Contract(contract)),
+                (
+                    legacy_bonding_key,
+                    StoredValue::CLValue(CLValue::from_t(bonding_queue.clone()).unwrap()),
+                ),
+            ],
+        )
+        .expect("should create state");
+        let reader = state
+            .checkout(root_hash)
+            .expect("should checkout")
+            .expect("should have reader");
+        let mut tracking_copy = TrackingCopy::new(reader);
+        let mut address_generator = AddressGenerator::new(&root_hash.value(), Phase::System);
+
+        migrate_proof_of_stake_storage(
+            correlation_id,
+            &mut tracking_copy,
+            &mut address_generator,
+            pos_uref,
+        )
+        .expect("should migrate");
+
+        let contract = tracking_copy
+            .get_contract(correlation_id, Key::URef(pos_uref))
+            .expect("should have contract");
+        assert!(!contract
             .named_keys()
             .keys()
-            .filter_map(|entry| utils::pos_validator_key_name_to_tuple(entry))
-            .collect::<HashMap<PublicKey, U512>>();
+            .any(|name| name.starts_with("v_")));
+        assert_eq!(
+            contract.named_keys().get(POS_BONDING_PURSE),
+            Some(&Key::URef(bonding_purse))
+        );
 
-        Ok(bonded_validators)
+        let stakes: Stakes = read_named_value(&mut tracking_copy, &contract, POS_STAKES);
+        let expected_stakes = Stakes::new(vec![(validator, U512::from(100))].into_iter().collect());
+        assert_eq!(stakes, expected_stakes);
+        let migrated_bonding_queue: Queue =
+            read_named_value(&mut tracking_copy, &contract, POS_BONDING_QUEUE);
+        assert_eq!(migrated_bonding_queue.0, bonding_queue.0);
+        let migrated_unbonding_queue: Queue =
+            read_named_value(&mut tracking_copy, &contract, POS_UNBONDING_QUEUE);
+        assert!(migrated_unbonding_queue.0.is_empty());
+
+        // Migrating again does not change anything.
+        migrate_proof_of_stake_storage(
+            correlation_id,
+            &mut tracking_copy,
+            &mut address_generator,
+            pos_uref,
+        )
+        .expect("should migrate");
+        let migrated_contract = tracking_copy
+            .get_contract(correlation_id, Key::URef(pos_uref))
+            .expect("should have contract");
+        assert_eq!(migrated_contract, contract);
+    }
+
+    #[test]
+    fn should_migrate_proof_of_stake_delegation_storage() {
+        let correlation_id = CorrelationId::new();
+        let pos_uref = URef::new(POS_ADDR, AccessRights::READ_ADD_WRITE);
+        let validator = PublicKey::ed25519_from(VALIDATOR);
+        let delegator = PublicKey::ed25519_from([43; 32]);
+        let contract = Contract::new(vec![], Default::default(), ProtocolVersion::V1_0_0);
+
+        let mut delegations = Delegations::default();
+        delegations.delegate(&validator, &delegator, U512::from(10));
+        let legacy_delegations_key =
+            Key::local(POS_ADDR, &4u8.into_bytes().expect("should serialize"));
+
+        let (state, root_hash) = InMemoryGlobalState::from_pairs(
+            correlation_id,
+            &[
+                (Key::URef(pos_uref), StoredValue::Contract(contract)),
+                (
+                    legacy_delegations_key,
+                    StoredValue::CLValue(CLValue::from_components(
+                        CLType::Any,
+                        delegations.to_bytes().expect("should serialize"),
+                    )),
+                ),
+            ],
+        )
+        .expect("should create state");
+        let reader = state
+            .checkout(root_hash)
+            .expect("should checkout")
+            .expect("should have reader");
+        let mut tracking_copy = TrackingCopy::new(reader);
+        let mut address_generator = AddressGenerator::new(&root_hash.value(), Phase::System);
+
+        migrate_proof_of_stake_delegation_storage(
+            correlation_id,
+            &mut tracking_copy,
+            &mut address_generator,
+            pos_uref,
+        )
+        .expect("should migrate");
+
+        let contract = tracking_copy
+            .get_contract(correlation_id, Key::URef(pos_uref))
+            .expect("should have contract");
+        let migrated_delegations: Delegations =
+            read_named_value(&mut tracking_copy, &contract, POS_DELEGATIONS);
+        assert_eq!(migrated_delegations, delegations);
+        let delegating_queue: DelegationQueue =
+            read_named_value(&mut tracking_copy, &contract, POS_DELEGATING_QUEUE);
+        assert!(delegating_queue.0.is_empty());
+        let undelegating_queue: DelegationQueue =
+            read_named_value(&mut tracking_copy, &contract, POS_UNDELEGATING_QUEUE);
+        assert!(undelegating_queue.0.is_empty());
+        let era_rewards: Option<EraRewards> =
+            read_named_value(&mut tracking_copy, &contract, POS_ERA_REWARDS);
+        assert_eq!(era_rewards, None);
+        let jail: Jail = read_named_value(&mut tracking_copy, &contract, POS_JAIL);
+        assert_eq!(jail, Jail::default());
+
+        // Migrating again does not change anything.
+        migrate_proof_of_stake_delegation_storage(
+            correlation_id,
+            &mut tracking_copy,
+            &mut address_generator,
+            pos_uref,
+        )
+        .expect("should migrate");
+        let migrated_contract = tracking_copy
+            .get_contract(correlation_id, Key::URef(pos_uref))
+            .expect("should have contract");
+        assert_eq!(migrated_contract, contract);
+    }
+
+    #[test]
+    fn should_update_proof_of_stake_params() {
+        let correlation_id = CorrelationId::new();
+        let pos_uref = URef::new(POS_ADDR, AccessRights::READ_ADD_WRITE);
+        let contract = Contract::new(vec![], Default::default(), ProtocolVersion::V1_0_0);
+
+        let (state, root_hash) = InMemoryGlobalState::from_pairs(
+            correlation_id,
+            &[(Key::URef(pos_uref), StoredValue::Contract(contract))],
+        )
+        .expect("should create state");
+        let reader = state
+            .checkout(root_hash)
+            .expect("should checkout")
+            .expect("should have reader");
+        let mut tracking_copy = TrackingCopy::new(reader);
+        let mut address_generator = AddressGenerator::new(&root_hash.value(), Phase::System);
+        // A contract without parameters gets the defaults.
+        update_proof_of_stake_params(
+            correlation_id,
+            &mut tracking_copy,
+            &mut address_generator,
+            pos_uref,
+            None,
+        )
+        .expect("should update params");
+        let (params_key, params) = read_params(&mut tracking_copy, pos_uref);
+        assert_eq!(params, PosParams::default());
+
+        // New parameters are written under the existing key.
+        let new_params = PosParams {
+            bond_delay: 10,
+            max_unbond_len: 5,
+            ..Default::default()
+        };
+        update_proof_of_stake_params(
+            correlation_id,
+            &mut tracking_copy,
+            &mut address_generator,
+            pos_uref,
+            Some(new_params),
+        )
+        .expect("should update params");
+        assert_eq!(
+            read_params(&mut tracking_copy, pos_uref),
+            (params_key, new_params)
+        );
+
+        // Without new parameters, the current ones are kept.
+        update_proof_of_stake_params(
+            correlation_id,
+            &mut tracking_copy,
+            &mut address_generator,
+            pos_uref,
+            None,
+        )
+        .expect("should update params");
+        assert_eq!(
+            read_params(&mut tracking_copy, pos_uref),
+            (params_key, new_params)
+        );
+    }
+
+    #[test]
+    fn should_create_proof_of_stake_treasury_purse() {
+        let correlation_id = CorrelationId::new();
+        let mint_uref = URef::new(MINT_ADDR, AccessRights::READ_ADD_WRITE);
+        let pos_uref = URef::new(POS_ADDR, AccessRights::READ_ADD_WRITE);
+        let contract = Contract::new(vec![], Default::default(), ProtocolVersion::V1_0_0);
+
+        let (state, root_hash) = InMemoryGlobalState::from_pairs(
+            correlation_id,
+            &[
+                (
+                    Key::URef(mint_uref),
+                    StoredValue::Contract(contract.clone()),
+                ),
+                (Key::URef(pos_uref), StoredValue::Contract(contract)),
+            ],
+        )
+        .expect("should create state");
+        let reader = state
+            .checkout(root_hash)
+            .expect("should checkout")
+            .expect("should have reader");
+        let mut tracking_copy = TrackingCopy::new(reader);
+        let mut address_generator = AddressGenerator::new(&root_hash.value(), Phase::System);
+
+        create_proof_of_stake_treasury_purse(
+            correlation_id,
+            &mut tracking_copy,
+            &mut address_generator,
+            mint_uref,
+            pos_uref,
+        )
+        .expect("should create purse");
+
+        let contract = tracking_copy
+            .get_contract(correlation_id, Key::URef(pos_uref))
+            .expect("should have contract");
+        let purse = match contract.named_keys().get(POS_TREASURY_PURSE) {
+            Some(Key::URef(purse)) => *purse,
+            _ => panic!("should have treasury purse"),
+        };
+        let balance_key = Key::local(MINT_ADDR, &purse.addr().into_bytes().unwrap());
+        let balance_uref = match tracking_copy.read(correlation_id, &balance_key) {
+            Ok(Some(StoredValue::CLValue(cl_value))) => {
+                cl_value.into_t::<Key>().expect("should convert")
+            }
+            _ => panic!("should have balance key"),
+        };
+        let mint = tracking_copy
+            .get_contract(correlation_id, Key::URef(mint_uref))
+            .expect("should have mint");
+        assert_eq!(
+            mint.named_keys()
+                .get(&purse.remove_access_rights().as_string()),
+            Some(&balance_uref)
+        );
+        match tracking_copy.read(correlation_id, &balance_uref) {
+            Ok(Some(StoredValue::CLValue(cl_value))) => {
+                assert_eq!(cl_value.into_t::<U512>().unwrap(), U512::zero())
+            }
+            _ => panic!("should have balance"),
+        }
+
+        // Creating the purse again does not change anything.
+        create_proof_of_stake_treasury_purse(
+            correlation_id,
+            &mut tracking_copy,
+            &mut address_generator,
+            mint_uref,
+            pos_uref,
+        )
+        .expect("should create purse");
+        let migrated_contract = tracking_copy
+            .get_contract(correlation_id, Key::URef(pos_uref))
+            .expect("should have contract");
+        assert_eq!(migrated_contract, contract);
     }
 }
@@ -0,0 +1,51 @@
+use engine_shared::newtypes::Blake2bHash;
+use types::ProtocolVersion;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DistributeRewardsRequest {
+    parent_state_hash: Blake2bHash,
+    protocol_version: ProtocolVersion,
+    era_id: u64,
+    inflation_rate: u64,
+    commission_rate: u64,
+}
+
+impl DistributeRewardsRequest {
+    pub fn new(
+        parent_state_hash: Blake2bHash,
+        protocol_version: ProtocolVersion,
+        era_id: u64,
+        inflation_rate: u64,
+        commission_rate: u64,
+    ) -> Self {
+        DistributeRewardsRequest {
+            parent_state_hash,
+            protocol_version,
+            era_id,
+            inflation_rate,
+            commission_rate,
+        }
+    }
+
+    pub fn parent_state_hash(&self) -> Blake2bHash {
+        self.parent_state_hash
+    }
+
+    pub fn protocol_version(&self) -> ProtocolVersion {
+        self.protocol_version
+    }
+
+    pub fn era_id(&self) -> u64 {
+        self.era_id
+    }
+
+    /// The share of the total supply minted as inflation for the era, in millionths.
+    pub fn inflation_rate(&self) -> u64 {
+        self.inflation_rate
+    }
+
+    /// The share of each validator's reward paid out rather than restaked, in millionths.
+    pub fn commission_rate(&self) -> u64 {
+        self.commission_rate
+    }
+}
@@ -0,0 +1,831 @@
+use engine_shared::{
+    account::Account,
+    additive_map::AdditiveMap,
+    gas::Gas,
+    motes::Motes,
+    newtypes::CorrelationId,
+    stored_value::StoredValue,
+    transform::{self, Transform},
+};
+use engine_storage::global_state::StateReader;
+use types::{
+    account::PublicKey,
+    bytesrepr::{self, FromBytes, ToBytes},
+    BlockTime, CLType, CLTyped, CLValue, Key, URef, U512,
+};
+
+use crate::{
+    engine_state::{
+        executable_deploy_item::ExecutableDeployItem, execution_result::ExecutionResult,
+        genesis::POS_REFUND_PURSE, Error, MAX_PAYMENT, SYSTEM_ACCOUNT_ADDR,
+    },
+    execution,
+    tracking_copy::{AddResult, TrackingCopy, TrackingCopyExt},
+};
+
+/// Prefix of the system account's named keys under which pending scheduled calls are indexed.
+pub const SCHEDULED_CALL_PREFIX: &str = "scheduled_call_";
+
+/// The fewest motes a call can be scheduled with, matching the balance a deploy's account needs.
+pub const MIN_ESCROW: u64 = MAX_PAYMENT;
+
+/// The most due calls an execute request runs.  Any others stay on the schedule for later blocks.
+pub const MAX_DUE_CALLS_PER_REQUEST: usize = 10;
+
+/// The context a scheduled call runs in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CallContext {
+    /// The call runs as session code of the account which scheduled it.
+    Session,
+    /// The call runs in the context of the stored contract which scheduled it, i.e. with that
+    /// contract's named keys.  A contract can only schedule calls to itself.
+    Contract,
+}
+
+impl CallContext {
+    fn tag(self) -> u8 {
+        match self {
+            CallContext::Session => 0,
+            CallContext::Contract => 1,
+        }
+    }
+}
+
+impl ToBytes for CallContext {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        self.tag().to_bytes()
+    }
+}
+
+impl FromBytes for CallContext {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (tag, rem): (u8, &[u8]) = FromBytes::from_bytes(bytes)?;
+        let context = match tag {
+            0 => CallContext::Session,
+            1 => CallContext::Contract,
+            _ => return Err(bytesrepr::Error::Formatting),
+        };
+        Ok((context, rem))
+    }
+}
+
+/// A call to a stored contract which runs once the block time reaches `due`.
+///
+/// The call runs in the given context, on behalf of the account whose deploy scheduled it but
+/// without any authorization keys.  Its gas is paid for by the motes held in `escrow_purse`, and
+/// whatever isn't spent is refunded to `refund_purse`, the purse the escrow was taken from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScheduledCall {
+    account: PublicKey,
+    context: CallContext,
+    contract: Key,
+    args: Vec<u8>,
+    due: BlockTime,
+    escrow_purse: URef,
+    refund_purse: URef,
+}
+
+impl ScheduledCall {
+    pub fn new(
+        account: PublicKey,
+        context: CallContext,
+        contract: Key,
+        args: Vec<u8>,
+        due: BlockTime,
+        escrow_purse: URef,
+        refund_purse: URef,
+    ) -> Self {
+        ScheduledCall {
+            account,
+            context,
+            contract,
+            args,
+            due,
+            escrow_purse,
+            refund_purse,
+        }
+    }
+
+    /// The account the call runs on behalf of.
+    pub fn account(&self) -> PublicKey {
+        self.account
+    }
+
+    /// The context the call runs in.
+    pub fn context(&self) -> CallContext {
+        self.context
+    }
+
+    /// The hash or URef key of the stored contract to call.
+    pub fn contract(&self) -> Key {
+        self.contract
+    }
+
+    /// The serialized arguments to call the contract with.
+    pub fn args(&self) -> &[u8] {
+        &self.args
+    }
+
+    /// The block time from which the call is due.
+    pub fn due(&self) -> BlockTime {
+        self.due
+    }
+
+    /// The purse holding the motes which pay for the call.
+    pub fn escrow_purse(&self) -> URef {
+        self.escrow_purse
+    }
+
+    /// The purse which receives whatever the call doesn't spend.
+    pub fn refund_purse(&self) -> URef {
+        self.refund_purse
+    }
+
+    /// Returns the call as session code, or `None` if the contract key is neither a hash nor a
+    /// URef.
+    pub fn into_session(self) -> Option<ExecutableDeployItem> {
+        let args = self.args;
+        match self.contract {
+            Key::Hash(hash) => Some(ExecutableDeployItem::StoredContractByHash {
+                hash: hash.to_vec(),
+                args,
+                named_args: Vec::new(),
+            }),
+            Key::URef(uref) => Some(ExecutableDeployItem::StoredContractByURef {
+                uref: uref.addr().to_vec(),
+                args,
+                named_args: Vec::new(),
+            }),
+            _ => None,
+        }
+    }
+}
+
+impl ToBytes for ScheduledCall {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = Vec::new();
+        result.append(&mut self.account.to_bytes()?);
+        result.append(&mut self.context.to_bytes()?);
+        result.append(&mut self.contract.to_bytes()?);
+        result.append(&mut self.args.to_bytes()?);
+        result.append(&mut self.due.to_bytes()?);
+        result.append(&mut self.escrow_purse.to_bytes()?);
+        result.append(&mut self.refund_purse.to_bytes()?);
+        Ok(result)
+    }
+}
+
+impl FromBytes for ScheduledCall {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (account, rem): (PublicKey, &[u8]) = FromBytes::from_bytes(bytes)?;
+        let (context, rem): (CallContext, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (contract, rem): (Key, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (args, rem): (Vec<u8>, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (due, rem): (BlockTime, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (escrow_purse, rem): (URef, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (refund_purse, rem): (URef, &[u8]) = FromBytes::from_bytes(rem)?;
+        let call = ScheduledCall::new(
+            account,
+            context,
+            contract,
+            args,
+            due,
+            escrow_purse,
+            refund_purse,
+        );
+        Ok((call, rem))
+    }
+}
+
+impl CLTyped for ScheduledCall {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+/// A scheduled call which is due, along with the name and hash under which it was scheduled.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct DueCall {
+    pub name: String,
+    pub hash: [u8; 32],
+    pub call: ScheduledCall,
+}
+
+/// Returns the name of the system account's named key which indexes the call stored under `hash`.
+pub fn named_key_name(hash: [u8; 32]) -> String {
+    format!("{}{}", SCHEDULED_CALL_PREFIX, base16::encode_lower(&hash))
+}
+
+/// Returns the earliest of the scheduled calls which are due at `block_time`, up to
+/// [`MAX_DUE_CALLS_PER_REQUEST`] of them, in the order they became due.
+pub(crate) fn due_calls<R>(
+    correlation_id: CorrelationId,
+    tracking_copy: &mut TrackingCopy<R>,
+    block_time: BlockTime,
+) -> Result<Vec<DueCall>, Error>
+where
+    R: StateReader<Key, StoredValue>,
+    R::Error: Into<execution::Error>,
+{
+    let system_account = tracking_copy.get_account(correlation_id, SYSTEM_ACCOUNT_ADDR)?;
+
+    let mut due_calls = Vec::new();
+    for (name, key) in system_account.named_keys() {
+        let hash = match key {
+            Key::Hash(hash) if name.starts_with(SCHEDULED_CALL_PREFIX) => *hash,
+            _ => continue,
+        };
+        let call: ScheduledCall = match tracking_copy
+            .get(correlation_id, key)
+            .map_err(|error| Error::Exec(error.into()))?
+        {
+            Some(StoredValue::CLValue(cl_value)) => cl_value
+                .into_t()
+                .map_err(|error| Error::Exec(error.into()))?,
+            _ => return Err(Error::Exec(execution::Error::KeyNotFound(*key))),
+        };
+        if call.due() <= block_time {
+            due_calls.push(DueCall {
+                name: name.clone(),
+                hash,
+                call,
+            });
+        }
+    }
+    // Named keys are ordered by name, i.e. by hash, which breaks ties between calls due together.
+    due_calls.sort_by_key(|due_call| due_call.call.due());
+    due_calls.truncate(MAX_DUE_CALLS_PER_REQUEST);
+    Ok(due_calls)
+}
+
+/// Writes `system_account` without the named key `name`, which takes the call it indexes off the
+/// schedule.
+pub(crate) fn unschedule<R>(
+    tracking_copy: &mut TrackingCopy<R>,
+    system_account: &Account,
+    name: &str,
+) where
+    R: StateReader<Key, StoredValue>,
+{
+    let mut system_account = system_account.clone();
+    system_account.named_keys_mut().remove(name);
+    tracking_copy.write(
+        Key::Account(SYSTEM_ACCOUNT_ADDR),
+        StoredValue::Account(system_account),
+    );
+}
+
+/// Drops `call` on top of `tracking_copy`, failing with `error`: the call is taken off the schedule
+/// and its escrow is refunded, but nothing else it did is kept.  If the refund fails, the call is
+/// still taken off the schedule and the refund's error is reported instead.
+pub(crate) fn drop_call<R>(
+    correlation_id: CorrelationId,
+    tracking_copy: &TrackingCopy<R>,
+    mint_reference: URef,
+    system_account: &Account,
+    name: &str,
+    call: &ScheduledCall,
+    error: Error,
+) -> ExecutionResult
+where
+    R: StateReader<Key, StoredValue>,
+    R::Error: Into<execution::Error>,
+{
+    let mut tracking_copy = tracking_copy.fork();
+    unschedule(&mut tracking_copy, system_account, name);
+    let refund_result = drain_escrow(
+        correlation_id,
+        &mut tracking_copy,
+        mint_reference,
+        call.escrow_purse(),
+        Key::URef(call.refund_purse()),
+    );
+    let error = match refund_result {
+        Ok(_) => error,
+        Err(refund_error) => refund_error,
+    };
+    ExecutionResult::Failure {
+        error,
+        effect: tracking_copy.effect(),
+        cost: Gas::default(),
+    }
+}
+
+/// Moves the whole balance of `escrow_purse` into `target_purse`, returning the amount moved.  If
+/// that fails, nothing is moved.
+pub(crate) fn drain_escrow<R>(
+    correlation_id: CorrelationId,
+    tracking_copy: &mut TrackingCopy<R>,
+    mint_reference: URef,
+    escrow_purse: URef,
+    target_purse: Key,
+) -> Result<Motes, Error>
+where
+    R: StateReader<Key, StoredValue>,
+    R::Error: Into<execution::Error>,
+{
+    let escrow_balance_key = tracking_copy.get_purse_balance_key(
+        correlation_id,
+        mint_reference,
+        Key::URef(escrow_purse),
+    )?;
+    let target_purse_balance_key =
+        tracking_copy.get_purse_balance_key(correlation_id, mint_reference, target_purse)?;
+    let escrow_balance = tracking_copy.get_purse_balance(correlation_id, escrow_balance_key)?;
+
+    let cl_value = |value: U512| {
+        CLValue::from_t(value)
+            .map(StoredValue::CLValue)
+            .map_err(|error| Error::Exec(error.into()))
+    };
+    let amount = cl_value(escrow_balance.value())?;
+    let zero = cl_value(U512::zero())?;
+    add(
+        correlation_id,
+        tracking_copy,
+        target_purse_balance_key,
+        amount,
+    )?;
+    tracking_copy.write(escrow_balance_key, zero);
+    Ok(escrow_balance)
+}
+
+/// Pays for a call out of its escrow: moves the whole balance of `escrow_purse` into the PoS
+/// contract's `payment_purse` and sets `refund_purse` as the PoS contract's refund purse, just as
+/// payment code would.  Returns the amount moved.  If either step fails, neither happens.
+pub(crate) fn pay_from_escrow<R>(
+    correlation_id: CorrelationId,
+    tracking_copy: &mut TrackingCopy<R>,
+    mint_reference: URef,
+    proof_of_stake_reference: URef,
+    payment_purse: Key,
+    escrow_purse: URef,
+    refund_purse: URef,
+) -> Result<Motes, Error>
+where
+    R: StateReader<Key, StoredValue>,
+    R::Error: Into<execution::Error>,
+{
+    let mut payment_tracking_copy = tracking_copy.fork();
+    let amount = drain_escrow(
+        correlation_id,
+        &mut payment_tracking_copy,
+        mint_reference,
+        escrow_purse,
+        payment_purse,
+    )?;
+    let refund_purse_key =
+        CLValue::from_t((String::from(POS_REFUND_PURSE), Key::URef(refund_purse)))
+            .map(StoredValue::CLValue)
+            .map_err(|error| Error::Exec(error.into()))?;
+    add(
+        correlation_id,
+        &mut payment_tracking_copy,
+        Key::URef(proof_of_stake_reference),
+        refund_purse_key,
+    )?;
+
+    let transforms = payment_tracking_copy.effect().transforms;
+    replay_transforms(correlation_id, tracking_copy, &transforms)?;
+    Ok(amount)
+}
+
+fn add<R>(
+    correlation_id: CorrelationId,
+    tracking_copy: &mut TrackingCopy<R>,
+    key: Key,
+    value: StoredValue,
+) -> Result<(), Error>
+where
+    R: StateReader<Key, StoredValue>,
+    R::Error: Into<execution::Error>,
+{
+    match tracking_copy
+        .add(correlation_id, key, value)
+        .map_err(|error| Error::Exec(error.into()))?
+    {
+        AddResult::Success => Ok(()),
+        AddResult::KeyNotFound(key) => Err(Error::Exec(execution::Error::KeyNotFound(key))),
+        AddResult::TypeMismatch(type_mismatch) => {
+            Err(Error::Exec(execution::Error::TypeMismatch(type_mismatch)))
+        }
+        AddResult::Serialization(error) => Err(Error::Serialization(error)),
+        AddResult::Overflow => Err(Error::Exec(execution::Error::Overflow)),
+    }
+}
+
+/// Writes the outcome of `transforms` into `tracking_copy`, so that it can be read by whatever runs
+/// next.  The transforms apply as they would on commit, and if any of them fails, none are written.
+pub(crate) fn replay_transforms<R>(
+    correlation_id: CorrelationId,
+    tracking_copy: &mut TrackingCopy<R>,
+    transforms: &AdditiveMap<Key, Transform>,
+) -> Result<(), Error>
+where
+    R: StateReader<Key, StoredValue>,
+    R::Error: Into<execution::Error>,
+{
+    let mut values = Vec::with_capacity(transforms.len());
+    for (key, transform) in transforms.iter() {
+        let value = match transform {
+            Transform::Identity => continue,
+            Transform::Write(value) => value.clone(),
+            transform => {
+                let current = tracking_copy
+                    .get(correlation_id, key)
+                    .map_err(|error| Error::Exec(error.into()))?
+                    .ok_or_else(|| Error::Exec(execution::Error::KeyNotFound(*key)))?;
+                transform
+                    .clone()
+                    .apply(current)
+                    .map_err(|error| match error {
+                        transform::Error::Serialization(error) => Error::Serialization(error),
+                        transform::Error::TypeMismatch(type_mismatch) => {
+                            Error::Exec(execution::Error::TypeMismatch(type_mismatch))
+                        }
+                        transform::Error::Overflow => Error::Exec(execution::Error::Overflow),
+                    })?
+            }
+        };
+        values.push((*key, value));
+    }
+    for (key, value) in values {
+        tracking_copy.write(key, value);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, iter};
+
+    use engine_shared::contract::Contract;
+    use engine_storage::global_state::{
+        in_memory::{InMemoryGlobalState, InMemoryGlobalStateView},
+        StateProvider,
+    };
+    use types::{AccessRights, ProtocolVersion};
+
+    use super::*;
+
+    const ACCOUNT: PublicKey = PublicKey::ed25519_from([1; 32]);
+    const MINT_ADDR: [u8; 32] = [9; 32];
+
+    fn scheduled_call(due: u64) -> ScheduledCall {
+        ScheduledCall::new(
+            ACCOUNT,
+            CallContext::Session,
+            Key::Hash([2; 32]),
+            vec![3, 4],
+            BlockTime::new(due),
+            URef::new([5; 32], AccessRights::READ_ADD_WRITE),
+            URef::new([6; 32], AccessRights::READ_ADD_WRITE),
+        )
+    }
+
+    fn tracking_copy(calls: &[([u8; 32], ScheduledCall)]) -> TrackingCopy<InMemoryGlobalStateView> {
+        let correlation_id = CorrelationId::new();
+        let named_keys = calls
+            .iter()
+            .map(|(hash, _)| (named_key_name(*hash), Key::Hash(*hash)))
+            .collect();
+        let system_account = Account::create(
+            SYSTEM_ACCOUNT_ADDR,
+            named_keys,
+            URef::new([0; 32], AccessRights::READ_ADD_WRITE),
+        );
+        let mut pairs = vec![(
+            Key::Account(SYSTEM_ACCOUNT_ADDR),
+            StoredValue::Account(system_account),
+        )];
+        pairs.extend(calls.iter().map(|(hash, call)| {
+            let value = CLValue::from_t(call.clone()).expect("should create CLValue");
+            (Key::Hash(*hash), StoredValue::CLValue(value))
+        }));
+        let (state, root_hash) =
+            InMemoryGlobalState::from_pairs(correlation_id, &pairs).expect("should create state");
+        let reader = state
+            .checkout(root_hash)
+            .expect("should checkout")
+            .expect("should have reader");
+        TrackingCopy::new(reader)
+    }
+
+    /// Returns a tracking copy of a state in which each of `purses` holds its balance, along with
+    /// the values in `pairs`.
+    fn purses_tracking_copy(
+        purses: &[(URef, CLValue)],
+        mut pairs: Vec<(Key, StoredValue)>,
+    ) -> TrackingCopy<InMemoryGlobalStateView> {
+        let correlation_id = CorrelationId::new();
+        for (purse, balance) in purses {
+            let balance_key = Key::Hash(purse.addr());
+            let local_key = Key::local(MINT_ADDR, &purse.addr().into_bytes().unwrap());
+            let balance_key_value = CLValue::from_t(balance_key).unwrap();
+            pairs.push((local_key, StoredValue::CLValue(balance_key_value)));
+            pairs.push((balance_key, StoredValue::CLValue(balance.clone())));
+        }
+        let (state, root_hash) =
+            InMemoryGlobalState::from_pairs(correlation_id, &pairs).expect("should create state");
+        let reader = state
+            .checkout(root_hash)
+            .expect("should checkout")
+            .expect("should have reader");
+        TrackingCopy::new(reader)
+    }
+
+    fn purse(addr: u8) -> URef {
+        URef::new([addr; 32], AccessRights::READ_ADD_WRITE)
+    }
+
+    fn motes(amount: u64) -> CLValue {
+        CLValue::from_t(U512::from(amount)).unwrap()
+    }
+
+    fn balance(tracking_copy: &mut TrackingCopy<InMemoryGlobalStateView>, purse: URef) -> U512 {
+        tracking_copy
+            .get_purse_balance(CorrelationId::new(), Key::Hash(purse.addr()))
+            .expect("should have balance")
+            .value()
+    }
+
+    #[test]
+    fn bytesrepr_roundtrip() {
+        bytesrepr::test_serialization_roundtrip(&scheduled_call(100));
+        bytesrepr::test_serialization_roundtrip(&CallContext::Contract);
+    }
+
+    #[test]
+    fn should_return_due_calls_in_order() {
+        let mut tracking_copy = tracking_copy(&[
+            ([1; 32], scheduled_call(300)),
+            ([2; 32], scheduled_call(100)),
+            ([3; 32], scheduled_call(200)),
+            ([4; 32], scheduled_call(100)),
+        ]);
+
+        let due_calls = due_calls(
+            CorrelationId::new(),
+            &mut tracking_copy,
+            BlockTime::new(200),
+        )
+        .expect("should get due calls");
+        let hashes: Vec<[u8; 32]> = due_calls.iter().map(|due_call| due_call.hash).collect();
+        assert_eq!(hashes, vec![[2; 32], [4; 32], [3; 32]]);
+        assert_eq!(due_calls[0].name, named_key_name([2; 32]));
+        assert_eq!(due_calls[0].call, scheduled_call(100));
+    }
+
+    #[test]
+    fn should_cap_due_calls() {
+        let calls: Vec<([u8; 32], ScheduledCall)> = (0..=MAX_DUE_CALLS_PER_REQUEST as u8)
+            .map(|index| ([index; 32], scheduled_call(u64::from(index))))
+            .collect();
+        let mut tracking_copy = tracking_copy(&calls);
+
+        let due_calls = due_calls(
+            CorrelationId::new(),
+            &mut tracking_copy,
+            BlockTime::new(u64::max_value()),
+        )
+        .expect("should get due calls");
+        let hashes: Vec<[u8; 32]> = due_calls.iter().map(|due_call| due_call.hash).collect();
+        let expected: Vec<[u8; 32]> = calls
+            .iter()
+            .take(MAX_DUE_CALLS_PER_REQUEST)
+            .map(|(hash, _)| *hash)
+            .collect();
+        assert_eq!(hashes, expected);
+    }
+
+    #[test]
+    fn should_drain_escrow() {
+        let correlation_id = CorrelationId::new();
+        let (escrow_purse, target_purse) = (purse(10), purse(11));
+        let mut tracking_copy = purses_tracking_copy(
+            &[(escrow_purse, motes(100)), (target_purse, motes(5))],
+            vec![],
+        );
+
+        let amount = drain_escrow(
+            correlation_id,
+            &mut tracking_copy,
+            URef::new(MINT_ADDR, AccessRights::READ_ADD_WRITE),
+            escrow_purse,
+            Key::URef(target_purse),
+        )
+        .expect("should drain escrow");
+
+        assert_eq!(amount, Motes::new(U512::from(100)));
+        assert_eq!(balance(&mut tracking_copy, escrow_purse), U512::zero());
+        assert_eq!(balance(&mut tracking_copy, target_purse), U512::from(105));
+    }
+
+    #[test]
+    fn should_leave_escrow_if_it_cant_be_drained() {
+        let correlation_id = CorrelationId::new();
+        let (escrow_purse, target_purse) = (purse(10), purse(11));
+        let invalid_balance = CLValue::from_t(5u64).unwrap();
+        let mut tracking_copy = purses_tracking_copy(
+            &[(escrow_purse, motes(100)), (target_purse, invalid_balance)],
+            vec![],
+        );
+
+        let result = drain_escrow(
+            correlation_id,
+            &mut tracking_copy,
+            URef::new(MINT_ADDR, AccessRights::READ_ADD_WRITE),
+            escrow_purse,
+            Key::URef(target_purse),
+        );
+
+        assert!(result.is_err());
+        assert_eq!(balance(&mut tracking_copy, escrow_purse), U512::from(100));
+    }
+
+    #[test]
+    fn should_pay_from_escrow() {
+        let correlation_id = CorrelationId::new();
+        let (escrow_purse, payment_purse, refund_purse) = (purse(10), purse(11), purse(12));
+        let proof_of_stake = purse(13);
+        let contract = Contract::new(vec![], BTreeMap::new(), ProtocolVersion::V1_0_0);
+        let mut tracking_copy = purses_tracking_copy(
+            &[(escrow_purse, motes(100)), (payment_purse, motes(0))],
+            vec![(Key::URef(proof_of_stake), StoredValue::Contract(contract))],
+        );
+
+        let amount = pay_from_escrow(
+            correlation_id,
+            &mut tracking_copy,
+            URef::new(MINT_ADDR, AccessRights::READ_ADD_WRITE),
+            proof_of_stake,
+            Key::URef(payment_purse),
+            escrow_purse,
+            refund_purse,
+        )
+        .expect("should pay from escrow");
+
+        assert_eq!(amount, Motes::new(U512::from(100)));
+        assert_eq!(balance(&mut tracking_copy, escrow_purse), U512::zero());
+        assert_eq!(balance(&mut tracking_copy, payment_purse), U512::from(100));
+        let contract = tracking_copy
+            .get_contract(correlation_id, Key::URef(proof_of_stake))
+            .expect("should have contract");
+        assert_eq!(
+            contract.named_keys().get(POS_REFUND_PURSE),
+            Some(&Key::URef(refund_purse))
+        );
+    }
+
+    #[test]
+    fn should_not_pay_from_escrow_without_proof_of_stake_contract() {
+        let correlation_id = CorrelationId::new();
+        let (escrow_purse, payment_purse, refund_purse) = (purse(10), purse(11), purse(12));
+        let mut tracking_copy = purses_tracking_copy(
+            &[(escrow_purse, motes(100)), (payment_purse, motes(0))],
+            vec![],
+        );
+
+        let result = pay_from_escrow(
+            correlation_id,
+            &mut tracking_copy,
+            URef::new(MINT_ADDR, AccessRights::READ_ADD_WRITE),
+            purse(13),
+            Key::URef(payment_purse),
+            escrow_purse,
+            refund_purse,
+        );
+
+        assert!(result.is_err());
+        assert_eq!(balance(&mut tracking_copy, escrow_purse), U512::from(100));
+        assert_eq!(balance(&mut tracking_copy, payment_purse), U512::zero());
+    }
+
+    fn failed_with_deploy_error(result: &ExecutionResult) -> bool {
+        match result.error() {
+            Some(Error::Deploy) => true,
+            _ => false,
+        }
+    }
+
+    fn unscheduled(result: &ExecutionResult) -> bool {
+        match result
+            .effect()
+            .transforms
+            .get(&Key::Account(SYSTEM_ACCOUNT_ADDR))
+        {
+            Some(Transform::Write(StoredValue::Account(account))) => {
+                account.named_keys().is_empty()
+            }
+            _ => false,
+        }
+    }
+
+    #[test]
+    fn should_drop_call() {
+        let correlation_id = CorrelationId::new();
+        let call = scheduled_call(100);
+        let name = named_key_name([2; 32]);
+        let named_keys = iter::once((name.clone(), Key::Hash([2; 32]))).collect();
+        let system_account = Account::create(SYSTEM_ACCOUNT_ADDR, named_keys, purse(0));
+        let tracking_copy = purses_tracking_copy(
+            &[
+                (call.escrow_purse(), motes(100)),
+                (call.refund_purse(), motes(5)),
+            ],
+            vec![],
+        );
+
+        let result = drop_call(
+            correlation_id,
+            &tracking_copy,
+            URef::new(MINT_ADDR, AccessRights::READ_ADD_WRITE),
+            &system_account,
+            &name,
+            &call,
+            Error::Deploy,
+        );
+
+        assert!(failed_with_deploy_error(&result));
+        assert!(unscheduled(&result));
+        let refund_balance_key = Key::Hash(call.refund_purse().addr());
+        assert_eq!(
+            result.effect().transforms.get(&refund_balance_key),
+            Some(&Transform::AddUInt512(U512::from(100)))
+        );
+    }
+
+    #[test]
+    fn should_drop_call_if_refund_fails() {
+        let correlation_id = CorrelationId::new();
+        let call = scheduled_call(100);
+        let name = named_key_name([2; 32]);
+        let named_keys = iter::once((name.clone(), Key::Hash([2; 32]))).collect();
+        let system_account = Account::create(SYSTEM_ACCOUNT_ADDR, named_keys, purse(0));
+        let invalid_balance = CLValue::from_t(5u64).unwrap();
+        let tracking_copy = purses_tracking_copy(
+            &[
+                (call.escrow_purse(), motes(100)),
+                (call.refund_purse(), invalid_balance),
+            ],
+            vec![],
+        );
+
+        let result = drop_call(
+            correlation_id,
+            &tracking_copy,
+            URef::new(MINT_ADDR, AccessRights::READ_ADD_WRITE),
+            &system_account,
+            &name,
+            &call,
+            Error::Deploy,
+        );
+
+        assert!(result.is_failure() && !failed_with_deploy_error(&result));
+        assert!(unscheduled(&result));
+        let escrow_balance_key = Key::Hash(call.escrow_purse().addr());
+        assert_eq!(result.effect().transforms.get(&escrow_balance_key), None);
+    }
+
+    #[test]
+    fn should_replay_transforms() {
+        let correlation_id = CorrelationId::new();
+        let mut tracking_copy = tracking_copy(&[]);
+        let key = Key::Hash([6; 32]);
+        let value = |value: u64| StoredValue::CLValue(CLValue::from_t(value).unwrap());
+
+        let mut transforms = AdditiveMap::new();
+        transforms.insert(key, Transform::Write(value(1)));
+        replay_transforms(correlation_id, &mut tracking_copy, &transforms)
+            .expect("should replay write");
+
+        let mut transforms = AdditiveMap::new();
+        transforms.insert(key, Transform::AddUInt64(2));
+        replay_transforms(correlation_id, &mut tracking_copy, &transforms)
+            .expect("should replay add");
+
+        assert_eq!(
+            tracking_copy.get(correlation_id, &key).unwrap(),
+            Some(value(3))
+        );
+    }
+
+    #[test]
+    fn should_not_replay_any_transform_if_one_fails() {
+        let correlation_id = CorrelationId::new();
+        let mut tracking_copy = tracking_copy(&[]);
+        let written_key = Key::Hash([6; 32]);
+        let missing_key = Key::Hash([7; 32]);
+        let value = StoredValue::CLValue(CLValue::from_t(1u64).unwrap());
+
+        let mut transforms = AdditiveMap::new();
+        transforms.insert(written_key, Transform::Write(value));
+        transforms.insert(missing_key, Transform::AddUInt64(2));
+        assert!(replay_transforms(correlation_id, &mut tracking_copy, &transforms).is_err());
+
+        assert_eq!(
+            tracking_copy.get(correlation_id, &written_key).unwrap(),
+            None
+        );
+    }
+}
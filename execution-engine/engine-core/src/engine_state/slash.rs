@@ -0,0 +1,47 @@
+use std::collections::BTreeMap;
+
+use engine_shared::newtypes::Blake2bHash;
+use proof_of_stake::Slash;
+use types::{account::PublicKey, BlockTime, ProtocolVersion};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlashRequest {
+    parent_state_hash: Blake2bHash,
+    protocol_version: ProtocolVersion,
+    block_time: BlockTime,
+    slashes: BTreeMap<PublicKey, Slash>,
+}
+
+impl SlashRequest {
+    pub fn new(
+        parent_state_hash: Blake2bHash,
+        protocol_version: ProtocolVersion,
+        block_time: BlockTime,
+        slashes: BTreeMap<PublicKey, Slash>,
+    ) -> Self {
+        SlashRequest {
+            parent_state_hash,
+            protocol_version,
+            block_time,
+            slashes,
+        }
+    }
+
+    pub fn parent_state_hash(&self) -> Blake2bHash {
+        self.parent_state_hash
+    }
+
+    pub fn protocol_version(&self) -> ProtocolVersion {
+        self.protocol_version
+    }
+
+    /// The time of the block the slashes are applied in. Stake released by jailing a validator is
+    /// paid out after the unbonding delay counted from this time.
+    pub fn block_time(&self) -> BlockTime {
+        self.block_time
+    }
+
+    pub fn slashes(&self) -> &BTreeMap<PublicKey, Slash> {
+        &self.slashes
+    }
+}
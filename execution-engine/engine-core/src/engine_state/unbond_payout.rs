@@ -0,0 +1,37 @@
+use engine_shared::newtypes::Blake2bHash;
+use types::{BlockTime, ProtocolVersion};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnbondPayoutRequest {
+    parent_state_hash: Blake2bHash,
+    protocol_version: ProtocolVersion,
+    block_time: BlockTime,
+}
+
+impl UnbondPayoutRequest {
+    pub fn new(
+        parent_state_hash: Blake2bHash,
+        protocol_version: ProtocolVersion,
+        block_time: BlockTime,
+    ) -> Self {
+        UnbondPayoutRequest {
+            parent_state_hash,
+            protocol_version,
+            block_time,
+        }
+    }
+
+    pub fn parent_state_hash(&self) -> Blake2bHash {
+        self.parent_state_hash
+    }
+
+    pub fn protocol_version(&self) -> ProtocolVersion {
+        self.protocol_version
+    }
+
+    /// The time of the block the payouts are made in. Bonding and unbonding requests are applied
+    /// once their delay, counted from the time they were made, has passed by this time.
+    pub fn block_time(&self) -> BlockTime {
+        self.block_time
+    }
+}
@@ -3,20 +3,30 @@ use std::fmt;
 use engine_shared::{newtypes::Blake2bHash, TypeMismatch};
 use engine_storage::global_state::CommitResult;
 use engine_wasm_prep::wasm_costs::WasmCosts;
+use proof_of_stake::PosParams;
 use types::{bytesrepr, Key, ProtocolVersion};
 
-use crate::engine_state::execution_effect::ExecutionEffect;
+use crate::engine_state::{
+    execution_effect::ExecutionEffect,
+    migration::{Migration, MigrationError},
+};
 
-pub type ActivationPoint = u64;
+pub use engine_storage::protocol_data::ActivationPoint;
 
 pub enum UpgradeResult {
     RootNotFound,
     KeyNotFound(Key),
     TypeMismatch(TypeMismatch),
     Serialization(bytesrepr::Error),
+    /// The migration at `index` in the upgrade's list doesn't fit the current state.
+    InvalidMigration {
+        index: usize,
+        error: MigrationError,
+    },
     Success {
         post_state_hash: Blake2bHash,
         effect: ExecutionEffect,
+        applied_migrations: usize,
     },
 }
 
@@ -29,16 +39,28 @@ impl fmt::Display for UpgradeResult {
                 write!(f, "Type mismatch: {:?}", type_mismatch)
             }
             UpgradeResult::Serialization(error) => write!(f, "Serialization error: {:?}", error),
+            UpgradeResult::InvalidMigration { index, error } => {
+                write!(f, "Invalid migration {}: {}", index, error)
+            }
             UpgradeResult::Success {
                 post_state_hash,
                 effect,
-            } => write!(f, "Success: {} {:?}", post_state_hash, effect),
+                applied_migrations,
+            } => write!(
+                f,
+                "Success: {} {:?}, {} migrations applied",
+                post_state_hash, effect, applied_migrations
+            ),
         }
     }
 }
 
 impl UpgradeResult {
-    pub fn from_commit_result(commit_result: CommitResult, effect: ExecutionEffect) -> Self {
+    pub fn from_commit_result(
+        commit_result: CommitResult,
+        effect: ExecutionEffect,
+        applied_migrations: usize,
+    ) -> Self {
         match commit_result {
             CommitResult::RootNotFound => UpgradeResult::RootNotFound,
             CommitResult::KeyNotFound(key) => UpgradeResult::KeyNotFound(key),
@@ -47,6 +69,7 @@ impl UpgradeResult {
             CommitResult::Success { state_root, .. } => UpgradeResult::Success {
                 post_state_hash: state_root,
                 effect,
+                applied_migrations,
             },
         }
     }
@@ -60,10 +83,14 @@ pub struct UpgradeConfig {
     upgrade_installer_args: Option<Vec<u8>>,
     upgrade_installer_bytes: Option<Vec<u8>>,
     wasm_costs: Option<WasmCosts>,
+    max_call_depth: Option<u32>,
     activation_point: Option<ActivationPoint>,
+    pos_params: Option<PosParams>,
+    migrations: Vec<Migration>,
 }
 
 impl UpgradeConfig {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         pre_state_hash: Blake2bHash,
         current_protocol_version: ProtocolVersion,
@@ -71,7 +98,10 @@ impl UpgradeConfig {
         upgrade_installer_args: Option<Vec<u8>>,
         upgrade_installer_bytes: Option<Vec<u8>>,
         wasm_costs: Option<WasmCosts>,
+        max_call_depth: Option<u32>,
         activation_point: Option<ActivationPoint>,
+        pos_params: Option<PosParams>,
+        migrations: Vec<Migration>,
     ) -> Self {
         UpgradeConfig {
             pre_state_hash,
@@ -80,7 +110,10 @@ impl UpgradeConfig {
             upgrade_installer_args,
             upgrade_installer_bytes,
             wasm_costs,
+            max_call_depth,
             activation_point,
+            pos_params,
+            migrations,
         }
     }
 
@@ -110,7 +143,20 @@ impl UpgradeConfig {
         self.wasm_costs
     }
 
-    pub fn activation_point(&self) -> Option<u64> {
+    pub fn max_call_depth(&self) -> Option<u32> {
+        self.max_call_depth
+    }
+
+    pub fn activation_point(&self) -> Option<ActivationPoint> {
         self.activation_point
     }
+
+    pub fn pos_params(&self) -> Option<PosParams> {
+        self.pos_params
+    }
+
+    /// Global state migrations applied in order after the upgrade installer has run.
+    pub fn migrations(&self) -> &[Migration] {
+        &self.migrations
+    }
 }
@@ -0,0 +1,127 @@
+use std::collections::BTreeSet;
+
+use types::{ApiError, Key};
+
+use crate::execution::Error;
+
+/// The stored contracts currently being executed on behalf of a deploy, outermost first.
+///
+/// The session or payment code of the deploy is not itself on the stack, i.e. it runs at a depth
+/// of zero and each nested `call_contract` adds one to the depth.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CallStack {
+    contracts: Vec<Key>,
+    non_reentrant: BTreeSet<Key>,
+}
+
+impl CallStack {
+    pub fn new() -> Self {
+        CallStack::default()
+    }
+
+    /// Returns the keys of the contracts on the stack, outermost first.
+    pub fn contracts(&self) -> &[Key] {
+        &self.contracts
+    }
+
+    /// Returns the number of contracts on the stack.
+    pub fn depth(&self) -> usize {
+        self.contracts.len()
+    }
+
+    /// Returns the stack to be used by a call to the contract under `key`.
+    ///
+    /// Keys are normalized before being pushed, so the stack doesn't depend on (or reveal) the
+    /// access rights of the `URef` the contract was called through.
+    ///
+    /// Fails with `Error::CallDepthExceeded` if the call would take the depth beyond
+    /// `max_depth`, or by reverting with `ApiError::Reentrancy` if the contract is already on the
+    /// stack and has declared itself non-reentrant.
+    pub fn push(&self, key: Key, max_depth: u32) -> Result<CallStack, Error> {
+        let key = key.normalize();
+        if self.non_reentrant.contains(&key) {
+            return Err(Error::Revert(ApiError::Reentrancy.into()));
+        }
+        if self.depth() >= max_depth as usize {
+            return Err(Error::CallDepthExceeded(max_depth));
+        }
+        let mut call_stack = self.clone();
+        call_stack.contracts.push(key);
+        Ok(call_stack)
+    }
+
+    /// Marks the contract under `key` as non-reentrant for as long as it remains on the stack.
+    ///
+    /// Has no effect if `key` is not on the stack, e.g. if it is the key of the deploying account.
+    pub fn set_non_reentrant(&mut self, key: Key) {
+        let key = key.normalize();
+        if self.contracts.contains(&key) {
+            self.non_reentrant.insert(key);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use types::{AccessRights, URef};
+
+    use super::*;
+
+    const CONTRACT_A: Key = Key::Hash([1; 32]);
+    const CONTRACT_B: Key = Key::Hash([2; 32]);
+
+    #[test]
+    fn should_enforce_max_depth() {
+        let call_stack = CallStack::new()
+            .push(CONTRACT_A, 2)
+            .and_then(|call_stack| call_stack.push(CONTRACT_B, 2))
+            .expect("should push up to max depth");
+        assert_eq!(call_stack.contracts(), &[CONTRACT_A, CONTRACT_B]);
+
+        match call_stack.push(CONTRACT_A, 2) {
+            Err(Error::CallDepthExceeded(2)) => (),
+            other => panic!("expected CallDepthExceeded, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn should_refuse_reentry_into_non_reentrant_contract() {
+        let mut call_stack = CallStack::new().push(CONTRACT_A, 10).unwrap();
+        let reentered = call_stack
+            .push(CONTRACT_B, 10)
+            .and_then(|call_stack| call_stack.push(CONTRACT_A, 10));
+        assert!(reentered.is_ok());
+
+        call_stack.set_non_reentrant(CONTRACT_A);
+        let call_stack = call_stack.push(CONTRACT_B, 10).unwrap();
+        let expected: u32 = ApiError::Reentrancy.into();
+        match call_stack.push(CONTRACT_A, 10) {
+            Err(Error::Revert(status)) if status == expected => (),
+            other => panic!("expected Reentrancy, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn should_compare_urefs_without_access_rights() {
+        let uref = URef::new([3; 32], AccessRights::READ_ADD_WRITE);
+        let mut call_stack = CallStack::new().push(Key::URef(uref), 10).unwrap();
+        assert_eq!(
+            call_stack.contracts(),
+            &[Key::URef(uref.remove_access_rights())]
+        );
+
+        call_stack.set_non_reentrant(Key::URef(uref.with_access_rights(AccessRights::READ)));
+        let expected: u32 = ApiError::Reentrancy.into();
+        match call_stack.push(Key::URef(uref.into_read()), 10) {
+            Err(Error::Revert(status)) if status == expected => (),
+            other => panic!("expected Reentrancy, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn should_ignore_non_reentrant_declaration_off_the_stack() {
+        let mut call_stack = CallStack::new();
+        call_stack.set_non_reentrant(CONTRACT_A);
+        assert!(call_stack.push(CONTRACT_A, 10).is_ok());
+    }
+}
@@ -4,7 +4,9 @@ use parity_wasm::elements;
 
 use engine_shared::TypeMismatch;
 use types::{
-    account::{AddKeyFailure, RemoveKeyFailure, SetThresholdFailure, UpdateKeyFailure},
+    account::{
+        AddKeyFailure, RecoveryFailure, RemoveKeyFailure, SetThresholdFailure, UpdateKeyFailure,
+    },
     bytesrepr, system_contract_errors, AccessRights, CLValueError, Key, URef,
 };
 
@@ -35,6 +37,7 @@ pub enum Error {
     RemoveKeyFailure(RemoveKeyFailure),
     UpdateKeyFailure(UpdateKeyFailure),
     SetThresholdFailure(SetThresholdFailure),
+    RecoveryFailure(RecoveryFailure),
     SystemContract(system_contract_errors::Error),
     DeploymentAuthorizationFailure,
     ExpectedReturnValue,
@@ -47,6 +50,10 @@ pub enum Error {
     CLValue(CLValueError),
     HostBufferEmpty,
     UnsupportedWasmStart,
+    /// A checked addition to a value in global state overflowed.
+    Overflow,
+    /// A contract call would exceed the maximum call depth given in the protocol data.
+    CallDepthExceeded(u32),
 }
 
 impl fmt::Display for Error {
@@ -117,6 +124,12 @@ impl From<SetThresholdFailure> for Error {
     }
 }
 
+impl From<RecoveryFailure> for Error {
+    fn from(err: RecoveryFailure) -> Self {
+        Error::RecoveryFailure(err)
+    }
+}
+
 impl From<system_contract_errors::Error> for Error {
     fn from(error: system_contract_errors::Error) -> Self {
         Error::SystemContract(error)
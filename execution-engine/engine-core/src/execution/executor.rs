@@ -14,14 +14,14 @@ use engine_storage::{global_state::StateReader, protocol_data::ProtocolData};
 use types::{
     account::PublicKey,
     bytesrepr::{self, FromBytes},
-    BlockTime, CLTyped, CLValue, Key, Phase, ProtocolVersion,
+    BlockTime, CLTyped, CLValue, Key, NamedArg, Phase, ProtocolVersion,
 };
 
 use crate::{
     engine_state::{
         execution_result::ExecutionResult, system_contract_cache::SystemContractCache, EngineConfig,
     },
-    execution::{address_generator::AddressGenerator, Error, FN_STORE_ID_INITIAL},
+    execution::{address_generator::AddressGenerator, CallStack, Error, FN_STORE_ID_INITIAL},
     runtime::{extract_access_rights_from_keys, instance_and_memory, Runtime},
     runtime_context::{self, RuntimeContext},
     tracking_copy::TrackingCopy,
@@ -86,6 +86,52 @@ impl Executor {
         &self,
         parity_module: Module,
         args: Vec<u8>,
+        named_args: Vec<NamedArg>,
+        base_key: Key,
+        account: &Account,
+        authorized_keys: BTreeSet<PublicKey>,
+        blocktime: BlockTime,
+        deploy_hash: [u8; 32],
+        gas_limit: Gas,
+        protocol_version: ProtocolVersion,
+        correlation_id: CorrelationId,
+        tc: Rc<RefCell<TrackingCopy<R>>>,
+        phase: Phase,
+        protocol_data: ProtocolData,
+        system_contract_cache: SystemContractCache,
+    ) -> ExecutionResult
+    where
+        R: StateReader<Key, StoredValue>,
+        R::Error: Into<Error>,
+    {
+        self.exec_with_named_keys(
+            parity_module,
+            args,
+            named_args,
+            account.named_keys().clone(),
+            base_key,
+            account,
+            authorized_keys,
+            blocktime,
+            deploy_hash,
+            gas_limit,
+            protocol_version,
+            correlation_id,
+            tc,
+            phase,
+            protocol_data,
+            system_contract_cache,
+        )
+    }
+
+    /// Like [`Executor::exec`], but runs with the given named keys rather than the account's, so
+    /// that the code can run in the context of the stored contract under `base_key`.
+    pub fn exec_with_named_keys<R>(
+        &self,
+        parity_module: Module,
+        args: Vec<u8>,
+        named_args: Vec<NamedArg>,
+        mut named_keys: BTreeMap<String, Key>,
         base_key: Key,
         account: &Account,
         authorized_keys: BTreeSet<PublicKey>,
@@ -106,8 +152,6 @@ impl Executor {
         let (instance, memory) =
             on_fail_charge!(instance_and_memory(parity_module.clone(), protocol_version));
 
-        let mut named_keys = account.named_keys().clone();
-
         let access_rights =
             {
                 let mut keys: Vec<Key> = named_keys.values().cloned().collect();
@@ -124,20 +168,18 @@ impl Executor {
         // only nonce update can be returned.
         let effects_snapshot = tc.borrow().effect();
 
-        let args: Vec<CLValue> = if args.is_empty() {
-            Vec::new()
-        } else {
-            // TODO: figure out how this works with the cost model
-            // https://casperlabs.atlassian.net/browse/EE-239
-            let gas = Gas::new(args.len().into());
-            on_fail_charge!(bytesrepr::deserialize(args), gas, effects_snapshot)
-        };
+        // TODO: figure out how this works with the cost model
+        // https://casperlabs.atlassian.net/browse/EE-239
+        let gas = Gas::new(args.len().into());
+        let (args, named_args) =
+            on_fail_charge!(runtime_args(args, named_args), gas, effects_snapshot);
 
         let context = RuntimeContext::new(
             tc,
             &mut named_keys,
             access_rights,
             args.clone(),
+            named_args,
             authorized_keys,
             &account,
             base_key,
@@ -151,6 +193,7 @@ impl Executor {
             correlation_id,
             phase,
             protocol_data,
+            CallStack::new(),
         );
 
         let mut runtime = Runtime::new(
@@ -168,6 +211,7 @@ impl Executor {
                     runtime.context().named_keys().to_owned(),
                     &args,
                     Default::default(),
+                    runtime.context().call_stack().clone(),
                 ) {
                     Ok(_value) => {
                         return ExecutionResult::Success {
@@ -189,6 +233,7 @@ impl Executor {
                     runtime.context().named_keys().to_owned(),
                     &args,
                     Default::default(),
+                    runtime.context().call_stack().clone(),
                 ) {
                     Ok(_value) => {
                         return ExecutionResult::Success {
@@ -277,6 +322,7 @@ impl Executor {
             &mut named_keys,
             access_rights,
             args.clone(),
+            BTreeMap::new(),
             authorization_keys,
             &account,
             base_key,
@@ -290,6 +336,7 @@ impl Executor {
             correlation_id,
             phase,
             protocol_data,
+            CallStack::new(),
         );
 
         let (instance, memory) =
@@ -309,6 +356,7 @@ impl Executor {
                 runtime.context().named_keys().to_owned(),
                 &args,
                 Default::default(),
+                runtime.context().call_stack().clone(),
             ) {
                 Ok(_value) => {
                     return ExecutionResult::Success {
@@ -408,6 +456,7 @@ impl Executor {
             keys,
             access_rights,
             args,
+            BTreeMap::new(),
             authorization_keys,
             account,
             base_key,
@@ -421,6 +470,7 @@ impl Executor {
             correlation_id,
             phase,
             protocol_data,
+            CallStack::new(),
         );
 
         let (instance, memory) = instance_and_memory(module.clone(), protocol_version)?;
@@ -509,3 +559,23 @@ impl Executor {
         Ok(ret)
     }
 }
+
+/// Deserializes the positional `args` and collects `named_args` by name.
+///
+/// Where only named arguments are provided, their values are also used as the positional arguments
+/// so that contracts reading their arguments by index can still be invoked with named arguments.
+fn runtime_args(
+    args: Vec<u8>,
+    named_args: Vec<NamedArg>,
+) -> Result<(Vec<CLValue>, BTreeMap<String, CLValue>), bytesrepr::Error> {
+    let args: Vec<CLValue> = if !args.is_empty() {
+        bytesrepr::deserialize(args)?
+    } else {
+        named_args
+            .iter()
+            .map(|named_arg| named_arg.cl_value().clone())
+            .collect()
+    };
+    let named_args = named_args.into_iter().map(NamedArg::destructure).collect();
+    Ok((args, named_args))
+}
@@ -1,4 +1,5 @@
 mod address_generator;
+mod call_stack;
 mod error;
 #[macro_use]
 mod executor;
@@ -7,6 +8,7 @@ mod tests;
 
 pub use self::{
     address_generator::{AddressGenerator, AddressGeneratorBuilder},
+    call_stack::CallStack,
     error::Error,
     executor::Executor,
 };
@@ -43,6 +43,15 @@ pub enum FunctionIndex {
     GetMainPurseIndex,
     GetArgSizeFuncIndex,
     ReadHostBufferIndex,
+    GetNamedArgSizeFuncIndex,
+    GetNamedArgFuncIndex,
+    SetNonReentrantIndex,
+    GetCallStackIndex,
+    SetRecoveryIndex,
+    RequestRecoveryIndex,
+    CancelRecoveryIndex,
+    RecoverAccountIndex,
+    ScheduleCallIndex,
 }
 
 impl Into<usize> for FunctionIndex {
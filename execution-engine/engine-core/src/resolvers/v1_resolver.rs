@@ -188,6 +188,63 @@ impl ModuleImportResolver for RuntimeModuleImportResolver {
                 Signature::new(&[ValueType::I32; 3][..], Some(ValueType::I32)),
                 FunctionIndex::ReadHostBufferIndex.into(),
             ),
+            "get_named_arg_size" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 3][..], Some(ValueType::I32)),
+                FunctionIndex::GetNamedArgSizeFuncIndex.into(),
+            ),
+            "get_named_arg" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 4][..], Some(ValueType::I32)),
+                FunctionIndex::GetNamedArgFuncIndex.into(),
+            ),
+            "set_non_reentrant" => FuncInstance::alloc_host(
+                Signature::new(&[][..], None),
+                FunctionIndex::SetNonReentrantIndex.into(),
+            ),
+            "get_call_stack" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 1][..], Some(ValueType::I32)),
+                FunctionIndex::GetCallStackIndex.into(),
+            ),
+            "set_recovery" => FuncInstance::alloc_host(
+                Signature::new(
+                    &[
+                        ValueType::I32,
+                        ValueType::I32,
+                        ValueType::I32,
+                        ValueType::I64,
+                    ][..],
+                    Some(ValueType::I32),
+                ),
+                FunctionIndex::SetRecoveryIndex.into(),
+            ),
+            "request_recovery" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 2][..], Some(ValueType::I32)),
+                FunctionIndex::RequestRecoveryIndex.into(),
+            ),
+            "cancel_recovery" => FuncInstance::alloc_host(
+                Signature::new(&[][..], Some(ValueType::I32)),
+                FunctionIndex::CancelRecoveryIndex.into(),
+            ),
+            "recover_account" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 2][..], Some(ValueType::I32)),
+                FunctionIndex::RecoverAccountIndex.into(),
+            ),
+            "schedule_call" => FuncInstance::alloc_host(
+                Signature::new(
+                    &[
+                        ValueType::I32,
+                        ValueType::I32,
+                        ValueType::I32,
+                        ValueType::I32,
+                        ValueType::I64,
+                        ValueType::I32,
+                        ValueType::I32,
+                        ValueType::I32,
+                        ValueType::I32,
+                    ][..],
+                    Some(ValueType::I32),
+                ),
+                FunctionIndex::ScheduleCallIndex.into(),
+            ),
             _ => {
                 return Err(InterpreterError::Function(format!(
                     "host module doesn't export function with name {}",
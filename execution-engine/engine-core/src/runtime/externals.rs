@@ -448,6 +448,106 @@ where
                 let ret = self.read_host_buffer(dest_ptr, dest_size as usize, bytes_written_ptr)?;
                 Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
             }
+
+            FunctionIndex::GetNamedArgSizeFuncIndex => {
+                // args(0) = pointer to name of host runtime arg to load
+                // args(1) = size of name
+                // args(2) = pointer to a argument size (output)
+                let (name_ptr, name_size, size_ptr): (_, u32, _) = Args::parse(args)?;
+                let ret = self.get_named_arg_size(name_ptr, name_size, size_ptr)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::GetNamedArgFuncIndex => {
+                // args(0) = pointer to name of host runtime arg to load
+                // args(1) = size of name
+                // args(2) = pointer to destination in Wasm memory
+                // args(3) = size of destination pointer memory
+                let (name_ptr, name_size, dest_ptr, dest_size): (_, u32, _, u32) =
+                    Args::parse(args)?;
+                let ret = self.get_named_arg(name_ptr, name_size, dest_ptr, dest_size as usize)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::SetNonReentrantIndex => {
+                self.set_non_reentrant();
+                Ok(None)
+            }
+
+            FunctionIndex::GetCallStackIndex => {
+                // args(0) = pointer where a size of serialized bytes will be stored
+                let output_size = Args::parse(args)?;
+                let ret = self.get_call_stack(output_size)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::SetRecoveryIndex => {
+                // args(0) = pointer to serialized map of recovery keys to weights
+                // args(1) = size of serialized map
+                // args(2) = weight of recovery keys needed to request a recovery
+                // args(3) = recovery delay in milliseconds
+                let (keys_ptr, keys_size, threshold_value, delay): (u32, u32, u8, u64) =
+                    Args::parse(args)?;
+                let value =
+                    self.set_recovery(keys_ptr, keys_size as usize, threshold_value, delay)?;
+                Ok(Some(RuntimeValue::I32(value)))
+            }
+
+            FunctionIndex::RequestRecoveryIndex => {
+                // args(0) = pointer to array of bytes of the recovered account's public key
+                // args(1) = size of the public key
+                let (public_key_ptr, public_key_size): (u32, u32) = Args::parse(args)?;
+                let value = self.request_recovery(public_key_ptr, public_key_size as usize)?;
+                Ok(Some(RuntimeValue::I32(value)))
+            }
+
+            FunctionIndex::CancelRecoveryIndex => {
+                let value = self.cancel_recovery()?;
+                Ok(Some(RuntimeValue::I32(value)))
+            }
+
+            FunctionIndex::RecoverAccountIndex => {
+                // args(0) = pointer to array of bytes of the recovered account's public key
+                // args(1) = size of the public key
+                let (public_key_ptr, public_key_size): (u32, u32) = Args::parse(args)?;
+                let value = self.recover_account(public_key_ptr, public_key_size as usize)?;
+                Ok(Some(RuntimeValue::I32(value)))
+            }
+
+            FunctionIndex::ScheduleCallIndex => {
+                // args(0) = pointer to array of bytes in Wasm memory of the contract's key
+                // args(1) = length of array of bytes in Wasm memory of the contract's key
+                // args(2) = pointer to array of bytes in Wasm memory of the call's arguments
+                // args(3) = length of array of bytes in Wasm memory of the call's arguments
+                // args(4) = block time at which the call is due
+                // args(5) = pointer to array of bytes in Wasm memory of a source purse
+                // args(6) = length of array of bytes in Wasm memory of a source purse
+                // args(7) = pointer to array of bytes in Wasm memory of an amount
+                // args(8) = length of array of bytes in Wasm memory of an amount
+                let (
+                    contract_ptr,
+                    contract_size,
+                    args_ptr,
+                    args_size,
+                    due,
+                    purse_ptr,
+                    purse_size,
+                    amount_ptr,
+                    amount_size,
+                ) = Args::parse(args)?;
+                let ret = self.schedule_call(
+                    contract_ptr,
+                    contract_size,
+                    args_ptr,
+                    args_size,
+                    due,
+                    purse_ptr,
+                    purse_size,
+                    amount_ptr,
+                    amount_size,
+                )?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
         }
     }
 }
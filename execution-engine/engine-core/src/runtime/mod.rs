@@ -7,7 +7,7 @@ use std::{
     cmp,
     collections::{BTreeMap, HashMap, HashSet},
     convert::TryFrom,
-    iter::IntoIterator,
+    iter::{self, IntoIterator},
 };
 
 use itertools::Itertools;
@@ -15,7 +15,7 @@ use parity_wasm::elements::Module;
 use wasmi::{ImportsBuilder, MemoryRef, ModuleInstance, ModuleRef, Trap, TrapKind};
 
 use ::mint::Mint;
-use ::proof_of_stake::ProofOfStake;
+use ::proof_of_stake::{ProofOfStake, Slash};
 use contract::args_parser::ArgsParser;
 use engine_shared::{account::Account, contract::Contract, gas::Gas, stored_value::StoredValue};
 use engine_storage::{global_state::StateReader, protocol_data::ProtocolData};
@@ -24,15 +24,19 @@ use types::{
     bytesrepr::{self, FromBytes, ToBytes},
     system_contract_errors,
     system_contract_errors::mint,
-    AccessRights, ApiError, CLType, CLTyped, CLValue, Key, ProtocolVersion, SystemContractType,
-    TransferResult, TransferredTo, URef, U128, U256, U512,
+    AccessRights, ApiError, BlockTime, CLType, CLTyped, CLValue, Key, ProtocolVersion,
+    SystemContractType, TransferResult, TransferredTo, URef, U128, U256, U512,
 };
 
 use crate::{
-    engine_state::{system_contract_cache::SystemContractCache, EngineConfig},
-    execution::{Error, MINT_NAME, POS_NAME},
+    engine_state::{
+        scheduled_call::{self, CallContext, ScheduledCall},
+        system_contract_cache::SystemContractCache,
+        EngineConfig,
+    },
+    execution::{CallStack, Error, MINT_NAME, POS_NAME},
     resolvers::{create_module_resolver, memory_resolver::MemoryResolver},
-    runtime_context::RuntimeContext,
+    runtime_context::{self, RuntimeContext},
     Address,
 };
 
@@ -146,9 +150,17 @@ fn extract_urefs(cl_value: &CLValue) -> Result<Vec<URef>, Error> {
         | CLType::U128
         | CLType::U256
         | CLType::U512
+        | CLType::I256
+        | CLType::Decimal
         | CLType::Unit
         | CLType::String
-        | CLType::Any => Ok(vec![]),
+        | CLType::Any
+        | CLType::ByteArray(_) => Ok(vec![]),
+        CLType::Struct { .. } | CLType::Enum { .. } => {
+            let (urefs, _) =
+                runtime_context::extract_urefs_by_type(cl_value.cl_type(), cl_value.inner_bytes())?;
+            Ok(urefs)
+        }
         CLType::Option(ty) => match **ty {
             CLType::URef => {
                 let opt: Option<URef> = cl_value.to_owned().into_t()?;
@@ -1506,6 +1518,54 @@ where
         Ok(Ok(()))
     }
 
+    fn get_named_arg_size(
+        &mut self,
+        name_ptr: u32,
+        name_size: u32,
+        size_ptr: u32,
+    ) -> Result<Result<(), ApiError>, Trap> {
+        let name = self.string_from_mem(name_ptr, name_size)?;
+        let arg_size = match self.context.named_arg(&name) {
+            Some(arg) if arg.inner_bytes().len() > u32::max_value() as usize => {
+                return Ok(Err(ApiError::OutOfMemory))
+            }
+            None => return Ok(Err(ApiError::MissingArgument)),
+            Some(arg) => arg.inner_bytes().len() as u32,
+        };
+
+        let arg_size_bytes = arg_size.to_le_bytes(); // Wasm is little-endian
+
+        if let Err(e) = self.memory.set(size_ptr, &arg_size_bytes) {
+            return Err(Error::Interpreter(e).into());
+        }
+
+        Ok(Ok(()))
+    }
+
+    fn get_named_arg(
+        &mut self,
+        name_ptr: u32,
+        name_size: u32,
+        output_ptr: u32,
+        output_size: usize,
+    ) -> Result<Result<(), ApiError>, Trap> {
+        let name = self.string_from_mem(name_ptr, name_size)?;
+        let arg = match self.context.named_arg(&name) {
+            Some(arg) => arg,
+            None => return Ok(Err(ApiError::MissingArgument)),
+        };
+
+        if arg.inner_bytes().len() > output_size {
+            return Ok(Err(ApiError::OutOfMemory));
+        }
+
+        if let Err(e) = self.memory.set(output_ptr, arg.inner_bytes()) {
+            return Err(Error::Interpreter(e).into());
+        }
+
+        Ok(Ok(()))
+    }
+
     /// Load the uref known by the given name into the Wasm memory
     fn load_key(
         &mut self,
@@ -1607,6 +1667,33 @@ where
         Ok(Ok(()))
     }
 
+    /// Writes the call stack of the current context into the host buffer, i.e. the key of the
+    /// deploying account followed by the keys of the contracts currently being executed,
+    /// outermost first.
+    fn get_call_stack(&mut self, output_size: u32) -> Result<Result<(), ApiError>, Trap> {
+        if !self.can_write_to_host_buffer() {
+            // Exit early if the host buffer is already occupied
+            return Ok(Err(ApiError::HostBufferFull));
+        }
+        let call_stack: Vec<Key> = iter::once(Key::Account(self.context.get_caller()))
+            .chain(self.context.call_stack().contracts().iter().cloned())
+            .collect();
+        let value = CLValue::from_t(call_stack).map_err(Error::CLValue)?;
+        let value_size = value.inner_bytes().len();
+
+        // Save serialized call stack into host buffer
+        if let Err(error) = self.write_host_buffer(value) {
+            return Ok(Err(error));
+        }
+
+        // Write output
+        let output_size_bytes = value_size.to_le_bytes(); // Wasm is little-endian
+        if let Err(error) = self.memory.set(output_size, &output_size_bytes) {
+            return Err(Error::Interpreter(error).into());
+        }
+        Ok(Ok(()))
+    }
+
     /// Writes runtime context's phase to [dest_ptr] in the Wasm memory.
     fn get_phase(&mut self, dest_ptr: u32) -> Result<(), Trap> {
         let phase = self.context.phase();
@@ -1616,6 +1703,11 @@ where
             .map_err(|e| Error::Interpreter(e).into())
     }
 
+    /// Declares the contract being executed non-reentrant for the remainder of its execution.
+    fn set_non_reentrant(&mut self) {
+        self.context.set_non_reentrant()
+    }
+
     /// Writes current blocktime to [dest_ptr] in Wasm memory.
     fn get_blocktime(&self, dest_ptr: u32) -> Result<(), Trap> {
         let blocktime = self
@@ -1689,11 +1781,16 @@ where
         mut named_keys: BTreeMap<String, Key>,
         args: &[CLValue],
         extra_urefs: &[Key],
+        call_stack: CallStack,
     ) -> Result<CLValue, Error> {
         const METHOD_MINT: &str = "mint";
         const METHOD_CREATE: &str = "create";
         const METHOD_BALANCE: &str = "balance";
         const METHOD_TRANSFER: &str = "transfer";
+        const METHOD_TOTAL_SUPPLY: &str = "total_supply";
+        const METHOD_AUTHORITY: &str = "authority";
+        const METHOD_MINT_TOKENS: &str = "mint_tokens";
+        const METHOD_BURN: &str = "burn";
 
         let state = self.context.state();
         let access_rights = {
@@ -1721,6 +1818,7 @@ where
             &mut named_keys,
             access_rights,
             args.to_owned(),
+            BTreeMap::new(),
             authorization_keys,
             account,
             base_key,
@@ -1734,6 +1832,7 @@ where
             correlation_id,
             phase,
             protocol_data,
+            call_stack,
         );
 
         let method_name: String = Self::get_argument(&args, 0)?;
@@ -1765,6 +1864,34 @@ where
                 let result: Result<(), mint::Error> = mint_context.transfer(source, target, amount);
                 CLValue::from_t(result).map_err(Self::reverter)?
             }
+            // Type: `fn total_supply() -> U512`
+            METHOD_TOTAL_SUPPLY => {
+                let total_supply: U512 = mint_context.total_supply().map_err(Self::reverter)?;
+                CLValue::from_t(total_supply).map_err(Self::reverter)?
+            }
+            // Type: `fn authority() -> Result<URef, Error>`
+            METHOD_AUTHORITY => {
+                let result: Result<URef, mint::Error> = mint_context.authority();
+                CLValue::from_t(result).map_err(Self::reverter)?
+            }
+            // Type: `fn mint_tokens(authority: URef, purse: URef, amount: U512) -> Result<(),
+            // Error>`
+            METHOD_MINT_TOKENS => {
+                let authority: URef = Self::get_argument(&args, 1)?;
+                let purse: URef = Self::get_argument(&args, 2)?;
+                let amount: U512 = Self::get_argument(&args, 3)?;
+                let result: Result<(), mint::Error> =
+                    mint_context.mint_tokens(authority, purse, amount);
+                CLValue::from_t(result).map_err(Self::reverter)?
+            }
+            // Type: `fn burn(authority: URef, purse: URef, amount: U512) -> Result<(), Error>`
+            METHOD_BURN => {
+                let authority: URef = Self::get_argument(&args, 1)?;
+                let purse: URef = Self::get_argument(&args, 2)?;
+                let amount: U512 = Self::get_argument(&args, 3)?;
+                let result: Result<(), mint::Error> = mint_context.burn(authority, purse, amount);
+                CLValue::from_t(result).map_err(Self::reverter)?
+            }
             _ => CLValue::from_t(()).map_err(Self::reverter)?,
         };
         let urefs = extract_urefs(&ret)?;
@@ -1779,13 +1906,21 @@ where
         mut named_keys: BTreeMap<String, Key>,
         args: &[CLValue],
         extra_urefs: &[Key],
+        call_stack: CallStack,
     ) -> Result<CLValue, Error> {
         const METHOD_BOND: &str = "bond";
         const METHOD_UNBOND: &str = "unbond";
+        const METHOD_DELEGATE: &str = "delegate";
+        const METHOD_UNDELEGATE: &str = "undelegate";
         const METHOD_GET_PAYMENT_PURSE: &str = "get_payment_purse";
         const METHOD_SET_REFUND_PURSE: &str = "set_refund_purse";
         const METHOD_GET_REFUND_PURSE: &str = "get_refund_purse";
         const METHOD_FINALIZE_PAYMENT: &str = "finalize_payment";
+        const METHOD_DISTRIBUTE_REWARDS: &str = "distribute_rewards";
+        const METHOD_GET_ERA_REWARDS: &str = "get_era_rewards";
+        const METHOD_GET_PARAMS: &str = "get_params";
+        const METHOD_SLASH: &str = "slash";
+        const METHOD_STEP: &str = "step";
 
         let state = self.context.state();
         let access_rights = {
@@ -1813,6 +1948,7 @@ where
             &mut named_keys,
             access_rights,
             args.to_owned(),
+            BTreeMap::new(),
             authorization_keys,
             account,
             base_key,
@@ -1826,6 +1962,7 @@ where
             correlation_id,
             phase,
             protocol_data,
+            call_stack,
         );
 
         let mut runtime = Runtime::new(
@@ -1856,6 +1993,25 @@ where
                     .map_err(Self::reverter)?;
                 CLValue::from_t(()).map_err(Self::reverter)?
             }
+            METHOD_DELEGATE => {
+                let delegator: PublicKey = runtime.context.get_caller();
+                let validator: PublicKey = Self::get_argument(&args, 1)?;
+                let amount: U512 = Self::get_argument(&args, 2)?;
+                let source_uref: URef = Self::get_argument(&args, 3)?;
+                runtime
+                    .delegate(delegator, validator, amount, source_uref)
+                    .map_err(Self::reverter)?;
+                CLValue::from_t(()).map_err(Self::reverter)?
+            }
+            METHOD_UNDELEGATE => {
+                let delegator: PublicKey = runtime.context.get_caller();
+                let validator: PublicKey = Self::get_argument(&args, 1)?;
+                let maybe_amount: Option<U512> = Self::get_argument(&args, 2)?;
+                runtime
+                    .undelegate(delegator, validator, maybe_amount)
+                    .map_err(Self::reverter)?;
+                CLValue::from_t(()).map_err(Self::reverter)?
+            }
             METHOD_GET_PAYMENT_PURSE => {
                 let rights_controlled_purse =
                     runtime.get_payment_purse().map_err(Self::reverter)?;
@@ -1878,6 +2034,32 @@ where
                     .map_err(Self::reverter)?;
                 CLValue::from_t(()).map_err(Self::reverter)?
             }
+            METHOD_DISTRIBUTE_REWARDS => {
+                let era_id: u64 = Self::get_argument(&args, 1)?;
+                let inflation_rate: u64 = Self::get_argument(&args, 2)?;
+                let commission_rate: u64 = Self::get_argument(&args, 3)?;
+                runtime
+                    .distribute_rewards(era_id, inflation_rate, commission_rate)
+                    .map_err(Self::reverter)?;
+                CLValue::from_t(()).map_err(Self::reverter)?
+            }
+            METHOD_GET_ERA_REWARDS => {
+                let maybe_era_rewards = runtime.get_era_rewards().map_err(Self::reverter)?;
+                CLValue::from_t(maybe_era_rewards).map_err(Self::reverter)?
+            }
+            METHOD_GET_PARAMS => {
+                let params = runtime.get_params().map_err(Self::reverter)?;
+                CLValue::from_t(params).map_err(Self::reverter)?
+            }
+            METHOD_SLASH => {
+                let slashes: BTreeMap<PublicKey, Slash> = Self::get_argument(&args, 1)?;
+                runtime.slash(slashes).map_err(Self::reverter)?;
+                CLValue::from_t(()).map_err(Self::reverter)?
+            }
+            METHOD_STEP => {
+                runtime.step().map_err(Self::reverter)?;
+                CLValue::from_t(()).map_err(Self::reverter)?
+            }
             _ => CLValue::from_t(()).map_err(Self::reverter)?,
         };
         let urefs = extract_urefs(&ret)?;
@@ -1888,6 +2070,11 @@ where
 
     /// Calls contract living under a `key`, with supplied `args`.
     pub fn call_contract(&mut self, key: Key, args_bytes: Vec<u8>) -> Result<CLValue, Error> {
+        let call_stack = self
+            .context
+            .call_stack()
+            .push(key, self.context.protocol_data().max_call_depth())?;
+
         let contract = match self.context.read_gs(&key)? {
             Some(StoredValue::Contract(contract)) => contract,
             Some(_) => {
@@ -1931,6 +2118,7 @@ where
                     contract.take_named_keys(),
                     &args,
                     &extra_urefs,
+                    call_stack,
                 );
             } else if self.is_proof_of_stake(key) {
                 return self.call_host_proof_of_stake(
@@ -1938,6 +2126,7 @@ where
                     contract.take_named_keys(),
                     &args,
                     &extra_urefs,
+                    call_stack,
                 );
             }
         }
@@ -1975,6 +2164,7 @@ where
             &mut named_keys,
             access_rights,
             args,
+            BTreeMap::new(),
             self.context.authorization_keys().clone(),
             &self.context.account(),
             key,
@@ -1988,6 +2178,7 @@ where
             self.context.correlation_id(),
             self.context.phase(),
             self.context.protocol_data(),
+            call_stack,
         );
 
         let mut runtime = Runtime {
@@ -2385,6 +2576,66 @@ where
         }
     }
 
+    fn set_recovery(
+        &mut self,
+        keys_ptr: u32,
+        keys_size: usize,
+        threshold_value: u8,
+        delay: u64,
+    ) -> Result<i32, Trap> {
+        let keys: BTreeMap<PublicKey, Weight> = {
+            let keys_serialized = self.bytes_from_mem(keys_ptr, keys_size)?;
+            bytesrepr::deserialize(keys_serialized).map_err(Error::BytesRepr)?
+        };
+        let threshold = Weight::new(threshold_value);
+        match self.context.set_recovery(keys, threshold, delay) {
+            Ok(_) => Ok(0),
+            // `RecoveryFailure` variants start at `1`, so `0` is safe to use for success.
+            Err(Error::RecoveryFailure(e)) => Ok(e as i32),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn request_recovery(
+        &mut self,
+        public_key_ptr: u32,
+        public_key_size: usize,
+    ) -> Result<i32, Trap> {
+        let public_key: PublicKey = {
+            let source_serialized = self.bytes_from_mem(public_key_ptr, public_key_size)?;
+            bytesrepr::deserialize(source_serialized).map_err(Error::BytesRepr)?
+        };
+        match self.context.request_recovery(public_key) {
+            Ok(_) => Ok(0),
+            Err(Error::RecoveryFailure(e)) => Ok(e as i32),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn cancel_recovery(&mut self) -> Result<i32, Trap> {
+        match self.context.cancel_recovery() {
+            Ok(_) => Ok(0),
+            Err(Error::RecoveryFailure(e)) => Ok(e as i32),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn recover_account(
+        &mut self,
+        public_key_ptr: u32,
+        public_key_size: usize,
+    ) -> Result<i32, Trap> {
+        let public_key: PublicKey = {
+            let source_serialized = self.bytes_from_mem(public_key_ptr, public_key_size)?;
+            bytesrepr::deserialize(source_serialized).map_err(Error::BytesRepr)?
+        };
+        match self.context.recover_account(public_key) {
+            Ok(_) => Ok(0),
+            Err(Error::RecoveryFailure(e)) => Ok(e as i32),
+            Err(e) => Err(e.into()),
+        }
+    }
+
     /// Looks up the public mint contract key in the context's protocol data.
     ///
     /// Returned URef is already attenuated depending on the calling account.
@@ -2439,6 +2690,39 @@ where
         Ok(result.map_err(system_contract_errors::Error::from)?)
     }
 
+    /// Calls the "total_supply" method on the mint contract at the given mint
+    /// contract key
+    fn mint_total_supply(&mut self, mint_contract_key: Key) -> Result<U512, Error> {
+        let args_bytes = {
+            let args = ("total_supply",);
+            ArgsParser::parse(args)?.into_bytes()?
+        };
+
+        let result = self.call_contract(mint_contract_key, args_bytes)?;
+        let total_supply = result.into_t()?;
+
+        Ok(total_supply)
+    }
+
+    /// Calls the "mint_tokens" method on the mint contract at the given mint
+    /// contract key
+    fn mint_issue_tokens(
+        &mut self,
+        mint_contract_key: Key,
+        authority: URef,
+        purse: URef,
+        amount: U512,
+    ) -> Result<(), Error> {
+        let args_bytes = {
+            let args = ("mint_tokens", authority, purse, amount);
+            ArgsParser::parse(args)?.into_bytes()?
+        };
+
+        let result = self.call_contract(mint_contract_key, args_bytes)?;
+        let result: Result<(), mint::Error> = result.into_t()?;
+        Ok(result.map_err(system_contract_errors::Error::from)?)
+    }
+
     /// Creates a new account at a given public key, transferring a given amount
     /// of motes from the given source purse to the new account's purse.
     fn transfer_to_new_account(
@@ -2593,6 +2877,87 @@ where
         }
     }
 
+    /// Schedules a call to the stored contract at `contract` for block time `due`, moving `amount`
+    /// from `purse` into a new escrow purse which pays for it.  `amount` must be at least
+    /// [`scheduled_call::MIN_ESCROW`].
+    ///
+    /// Session code schedules calls which run as session code of its account, while a stored
+    /// contract can only schedule calls to itself, which run in its own context.
+    #[allow(clippy::too_many_arguments)]
+    fn schedule_call(
+        &mut self,
+        contract_ptr: u32,
+        contract_size: u32,
+        args_ptr: u32,
+        args_size: u32,
+        due: u64,
+        purse_ptr: u32,
+        purse_size: u32,
+        amount_ptr: u32,
+        amount_size: u32,
+    ) -> Result<Result<(), ApiError>, Error> {
+        let contract = self.key_from_mem(contract_ptr, contract_size)?.normalize();
+        match contract {
+            Key::Hash(_) | Key::URef(_) => (),
+            _ => return Ok(Err(ApiError::InvalidArgument)),
+        }
+        match self.context.read_gs(&contract)? {
+            Some(StoredValue::Contract(_)) => (),
+            _ => return Ok(Err(ApiError::InvalidArgument)),
+        }
+
+        let base_key = self.context.base_key();
+        let call_context = if base_key == Key::Account(self.context.account().public_key()) {
+            CallContext::Session
+        } else if base_key.normalize() == contract {
+            CallContext::Contract
+        } else {
+            return Err(Error::InvalidContext);
+        };
+
+        let args_bytes = self.bytes_from_mem(args_ptr, args_size as usize)?;
+        let args: Vec<CLValue> = bytesrepr::deserialize(args_bytes.clone())?;
+        for arg in &args {
+            for uref in extract_urefs(arg)? {
+                self.context.validate_key(&Key::from(uref))?;
+            }
+        }
+
+        let purse: URef = {
+            let bytes = self.bytes_from_mem(purse_ptr, purse_size as usize)?;
+            bytesrepr::deserialize(bytes).map_err(Error::BytesRepr)?
+        };
+
+        let amount: U512 = {
+            let bytes = self.bytes_from_mem(amount_ptr, amount_size as usize)?;
+            bytesrepr::deserialize(bytes).map_err(Error::BytesRepr)?
+        };
+        if amount < U512::from(scheduled_call::MIN_ESCROW) {
+            return Ok(Err(ApiError::InvalidArgument));
+        }
+
+        let mint_contract_key = self.get_mint_contract_uref().into();
+        let escrow_purse = self.mint_create(mint_contract_key)?;
+        if self
+            .mint_transfer(mint_contract_key, purse, escrow_purse, amount)
+            .is_err()
+        {
+            return Ok(Err(ApiError::Transfer));
+        }
+
+        let call = ScheduledCall::new(
+            self.context.account().public_key(),
+            call_context,
+            contract,
+            args_bytes,
+            BlockTime::new(due),
+            escrow_purse,
+            purse,
+        );
+        self.context.schedule_call(call)?;
+        Ok(Ok(()))
+    }
+
     fn get_balance(&mut self, purse: URef) -> Result<Option<U512>, Error> {
         let seed = self.get_mint_contract_uref().addr();
 
@@ -2783,10 +3148,47 @@ mod tests {
         result,
     };
 
-    use types::{gens::*, CLType, CLValue, Key, URef};
+    use types::{bytesrepr::ToBytes, gens::*, CLField, CLType, CLValue, CLVariant, Key, URef};
 
     use super::extract_urefs;
 
+    fn record_type() -> CLType {
+        CLType::Struct {
+            name: String::from("Record"),
+            fields: vec![
+                CLField::new("unit", CLType::Unit),
+                CLField::new("name", CLType::String),
+                CLField::new("purse", CLType::URef),
+                CLField::new("owner", CLType::Option(Box::new(CLType::Key))),
+            ],
+        }
+    }
+
+    fn choice_type() -> CLType {
+        CLType::Enum {
+            name: String::from("Choice"),
+            variants: vec![
+                CLVariant::new(0, "Nothing", vec![]),
+                CLVariant::new(
+                    1,
+                    "Purses",
+                    vec![CLField::new("0", CLType::List(Box::new(record_type())))],
+                ),
+            ],
+        }
+    }
+
+    fn record_arb() -> impl Strategy<Value = (Vec<u8>, Vec<URef>)> {
+        (".*", uref_arb(), option::of(key_arb())).prop_map(|(name, purse, owner)| {
+            let mut bytes = name.into_bytes().expect("should serialize");
+            bytes.append(&mut purse.to_bytes().expect("should serialize"));
+            bytes.append(&mut owner.to_bytes().expect("should serialize"));
+            let mut urefs = vec![purse];
+            urefs.extend(owner.iter().filter_map(Key::as_uref).cloned());
+            (bytes, urefs)
+        })
+    }
+
     fn cl_value_with_urefs_arb() -> impl Strategy<Value = (CLValue, Vec<URef>)> {
         // If compiler brings you here it most probably means you've added a variant to `CLType`
         // enum but forgot to add generator for it.
@@ -2802,6 +3204,8 @@ mod tests {
                 | CLType::U128
                 | CLType::U256
                 | CLType::U512
+                | CLType::I256
+                | CLType::Decimal
                 | CLType::Unit
                 | CLType::String
                 | CLType::Key
@@ -2809,12 +3213,15 @@ mod tests {
                 | CLType::Option(_)
                 | CLType::List(_)
                 | CLType::FixedList(..)
+                | CLType::ByteArray(_)
                 | CLType::Result { .. }
                 | CLType::Map { .. }
                 | CLType::Tuple1(_)
                 | CLType::Tuple2(_)
                 | CLType::Tuple3(_)
-                | CLType::Any => (),
+                | CLType::Any
+                | CLType::Struct { .. }
+                | CLType::Enum { .. } => (),
             }
         };
 
@@ -2830,6 +3237,9 @@ mod tests {
             u128_arb().prop_map(|x| (CLValue::from_t(x).expect("should create CLValue"), vec![])),
             u256_arb().prop_map(|x| (CLValue::from_t(x).expect("should create CLValue"), vec![])),
             u512_arb().prop_map(|x| (CLValue::from_t(x).expect("should create CLValue"), vec![])),
+            i256_arb().prop_map(|x| (CLValue::from_t(x).expect("should create CLValue"), vec![])),
+            decimal_arb()
+                .prop_map(|x| (CLValue::from_t(x).expect("should create CLValue"), vec![])),
             key_arb().prop_map(|x| {
                 let urefs = x.as_uref().into_iter().cloned().collect();
                 (CLValue::from_t(x).expect("should create CLValue"), urefs)
@@ -2858,6 +3268,8 @@ mod tests {
             )),
             [any::<u64>(); 32]
                 .prop_map(|x| (CLValue::from_t(x).expect("should create CLValue"), vec![])),
+            u8_slice_32()
+                .prop_map(|x| (CLValue::from_t(x).expect("should create CLValue"), vec![])),
             array::uniform8(uref_arb()).prop_map(|x| {
                 let urefs = x.to_vec();
                 (CLValue::from_t(x).expect("should create CLValue"), urefs)
@@ -2917,6 +3329,19 @@ mod tests {
                 urefs.extend(x.1.as_uref().into_iter().cloned());
                 (CLValue::from_t(x).expect("should create CLValue"), urefs)
             }),
+            record_arb()
+                .prop_map(|(bytes, urefs)| (CLValue::from_components(record_type(), bytes), urefs)),
+            Just((CLValue::from_components(choice_type(), vec![0]), vec![])),
+            vec(record_arb(), 0..10).prop_map(|records| {
+                let mut bytes = vec![1];
+                bytes.append(&mut (records.len() as u32).to_bytes().expect("should serialize"));
+                let mut urefs = vec![];
+                for (mut record_bytes, mut record_urefs) in records {
+                    bytes.append(&mut record_bytes);
+                    urefs.append(&mut record_urefs);
+                }
+                (CLValue::from_components(choice_type(), bytes), urefs)
+            }),
         ]
     }
 
@@ -2927,4 +3352,14 @@ mod tests {
             assert_eq!(extracted_urefs, urefs);
         }
     }
+
+    #[test]
+    fn should_reject_struct_with_field_of_unknown_layout() {
+        let cl_type = CLType::Struct {
+            name: String::from("Opaque"),
+            fields: vec![CLField::new("inner", CLType::Any)],
+        };
+        let cl_value = CLValue::from_components(cl_type, vec![]);
+        assert!(extract_urefs(&cl_value).is_err());
+    }
 }
@@ -1,20 +1,76 @@
-use std::collections::{BTreeMap, BTreeSet};
-
 use engine_shared::stored_value::StoredValue;
 use engine_storage::global_state::StateReader;
 use proof_of_stake::{
-    MintProvider, ProofOfStake, Queue, QueueProvider, RuntimeProvider, Stakes, StakesProvider,
+    DelegationQueue, Delegations, DelegationsProvider, EraRewards, Jail, JailProvider,
+    MintProvider, ParamsProvider, PosParams, ProofOfStake, Queue, QueueProvider, RewardsProvider,
+    RuntimeProvider, Stakes, StakesProvider,
 };
 use types::{
-    account::PublicKey, bytesrepr::ToBytes, system_contract_errors::pos::Error, ApiError,
-    BlockTime, CLValue, Key, Phase, TransferredTo, URef, U512,
+    account::PublicKey,
+    bytesrepr::{FromBytes, ToBytes},
+    system_contract_errors::pos::Error,
+    ApiError, BlockTime, CLTyped, CLValue, Key, Phase, TransferredTo, URef, U512,
 };
 
 use crate::{execution, runtime::Runtime};
-use std::fmt::Write;
 
-const BONDING_KEY: u8 = 1;
-const UNBONDING_KEY: u8 = 2;
+const STAKES_KEY: &str = "pos_stakes";
+const BONDING_QUEUE_KEY: &str = "pos_bonding_queue";
+const UNBONDING_QUEUE_KEY: &str = "pos_unbonding_queue";
+const PARAMS_KEY: &str = "pos_params";
+const DELEGATIONS_KEY: &str = "pos_delegations";
+const DELEGATING_QUEUE_KEY: &str = "pos_delegating_queue";
+const UNDELEGATING_QUEUE_KEY: &str = "pos_undelegating_queue";
+const ERA_REWARDS_KEY: &str = "pos_era_rewards";
+const JAIL_KEY: &str = "pos_jail";
+
+impl<'a, R> DelegationsProvider for Runtime<'a, R>
+where
+    R: StateReader<Key, StoredValue>,
+    R::Error: Into<execution::Error>,
+{
+    fn read_delegations(&mut self) -> Result<Delegations, Error> {
+        Ok(self.read_pos_value(DELEGATIONS_KEY)?.unwrap_or_default())
+    }
+
+    fn write_delegations(&mut self, delegations: Delegations) -> Result<(), Error> {
+        self.write_pos_value(DELEGATIONS_KEY, delegations)
+    }
+
+    fn read_delegating(&mut self) -> Result<DelegationQueue, Error> {
+        Ok(self
+            .read_pos_value(DELEGATING_QUEUE_KEY)?
+            .unwrap_or_default())
+    }
+
+    fn read_undelegating(&mut self) -> Result<DelegationQueue, Error> {
+        Ok(self
+            .read_pos_value(UNDELEGATING_QUEUE_KEY)?
+            .unwrap_or_default())
+    }
+
+    fn write_delegating(&mut self, queue: DelegationQueue) -> Result<(), Error> {
+        self.write_pos_value(DELEGATING_QUEUE_KEY, queue)
+    }
+
+    fn write_undelegating(&mut self, queue: DelegationQueue) -> Result<(), Error> {
+        self.write_pos_value(UNDELEGATING_QUEUE_KEY, queue)
+    }
+}
+
+impl<'a, R> JailProvider for Runtime<'a, R>
+where
+    R: StateReader<Key, StoredValue>,
+    R::Error: Into<execution::Error>,
+{
+    fn read_jail(&mut self) -> Result<Jail, Error> {
+        Ok(self.read_pos_value(JAIL_KEY)?.unwrap_or_default())
+    }
+
+    fn write_jail(&mut self, jail: Jail) -> Result<(), Error> {
+        self.write_pos_value(JAIL_KEY, jail)
+    }
+}
 
 // TODO: Update MintProvider to better handle errors
 impl<'a, R> MintProvider for Runtime<'a, R>
@@ -52,44 +108,110 @@ where
     fn balance(&mut self, purse: URef) -> Option<U512> {
         self.get_balance(purse).expect("should get balance")
     }
+
+    fn total_supply(&mut self) -> U512 {
+        let mint_contract_key = self.get_mint_contract_uref().into();
+        self.mint_total_supply(mint_contract_key)
+            .expect("should get total supply")
+    }
+
+    fn mint_tokens(&mut self, authority: URef, purse: URef, amount: U512) -> Result<(), ()> {
+        let mint_contract_key = self.get_mint_contract_uref().into();
+        self.mint_issue_tokens(mint_contract_key, authority, purse, amount)
+            .map_err(|_| ())
+    }
 }
 
-// TODO: Update QueueProvider to better handle errors
-impl<'a, R> QueueProvider for Runtime<'a, R>
+impl<'a, R> RewardsProvider for Runtime<'a, R>
 where
     R: StateReader<Key, StoredValue>,
     R::Error: Into<execution::Error>,
 {
-    fn read_bonding(&mut self) -> Queue {
-        let key = BONDING_KEY.to_bytes().expect("should serialize");
-        match self.context.read_ls(&key) {
-            Ok(Some(cl_value)) => cl_value.into_t().expect("should convert"),
-            _ => Queue::default(),
+    fn read_era_rewards(&mut self) -> Result<Option<EraRewards>, Error> {
+        Ok(self.read_pos_value(ERA_REWARDS_KEY)?.unwrap_or_default())
+    }
+
+    fn write_era_rewards(&mut self, era_rewards: EraRewards) -> Result<(), Error> {
+        self.write_pos_value(ERA_REWARDS_KEY, Some(era_rewards))
+    }
+}
+
+impl<'a, R> Runtime<'a, R>
+where
+    R: StateReader<Key, StoredValue>,
+    R::Error: Into<execution::Error>,
+{
+    /// Returns the `URef` key named `name` in the PoS contract's named keys.
+    fn get_pos_value_key(&self, name: &str) -> Result<Key, Error> {
+        match self.context.named_keys_get(name) {
+            Some(key @ Key::URef(_)) => Ok(*key),
+            Some(_) => Err(Error::StateKeyUnexpectedType),
+            None => Err(Error::StateKeyNotFound),
         }
     }
 
-    fn read_unbonding(&mut self) -> Queue {
-        let key = UNBONDING_KEY.to_bytes().expect("should serialize");
-        match self.context.read_ls(&key) {
-            Ok(Some(cl_value)) => cl_value.into_t().expect("should convert"),
-            _ => Queue::default(),
+    /// Reads the value stored under the `URef` named `name` in the PoS contract's named keys, or
+    /// `None` if nothing has been written there yet.
+    fn read_pos_value<T: CLTyped + FromBytes>(&self, name: &str) -> Result<Option<T>, Error> {
+        let key = self.get_pos_value_key(name)?;
+        let state = self.context.state();
+        let maybe_stored_value = state
+            .borrow_mut()
+            .read(self.context.correlation_id(), &key)
+            .map_err(|_| Error::StateSerializationFailed)?;
+        match maybe_stored_value {
+            Some(StoredValue::CLValue(cl_value)) => cl_value
+                .into_t()
+                .map(Some)
+                .map_err(|_| Error::StateSerializationFailed),
+            Some(_) => Err(Error::StateSerializationFailed),
+            None => Ok(None),
         }
     }
 
-    fn write_bonding(&mut self, queue: Queue) {
-        let key = BONDING_KEY.to_bytes().expect("should serialize");
-        let value = CLValue::from_t(queue).expect("should convert");
+    /// Writes `value` under the `URef` named `name` in the PoS contract's named keys.
+    fn write_pos_value<T: CLTyped + ToBytes>(&mut self, name: &str, value: T) -> Result<(), Error> {
+        let key = self.get_pos_value_key(name)?;
+        let value = CLValue::from_t(value).map_err(|_| Error::StateSerializationFailed)?;
         self.context
-            .write_ls(&key, value)
-            .expect("should write local state")
+            .state()
+            .borrow_mut()
+            .write(key, StoredValue::CLValue(value));
+        Ok(())
     }
+}
 
-    fn write_unbonding(&mut self, queue: Queue) {
-        let key = UNBONDING_KEY.to_bytes().expect("should serialize");
-        let value = CLValue::from_t(queue).expect("should convert");
-        self.context
-            .write_ls(&key, value)
-            .expect("should write local state")
+impl<'a, R> ParamsProvider for Runtime<'a, R>
+where
+    R: StateReader<Key, StoredValue>,
+    R::Error: Into<execution::Error>,
+{
+    fn read_params(&mut self) -> Result<PosParams, Error> {
+        Ok(self.read_pos_value(PARAMS_KEY)?.unwrap_or_default())
+    }
+}
+
+impl<'a, R> QueueProvider for Runtime<'a, R>
+where
+    R: StateReader<Key, StoredValue>,
+    R::Error: Into<execution::Error>,
+{
+    fn read_bonding(&mut self) -> Result<Queue, Error> {
+        Ok(self.read_pos_value(BONDING_QUEUE_KEY)?.unwrap_or_default())
+    }
+
+    fn read_unbonding(&mut self) -> Result<Queue, Error> {
+        Ok(self
+            .read_pos_value(UNBONDING_QUEUE_KEY)?
+            .unwrap_or_default())
+    }
+
+    fn write_bonding(&mut self, queue: Queue) -> Result<(), Error> {
+        self.write_pos_value(BONDING_QUEUE_KEY, queue)
+    }
+
+    fn write_unbonding(&mut self, queue: Queue) -> Result<(), Error> {
+        self.write_pos_value(UNBONDING_QUEUE_KEY, queue)
     }
 }
 
@@ -132,68 +254,15 @@ where
     R::Error: Into<execution::Error>,
 {
     fn read(&self) -> Result<Stakes, Error> {
-        let mut stakes = BTreeMap::new();
-        for (name, _) in self.context.named_keys().iter() {
-            let mut split_name = name.split('_');
-            if Some("v") != split_name.next() {
-                continue;
-            }
-            let hex_key = split_name
-                .next()
-                .ok_or(Error::StakesKeyDeserializationFailed)?;
-            if hex_key.len() != 64 {
-                return Err(Error::StakesKeyDeserializationFailed);
-            }
-            let mut key_bytes = [0u8; 32];
-            let _bytes_written = base16::decode_slice(hex_key, &mut key_bytes)
-                .map_err(|_| Error::StakesKeyDeserializationFailed)?;
-            debug_assert!(_bytes_written == key_bytes.len());
-            let pub_key = PublicKey::ed25519_from(key_bytes);
-            let balance = split_name
-                .next()
-                .and_then(|b| U512::from_dec_str(b).ok())
-                .ok_or(Error::StakesDeserializationFailed)?;
-            stakes.insert(pub_key, balance);
-        }
-        if stakes.is_empty() {
-            return Err(Error::StakesNotFound);
-        }
-        Ok(Stakes(stakes))
-    }
-
-    fn write(&mut self, stakes: &Stakes) {
-        // Encode the stakes as a set of uref names.
-        let mut new_urefs: BTreeSet<String> = stakes
-            .0
-            .iter()
-            .map(|(pub_key, balance)| {
-                let key_bytes = pub_key.value();
-                let mut hex_key = String::with_capacity(64);
-                for byte in &key_bytes[..32] {
-                    write!(hex_key, "{:02x}", byte).expect("Writing to a string cannot fail");
-                }
-                let mut uref = String::new();
-                uref.write_fmt(format_args!("v_{}_{}", hex_key, balance))
-                    .expect("Writing to a string cannot fail");
-                uref
-            })
-            .collect();
-        // Remove and add urefs to update the contract's known urefs accordingly.
-        let mut removes = Vec::new();
-        for (name, _) in self.context.named_keys().iter() {
-            if name.starts_with("v_") && !new_urefs.remove(name) {
-                removes.push(name.to_owned())
-            }
-        }
-        for name in removes.iter() {
-            self.context.remove_key(name).expect("should remove key")
-        }
-        for name in new_urefs {
-            self.context
-                .put_key(name, Key::Hash([0; 32]))
-                .expect("should put key")
+        match self.read_pos_value::<Stakes>(STAKES_KEY)? {
+            Some(stakes) if !stakes.0.is_empty() => Ok(stakes),
+            _ => Err(Error::StakesNotFound),
         }
     }
+
+    fn write(&mut self, stakes: &Stakes) -> Result<(), Error> {
+        self.write_pos_value(STAKES_KEY, stakes.clone())
+    }
 }
 
 impl<'a, R> ProofOfStake for Runtime<'a, R>
@@ -12,23 +12,30 @@ use blake2::{
 };
 
 use engine_shared::{
-    account::Account, contract::Contract, gas::Gas, newtypes::CorrelationId,
+    account::{Account, AssociatedKeys, Recovery},
+    contract::Contract,
+    gas::Gas,
+    newtypes::CorrelationId,
     stored_value::StoredValue,
 };
 use engine_storage::{global_state::StateReader, protocol_data::ProtocolData};
 use types::{
     account::{
-        ActionType, AddKeyFailure, PublicKey, RemoveKeyFailure, SetThresholdFailure,
-        UpdateKeyFailure, Weight,
+        ActionType, AddKeyFailure, PublicKey, RecoveryFailure, RemoveKeyFailure,
+        SetThresholdFailure, UpdateKeyFailure, Weight,
     },
-    bytesrepr::{self, ToBytes},
-    AccessRights, BlockTime, CLType, CLValue, Key, Phase, ProtocolVersion, URef,
-    KEY_LOCAL_SEED_LENGTH,
+    bytesrepr::{self, FromBytes, ToBytes},
+    AccessRights, BlockTime, CLType, CLValue, Decimal, Key, Phase, ProtocolVersion, URef, I256,
+    KEY_LOCAL_SEED_LENGTH, U128, U256, U512,
 };
 
 use crate::{
-    engine_state::{execution_effect::ExecutionEffect, SYSTEM_ACCOUNT_ADDR},
-    execution::{AddressGenerator, Error},
+    engine_state::{
+        execution_effect::ExecutionEffect,
+        scheduled_call::{self, ScheduledCall},
+        SYSTEM_ACCOUNT_ADDR,
+    },
+    execution::{AddressGenerator, CallStack, Error},
     tracking_copy::{AddResult, TrackingCopy},
     Address,
 };
@@ -50,6 +57,128 @@ pub(crate) fn attenuate_uref_for_account(account: &Account, uref: URef) -> URef
     }
 }
 
+/// Walks a serialized value of type `cl_type` and returns the URefs it holds, including those of
+/// its keys, along with the remainder of `bytes`.
+///
+/// Values of `CLType::Any` have no known layout, so they can't be walked and are rejected.
+pub(crate) fn extract_urefs_by_type<'a>(
+    cl_type: &CLType,
+    bytes: &'a [u8],
+) -> Result<(Vec<URef>, &'a [u8]), bytesrepr::Error> {
+    fn skip<T: FromBytes>(bytes: &[u8]) -> Result<(Vec<URef>, &[u8]), bytesrepr::Error> {
+        let (_, remainder) = T::from_bytes(bytes)?;
+        Ok((vec![], remainder))
+    }
+
+    fn extract_urefs_in_sequence<'a, 'b, I: IntoIterator<Item = &'b CLType>>(
+        cl_types: I,
+        mut bytes: &'a [u8],
+    ) -> Result<(Vec<URef>, &'a [u8]), bytesrepr::Error> {
+        let mut urefs = vec![];
+        for cl_type in cl_types {
+            let (mut found, remainder) = extract_urefs_by_type(cl_type, bytes)?;
+            urefs.append(&mut found);
+            bytes = remainder;
+        }
+        Ok((urefs, bytes))
+    }
+
+    fn extract_urefs_in_list<'a>(
+        cl_type: &CLType,
+        len: u32,
+        mut bytes: &'a [u8],
+    ) -> Result<(Vec<URef>, &'a [u8]), bytesrepr::Error> {
+        let mut urefs = vec![];
+        for _ in 0..len {
+            let (mut found, remainder) = extract_urefs_by_type(cl_type, bytes)?;
+            // An item taking no bytes holds no URefs, and neither do the rest of them.
+            if remainder.len() == bytes.len() {
+                break;
+            }
+            urefs.append(&mut found);
+            bytes = remainder;
+        }
+        Ok((urefs, bytes))
+    }
+
+    match cl_type {
+        CLType::Bool => skip::<bool>(bytes),
+        CLType::I32 => skip::<i32>(bytes),
+        CLType::I64 => skip::<i64>(bytes),
+        CLType::U8 => skip::<u8>(bytes),
+        CLType::U32 => skip::<u32>(bytes),
+        CLType::U64 => skip::<u64>(bytes),
+        CLType::U128 => skip::<U128>(bytes),
+        CLType::U256 => skip::<U256>(bytes),
+        CLType::U512 => skip::<U512>(bytes),
+        CLType::I256 => skip::<I256>(bytes),
+        CLType::Decimal => skip::<Decimal>(bytes),
+        CLType::Unit => Ok((vec![], bytes)),
+        CLType::String => skip::<String>(bytes),
+        CLType::Key => {
+            let (key, remainder) = Key::from_bytes(bytes)?;
+            Ok((key.into_uref().into_iter().collect(), remainder))
+        }
+        CLType::URef => {
+            let (uref, remainder) = URef::from_bytes(bytes)?;
+            Ok((vec![uref], remainder))
+        }
+        CLType::Option(cl_type) => {
+            let (tag, remainder) = u8::from_bytes(bytes)?;
+            match tag {
+                0 => Ok((vec![], remainder)),
+                1 => extract_urefs_by_type(cl_type, remainder),
+                _ => Err(bytesrepr::Error::Formatting),
+            }
+        }
+        CLType::List(cl_type) => {
+            let (len, remainder) = u32::from_bytes(bytes)?;
+            extract_urefs_in_list(cl_type, len, remainder)
+        }
+        CLType::FixedList(cl_type, len) => extract_urefs_in_list(cl_type, *len, bytes),
+        CLType::ByteArray(len) => {
+            if bytes.len() < *len as usize {
+                return Err(bytesrepr::Error::EarlyEndOfStream);
+            }
+            Ok((vec![], &bytes[*len as usize..]))
+        }
+        CLType::Result { ok, err } => {
+            let (tag, remainder) = u8::from_bytes(bytes)?;
+            match tag {
+                0 => extract_urefs_by_type(err, remainder),
+                1 => extract_urefs_by_type(ok, remainder),
+                _ => Err(bytesrepr::Error::Formatting),
+            }
+        }
+        CLType::Map { key, value } => {
+            let (len, remainder) = u32::from_bytes(bytes)?;
+            let entry = CLType::Tuple2([key.clone(), value.clone()]);
+            extract_urefs_in_list(&entry, len, remainder)
+        }
+        CLType::Tuple1(cl_types) => {
+            extract_urefs_in_sequence(cl_types.iter().map(|cl_type| &**cl_type), bytes)
+        }
+        CLType::Tuple2(cl_types) => {
+            extract_urefs_in_sequence(cl_types.iter().map(|cl_type| &**cl_type), bytes)
+        }
+        CLType::Tuple3(cl_types) => {
+            extract_urefs_in_sequence(cl_types.iter().map(|cl_type| &**cl_type), bytes)
+        }
+        CLType::Any => Err(bytesrepr::Error::Formatting),
+        CLType::Struct { fields, .. } => {
+            extract_urefs_in_sequence(fields.iter().map(|field| &field.cl_type), bytes)
+        }
+        CLType::Enum { variants, .. } => {
+            let (tag, remainder) = u8::from_bytes(bytes)?;
+            let variant = variants
+                .iter()
+                .find(|variant| variant.tag == tag)
+                .ok_or(bytesrepr::Error::Formatting)?;
+            extract_urefs_in_sequence(variant.fields.iter().map(|field| &field.cl_type), remainder)
+        }
+    }
+}
+
 /// Holds information specific to the deployed contract.
 pub struct RuntimeContext<'a, R> {
     state: Rc<RefCell<TrackingCopy<R>>>,
@@ -60,6 +189,7 @@ pub struct RuntimeContext<'a, R> {
     // Original account for read only tasks taken before execution
     account: &'a Account,
     args: Vec<CLValue>,
+    named_args: BTreeMap<String, CLValue>,
     authorization_keys: BTreeSet<PublicKey>,
     // Key pointing to the entity we are currently running
     //(could point at an account or contract in the global state)
@@ -74,6 +204,7 @@ pub struct RuntimeContext<'a, R> {
     correlation_id: CorrelationId,
     phase: Phase,
     protocol_data: ProtocolData,
+    call_stack: CallStack,
 }
 
 impl<'a, R> RuntimeContext<'a, R>
@@ -87,6 +218,7 @@ where
         named_keys: &'a mut BTreeMap<String, Key>,
         access_rights: HashMap<Address, HashSet<AccessRights>>,
         args: Vec<CLValue>,
+        named_args: BTreeMap<String, CLValue>,
         authorization_keys: BTreeSet<PublicKey>,
         account: &'a Account,
         base_key: Key,
@@ -100,12 +232,14 @@ where
         correlation_id: CorrelationId,
         phase: Phase,
         protocol_data: ProtocolData,
+        call_stack: CallStack,
     ) -> Self {
         RuntimeContext {
             state,
             named_keys,
             access_rights,
             args,
+            named_args,
             account,
             authorization_keys,
             blocktime,
@@ -119,6 +253,7 @@ where
             correlation_id,
             phase,
             protocol_data,
+            call_stack,
         }
     }
 
@@ -227,6 +362,10 @@ where
         &self.args
     }
 
+    pub fn named_arg(&self, name: &str) -> Option<&CLValue> {
+        self.named_args.get(name)
+    }
+
     pub fn address_generator(&self) -> Rc<RefCell<AddressGenerator>> {
         Rc::clone(&self.address_generator)
     }
@@ -476,16 +615,24 @@ where
                 | CLType::U128
                 | CLType::U256
                 | CLType::U512
+                | CLType::I256
+                | CLType::Decimal
                 | CLType::Unit
                 | CLType::String
                 | CLType::Option(_)
                 | CLType::List(_)
                 | CLType::FixedList(..)
+                | CLType::ByteArray(_)
                 | CLType::Result { .. }
                 | CLType::Map { .. }
                 | CLType::Tuple1(_)
                 | CLType::Tuple3(_)
                 | CLType::Any => Ok(()),
+                CLType::Struct { .. } | CLType::Enum { .. } => {
+                    let (urefs, _) =
+                        extract_urefs_by_type(cl_value.cl_type(), cl_value.inner_bytes())?;
+                    urefs.iter().try_for_each(|uref| self.validate_uref(uref))
+                }
                 CLType::Key => {
                     let key: Key = cl_value.to_owned().into_t()?; // TODO: optimize?
                     self.validate_key(&key)
@@ -657,6 +804,7 @@ where
             Ok(AddResult::KeyNotFound(key)) => Err(Error::KeyNotFound(key)),
             Ok(AddResult::TypeMismatch(type_mismatch)) => Err(Error::TypeMismatch(type_mismatch)),
             Ok(AddResult::Serialization(error)) => Err(Error::BytesRepr(error)),
+            Ok(AddResult::Overflow) => Err(Error::Overflow),
         }
     }
 
@@ -810,6 +958,111 @@ where
         Ok(())
     }
 
+    /// Designates the recovery keys of the current account, the weight of them needed to request a
+    /// recovery and the delay its own keys have to cancel a recovery request.  Passing no keys opts
+    /// the account out of recovery.
+    pub fn set_recovery(
+        &mut self,
+        keys: BTreeMap<PublicKey, Weight>,
+        threshold: Weight,
+        delay: u64,
+    ) -> Result<(), Error> {
+        if !self.can_manage_own_keys() {
+            return Err(RecoveryFailure::PermissionDenied.into());
+        }
+
+        let recovery = if keys.is_empty() {
+            None
+        } else {
+            let mut recovery_keys = AssociatedKeys::default();
+            for (public_key, weight) in keys {
+                recovery_keys
+                    .add_key(public_key, weight)
+                    .map_err(|_| RecoveryFailure::MaxKeysLimit)?;
+            }
+            Some(Recovery::new(recovery_keys, threshold, delay)?)
+        };
+
+        let block_time = self.get_blocktime();
+        let key = Key::Account(self.account().public_key());
+        let mut account: Account = self.read_gs_typed(&key)?;
+        account.set_recovery(recovery, block_time)?;
+        self.write_account(key, account)
+    }
+
+    /// Approves recovery of `public_key`'s account, with the current account acting as one of its
+    /// recovery keys.  The recovery is requested once the approving keys meet the account's
+    /// recovery threshold.
+    pub fn request_recovery(&mut self, public_key: PublicKey) -> Result<(), Error> {
+        if !self.can_manage_own_keys() {
+            return Err(RecoveryFailure::PermissionDenied.into());
+        }
+        let recovery_key = self.account().public_key();
+        let block_time = self.get_blocktime();
+        self.update_recovered_account(public_key, |account| {
+            account.request_recovery(recovery_key, block_time)
+        })
+    }
+
+    /// Cancels a pending recovery request of the current account.
+    pub fn cancel_recovery(&mut self) -> Result<(), Error> {
+        if !self.can_manage_own_keys() {
+            return Err(RecoveryFailure::PermissionDenied.into());
+        }
+        let block_time = self.get_blocktime();
+        let key = Key::Account(self.account().public_key());
+        let mut account: Account = self.read_gs_typed(&key)?;
+        account.cancel_recovery(block_time)?;
+        self.write_account(key, account)
+    }
+
+    /// Completes recovery of `public_key`'s account once the delay of its request has elapsed,
+    /// with the current account acting as one of its recovery keys.
+    pub fn recover_account(&mut self, public_key: PublicKey) -> Result<(), Error> {
+        if !self.can_manage_own_keys() {
+            return Err(RecoveryFailure::PermissionDenied.into());
+        }
+        let recovery_key = self.account().public_key();
+        let block_time = self.get_blocktime();
+        self.update_recovered_account(public_key, |account| {
+            account.recover(recovery_key, block_time)
+        })
+    }
+
+    /// Checks if the current account is the context and the authorization keys meet its key
+    /// management threshold.
+    fn can_manage_own_keys(&self) -> bool {
+        self.is_valid_context()
+            && self
+                .account()
+                .can_manage_keys_with(&self.authorization_keys)
+    }
+
+    /// Applies `update` to the account being recovered.  That account isn't the context, so it's
+    /// read and written directly; `update` is responsible for checking the recovery keys.  Its
+    /// named keys are left untouched, so they aren't validated against the current context.
+    fn update_recovered_account<F>(&mut self, public_key: PublicKey, update: F) -> Result<(), Error>
+    where
+        F: FnOnce(&mut Account) -> Result<(), RecoveryFailure>,
+    {
+        let key = Key::Account(public_key);
+        let mut account = match self.read_gs_direct(&key)? {
+            Some(StoredValue::Account(account)) => account,
+            _ => return Err(RecoveryFailure::NotConfigured.into()),
+        };
+        update(&mut account)?;
+        self.state
+            .borrow_mut()
+            .write(key, StoredValue::Account(account));
+        Ok(())
+    }
+
+    fn write_account(&mut self, key: Key, account: Account) -> Result<(), Error> {
+        let account_value = self.account_to_validated_value(account)?;
+        self.state.borrow_mut().write(key, account_value);
+        Ok(())
+    }
+
     pub fn upgrade_contract_at_uref(
         &mut self,
         key: Key,
@@ -827,10 +1080,35 @@ where
         Ok(())
     }
 
+    /// Stores `call` under a new hash and indexes it in the system account's named keys, where the
+    /// engine looks for calls which are due.
+    ///
+    /// The caller is responsible for checking that the current context may schedule `call`.
+    pub fn schedule_call(&mut self, call: ScheduledCall) -> Result<(), Error> {
+        let hash = self.new_function_address()?;
+        let key = Key::Hash(hash);
+        let call_value = StoredValue::CLValue(CLValue::from_t(call)?);
+        self.state.borrow_mut().write(key, call_value);
+
+        let name = scheduled_call::named_key_name(hash);
+        let named_key_value = StoredValue::CLValue(CLValue::from_t((name, key))?);
+        self.add_unsafe(Key::Account(SYSTEM_ACCOUNT_ADDR), named_key_value)
+    }
+
     pub fn protocol_data(&self) -> ProtocolData {
         self.protocol_data
     }
 
+    pub fn call_stack(&self) -> &CallStack {
+        &self.call_stack
+    }
+
+    /// Declares the contract being executed non-reentrant, so that it can't be called again until
+    /// it returns.
+    pub fn set_non_reentrant(&mut self) {
+        self.call_stack.set_non_reentrant(self.base_key);
+    }
+
     /// Attenuates URef for a given account.
     ///
     /// If the account is system account, then given URef receives
@@ -24,13 +24,17 @@ use types::{
     account::{
         ActionType, AddKeyFailure, PublicKey, RemoveKeyFailure, SetThresholdFailure, Weight,
     },
-    AccessRights, BlockTime, CLValue, Key, Phase, ProtocolVersion, URef, KEY_LOCAL_SEED_LENGTH,
+    bytesrepr::ToBytes,
+    AccessRights, BlockTime, CLField, CLType, CLValue, CLVariant, Key, Phase, ProtocolVersion,
+    URef, KEY_LOCAL_SEED_LENGTH,
 };
 
 use super::{attenuate_uref_for_account, Address, Error, RuntimeContext};
 use crate::{
-    engine_state::SYSTEM_ACCOUNT_ADDR, execution::AddressGenerator,
-    runtime::extract_access_rights_from_keys, tracking_copy::TrackingCopy,
+    engine_state::SYSTEM_ACCOUNT_ADDR,
+    execution::{AddressGenerator, CallStack},
+    runtime::extract_access_rights_from_keys,
+    tracking_copy::TrackingCopy,
 };
 
 const DEPLOY_HASH: [u8; 32] = [1u8; 32];
@@ -118,6 +122,7 @@ fn mock_runtime_context<'a>(
         named_keys,
         access_rights,
         Vec::new(),
+        BTreeMap::new(),
         BTreeSet::from_iter(vec![PublicKey::ed25519_from([0; 32])]),
         &account,
         base_key,
@@ -131,6 +136,7 @@ fn mock_runtime_context<'a>(
         CorrelationId::new(),
         Phase::Session,
         Default::default(),
+        CallStack::new(),
     )
 }
 
@@ -198,6 +204,63 @@ fn use_uref_forged() {
     assert_forged_reference(query_result);
 }
 
+fn purse_holder_value(purse: URef) -> StoredValue {
+    let cl_type = CLType::Struct {
+        name: String::from("PurseHolder"),
+        fields: vec![
+            CLField::new("id", CLType::U64),
+            CLField::new("purse", CLType::URef),
+        ],
+    };
+    let mut bytes = 7u64.to_bytes().unwrap();
+    bytes.append(&mut purse.to_bytes().unwrap());
+    StoredValue::CLValue(CLValue::from_components(cl_type, bytes))
+}
+
+#[test]
+fn write_struct_with_uref_valid() {
+    let mut rng = AddressGenerator::new(&DEPLOY_HASH, PHASE);
+    let uref = create_uref(&mut rng, AccessRights::READ_WRITE);
+    let purse = create_uref(&mut rng, AccessRights::READ_ADD_WRITE);
+    let access_rights = extract_access_rights_from_keys(vec![uref, purse]);
+    let value = purse_holder_value(purse.into_uref().unwrap());
+    let query_result = test(access_rights, |mut rc| rc.write_gs(uref, value));
+    query_result.expect("writing a struct with a valid uref should succeed");
+}
+
+#[test]
+fn write_struct_with_uref_forged() {
+    let mut rng = AddressGenerator::new(&DEPLOY_HASH, PHASE);
+    let uref = create_uref(&mut rng, AccessRights::READ_WRITE);
+    let forged_purse = create_uref(&mut rng, AccessRights::READ_ADD_WRITE);
+    let access_rights = extract_access_rights_from_keys(vec![uref]);
+    let value = purse_holder_value(forged_purse.into_uref().unwrap());
+    let query_result = test(access_rights, |mut rc| rc.write_gs(uref, value));
+
+    assert_forged_reference(query_result);
+}
+
+#[test]
+fn write_enum_with_uref_forged() {
+    let mut rng = AddressGenerator::new(&DEPLOY_HASH, PHASE);
+    let uref = create_uref(&mut rng, AccessRights::READ_WRITE);
+    let forged_key = create_uref(&mut rng, AccessRights::READ_ADD_WRITE);
+    let access_rights = extract_access_rights_from_keys(vec![uref]);
+    let cl_type = CLType::Enum {
+        name: String::from("MaybeKey"),
+        variants: vec![
+            CLVariant::new(0, "Nothing", vec![]),
+            CLVariant::new(1, "Just", vec![CLField::new("0", CLType::Key)]),
+        ],
+    };
+    let mut bytes = vec![1];
+    bytes.append(&mut forged_key.to_bytes().unwrap());
+    let value = StoredValue::CLValue(CLValue::from_components(cl_type, bytes));
+    let query_result = test(access_rights, |mut rc| rc.write_gs(uref, value));
+
+    assert_forged_reference(query_result);
+}
+
 #[test]
 fn store_contract_with_uref_valid() {
     let mut rng = AddressGenerator::new(&DEPLOY_HASH, PHASE);
@@ -449,6 +512,7 @@ fn contract_key_addable_valid() {
         &mut uref_map,
         access_rights,
         Vec::new(),
+        BTreeMap::new(),
         BTreeSet::from_iter(vec![PublicKey::ed25519_from(base_acc_addr)]),
         &account,
         contract_key,
@@ -462,6 +526,7 @@ fn contract_key_addable_valid() {
         CorrelationId::new(),
         PHASE,
         Default::default(),
+        CallStack::new(),
     );
 
     let uref_name = "NewURef".to_owned();
@@ -511,6 +576,7 @@ fn contract_key_addable_invalid() {
         &mut uref_map,
         access_rights,
         Vec::new(),
+        BTreeMap::new(),
         BTreeSet::from_iter(vec![PublicKey::ed25519_from(base_acc_addr)]),
         &account,
         other_contract_key,
@@ -524,6 +590,7 @@ fn contract_key_addable_invalid() {
         CorrelationId::new(),
         PHASE,
         Default::default(),
+        CallStack::new(),
     );
 
     let uref_name = "NewURef".to_owned();
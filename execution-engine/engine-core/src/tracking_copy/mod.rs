@@ -162,6 +162,7 @@ pub enum AddResult {
     KeyNotFound(Key),
     TypeMismatch(TypeMismatch),
     Serialization(bytesrepr::Error),
+    Overflow,
 }
 
 impl From<CLValueError> for AddResult {
@@ -266,7 +267,7 @@ impl<R: StateReader<Key, StoredValue>> TrackingCopy<R> {
         let type_name = value.type_name();
         let mismatch = || {
             Ok(AddResult::TypeMismatch(TypeMismatch::new(
-                "I32, U64, U128, U256, U512 or (String, Key) tuple".to_string(),
+                "I32, U64, U128, U256, U512, I256, Decimal or (String, Key) tuple".to_string(),
                 type_name,
             )))
         };
@@ -293,6 +294,14 @@ impl<R: StateReader<Key, StoredValue>> TrackingCopy<R> {
                     Ok(value) => Transform::AddUInt512(value),
                     Err(error) => return Ok(AddResult::from(error)),
                 },
+                CLType::I256 => match cl_value.into_t() {
+                    Ok(value) => Transform::AddInt256(value),
+                    Err(error) => return Ok(AddResult::from(error)),
+                },
+                CLType::Decimal => match cl_value.into_t() {
+                    Ok(value) => Transform::AddDecimal(value),
+                    Err(error) => return Ok(AddResult::from(error)),
+                },
                 _ => {
                     if *cl_value.cl_type() == types::named_key_type() {
                         match cl_value.into_t() {
@@ -310,7 +319,7 @@ impl<R: StateReader<Key, StoredValue>> TrackingCopy<R> {
             _ => return mismatch(),
         };
 
-        match transform.clone().apply(current_value) {
+        match transform.clone().checked_apply(current_value) {
             Ok(new_value) => {
                 self.cache.insert_write(normalized_key, new_value);
                 self.ops.insert_add(normalized_key, Op::Add);
@@ -321,6 +330,7 @@ impl<R: StateReader<Key, StoredValue>> TrackingCopy<R> {
                 Ok(AddResult::TypeMismatch(type_mismatch))
             }
             Err(transform::Error::Serialization(error)) => Ok(AddResult::Serialization(error)),
+            Err(transform::Error::Overflow) => Ok(AddResult::Overflow),
         }
     }
 
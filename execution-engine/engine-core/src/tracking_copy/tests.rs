@@ -14,7 +14,7 @@ use engine_storage::global_state::{in_memory::InMemoryGlobalState, StateProvider
 use types::{
     account::{PublicKey, Weight, ED25519_LENGTH},
     gens::*,
-    AccessRights, CLValue, Key, ProtocolVersion, URef,
+    AccessRights, CLValue, Key, ProtocolVersion, URef, I256,
 };
 
 use super::{
@@ -173,6 +173,49 @@ fn tracking_copy_add_i32() {
     assert_eq!(tc.ops.get(&k), Some(&Op::Add));
 }
 
+#[test]
+fn tracking_copy_add_i256() {
+    let correlation_id = CorrelationId::new();
+    let db = CountingDb::new_init(StoredValue::CLValue(CLValue::from_t(I256::MAX).unwrap()));
+    let mut tc = TrackingCopy::new(db);
+    let k = Key::Hash([0u8; 32]);
+
+    let minus_one = StoredValue::CLValue(CLValue::from_t(I256::from(-1)).unwrap());
+    let add = tc.add(correlation_id, k, minus_one);
+    assert_matches!(add, Ok(AddResult::Success));
+    assert_eq!(tc.fns.get(&k), Some(&Transform::AddInt256(I256::from(-1))));
+
+    // overflowing the value should fail and leave the existing transform unchanged
+    let two = StoredValue::CLValue(CLValue::from_t(I256::from(2)).unwrap());
+    let failed_add = tc.add(correlation_id, k, two);
+    assert_matches!(failed_add, Ok(AddResult::Overflow));
+    assert_eq!(tc.fns.get(&k), Some(&Transform::AddInt256(I256::from(-1))));
+}
+
+#[test]
+fn tracking_copy_add_i256_should_commit_final_value() {
+    let correlation_id = CorrelationId::new();
+    let db = CountingDb::new_init(StoredValue::CLValue(
+        CLValue::from_t(I256::from(-5)).unwrap(),
+    ));
+    let mut tc = TrackingCopy::new(db);
+    let k = Key::Hash([0u8; 32]);
+
+    // the sum of the additions overflows, but the value never does
+    for to_add in &[I256::MAX, I256::from(3)] {
+        let value = StoredValue::CLValue(CLValue::from_t(*to_add).unwrap());
+        let add = tc.add(correlation_id, k, value);
+        assert_matches!(add, Ok(AddResult::Success));
+    }
+
+    let expected = StoredValue::CLValue(
+        CLValue::from_t(I256::MAX.checked_sub(I256::from(2)).unwrap()).unwrap(),
+    );
+    let initial = StoredValue::CLValue(CLValue::from_t(I256::from(-5)).unwrap());
+    let transform = tc.fns.get(&k).cloned().expect("should have transform");
+    assert_eq!(transform.apply(initial), Ok(expected));
+}
+
 #[test]
 fn tracking_copy_add_named_key() {
     let zero_public_key = PublicKey::ed25519_from([0u8; ED25519_LENGTH]);
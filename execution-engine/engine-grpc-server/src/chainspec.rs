@@ -0,0 +1,546 @@
+//! Parsing of TOML chainspec files into a [`GenesisConfig`].
+//!
+//! Motes amounts are given as decimal strings since they don't fit into TOML integers.  Paths to
+//! the installer wasm files are relative to the directory containing the chainspec.
+//!
+//! ```toml
+//! [genesis]
+//! name = "casperlabs-devnet"
+//! timestamp = 1568805354071
+//! protocol_version = "1.0.0"
+//! mint_installer_path = "mint_install.wasm"
+//! pos_installer_path = "pos_install.wasm"
+//!
+//! [[genesis.accounts]]
+//! public_key = "d7ef0dd4e4b1b7de0b7b5ecfb4ec5d3a7c8b3ca5f6d4e9c2bdb0fd5b7e3b1c2a"
+//! balance = "100000000000"
+//! bonded_amount = "1000000"
+//!
+//! [[genesis.accounts]]
+//! public_key = "0101010101010101010101010101010101010101010101010101010101010101"
+//! balance = "0"
+//! deployment_threshold = 1
+//! key_management_threshold = 2
+//!
+//! [[genesis.accounts.associated_keys]]
+//! public_key = "0101010101010101010101010101010101010101010101010101010101010101"
+//! weight = 1
+//!
+//! [[genesis.accounts.associated_keys]]
+//! public_key = "0202020202020202020202020202020202020202020202020202020202020202"
+//! weight = 1
+//!
+//! [[genesis.installers]]
+//! path = "faucet_install.wasm"
+//! args = [
+//!     { cl_type = "U512", value = "1000000000" },
+//!     { cl_type = { List = "U8" }, value = [1, 2, 3] },
+//! ]
+//!
+//! [wasm_costs]
+//! regular = 1
+//! div = 16
+//! mul = 4
+//! mem = 2
+//! initial_mem = 4096
+//! grow_mem = 8192
+//! memcpy = 1
+//! max_stack_height = 65536
+//! opcodes_mul = 3
+//! opcodes_div = 8
+//! ```
+//!
+//! The optional `genesis.max_call_depth` defaults to [`DEFAULT_MAX_CALL_DEPTH`], and the optional
+//! `[proof_of_stake]` table overrides individual fields of the default [`PosParams`].  Accounts
+//! without `associated_keys` are controlled by their own key with weight 1, and unset thresholds
+//! default to 1.  Installers are run in the order given, with the optional `args` passed as
+//! positional arguments.  Each argument is given in the JSON form of a `CLValue` (see
+//! [`engine_shared::json`]), written as a TOML table.
+
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+use engine_core::engine_state::genesis::{GenesisAccount, GenesisConfig, GenesisInstaller};
+use engine_shared::{
+    account::{ActionThresholds, AssociatedKeys},
+    json,
+    motes::Motes,
+};
+use engine_storage::protocol_data::DEFAULT_MAX_CALL_DEPTH;
+use engine_wasm_prep::wasm_costs::WasmCosts;
+use proof_of_stake::PosParams;
+use types::{
+    account::{PublicKey, Weight},
+    bytesrepr::ToBytes,
+    CLValue, ProtocolVersion, U512,
+};
+
+#[derive(Debug)]
+pub enum Error {
+    Io { path: PathBuf, error: io::Error },
+    Toml(toml::de::Error),
+    InvalidProtocolVersion(String),
+    InvalidPublicKey(String),
+    InvalidAmount(String),
+    InvalidAssociatedKeys(String),
+    InvalidInstallerArgs { path: PathBuf, error: String },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Io { path, error } => write!(f, "Could not read {}: {}", path.display(), error),
+            Error::Toml(error) => write!(f, "Invalid chainspec: {}", error),
+            Error::InvalidProtocolVersion(value) => {
+                write!(f, "Invalid protocol version: {}", value)
+            }
+            Error::InvalidPublicKey(value) => write!(f, "Invalid public key: {}", value),
+            Error::InvalidAmount(value) => write!(f, "Invalid amount: {}", value),
+            Error::InvalidAssociatedKeys(value) => {
+                write!(f, "Invalid associated keys of account {}", value)
+            }
+            Error::InvalidInstallerArgs { path, error } => {
+                write!(f, "Invalid args of installer {}: {}", path.display(), error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<toml::de::Error> for Error {
+    fn from(error: toml::de::Error) -> Self {
+        Error::Toml(error)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Chainspec {
+    genesis: Genesis,
+    wasm_costs: WasmCostsConfig,
+    #[serde(default)]
+    proof_of_stake: PosParamsConfig,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Genesis {
+    name: String,
+    timestamp: u64,
+    protocol_version: String,
+    mint_installer_path: PathBuf,
+    pos_installer_path: PathBuf,
+    max_call_depth: Option<u32>,
+    #[serde(default)]
+    accounts: Vec<Account>,
+    #[serde(default)]
+    installers: Vec<Installer>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Account {
+    public_key: String,
+    balance: String,
+    bonded_amount: Option<String>,
+    associated_keys: Option<Vec<AssociatedKey>>,
+    deployment_threshold: Option<u8>,
+    key_management_threshold: Option<u8>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct AssociatedKey {
+    public_key: String,
+    weight: u8,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Installer {
+    path: PathBuf,
+    #[serde(default)]
+    args: Vec<serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct WasmCostsConfig {
+    regular: u32,
+    div: u32,
+    mul: u32,
+    mem: u32,
+    initial_mem: u32,
+    grow_mem: u32,
+    memcpy: u32,
+    max_stack_height: u32,
+    opcodes_mul: u32,
+    opcodes_div: u32,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PosParamsConfig {
+    bond_delay: Option<u64>,
+    unbond_delay: Option<u64>,
+    max_bond_len: Option<u64>,
+    max_unbond_len: Option<u64>,
+    max_spread: Option<String>,
+    max_increase: Option<String>,
+    max_decrease: Option<String>,
+    max_rel_increase: Option<u64>,
+    max_rel_decrease: Option<u64>,
+}
+
+impl Chainspec {
+    /// Parses a chainspec from the contents of a TOML file.
+    pub fn from_toml(contents: &str) -> Result<Self, Error> {
+        Ok(toml::from_str(contents)?)
+    }
+
+    /// Builds the genesis config, reading the installer wasm files relative to `base_dir`.
+    pub fn into_genesis_config(self, base_dir: &Path) -> Result<GenesisConfig, Error> {
+        let genesis = self.genesis;
+        let protocol_version = parse_protocol_version(&genesis.protocol_version)?;
+        let mint_installer_bytes = read_file(&base_dir.join(&genesis.mint_installer_path))?;
+        let proof_of_stake_installer_bytes =
+            read_file(&base_dir.join(&genesis.pos_installer_path))?;
+        let accounts = genesis
+            .accounts
+            .iter()
+            .map(Account::to_genesis_account)
+            .collect::<Result<Vec<_>, _>>()?;
+        let installers = genesis
+            .installers
+            .iter()
+            .map(|installer| {
+                let installer_bytes = read_file(&base_dir.join(&installer.path))?;
+                Ok(GenesisInstaller::new(
+                    installer_bytes,
+                    installer.args_bytes()?,
+                ))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        let max_call_depth = genesis.max_call_depth.unwrap_or(DEFAULT_MAX_CALL_DEPTH);
+        let pos_params = self.proof_of_stake.to_pos_params()?;
+
+        let mut genesis_config = GenesisConfig::new(
+            genesis.name,
+            genesis.timestamp,
+            protocol_version,
+            mint_installer_bytes,
+            proof_of_stake_installer_bytes,
+            accounts,
+            self.wasm_costs.into(),
+            max_call_depth,
+            pos_params,
+        );
+        for installer in installers {
+            genesis_config.push_installer(installer);
+        }
+        Ok(genesis_config)
+    }
+}
+
+impl Account {
+    fn to_genesis_account(&self) -> Result<GenesisAccount, Error> {
+        let public_key = parse_public_key(&self.public_key)?;
+        let balance = parse_amount(&self.balance)?;
+        let bonded_amount = match &self.bonded_amount {
+            Some(bonded_amount) => parse_amount(bonded_amount)?,
+            None => U512::zero(),
+        };
+        let invalid_keys = || Error::InvalidAssociatedKeys(self.public_key.clone());
+        let associated_keys = match &self.associated_keys {
+            Some(keys) => {
+                let mut associated_keys = AssociatedKeys::default();
+                for key in keys {
+                    associated_keys
+                        .add_key(parse_public_key(&key.public_key)?, Weight::new(key.weight))
+                        .map_err(|_| invalid_keys())?;
+                }
+                associated_keys
+            }
+            None => AssociatedKeys::new(public_key, Weight::new(1)),
+        };
+        let action_thresholds = ActionThresholds::new(
+            Weight::new(self.deployment_threshold.unwrap_or(1)),
+            Weight::new(self.key_management_threshold.unwrap_or(1)),
+        )
+        .map_err(|_| invalid_keys())?;
+        Ok(
+            GenesisAccount::new(public_key, Motes::new(balance), Motes::new(bonded_amount))
+                .with_associated_keys(associated_keys, action_thresholds),
+        )
+    }
+}
+
+impl Installer {
+    /// Parses the installer's arguments and serializes them the way the engine expects them.
+    fn args_bytes(&self) -> Result<Vec<u8>, Error> {
+        self.args
+            .iter()
+            .map(json::cl_value_from_json)
+            .collect::<Result<Vec<CLValue>, _>>()
+            .and_then(|args| args.into_bytes().map_err(json::Error::from))
+            .map_err(|error| Error::InvalidInstallerArgs {
+                path: self.path.clone(),
+                error: error.to_string(),
+            })
+    }
+}
+
+impl From<WasmCostsConfig> for WasmCosts {
+    fn from(config: WasmCostsConfig) -> Self {
+        WasmCosts {
+            regular: config.regular,
+            div: config.div,
+            mul: config.mul,
+            mem: config.mem,
+            initial_mem: config.initial_mem,
+            grow_mem: config.grow_mem,
+            memcpy: config.memcpy,
+            max_stack_height: config.max_stack_height,
+            opcodes_mul: config.opcodes_mul,
+            opcodes_div: config.opcodes_div,
+        }
+    }
+}
+
+impl PosParamsConfig {
+    fn to_pos_params(&self) -> Result<PosParams, Error> {
+        let default = PosParams::default();
+        let parse_or = |value: &Option<String>, default: U512| match value {
+            Some(value) => parse_amount(value),
+            None => Ok(default),
+        };
+        Ok(PosParams::new(
+            self.bond_delay.unwrap_or(default.bond_delay),
+            self.unbond_delay.unwrap_or(default.unbond_delay),
+            self.max_bond_len.unwrap_or(default.max_bond_len),
+            self.max_unbond_len.unwrap_or(default.max_unbond_len),
+            parse_or(&self.max_spread, default.max_spread)?,
+            parse_or(&self.max_increase, default.max_increase)?,
+            parse_or(&self.max_decrease, default.max_decrease)?,
+            self.max_rel_increase.unwrap_or(default.max_rel_increase),
+            self.max_rel_decrease.unwrap_or(default.max_rel_decrease),
+        ))
+    }
+}
+
+/// Reads the chainspec at `path` and builds the genesis config it describes.
+pub fn read_genesis_config(path: &Path) -> Result<GenesisConfig, Error> {
+    let contents = fs::read_to_string(path).map_err(|error| Error::Io {
+        path: path.to_path_buf(),
+        error,
+    })?;
+    let base_dir = path.parent().unwrap_or_else(|| Path::new(""));
+    Chainspec::from_toml(&contents)?.into_genesis_config(base_dir)
+}
+
+fn read_file(path: &Path) -> Result<Vec<u8>, Error> {
+    fs::read(path).map_err(|error| Error::Io {
+        path: path.to_path_buf(),
+        error,
+    })
+}
+
+/// Parses a protocol version of the form `major.minor.patch`.
+fn parse_protocol_version(value: &str) -> Result<ProtocolVersion, Error> {
+    let parts = value
+        .split('.')
+        .map(str::parse)
+        .collect::<Result<Vec<u32>, _>>()
+        .map_err(|_| Error::InvalidProtocolVersion(value.to_string()))?;
+    match parts.as_slice() {
+        [major, minor, patch] => Ok(ProtocolVersion::from_parts(*major, *minor, *patch)),
+        _ => Err(Error::InvalidProtocolVersion(value.to_string())),
+    }
+}
+
+/// Parses a base16 encoded ed25519 public key.
+fn parse_public_key(value: &str) -> Result<PublicKey, Error> {
+    let bytes = base16::decode(value).map_err(|_| Error::InvalidPublicKey(value.to_string()))?;
+    PublicKey::ed25519_try_from(bytes.as_slice())
+        .map_err(|_| Error::InvalidPublicKey(value.to_string()))
+}
+
+fn parse_amount(value: &str) -> Result<U512, Error> {
+    U512::from_dec_str(value).map_err(|_| Error::InvalidAmount(value.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHAINSPEC: &str = r#"
+        [genesis]
+        name = "test-chain"
+        timestamp = 1568805354071
+        protocol_version = "1.2.3"
+        mint_installer_path = "mint_install.wasm"
+        pos_installer_path = "pos_install.wasm"
+
+        [[genesis.accounts]]
+        public_key = "0101010101010101010101010101010101010101010101010101010101010101"
+        balance = "100000000000000000000000000000"
+        bonded_amount = "1000"
+
+        [[genesis.accounts]]
+        public_key = "0202020202020202020202020202020202020202020202020202020202020202"
+        balance = "500"
+        key_management_threshold = 2
+
+        [[genesis.accounts.associated_keys]]
+        public_key = "0202020202020202020202020202020202020202020202020202020202020202"
+        weight = 1
+
+        [[genesis.accounts.associated_keys]]
+        public_key = "0303030303030303030303030303030303030303030303030303030303030303"
+        weight = 1
+
+        [[genesis.installers]]
+        path = "faucet_install.wasm"
+        args = [
+            { cl_type = "U512", value = "1000" },
+            { cl_type = { Option = "U64" }, value = 5 },
+        ]
+
+        [wasm_costs]
+        regular = 1
+        div = 16
+        mul = 4
+        mem = 2
+        initial_mem = 4096
+        grow_mem = 8192
+        memcpy = 1
+        max_stack_height = 65536
+        opcodes_mul = 3
+        opcodes_div = 8
+
+        [proof_of_stake]
+        unbond_delay = 100
+        max_spread = "2000"
+    "#;
+
+    #[test]
+    fn should_parse_chainspec() {
+        let chainspec = Chainspec::from_toml(CHAINSPEC).expect("should parse");
+        assert_eq!(chainspec.genesis.name, "test-chain");
+        assert_eq!(
+            parse_protocol_version(&chainspec.genesis.protocol_version).unwrap(),
+            ProtocolVersion::from_parts(1, 2, 3)
+        );
+
+        let accounts = chainspec
+            .genesis
+            .accounts
+            .iter()
+            .map(Account::to_genesis_account)
+            .collect::<Result<Vec<_>, _>>()
+            .expect("should convert accounts");
+        let expected_balance = U512::from_dec_str("100000000000000000000000000000").unwrap();
+        let mut multisig_keys =
+            AssociatedKeys::new(PublicKey::ed25519_from([2; 32]), Weight::new(1));
+        multisig_keys
+            .add_key(PublicKey::ed25519_from([3; 32]), Weight::new(1))
+            .unwrap();
+        let multisig_thresholds = ActionThresholds::new(Weight::new(1), Weight::new(2)).unwrap();
+        assert_eq!(
+            accounts,
+            vec![
+                GenesisAccount::new(
+                    PublicKey::ed25519_from([1; 32]),
+                    Motes::new(expected_balance),
+                    Motes::new(U512::from(1000)),
+                ),
+                GenesisAccount::new(
+                    PublicKey::ed25519_from([2; 32]),
+                    Motes::new(U512::from(500)),
+                    Motes::new(U512::zero()),
+                )
+                .with_associated_keys(multisig_keys, multisig_thresholds),
+            ]
+        );
+        assert_eq!(
+            chainspec.genesis.installers[0].path,
+            Path::new("faucet_install.wasm")
+        );
+        let expected_args = vec![
+            CLValue::from_t(U512::from(1000)).unwrap(),
+            CLValue::from_t(Some(5u64)).unwrap(),
+        ];
+        assert_eq!(
+            chainspec.genesis.installers[0].args_bytes().unwrap(),
+            expected_args.into_bytes().unwrap()
+        );
+
+        let pos_params = chainspec.proof_of_stake.to_pos_params().unwrap();
+        assert_eq!(pos_params.unbond_delay, 100);
+        assert_eq!(pos_params.max_spread, U512::from(2000));
+        assert_eq!(pos_params.bond_delay, PosParams::default().bond_delay);
+
+        let wasm_costs: WasmCosts = chainspec.wasm_costs.into();
+        assert_eq!(wasm_costs.max_stack_height, 65536);
+    }
+
+    #[test]
+    fn should_reject_unknown_fields() {
+        let chainspec = CHAINSPEC.replace("memcpy = 1", "memcpy = 1\nmemmove = 1");
+        assert!(Chainspec::from_toml(&chainspec).is_err());
+    }
+
+    #[test]
+    fn should_report_missing_installer() {
+        let chainspec = Chainspec::from_toml(CHAINSPEC).expect("should parse");
+        match chainspec.into_genesis_config(Path::new("/nonexistent")) {
+            Err(Error::Io { path, .. }) => {
+                assert_eq!(path, Path::new("/nonexistent/mint_install.wasm"))
+            }
+            result => panic!("unexpected result: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn should_reject_invalid_installer_args() {
+        let chainspec = CHAINSPEC.replace(
+            r#"{ cl_type = "U512", value = "1000" }"#,
+            r#"{ cl_type = "U512", value = "-1" }"#,
+        );
+        let chainspec = Chainspec::from_toml(&chainspec).expect("should parse");
+        match chainspec.genesis.installers[0].args_bytes() {
+            Err(Error::InvalidInstallerArgs { path, .. }) => {
+                assert_eq!(path, Path::new("faucet_install.wasm"))
+            }
+            result => panic!("unexpected result: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn should_reject_deployment_threshold_above_key_management() {
+        let chainspec = CHAINSPEC.replace(
+            "key_management_threshold = 2",
+            "key_management_threshold = 2\ndeployment_threshold = 3",
+        );
+        let chainspec = Chainspec::from_toml(&chainspec).expect("should parse");
+        let result = chainspec.genesis.accounts[1].to_genesis_account();
+        match result {
+            Err(Error::InvalidAssociatedKeys(_)) => (),
+            result => panic!("unexpected result: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn should_reject_invalid_values() {
+        assert!(parse_protocol_version("1.2").is_err());
+        assert!(parse_protocol_version("1.2.x").is_err());
+        assert!(parse_public_key("0101").is_err());
+        assert!(parse_public_key("not hex").is_err());
+        assert!(parse_amount("-1").is_err());
+    }
+}
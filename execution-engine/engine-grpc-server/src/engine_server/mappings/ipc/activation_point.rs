@@ -0,0 +1,45 @@
+use engine_core::engine_state::upgrade::ActivationPoint;
+use types::BlockTime;
+
+use crate::engine_server::ipc::ChainSpec_ActivationPoint;
+
+impl From<ActivationPoint> for ChainSpec_ActivationPoint {
+    fn from(activation_point: ActivationPoint) -> Self {
+        let mut pb_activation_point = ChainSpec_ActivationPoint::new();
+        match activation_point {
+            ActivationPoint::Rank(rank) => pb_activation_point.set_rank(rank),
+            ActivationPoint::BlockTime(block_time) => {
+                pb_activation_point.set_block_time(block_time.into())
+            }
+        }
+        pb_activation_point
+    }
+}
+
+impl From<ChainSpec_ActivationPoint> for ActivationPoint {
+    fn from(pb_activation_point: ChainSpec_ActivationPoint) -> Self {
+        match pb_activation_point.block_time {
+            0 => ActivationPoint::Rank(pb_activation_point.rank),
+            block_time => ActivationPoint::BlockTime(BlockTime::new(block_time)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine_server::mappings::test_utils;
+
+    #[test]
+    fn round_trip() {
+        for activation_point in &[
+            ActivationPoint::Rank(0),
+            ActivationPoint::Rank(10),
+            ActivationPoint::BlockTime(BlockTime::new(1_000)),
+        ] {
+            test_utils::protobuf_round_trip::<ActivationPoint, ChainSpec_ActivationPoint>(
+                *activation_point,
+            );
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use std::convert::{TryFrom, TryInto};
+
+use engine_core::engine_state::rewards::DistributeRewardsRequest;
+use engine_shared::newtypes::BLAKE2B_DIGEST_LENGTH;
+
+use crate::engine_server::{ipc, mappings::MappingError};
+
+impl TryFrom<ipc::DistributeRewardsRequest> for DistributeRewardsRequest {
+    type Error = MappingError;
+
+    fn try_from(
+        mut distribute_rewards_request: ipc::DistributeRewardsRequest,
+    ) -> Result<Self, Self::Error> {
+        let parent_state_hash = {
+            let parent_state_hash = distribute_rewards_request.get_parent_state_hash();
+            let length = parent_state_hash.len();
+            if length != BLAKE2B_DIGEST_LENGTH {
+                return Err(MappingError::InvalidStateHashLength {
+                    expected: BLAKE2B_DIGEST_LENGTH,
+                    actual: length,
+                });
+            }
+            parent_state_hash
+                .try_into()
+                .map_err(|_| MappingError::TryFromSlice)?
+        };
+
+        let protocol_version = distribute_rewards_request.take_protocol_version().into();
+
+        Ok(DistributeRewardsRequest::new(
+            parent_state_hash,
+            protocol_version,
+            distribute_rewards_request.get_era_id(),
+            distribute_rewards_request.get_inflation_rate(),
+            distribute_rewards_request.get_commission_rate(),
+        ))
+    }
+}
@@ -1,35 +1,57 @@
+use std::{
+    collections::BTreeSet,
+    convert::{TryFrom, TryInto},
+};
+
 use engine_core::engine_state::executable_deploy_item::ExecutableDeployItem;
+use types::NamedArg;
+
+use crate::engine_server::{
+    ipc::{self, DeployPayload, DeployPayload_oneof_payload},
+    mappings::{MappingError, ParsingError},
+};
 
-use crate::engine_server::ipc::{DeployPayload, DeployPayload_oneof_payload};
+impl TryFrom<DeployPayload_oneof_payload> for ExecutableDeployItem {
+    type Error = MappingError;
 
-impl From<DeployPayload_oneof_payload> for ExecutableDeployItem {
-    fn from(pb_deploy_payload: DeployPayload_oneof_payload) -> Self {
-        match pb_deploy_payload {
-            DeployPayload_oneof_payload::deploy_code(pb_deploy_code) => {
+    fn try_from(pb_deploy_payload: DeployPayload_oneof_payload) -> Result<Self, Self::Error> {
+        let deploy_item = match pb_deploy_payload {
+            DeployPayload_oneof_payload::deploy_code(mut pb_deploy_code) => {
                 ExecutableDeployItem::ModuleBytes {
+                    named_args: parse_named_args(pb_deploy_code.take_named_args().into_vec())?,
                     module_bytes: pb_deploy_code.code,
                     args: pb_deploy_code.args,
                 }
             }
-            DeployPayload_oneof_payload::stored_contract_hash(pb_stored_contract_hash) => {
+            DeployPayload_oneof_payload::stored_contract_hash(mut pb_stored_contract_hash) => {
                 ExecutableDeployItem::StoredContractByHash {
+                    named_args: parse_named_args(
+                        pb_stored_contract_hash.take_named_args().into_vec(),
+                    )?,
                     hash: pb_stored_contract_hash.hash,
                     args: pb_stored_contract_hash.args,
                 }
             }
-            DeployPayload_oneof_payload::stored_contract_name(pb_stored_contract_name) => {
+            DeployPayload_oneof_payload::stored_contract_name(mut pb_stored_contract_name) => {
                 ExecutableDeployItem::StoredContractByName {
+                    named_args: parse_named_args(
+                        pb_stored_contract_name.take_named_args().into_vec(),
+                    )?,
                     name: pb_stored_contract_name.stored_contract_name,
                     args: pb_stored_contract_name.args,
                 }
             }
-            DeployPayload_oneof_payload::stored_contract_uref(pb_stored_contract_uref) => {
+            DeployPayload_oneof_payload::stored_contract_uref(mut pb_stored_contract_uref) => {
                 ExecutableDeployItem::StoredContractByURef {
+                    named_args: parse_named_args(
+                        pb_stored_contract_uref.take_named_args().into_vec(),
+                    )?,
                     uref: pb_stored_contract_uref.uref,
                     args: pb_stored_contract_uref.args,
                 }
             }
-        }
+        };
+        Ok(deploy_item)
     }
 }
 
@@ -37,27 +59,103 @@ impl From<ExecutableDeployItem> for DeployPayload {
     fn from(edi: ExecutableDeployItem) -> Self {
         let mut result = DeployPayload::new();
         match edi {
-            ExecutableDeployItem::ModuleBytes { module_bytes, args } => {
+            ExecutableDeployItem::ModuleBytes {
+                module_bytes,
+                args,
+                named_args,
+            } => {
                 let code = result.mut_deploy_code();
                 code.set_code(module_bytes);
                 code.set_args(args);
+                code.set_named_args(to_pb_named_args(named_args).into());
             }
-            ExecutableDeployItem::StoredContractByHash { hash, args } => {
+            ExecutableDeployItem::StoredContractByHash {
+                hash,
+                args,
+                named_args,
+            } => {
                 let inner = result.mut_stored_contract_hash();
                 inner.set_hash(hash);
                 inner.set_args(args);
+                inner.set_named_args(to_pb_named_args(named_args).into());
             }
-            ExecutableDeployItem::StoredContractByName { name, args } => {
+            ExecutableDeployItem::StoredContractByName {
+                name,
+                args,
+                named_args,
+            } => {
                 let inner = result.mut_stored_contract_name();
                 inner.set_stored_contract_name(name);
                 inner.set_args(args);
+                inner.set_named_args(to_pb_named_args(named_args).into());
             }
-            ExecutableDeployItem::StoredContractByURef { uref, args } => {
+            ExecutableDeployItem::StoredContractByURef {
+                uref,
+                args,
+                named_args,
+            } => {
                 let inner = result.mut_stored_contract_uref();
                 inner.set_uref(uref);
                 inner.set_args(args);
+                inner.set_named_args(to_pb_named_args(named_args).into());
             }
         }
         result
     }
 }
+
+/// Parses the named args, rejecting any repeated names.
+fn parse_named_args(pb_named_args: Vec<ipc::NamedArg>) -> Result<Vec<NamedArg>, MappingError> {
+    let named_args = pb_named_args
+        .into_iter()
+        .map(TryInto::try_into)
+        .collect::<Result<Vec<NamedArg>, ParsingError>>()?;
+
+    let mut names = BTreeSet::new();
+    for named_arg in &named_args {
+        if !names.insert(named_arg.name()) {
+            return Err(ParsingError(format!("Duplicate named arg {}", named_arg.name())).into());
+        }
+    }
+
+    Ok(named_args)
+}
+
+fn to_pb_named_args(named_args: Vec<NamedArg>) -> Vec<ipc::NamedArg> {
+    named_args.into_iter().map(Into::into).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use types::CLValue;
+
+    use super::*;
+
+    fn named_arg(name: &str, value: u64) -> NamedArg {
+        NamedArg::new(name.to_string(), CLValue::from_t(value).unwrap())
+    }
+
+    #[test]
+    fn should_round_trip_named_args() {
+        let deploy_item = ExecutableDeployItem::StoredContractByName {
+            name: "contract".to_string(),
+            args: vec![1, 2, 3],
+            named_args: vec![named_arg("amount", 10), named_arg("count", 2)],
+        };
+        let pb_deploy_payload = DeployPayload::from(deploy_item.clone());
+        let parsed = ExecutableDeployItem::try_from(pb_deploy_payload.payload.unwrap())
+            .expect("should parse");
+        assert!(parsed == deploy_item);
+    }
+
+    #[test]
+    fn should_reject_duplicate_names() {
+        let deploy_item = ExecutableDeployItem::ModuleBytes {
+            module_bytes: vec![0, 97, 115, 109],
+            args: vec![],
+            named_args: vec![named_arg("amount", 10), named_arg("amount", 2)],
+        };
+        let pb_deploy_payload = DeployPayload::from(deploy_item);
+        assert!(ExecutableDeployItem::try_from(pb_deploy_payload.payload.unwrap()).is_err());
+    }
+}
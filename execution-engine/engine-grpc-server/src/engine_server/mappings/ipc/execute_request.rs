@@ -29,6 +29,12 @@ impl TryFrom<ipc::ExecuteRequest> for ExecuteRequest {
         };
 
         let block_time = request.get_block_time();
+        let rank = if request.get_has_rank() {
+            Some(request.get_rank())
+        } else {
+            None
+        };
+        let run_scheduled_calls = request.get_run_scheduled_calls();
 
         let deploys = Into::<Vec<_>>::into(request.take_deploys())
             .into_iter()
@@ -44,8 +50,10 @@ impl TryFrom<ipc::ExecuteRequest> for ExecuteRequest {
         Ok(ExecuteRequest::new(
             parent_state_hash,
             block_time,
+            rank,
             deploys,
             protocol_version,
+            run_scheduled_calls,
         ))
     }
 }
@@ -55,6 +63,10 @@ impl From<ExecuteRequest> for ipc::ExecuteRequest {
         let mut result = ipc::ExecuteRequest::new();
         result.set_parent_state_hash(req.parent_state_hash.to_vec());
         result.set_block_time(req.block_time);
+        if let Some(rank) = req.rank {
+            result.set_rank(rank);
+            result.set_has_rank(true);
+        }
         result.set_deploys(
             req.deploys
                 .into_iter()
@@ -65,6 +77,7 @@ impl From<ExecuteRequest> for ipc::ExecuteRequest {
                 .collect(),
         );
         result.set_protocol_version(req.protocol_version.into());
+        result.set_run_scheduled_calls(req.run_scheduled_calls);
         result
     }
 }
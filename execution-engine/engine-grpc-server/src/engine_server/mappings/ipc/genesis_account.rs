@@ -1,10 +1,17 @@
 use std::convert::{TryFrom, TryInto};
 
 use engine_core::engine_state::genesis::GenesisAccount;
-use engine_shared::motes::Motes;
-use types::account::PublicKey;
+use engine_shared::{
+    account::{ActionThresholds, AssociatedKeys},
+    motes::Motes,
+};
+use types::account::{PublicKey, Weight};
 
-use crate::engine_server::{ipc::ChainSpec_GenesisAccount, mappings::MappingError};
+use crate::engine_server::{
+    ipc::ChainSpec_GenesisAccount,
+    mappings::{MappingError, ParsingError},
+    state::Account_AssociatedKey,
+};
 
 impl From<GenesisAccount> for ChainSpec_GenesisAccount {
     fn from(genesis_account: GenesisAccount) -> Self {
@@ -14,6 +21,22 @@ impl From<GenesisAccount> for ChainSpec_GenesisAccount {
         pb_genesis_account.set_balance(genesis_account.balance().value().into());
         pb_genesis_account.set_bonded_amount(genesis_account.bonded_amount().value().into());
 
+        let associated_keys: Vec<Account_AssociatedKey> = genesis_account
+            .associated_keys()
+            .iter()
+            .map(Into::into)
+            .collect();
+        pb_genesis_account.set_associated_keys(associated_keys.into());
+
+        {
+            let action_thresholds = genesis_account.action_thresholds();
+            let pb_action_thresholds = pb_genesis_account.mut_action_thresholds();
+            pb_action_thresholds
+                .set_deployment_threshold(action_thresholds.deployment().value().into());
+            pb_action_thresholds
+                .set_key_management_threshold(action_thresholds.key_management().value().into());
+        }
+
         pb_genesis_account
     }
 }
@@ -35,10 +58,48 @@ impl TryFrom<ChainSpec_GenesisAccount> for GenesisAccount {
             .take_bonded_amount()
             .try_into()
             .map(Motes::new)?;
-        Ok(GenesisAccount::new(public_key, balance, bonded_amount))
+
+        let associated_keys = if pb_genesis_account.associated_keys.is_empty() {
+            AssociatedKeys::new(public_key, Weight::new(1))
+        } else {
+            let mut associated_keys = AssociatedKeys::default();
+            for pb_associated_key in pb_genesis_account.take_associated_keys().into_vec() {
+                let (key, weight) = pb_associated_key.try_into()?;
+                associated_keys.add_key(key, weight).map_err(|error| {
+                    ParsingError(format!(
+                        "Error parsing GenesisAccount associated keys: {:?}",
+                        error
+                    ))
+                })?;
+            }
+            associated_keys
+        };
+
+        let action_thresholds = if pb_genesis_account.has_action_thresholds() {
+            let pb_action_thresholds = pb_genesis_account.take_action_thresholds();
+            ActionThresholds::new(
+                weight_from(pb_action_thresholds.deployment_threshold)?,
+                weight_from(pb_action_thresholds.key_management_threshold)?,
+            )
+            .map_err(ParsingError::from)?
+        } else {
+            ActionThresholds::default()
+        };
+
+        Ok(GenesisAccount::new(public_key, balance, bonded_amount)
+            .with_associated_keys(associated_keys, action_thresholds))
     }
 }
 
+fn weight_from(value: u32) -> Result<Weight, ParsingError> {
+    u8::try_from(value).map(Weight::new).map_err(|_| {
+        ParsingError(format!(
+            "Unable to convert {} to u8 while parsing GenesisAccount action thresholds",
+            value
+        ))
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,9 +1,11 @@
 use std::convert::{TryFrom, TryInto};
 
-use engine_core::engine_state::genesis::{GenesisAccount, GenesisConfig};
+use engine_core::engine_state::genesis::{GenesisAccount, GenesisConfig, GenesisInstaller};
+use engine_storage::protocol_data::DEFAULT_MAX_CALL_DEPTH;
+use proof_of_stake::PosParams;
 
 use crate::engine_server::{
-    ipc::{ChainSpec_GenesisAccount, ChainSpec_GenesisConfig},
+    ipc::{ChainSpec_GenesisAccount, ChainSpec_GenesisConfig, ChainSpec_GenesisInstaller},
     mappings::MappingError,
 };
 
@@ -29,6 +31,17 @@ impl From<GenesisConfig> for ChainSpec_GenesisConfig {
         pb_genesis_config
             .mut_costs()
             .set_wasm(genesis_config.wasm_costs().into());
+        pb_genesis_config.set_max_call_depth(genesis_config.max_call_depth());
+        pb_genesis_config.set_pos_params(genesis_config.pos_params().into());
+        {
+            let installers = genesis_config
+                .installers()
+                .iter()
+                .cloned()
+                .map(Into::into)
+                .collect::<Vec<ChainSpec_GenesisInstaller>>();
+            pb_genesis_config.set_installers(installers.into());
+        }
         pb_genesis_config
     }
 }
@@ -46,9 +59,23 @@ impl TryFrom<ChainSpec_GenesisConfig> for GenesisConfig {
             .map(TryInto::try_into)
             .collect::<Result<Vec<GenesisAccount>, Self::Error>>()?;
         let wasm_costs = pb_genesis_config.take_costs().take_wasm().into();
+        let installers = pb_genesis_config
+            .take_installers()
+            .into_iter()
+            .map(Into::into)
+            .collect::<Vec<GenesisInstaller>>();
+        let pos_params = if pb_genesis_config.has_pos_params() {
+            pb_genesis_config.take_pos_params().try_into()?
+        } else {
+            PosParams::default()
+        };
         let mint_initializer_bytes = pb_genesis_config.mint_installer;
         let proof_of_stake_initializer_bytes = pb_genesis_config.pos_installer;
-        Ok(GenesisConfig::new(
+        let max_call_depth = match pb_genesis_config.max_call_depth {
+            0 => DEFAULT_MAX_CALL_DEPTH,
+            max_call_depth => max_call_depth,
+        };
+        let mut genesis_config = GenesisConfig::new(
             name,
             timestamp,
             protocol_version,
@@ -56,7 +83,28 @@ impl TryFrom<ChainSpec_GenesisConfig> for GenesisConfig {
             proof_of_stake_initializer_bytes,
             accounts,
             wasm_costs,
-        ))
+            max_call_depth,
+            pos_params,
+        );
+        for installer in installers {
+            genesis_config.push_installer(installer);
+        }
+        Ok(genesis_config)
+    }
+}
+
+impl From<GenesisInstaller> for ChainSpec_GenesisInstaller {
+    fn from(installer: GenesisInstaller) -> Self {
+        let mut pb_installer = ChainSpec_GenesisInstaller::new();
+        pb_installer.set_code(installer.installer_bytes().to_vec());
+        pb_installer.set_args(installer.args().to_vec());
+        pb_installer
+    }
+}
+
+impl From<ChainSpec_GenesisInstaller> for GenesisInstaller {
+    fn from(mut pb_installer: ChainSpec_GenesisInstaller) -> Self {
+        GenesisInstaller::new(pb_installer.take_code(), pb_installer.take_args())
     }
 }
 
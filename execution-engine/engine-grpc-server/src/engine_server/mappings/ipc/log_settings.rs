@@ -0,0 +1,102 @@
+use std::convert::TryFrom;
+
+use engine_shared::logging::{Settings, Style};
+use log::LevelFilter;
+
+use crate::engine_server::{
+    ipc::{LogSettings, LogSettings_Level, LogSettings_Style},
+    mappings::ParsingError,
+};
+
+impl From<Settings> for LogSettings {
+    fn from(settings: Settings) -> Self {
+        let mut pb_log_settings = LogSettings::new();
+
+        let pb_level = match settings.max_level() {
+            LevelFilter::Off => LogSettings_Level::OFF,
+            LevelFilter::Error => LogSettings_Level::ERROR,
+            LevelFilter::Warn => LogSettings_Level::WARN,
+            LevelFilter::Info => LogSettings_Level::INFO,
+            LevelFilter::Debug => LogSettings_Level::DEBUG,
+            LevelFilter::Trace => LogSettings_Level::TRACE,
+        };
+        pb_log_settings.set_max_level(pb_level);
+
+        pb_log_settings.set_enable_metrics(settings.enable_metrics());
+
+        let pb_style = match settings.style() {
+            Style::Structured => LogSettings_Style::STRUCTURED,
+            Style::HumanReadable => LogSettings_Style::HUMAN_READABLE,
+        };
+        pb_log_settings.set_style(pb_style);
+
+        pb_log_settings
+    }
+}
+
+impl TryFrom<LogSettings> for Settings {
+    type Error = ParsingError;
+
+    fn try_from(pb_log_settings: LogSettings) -> Result<Self, Self::Error> {
+        let max_level = match pb_log_settings.get_max_level() {
+            LogSettings_Level::UNKNOWN => {
+                return Err(ParsingError::from(
+                    "Protobuf LogSettings missing max_level field",
+                ))
+            }
+            LogSettings_Level::OFF => LevelFilter::Off,
+            LogSettings_Level::ERROR => LevelFilter::Error,
+            LogSettings_Level::WARN => LevelFilter::Warn,
+            LogSettings_Level::INFO => LevelFilter::Info,
+            LogSettings_Level::DEBUG => LevelFilter::Debug,
+            LogSettings_Level::TRACE => LevelFilter::Trace,
+        };
+
+        let style = match pb_log_settings.get_style() {
+            LogSettings_Style::STRUCTURED => Style::Structured,
+            LogSettings_Style::HUMAN_READABLE => Style::HumanReadable,
+        };
+
+        let settings = Settings::new(max_level)
+            .with_metrics_enabled(pb_log_settings.get_enable_metrics())
+            .with_style(style);
+        Ok(settings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::{prelude::*, proptest};
+
+    use super::*;
+    use crate::engine_server::mappings::test_utils;
+
+    fn settings_arb() -> impl Strategy<Value = Settings> {
+        let level_arb = prop_oneof![
+            Just(LevelFilter::Off),
+            Just(LevelFilter::Error),
+            Just(LevelFilter::Warn),
+            Just(LevelFilter::Info),
+            Just(LevelFilter::Debug),
+            Just(LevelFilter::Trace),
+        ];
+        let style_arb = prop_oneof![Just(Style::Structured), Just(Style::HumanReadable)];
+        (level_arb, any::<bool>(), style_arb).prop_map(|(max_level, enable_metrics, style)| {
+            Settings::new(max_level)
+                .with_metrics_enabled(enable_metrics)
+                .with_style(style)
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn round_trip(settings in settings_arb()) {
+            test_utils::protobuf_round_trip::<Settings, LogSettings>(settings);
+        }
+    }
+
+    #[test]
+    fn should_fail_to_parse_unknown_level() {
+        assert!(Settings::try_from(LogSettings::new()).is_err());
+    }
+}
@@ -0,0 +1,158 @@
+use std::convert::{TryFrom, TryInto};
+
+use engine_core::engine_state::migration::Migration;
+use engine_shared::account::ActionThresholds;
+use types::account::{PublicKey, Weight};
+
+use crate::engine_server::{
+    ipc::{ChainSpec_Migration, ChainSpec_Migration_oneof_migration},
+    mappings::{MappingError, ParsingError},
+};
+
+impl From<Migration> for ChainSpec_Migration {
+    fn from(migration: Migration) -> Self {
+        let mut pb_migration = ChainSpec_Migration::new();
+        match migration {
+            Migration::WriteValue { key, value } => {
+                let pb_write_value = pb_migration.mut_write_value();
+                pb_write_value.set_key(key.into());
+                pb_write_value.set_value(value.into());
+            }
+            Migration::MoveNamedKey {
+                from,
+                from_name,
+                to,
+                to_name,
+            } => {
+                let pb_move_named_key = pb_migration.mut_move_named_key();
+                pb_move_named_key.set_from(from.into());
+                pb_move_named_key.set_from_name(from_name);
+                pb_move_named_key.set_to(to.into());
+                pb_move_named_key.set_to_name(to_name);
+            }
+            Migration::SetActionThresholds {
+                account,
+                action_thresholds,
+            } => {
+                let pb_set_action_thresholds = pb_migration.mut_set_action_thresholds();
+                pb_set_action_thresholds.set_public_key(account.as_bytes().to_vec());
+                let pb_action_thresholds = pb_set_action_thresholds.mut_action_thresholds();
+                pb_action_thresholds
+                    .set_deployment_threshold(action_thresholds.deployment().value().into());
+                pb_action_thresholds.set_key_management_threshold(
+                    action_thresholds.key_management().value().into(),
+                );
+            }
+            Migration::PutNamedValue { key, name, value } => {
+                let pb_put_named_value = pb_migration.mut_put_named_value();
+                pb_put_named_value.set_key(key.into());
+                pb_put_named_value.set_name(name);
+                pb_put_named_value.set_value(value.into());
+            }
+        }
+        pb_migration
+    }
+}
+
+impl TryFrom<ChainSpec_Migration> for Migration {
+    type Error = MappingError;
+
+    fn try_from(mut pb_migration: ChainSpec_Migration) -> Result<Self, Self::Error> {
+        let migration = match pb_migration.migration.take() {
+            Some(ChainSpec_Migration_oneof_migration::write_value(mut pb_write_value)) => {
+                Migration::WriteValue {
+                    key: pb_write_value.take_key().try_into()?,
+                    value: pb_write_value.take_value().try_into()?,
+                }
+            }
+            Some(ChainSpec_Migration_oneof_migration::move_named_key(mut pb_move_named_key)) => {
+                Migration::MoveNamedKey {
+                    from: pb_move_named_key.take_from().try_into()?,
+                    from_name: pb_move_named_key.take_from_name(),
+                    to: pb_move_named_key.take_to().try_into()?,
+                    to_name: pb_move_named_key.take_to_name(),
+                }
+            }
+            Some(ChainSpec_Migration_oneof_migration::set_action_thresholds(
+                mut pb_set_action_thresholds,
+            )) => {
+                let account =
+                    PublicKey::ed25519_try_from(pb_set_action_thresholds.get_public_key())
+                        .map_err(|_| {
+                            MappingError::invalid_public_key_length(
+                                pb_set_action_thresholds.public_key.len(),
+                            )
+                        })?;
+                let pb_action_thresholds = pb_set_action_thresholds.take_action_thresholds();
+                let action_thresholds = ActionThresholds::new(
+                    weight_from(pb_action_thresholds.deployment_threshold)?,
+                    weight_from(pb_action_thresholds.key_management_threshold)?,
+                )
+                .map_err(ParsingError::from)?;
+                Migration::SetActionThresholds {
+                    account,
+                    action_thresholds,
+                }
+            }
+            Some(ChainSpec_Migration_oneof_migration::put_named_value(mut pb_put_named_value)) => {
+                Migration::PutNamedValue {
+                    key: pb_put_named_value.take_key().try_into()?,
+                    name: pb_put_named_value.take_name(),
+                    value: pb_put_named_value.take_value().try_into()?,
+                }
+            }
+            None => return Err(ParsingError::from("Missing migration").into()),
+        };
+        Ok(migration)
+    }
+}
+
+fn weight_from(value: u32) -> Result<Weight, ParsingError> {
+    u8::try_from(value).map(Weight::new).map_err(|_| {
+        ParsingError(format!(
+            "Unable to convert {} to u8 while parsing Migration action thresholds",
+            value
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use types::{CLValue, Key, U512};
+
+    use super::*;
+    use crate::engine_server::mappings::test_utils;
+
+    #[test]
+    fn round_trip() {
+        let migrations = vec![
+            Migration::WriteValue {
+                key: Key::Hash([1; 32]),
+                value: CLValue::from_t(42u64).unwrap(),
+            },
+            Migration::MoveNamedKey {
+                from: Key::Account(PublicKey::ed25519_from([2; 32])),
+                from_name: "old".to_string(),
+                to: Key::Hash([3; 32]),
+                to_name: "new".to_string(),
+            },
+            Migration::SetActionThresholds {
+                account: PublicKey::ed25519_from([4; 32]),
+                action_thresholds: ActionThresholds::new(Weight::new(1), Weight::new(2)).unwrap(),
+            },
+            Migration::PutNamedValue {
+                key: Key::Hash([5; 32]),
+                name: "total_supply".to_string(),
+                value: CLValue::from_t(U512::from(1_000)).unwrap(),
+            },
+        ];
+        for migration in migrations {
+            test_utils::protobuf_round_trip::<Migration, ChainSpec_Migration>(migration);
+        }
+    }
+
+    #[test]
+    fn should_fail_to_parse_empty_migration() {
+        assert!(Migration::try_from(ChainSpec_Migration::new()).is_err());
+    }
+}
@@ -1,14 +1,23 @@
 //! Functions for converting between CasperLabs types and their Protobuf equivalents which are
 //! defined in protobuf/io/casperlabs/ipc/ipc.proto
 
+mod activation_point;
 mod bond;
 mod deploy_item;
 mod deploy_result;
+mod distribute_rewards_request;
 mod executable_deploy_item;
 mod execute_request;
 mod execution_effect;
 mod genesis_account;
 mod genesis_config;
+mod log_settings;
+mod migration;
+mod named_arg;
+mod pos_params;
+mod protocol_version_info;
 mod query_request;
+mod slash_request;
+mod unbond_payout_request;
 mod upgrade_request;
 mod wasm_costs;
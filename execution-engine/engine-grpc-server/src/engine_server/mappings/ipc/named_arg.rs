@@ -0,0 +1,41 @@
+use std::convert::{TryFrom, TryInto};
+
+use types::NamedArg;
+
+use crate::engine_server::{ipc, mappings::ParsingError};
+
+impl From<NamedArg> for ipc::NamedArg {
+    fn from(named_arg: NamedArg) -> Self {
+        let (name, value) = named_arg.destructure();
+        let mut pb_named_arg = ipc::NamedArg::new();
+        pb_named_arg.set_name(name);
+        pb_named_arg.set_value(value.into());
+        pb_named_arg
+    }
+}
+
+impl TryFrom<ipc::NamedArg> for NamedArg {
+    type Error = ParsingError;
+
+    fn try_from(mut pb_named_arg: ipc::NamedArg) -> Result<Self, Self::Error> {
+        let value = pb_named_arg.take_value().try_into()?;
+        Ok(NamedArg::new(pb_named_arg.take_name(), value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::proptest;
+
+    use types::gens;
+
+    use super::*;
+    use crate::engine_server::mappings::test_utils;
+
+    proptest! {
+        #[test]
+        fn round_trip(named_arg in gens::named_arg_arb()) {
+            test_utils::protobuf_round_trip::<NamedArg, ipc::NamedArg>(named_arg);
+        }
+    }
+}
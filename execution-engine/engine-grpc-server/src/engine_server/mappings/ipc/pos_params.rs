@@ -0,0 +1,74 @@
+use std::convert::{TryFrom, TryInto};
+
+use proof_of_stake::PosParams;
+
+use crate::engine_server::{ipc::ChainSpec_ProofOfStakeParams, mappings::ParsingError};
+
+impl From<PosParams> for ChainSpec_ProofOfStakeParams {
+    fn from(pos_params: PosParams) -> Self {
+        let mut pb_pos_params = ChainSpec_ProofOfStakeParams::new();
+        pb_pos_params.set_bond_delay(pos_params.bond_delay);
+        pb_pos_params.set_unbond_delay(pos_params.unbond_delay);
+        pb_pos_params.set_max_bond_len(pos_params.max_bond_len);
+        pb_pos_params.set_max_unbond_len(pos_params.max_unbond_len);
+        pb_pos_params.set_max_spread(pos_params.max_spread.into());
+        pb_pos_params.set_max_increase(pos_params.max_increase.into());
+        pb_pos_params.set_max_decrease(pos_params.max_decrease.into());
+        pb_pos_params.set_max_rel_increase(pos_params.max_rel_increase);
+        pb_pos_params.set_max_rel_decrease(pos_params.max_rel_decrease);
+        pb_pos_params
+    }
+}
+
+impl TryFrom<ChainSpec_ProofOfStakeParams> for PosParams {
+    type Error = ParsingError;
+
+    fn try_from(mut pb_pos_params: ChainSpec_ProofOfStakeParams) -> Result<Self, Self::Error> {
+        Ok(PosParams::new(
+            pb_pos_params.get_bond_delay(),
+            pb_pos_params.get_unbond_delay(),
+            pb_pos_params.get_max_bond_len(),
+            pb_pos_params.get_max_unbond_len(),
+            pb_pos_params.take_max_spread().try_into()?,
+            pb_pos_params.take_max_increase().try_into()?,
+            pb_pos_params.take_max_decrease().try_into()?,
+            pb_pos_params.get_max_rel_increase(),
+            pb_pos_params.get_max_rel_decrease(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::proptest;
+
+    use types::gens;
+
+    use super::*;
+    use crate::engine_server::mappings::test_utils;
+
+    proptest! {
+        #[test]
+        fn round_trip(
+            delays in (0u64.., 0u64..),
+            queue_lengths in (0u64.., 0u64..),
+            max_spread in gens::u512_arb(),
+            max_increase in gens::u512_arb(),
+            max_decrease in gens::u512_arb(),
+            relative_limits in (0u64.., 0u64..),
+        ) {
+            let pos_params = PosParams::new(
+                delays.0,
+                delays.1,
+                queue_lengths.0,
+                queue_lengths.1,
+                max_spread,
+                max_increase,
+                max_decrease,
+                relative_limits.0,
+                relative_limits.1,
+            );
+            test_utils::protobuf_round_trip::<PosParams, ChainSpec_ProofOfStakeParams>(pos_params);
+        }
+    }
+}
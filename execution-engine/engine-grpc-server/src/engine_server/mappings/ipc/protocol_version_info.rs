@@ -0,0 +1,45 @@
+use engine_wasm_prep::wasm_costs::WasmCosts;
+use types::ProtocolVersion;
+
+use crate::engine_server::ipc::EngineInfo_ProtocolVersionInfo;
+
+impl From<(ProtocolVersion, WasmCosts)> for EngineInfo_ProtocolVersionInfo {
+    fn from((protocol_version, wasm_costs): (ProtocolVersion, WasmCosts)) -> Self {
+        let mut pb_protocol_version_info = EngineInfo_ProtocolVersionInfo::new();
+        pb_protocol_version_info.set_protocol_version(protocol_version.into());
+        pb_protocol_version_info.set_wasm_costs(wasm_costs.into());
+        pb_protocol_version_info
+    }
+}
+
+impl From<EngineInfo_ProtocolVersionInfo> for (ProtocolVersion, WasmCosts) {
+    fn from(mut pb_protocol_version_info: EngineInfo_ProtocolVersionInfo) -> Self {
+        let protocol_version = pb_protocol_version_info.take_protocol_version().into();
+        let wasm_costs = pb_protocol_version_info.take_wasm_costs().into();
+        (protocol_version, wasm_costs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::proptest;
+
+    use engine_wasm_prep::wasm_costs::gens as wasm_costs_gens;
+    use types::gens;
+
+    use super::*;
+    use crate::engine_server::mappings::test_utils;
+
+    proptest! {
+        #[test]
+        fn round_trip(
+            protocol_version in gens::protocol_version_arb(),
+            wasm_costs in wasm_costs_gens::wasm_costs_arb()
+        ) {
+            test_utils::protobuf_round_trip::<
+                (ProtocolVersion, WasmCosts),
+                EngineInfo_ProtocolVersionInfo,
+            >((protocol_version, wasm_costs));
+        }
+    }
+}
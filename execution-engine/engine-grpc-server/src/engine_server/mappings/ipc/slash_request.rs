@@ -0,0 +1,130 @@
+use std::{
+    collections::BTreeMap,
+    convert::{TryFrom, TryInto},
+};
+
+use engine_core::engine_state::slash::SlashRequest;
+use engine_shared::newtypes::BLAKE2B_DIGEST_LENGTH;
+use proof_of_stake::{Slash, SlashAmount};
+use types::{account::PublicKey, BlockTime};
+
+use crate::engine_server::{
+    ipc::{self, SlashRequest_ValidatorSlash, SlashRequest_ValidatorSlash_oneof_amount},
+    mappings::{MappingError, ParsingError},
+};
+
+impl From<(PublicKey, Slash)> for SlashRequest_ValidatorSlash {
+    fn from((validator, slash): (PublicKey, Slash)) -> Self {
+        let mut pb_slash = SlashRequest_ValidatorSlash::new();
+        pb_slash.set_validator_id(validator.as_bytes().to_vec());
+        match slash.amount {
+            SlashAmount::Fixed(amount) => pb_slash.set_value(amount.into()),
+            SlashAmount::Rate(rate) => pb_slash.set_rate(rate),
+        }
+        pb_slash.set_jailed_until_era(slash.jailed_until_era);
+        pb_slash
+    }
+}
+
+impl TryFrom<SlashRequest_ValidatorSlash> for (PublicKey, Slash) {
+    type Error = MappingError;
+
+    fn try_from(mut pb_slash: SlashRequest_ValidatorSlash) -> Result<Self, Self::Error> {
+        let validator = PublicKey::ed25519_try_from(pb_slash.get_validator_id())
+            .map_err(|_| MappingError::invalid_public_key_length(pb_slash.validator_id.len()))?;
+
+        let amount = match pb_slash.amount.take() {
+            Some(SlashRequest_ValidatorSlash_oneof_amount::value(pb_value)) => {
+                SlashAmount::Fixed(pb_value.try_into()?)
+            }
+            Some(SlashRequest_ValidatorSlash_oneof_amount::rate(rate)) => SlashAmount::Rate(rate),
+            None => return Err(ParsingError::from("Missing slash amount").into()),
+        };
+
+        Ok((
+            validator,
+            Slash::new(amount, pb_slash.get_jailed_until_era()),
+        ))
+    }
+}
+
+impl TryFrom<ipc::SlashRequest> for SlashRequest {
+    type Error = MappingError;
+
+    fn try_from(mut slash_request: ipc::SlashRequest) -> Result<Self, Self::Error> {
+        let parent_state_hash = {
+            let parent_state_hash = slash_request.get_parent_state_hash();
+            let length = parent_state_hash.len();
+            if length != BLAKE2B_DIGEST_LENGTH {
+                return Err(MappingError::InvalidStateHashLength {
+                    expected: BLAKE2B_DIGEST_LENGTH,
+                    actual: length,
+                });
+            }
+            parent_state_hash
+                .try_into()
+                .map_err(|_| MappingError::TryFromSlice)?
+        };
+
+        let protocol_version = slash_request.take_protocol_version().into();
+
+        let mut slashes = BTreeMap::new();
+        for pb_slash in slash_request.take_slashes().into_vec() {
+            let (validator, slash) = pb_slash.try_into()?;
+            if slashes.insert(validator, slash).is_some() {
+                let message = format!("Multiple slashes for {}", validator);
+                return Err(ParsingError(message).into());
+            }
+        }
+
+        Ok(SlashRequest::new(
+            parent_state_hash,
+            protocol_version,
+            BlockTime::new(slash_request.get_block_time()),
+            slashes,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::proptest;
+
+    use types::{gens, U512};
+
+    use super::*;
+    use crate::engine_server::mappings::test_utils;
+
+    proptest! {
+        #[test]
+        fn round_trip(
+            public_key in gens::public_key_arb(),
+            u512 in gens::u512_arb(),
+            rate: u64,
+            jailed_until_era: u64,
+        ) {
+            let fixed = Slash::new(SlashAmount::Fixed(u512), jailed_until_era);
+            test_utils::protobuf_round_trip::<(PublicKey, Slash), SlashRequest_ValidatorSlash>(
+                (public_key, fixed),
+            );
+            let proportional = Slash::new(SlashAmount::Rate(rate), jailed_until_era);
+            test_utils::protobuf_round_trip::<(PublicKey, Slash), SlashRequest_ValidatorSlash>(
+                (public_key, proportional),
+            );
+        }
+    }
+
+    #[test]
+    fn should_reject_duplicate_slashes() {
+        let validator = PublicKey::ed25519_from([1; 32]);
+        let mut pb_slash_request = ipc::SlashRequest::new();
+        pb_slash_request.set_parent_state_hash(vec![0; BLAKE2B_DIGEST_LENGTH]);
+        for amount in &[1, 2] {
+            let slash = Slash::new(SlashAmount::Fixed(U512::from(*amount)), 0);
+            pb_slash_request
+                .mut_slashes()
+                .push((validator, slash).into());
+        }
+        assert!(SlashRequest::try_from(pb_slash_request).is_err());
+    }
+}
@@ -0,0 +1,35 @@
+use std::convert::{TryFrom, TryInto};
+
+use engine_core::engine_state::unbond_payout::UnbondPayoutRequest;
+use engine_shared::newtypes::BLAKE2B_DIGEST_LENGTH;
+use types::BlockTime;
+
+use crate::engine_server::{ipc, mappings::MappingError};
+
+impl TryFrom<ipc::UnbondPayoutRequest> for UnbondPayoutRequest {
+    type Error = MappingError;
+
+    fn try_from(mut unbond_payout_request: ipc::UnbondPayoutRequest) -> Result<Self, Self::Error> {
+        let parent_state_hash = {
+            let parent_state_hash = unbond_payout_request.get_parent_state_hash();
+            let length = parent_state_hash.len();
+            if length != BLAKE2B_DIGEST_LENGTH {
+                return Err(MappingError::InvalidStateHashLength {
+                    expected: BLAKE2B_DIGEST_LENGTH,
+                    actual: length,
+                });
+            }
+            parent_state_hash
+                .try_into()
+                .map_err(|_| MappingError::TryFromSlice)?
+        };
+
+        let protocol_version = unbond_payout_request.take_protocol_version().into();
+
+        Ok(UnbondPayoutRequest::new(
+            parent_state_hash,
+            protocol_version,
+            BlockTime::new(unbond_payout_request.get_block_time()),
+        ))
+    }
+}
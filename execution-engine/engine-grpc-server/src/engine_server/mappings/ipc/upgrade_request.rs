@@ -35,11 +35,25 @@ impl TryFrom<UpgradeRequest> for UpgradeConfig {
         } else {
             Some(upgrade_point.mut_new_costs().take_wasm().into())
         };
+        let max_call_depth = match upgrade_point.new_max_call_depth {
+            0 => None,
+            max_call_depth => Some(max_call_depth),
+        };
+        let pos_params = if !upgrade_point.has_new_pos_params() {
+            None
+        } else {
+            Some(upgrade_point.take_new_pos_params().try_into()?)
+        };
         let activation_point = if !upgrade_point.has_activation_point() {
             None
         } else {
-            Some(upgrade_point.get_activation_point().rank)
+            Some(upgrade_point.take_activation_point().into())
         };
+        let migrations = upgrade_point
+            .take_migrations()
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<_>, _>>()?;
 
         Ok(UpgradeConfig::new(
             pre_state_hash,
@@ -48,7 +62,10 @@ impl TryFrom<UpgradeRequest> for UpgradeConfig {
             upgrade_installer_args,
             upgrade_installer_bytes,
             wasm_costs,
+            max_call_depth,
             activation_point,
+            pos_params,
+            migrations,
         ))
     }
 }
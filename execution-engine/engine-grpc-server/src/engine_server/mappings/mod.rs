@@ -13,6 +13,7 @@ use std::{
 use engine_core::{engine_state, DEPLOY_HASH_LENGTH};
 use types::account::ED25519_LENGTH;
 
+pub(crate) use state::{ContractBody, NamedKeyMap};
 pub use transforms::TransformMap;
 
 /// Try to convert a `Vec<u8>` to a 32-byte array.
@@ -4,13 +4,16 @@ use std::{
     mem,
 };
 
-use engine_shared::account::{Account, ActionThresholds, AssociatedKeys};
-use types::account::{PublicKey, Weight};
+use engine_shared::account::{Account, ActionThresholds, AssociatedKeys, Recovery};
+use types::{
+    account::{PublicKey, Weight},
+    BlockTime,
+};
 
 use super::NamedKeyMap;
 use crate::engine_server::{
     mappings::{self, ParsingError},
-    state::{self, Account_AssociatedKey, NamedKey},
+    state::{self, Account_AssociatedKey, Account_Recovery, NamedKey},
 };
 
 impl From<Account> for state::Account {
@@ -37,6 +40,10 @@ impl From<Account> for state::Account {
             pb_action_thresholds.set_key_management_threshold(key_management)
         }
 
+        if let Some(recovery) = account.recovery() {
+            pb_account.set_recovery(recovery.into());
+        }
+
         pb_account
     }
 }
@@ -58,19 +65,8 @@ impl TryFrom<state::Account> for Account {
             pb_uref.try_into()?
         };
 
-        let associated_keys = {
-            let mut associated_keys = AssociatedKeys::default();
-            for pb_associated_key in pb_account.associated_keys.into_vec() {
-                let (key, weight) = pb_associated_key.try_into()?;
-                associated_keys.add_key(key, weight).map_err(|error| {
-                    ParsingError(format!(
-                        "Error parsing Protobuf Account::AssociatedKeys: {:?}",
-                        error
-                    ))
-                })?;
-            }
-            associated_keys
-        };
+        let associated_keys =
+            associated_keys_from(pb_account.associated_keys.into_vec(), "AssociatedKeys")?;
 
         let action_thresholds = {
             let pb_action_thresholds =
@@ -98,8 +94,70 @@ impl TryFrom<state::Account> for Account {
             associated_keys,
             action_thresholds,
         );
-        Ok(account)
+        match pb_account.recovery.into_option() {
+            Some(pb_recovery) => Ok(account.with_recovery(pb_recovery.try_into()?)),
+            None => Ok(account),
+        }
+    }
+}
+
+impl From<&Recovery> for Account_Recovery {
+    fn from(recovery: &Recovery) -> Self {
+        let mut pb_recovery = Account_Recovery::new();
+        let keys: Vec<Account_AssociatedKey> = recovery.keys().iter().map(Into::into).collect();
+        pb_recovery.set_keys(keys.into());
+        pb_recovery.set_threshold(recovery.threshold().value().into());
+        pb_recovery.set_delay(recovery.delay());
+        let approvals: Vec<Account_AssociatedKey> =
+            recovery.approvals().iter().map(Into::into).collect();
+        pb_recovery.set_approvals(approvals.into());
+        if let Some(requested_at) = recovery.requested_at() {
+            pb_recovery
+                .mut_request()
+                .set_requested_at(requested_at.into());
+        }
+        pb_recovery
+    }
+}
+
+impl TryFrom<Account_Recovery> for Recovery {
+    type Error = ParsingError;
+
+    fn try_from(pb_recovery: Account_Recovery) -> Result<Self, Self::Error> {
+        let keys = associated_keys_from(pb_recovery.keys.into_vec(), "Recovery::Keys")?;
+        let threshold = weight_from(pb_recovery.threshold, "Protobuf Recovery::Threshold")?;
+        let approvals =
+            associated_keys_from(pb_recovery.approvals.into_vec(), "Recovery::Approvals")?;
+        let recovery = Recovery::new(keys, threshold, pb_recovery.delay)
+            .map_err(|error| {
+                ParsingError(format!(
+                    "Error parsing Protobuf Account::Recovery: {:?}",
+                    error
+                ))
+            })?
+            .with_approvals(approvals);
+        match pb_recovery.request.into_option() {
+            Some(pb_request) => Ok(recovery.with_request(BlockTime::new(pb_request.requested_at))),
+            None => Ok(recovery),
+        }
+    }
+}
+
+fn associated_keys_from(
+    pb_associated_keys: Vec<Account_AssociatedKey>,
+    field_name: &str,
+) -> Result<AssociatedKeys, ParsingError> {
+    let mut associated_keys = AssociatedKeys::default();
+    for pb_associated_key in pb_associated_keys {
+        let (key, weight) = pb_associated_key.try_into()?;
+        associated_keys.add_key(key, weight).map_err(|error| {
+            ParsingError(format!(
+                "Error parsing Protobuf Account::{}: {:?}",
+                field_name, error
+            ))
+        })?;
     }
+    Ok(associated_keys)
 }
 
 impl From<(&PublicKey, &Weight)> for Account_AssociatedKey {
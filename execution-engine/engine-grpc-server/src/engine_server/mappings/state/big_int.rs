@@ -1,6 +1,6 @@
 use std::convert::TryFrom;
 
-use types::{CLValue, U128, U256, U512};
+use types::{CLValue, Decimal, I256, U128, U256, U512};
 
 use crate::engine_server::{mappings::ParsingError, state::BigInt};
 
@@ -8,10 +8,17 @@ impl TryFrom<BigInt> for CLValue {
     type Error = ParsingError;
 
     fn try_from(pb_big_int: BigInt) -> Result<CLValue, Self::Error> {
+        let signed = pb_big_int.get_signed();
+        let scale = pb_big_int.get_scale();
         let cl_value_result = match pb_big_int.get_bit_width() {
-            128 => CLValue::from_t(U128::try_from(pb_big_int)?),
-            256 => CLValue::from_t(U256::try_from(pb_big_int)?),
-            512 => CLValue::from_t(U512::try_from(pb_big_int)?),
+            128 if !signed => CLValue::from_t(U128::try_from(pb_big_int)?),
+            256 if !signed => CLValue::from_t(U256::try_from(pb_big_int)?),
+            512 if !signed => CLValue::from_t(U512::try_from(pb_big_int)?),
+            256 if scale == 0 => CLValue::from_t(I256::try_from(pb_big_int)?),
+            256 if scale == Decimal::SCALE => CLValue::from_t(Decimal::try_from(pb_big_int)?),
+            bit_width @ 128 | bit_width @ 256 | bit_width @ 512 => {
+                return Err(invalid_sign_or_scale(bit_width, signed, scale))
+            }
             other => return Err(invalid_bit_width(other)),
         };
         cl_value_result.map_err(|error| ParsingError(format!("{:?}", error)))
@@ -25,6 +32,13 @@ fn invalid_bit_width(bit_width: u32) -> ParsingError {
     ))
 }
 
+fn invalid_sign_or_scale(bit_width: u32, signed: bool, scale: u32) -> ParsingError {
+    ParsingError(format!(
+        "Protobuf BigInt with bit width of {}, signed: {} and scale of {} is invalid",
+        bit_width, signed, scale
+    ))
+}
+
 macro_rules! protobuf_conversions_for_uint {
     ($type:ty, $bit_width:literal) => {
         impl From<$type> for BigInt {
@@ -40,9 +54,12 @@ macro_rules! protobuf_conversions_for_uint {
             type Error = ParsingError;
             fn try_from(pb_big_int: BigInt) -> Result<Self, Self::Error> {
                 let value = pb_big_int.get_value();
+                let signed = pb_big_int.get_signed();
+                let scale = pb_big_int.get_scale();
                 match pb_big_int.get_bit_width() {
-                    $bit_width => <$type>::from_dec_str(value)
+                    $bit_width if !signed && scale == 0 => <$type>::from_dec_str(value)
                         .map_err(|error| ParsingError(format!("{:?}", error))),
+                    $bit_width => Err(invalid_sign_or_scale($bit_width, signed, scale)),
                     other => Err(invalid_bit_width(other)),
                 }
             }
@@ -54,6 +71,40 @@ protobuf_conversions_for_uint!(U128, 128);
 protobuf_conversions_for_uint!(U256, 256);
 protobuf_conversions_for_uint!(U512, 512);
 
+macro_rules! protobuf_conversions_for_signed {
+    ($type:ty, $scale:expr) => {
+        impl From<$type> for BigInt {
+            fn from(value: $type) -> Self {
+                let mut pb_big_int = BigInt::new();
+                pb_big_int.set_value(format!("{}", value));
+                pb_big_int.set_bit_width(256);
+                pb_big_int.set_signed(true);
+                pb_big_int.set_scale($scale);
+                pb_big_int
+            }
+        }
+
+        impl TryFrom<BigInt> for $type {
+            type Error = ParsingError;
+            fn try_from(pb_big_int: BigInt) -> Result<Self, Self::Error> {
+                let signed = pb_big_int.get_signed();
+                let scale = pb_big_int.get_scale();
+                match pb_big_int.get_bit_width() {
+                    256 if signed && scale == $scale => pb_big_int
+                        .get_value()
+                        .parse::<$type>()
+                        .map_err(|error| ParsingError(format!("{:?}", error))),
+                    256 => Err(invalid_sign_or_scale(256, signed, scale)),
+                    other => Err(invalid_bit_width(other)),
+                }
+            }
+        }
+    };
+}
+
+protobuf_conversions_for_signed!(I256, 0);
+protobuf_conversions_for_signed!(Decimal, Decimal::SCALE);
+
 #[cfg(test)]
 mod tests {
     use std::fmt::Debug;
@@ -80,6 +131,16 @@ mod tests {
         fn u512_round_trip(u512 in gens::u512_arb()) {
             test_utils::protobuf_round_trip::<U512, BigInt>(u512);
         }
+
+        #[test]
+        fn i256_round_trip(i256 in gens::i256_arb()) {
+            test_utils::protobuf_round_trip::<I256, BigInt>(i256);
+        }
+
+        #[test]
+        fn decimal_round_trip(decimal in gens::decimal_arb()) {
+            test_utils::protobuf_round_trip::<Decimal, BigInt>(decimal);
+        }
     }
 
     fn try_with_bad_value<T>(value: T)
@@ -132,5 +193,49 @@ mod tests {
         try_with_invalid_bit_width(U128::one());
         try_with_invalid_bit_width(U256::one());
         try_with_invalid_bit_width(U512::one());
+        try_with_invalid_bit_width(I256::one());
+        try_with_invalid_bit_width(Decimal::one());
+    }
+
+    #[test]
+    fn should_parse_signed_values() {
+        let mut pb_big_int: BigInt = I256::from(-5).into();
+        assert_eq!(pb_big_int.get_value(), "-5");
+        assert_eq!(
+            CLValue::try_from(pb_big_int.clone()),
+            Ok(CLValue::from_t(I256::from(-5)).unwrap())
+        );
+
+        pb_big_int.set_value("-1.5".to_string());
+        pb_big_int.set_scale(Decimal::SCALE);
+        assert_eq!(
+            CLValue::try_from(pb_big_int),
+            Ok(CLValue::from_t("-1.5".parse::<Decimal>().unwrap()).unwrap())
+        );
+    }
+
+    #[test]
+    fn should_fail_to_parse_with_invalid_sign_or_scale() {
+        let mut signed_u128: BigInt = U128::one().into();
+        signed_u128.set_signed(true);
+        assert_eq!(
+            CLValue::try_from(signed_u128.clone()),
+            Err(invalid_sign_or_scale(128, true, 0))
+        );
+        assert_eq!(
+            U128::try_from(signed_u128),
+            Err(invalid_sign_or_scale(128, true, 0))
+        );
+
+        let mut scaled_i256: BigInt = I256::one().into();
+        scaled_i256.set_scale(2);
+        assert_eq!(
+            CLValue::try_from(scaled_i256.clone()),
+            Err(invalid_sign_or_scale(256, true, 2))
+        );
+        assert_eq!(
+            I256::try_from(scaled_i256),
+            Err(invalid_sign_or_scale(256, true, 2))
+        );
     }
 }
@@ -1,12 +1,60 @@
 use std::convert::{TryFrom, TryInto};
 
-use types::CLType;
+use types::{CLField, CLType, CLVariant};
 
 use crate::engine_server::{
     mappings::ParsingError,
-    state::{self, CLType_Simple, CLType_oneof_variants},
+    state::{self, CLType_Field, CLType_Simple, CLType_Variant, CLType_oneof_variants},
 };
 
+impl From<CLField> for CLType_Field {
+    fn from(field: CLField) -> Self {
+        let mut pb_field = CLType_Field::new();
+        pb_field.set_name(field.name);
+        pb_field.set_cl_type(field.cl_type.into());
+        pb_field
+    }
+}
+
+impl TryFrom<CLType_Field> for CLField {
+    type Error = ParsingError;
+
+    fn try_from(mut pb_field: CLType_Field) -> Result<Self, Self::Error> {
+        let cl_type = pb_field.take_cl_type().try_into()?;
+        Ok(CLField::new(pb_field.take_name(), cl_type))
+    }
+}
+
+impl From<CLVariant> for CLType_Variant {
+    fn from(variant: CLVariant) -> Self {
+        let mut pb_variant = CLType_Variant::new();
+        pb_variant.set_tag(variant.tag.into());
+        pb_variant.set_name(variant.name);
+        let pb_fields: Vec<CLType_Field> = variant.fields.into_iter().map(Into::into).collect();
+        pb_variant.set_fields(pb_fields.into());
+        pb_variant
+    }
+}
+
+impl TryFrom<CLType_Variant> for CLVariant {
+    type Error = ParsingError;
+
+    fn try_from(mut pb_variant: CLType_Variant) -> Result<Self, Self::Error> {
+        let tag = u8::try_from(pb_variant.tag).map_err(|_| {
+            ParsingError(format!(
+                "Unable to parse Protobuf CLType variant tag {}",
+                pb_variant.tag
+            ))
+        })?;
+        let fields = parse_fields(pb_variant.take_fields().into_vec())?;
+        Ok(CLVariant::new(tag, pb_variant.take_name(), fields))
+    }
+}
+
+fn parse_fields(pb_fields: Vec<CLType_Field>) -> Result<Vec<CLField>, ParsingError> {
+    pb_fields.into_iter().map(TryInto::try_into).collect()
+}
+
 impl From<CLType> for state::CLType {
     fn from(cl_type: CLType) -> Self {
         let mut pb_type = state::CLType::new();
@@ -20,6 +68,8 @@ impl From<CLType> for state::CLType {
             CLType::U128 => pb_type.set_simple_type(state::CLType_Simple::U128),
             CLType::U256 => pb_type.set_simple_type(state::CLType_Simple::U256),
             CLType::U512 => pb_type.set_simple_type(state::CLType_Simple::U512),
+            CLType::I256 => pb_type.set_simple_type(state::CLType_Simple::I256),
+            CLType::Decimal => pb_type.set_simple_type(state::CLType_Simple::DECIMAL),
             CLType::Unit => pb_type.set_simple_type(state::CLType_Simple::UNIT),
             CLType::String => pb_type.set_simple_type(state::CLType_Simple::STRING),
             CLType::Key => pb_type.set_simple_type(state::CLType_Simple::KEY),
@@ -35,6 +85,7 @@ impl From<CLType> for state::CLType {
                 pb_fixed_list.set_inner((*inner).into());
                 pb_fixed_list.set_len(len);
             }
+            CLType::ByteArray(len) => pb_type.mut_byte_array_type().set_len(len),
             CLType::Result { ok, err } => {
                 let pb_result = pb_type.mut_result_type();
                 pb_result.set_ok((*ok).into());
@@ -67,6 +118,19 @@ impl From<CLType> for state::CLType {
             CLType::Any => {
                 let _pb_any = pb_type.mut_any_type();
             }
+            CLType::Struct { name, fields } => {
+                let pb_struct = pb_type.mut_struct_type();
+                pb_struct.set_name(name);
+                let pb_fields: Vec<CLType_Field> = fields.into_iter().map(Into::into).collect();
+                pb_struct.set_fields(pb_fields.into());
+            }
+            CLType::Enum { name, variants } => {
+                let pb_enum = pb_type.mut_enum_type();
+                pb_enum.set_name(name);
+                let pb_variants: Vec<CLType_Variant> =
+                    variants.into_iter().map(Into::into).collect();
+                pb_enum.set_variants(pb_variants.into());
+            }
         };
         pb_type
     }
@@ -90,6 +154,8 @@ impl TryFrom<state::CLType> for CLType {
             CLType_oneof_variants::simple_type(CLType_Simple::U128) => CLType::U128,
             CLType_oneof_variants::simple_type(CLType_Simple::U256) => CLType::U256,
             CLType_oneof_variants::simple_type(CLType_Simple::U512) => CLType::U512,
+            CLType_oneof_variants::simple_type(CLType_Simple::I256) => CLType::I256,
+            CLType_oneof_variants::simple_type(CLType_Simple::DECIMAL) => CLType::Decimal,
             CLType_oneof_variants::simple_type(CLType_Simple::UNIT) => CLType::Unit,
             CLType_oneof_variants::simple_type(CLType_Simple::STRING) => CLType::String,
             CLType_oneof_variants::simple_type(CLType_Simple::KEY) => CLType::Key,
@@ -106,6 +172,9 @@ impl TryFrom<state::CLType> for CLType {
                 let inner = pb_fixed_list.take_inner().try_into()?;
                 CLType::FixedList(Box::new(inner), pb_fixed_list.len)
             }
+            CLType_oneof_variants::byte_array_type(pb_byte_array) => {
+                CLType::ByteArray(pb_byte_array.len)
+            }
             CLType_oneof_variants::result_type(mut pb_result) => {
                 let ok = pb_result.take_ok().try_into()?;
                 let err = pb_result.take_err().try_into()?;
@@ -138,7 +207,67 @@ impl TryFrom<state::CLType> for CLType {
                 CLType::Tuple3([Box::new(type0), Box::new(type1), Box::new(type2)])
             }
             CLType_oneof_variants::any_type(_) => CLType::Any,
+            CLType_oneof_variants::struct_type(mut pb_struct) => {
+                let fields = parse_fields(pb_struct.take_fields().into_vec())?;
+                CLType::Struct {
+                    name: pb_struct.take_name(),
+                    fields,
+                }
+            }
+            CLType_oneof_variants::enum_type(mut pb_enum) => {
+                let variants = pb_enum
+                    .take_variants()
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<_, _>>()?;
+                CLType::Enum {
+                    name: pb_enum.take_name(),
+                    variants,
+                }
+            }
         };
         Ok(cl_type)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine_server::mappings::test_utils;
+
+    #[test]
+    fn struct_and_enum_round_trip() {
+        let field = CLField::new("inner", CLType::List(Box::new(CLType::Key)));
+        let cl_type = CLType::Enum {
+            name: String::from("Outer"),
+            variants: vec![
+                CLVariant::new(0, "Unit", vec![]),
+                CLVariant::new(
+                    3,
+                    "Record",
+                    vec![CLField::new(
+                        "0",
+                        CLType::Struct {
+                            name: String::from("Inner"),
+                            fields: vec![field],
+                        },
+                    )],
+                ),
+            ],
+        };
+        test_utils::protobuf_round_trip::<CLType, state::CLType>(cl_type);
+    }
+
+    #[test]
+    fn byte_array_round_trip() {
+        let cl_type = CLType::Option(Box::new(CLType::ByteArray(32)));
+        test_utils::protobuf_round_trip::<CLType, state::CLType>(cl_type);
+    }
+
+    #[test]
+    fn should_fail_to_parse_oversized_tag() {
+        let mut pb_variant = CLType_Variant::new();
+        pb_variant.set_tag(256);
+        assert!(CLVariant::try_from(pb_variant).is_err());
+    }
+}
@@ -5,7 +5,7 @@ use engine_shared::contract::Contract;
 use super::NamedKeyMap;
 use crate::engine_server::{
     mappings::ParsingError,
-    state::{self, NamedKey},
+    state::{self, ContractBodyChunk, NamedKey},
 };
 
 impl From<Contract> for state::Contract {
@@ -31,9 +31,48 @@ impl TryFrom<state::Contract> for Contract {
     }
 }
 
+/// Thin wrapper to allow us to convert a contract's wasm bytes to and from
+/// `Vec<ContractBodyChunk>`.
+#[derive(Clone, PartialEq, Debug)]
+pub(crate) struct ContractBody(Vec<u8>);
+
+impl ContractBody {
+    pub fn new(inner: Vec<u8>) -> Self {
+        Self(inner)
+    }
+
+    /// Splits the bytes into chunks of at most `chunk_size` bytes each.
+    ///
+    /// An empty body yields a single empty chunk so that a stream of chunks is never empty.
+    pub fn into_chunks(self, chunk_size: usize) -> Vec<ContractBodyChunk> {
+        let chunk_size = chunk_size.max(1);
+        if self.0.is_empty() {
+            return vec![ContractBodyChunk::new()];
+        }
+        self.0
+            .chunks(chunk_size)
+            .map(|chunk| {
+                let mut pb_chunk = ContractBodyChunk::new();
+                pb_chunk.set_body(chunk.to_vec());
+                pb_chunk
+            })
+            .collect()
+    }
+}
+
+impl From<Vec<ContractBodyChunk>> for ContractBody {
+    fn from(pb_chunks: Vec<ContractBodyChunk>) -> Self {
+        let bytes = pb_chunks
+            .into_iter()
+            .flat_map(|pb_chunk| pb_chunk.body)
+            .collect();
+        ContractBody(bytes)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use proptest::proptest;
+    use proptest::{collection::vec, prelude::any, proptest};
 
     use engine_shared::contract::gens;
 
@@ -45,5 +84,16 @@ mod tests {
         fn round_trip(contract in gens::contract_arb()) {
             test_utils::protobuf_round_trip::<Contract, state::Contract>(contract);
         }
+
+        #[test]
+        fn chunks_round_trip(bytes in vec(any::<u8>(), 0..100), chunk_size in 0usize..32) {
+            let contract_body = ContractBody::new(bytes);
+            let pb_chunks = contract_body.clone().into_chunks(chunk_size);
+            assert!(!pb_chunks.is_empty());
+            assert!(pb_chunks
+                .iter()
+                .all(|pb_chunk| pb_chunk.get_body().len() <= chunk_size.max(1)));
+            assert_eq!(contract_body, ContractBody::from(pb_chunks));
+        }
     }
 }
@@ -12,4 +12,5 @@ mod protocol_version;
 mod stored_value;
 mod uref;
 
+pub(crate) use contract::ContractBody;
 pub(crate) use named_key::NamedKeyMap;
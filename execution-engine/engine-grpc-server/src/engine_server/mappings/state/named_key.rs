@@ -5,7 +5,10 @@ use std::{
 
 use types::Key;
 
-use crate::engine_server::{mappings::ParsingError, state::NamedKey};
+use crate::engine_server::{
+    mappings::ParsingError,
+    state::{NamedKey, NamedKeysPage},
+};
 
 impl From<(String, Key)> for NamedKey {
     fn from((name, key): (String, Key)) -> Self {
@@ -39,6 +42,25 @@ impl NamedKeyMap {
     pub fn into_inner(self) -> BTreeMap<String, Key> {
         self.0
     }
+
+    /// Splits the map into pages of at most `page_size` named keys each, in key order.
+    ///
+    /// An empty map yields a single empty page so that a stream of pages is never empty.
+    pub fn into_pages(self, page_size: usize) -> Vec<NamedKeysPage> {
+        let page_size = page_size.max(1);
+        let pb_named_keys: Vec<NamedKey> = self.into();
+        if pb_named_keys.is_empty() {
+            return vec![NamedKeysPage::new()];
+        }
+        pb_named_keys
+            .chunks(page_size)
+            .map(|chunk| {
+                let mut pb_page = NamedKeysPage::new();
+                pb_page.set_named_keys(chunk.to_vec().into());
+                pb_page
+            })
+            .collect()
+    }
 }
 
 impl From<NamedKeyMap> for Vec<NamedKey> {
@@ -61,6 +83,18 @@ impl TryFrom<Vec<NamedKey>> for NamedKeyMap {
     }
 }
 
+impl TryFrom<Vec<NamedKeysPage>> for NamedKeyMap {
+    type Error = ParsingError;
+
+    fn try_from(pb_pages: Vec<NamedKeysPage>) -> Result<Self, Self::Error> {
+        let pb_named_keys: Vec<NamedKey> = pb_pages
+            .into_iter()
+            .flat_map(|mut pb_page| pb_page.take_named_keys().into_vec())
+            .collect();
+        pb_named_keys.try_into()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use proptest::proptest;
@@ -81,5 +115,17 @@ mod tests {
             let named_key_map = NamedKeyMap(named_keys);
             test_utils::protobuf_round_trip::<NamedKeyMap, Vec<NamedKey>>(named_key_map);
         }
+
+        #[test]
+        fn pages_round_trip(named_keys in gens::named_keys_arb(10), page_size in 0usize..12) {
+            let named_key_map = NamedKeyMap(named_keys);
+            let pb_pages = named_key_map.clone().into_pages(page_size);
+            assert!(!pb_pages.is_empty());
+            assert!(pb_pages
+                .iter()
+                .all(|pb_page| pb_page.get_named_keys().len() <= page_size.max(1)));
+            let parsed = NamedKeyMap::try_from(pb_pages).expect("should parse pages");
+            assert_eq!(named_key_map, parsed);
+        }
     }
 }
@@ -4,7 +4,7 @@ use engine_shared::{
     stored_value::StoredValue,
     transform::{Error as TransformError, Transform},
 };
-use types::{CLType, CLValue, U128, U256, U512};
+use types::{CLType, CLValue, Decimal, I256, U128, U256, U512};
 
 use crate::engine_server::{
     mappings::{state::NamedKeyMap, ParsingError},
@@ -42,6 +42,12 @@ impl From<Transform> for transforms::Transform {
             Transform::AddUInt512(uint512) => {
                 pb_transform.mut_add_big_int().set_value(uint512.into());
             }
+            Transform::AddInt256(int256) => {
+                pb_transform.mut_add_big_int().set_value(int256.into());
+            }
+            Transform::AddDecimal(decimal) => {
+                pb_transform.mut_add_big_int().set_value(decimal.into());
+            }
         };
         pb_transform
     }
@@ -83,9 +89,21 @@ impl TryFrom<transforms::Transform> for Transform {
                             .map_err(|error| ParsingError(format!("{:?}", error)))?;
                         u512.into()
                     }
+                    CLType::I256 => {
+                        let i256: I256 = cl_value
+                            .into_t()
+                            .map_err(|error| ParsingError(format!("{:?}", error)))?;
+                        i256.into()
+                    }
+                    CLType::Decimal => {
+                        let decimal: Decimal = cl_value
+                            .into_t()
+                            .map_err(|error| ParsingError(format!("{:?}", error)))?;
+                        decimal.into()
+                    }
                     other => {
                         return Err(ParsingError(format!(
-                            "Protobuf BigInt was turned into a non-uint Value type: {:?}",
+                            "Protobuf BigInt was turned into a non-numeric Value type: {:?}",
                             other
                         )));
                     }
@@ -15,59 +15,93 @@ include!(concat!(
     "/../../../../generated_protobuf/transforms.rs"
 ));
 pub mod mappings;
+pub mod tls;
 
 use std::{
+    cmp,
     collections::BTreeMap,
     convert::{TryFrom, TryInto},
     fmt::Debug,
     io::ErrorKind,
     iter::FromIterator,
     marker::{Send, Sync},
+    mem,
+    net::SocketAddr,
     time::Instant,
 };
 
-use grpc::{RequestOptions, ServerBuilder, SingleResponse};
+use grpc::{RequestOptions, ServerBuilder, SingleResponse, StreamingResponse};
 use log::{info, warn, Level};
 
 use engine_core::engine_state::{
-    execute_request::ExecuteRequest,
+    execute_request::{ExecuteRequest, ExecuteResults},
     genesis::{GenesisConfig, GenesisResult},
     query::{QueryRequest, QueryResult},
+    rewards::DistributeRewardsRequest as EngineDistributeRewardsRequest,
+    slash::SlashRequest as EngineSlashRequest,
+    unbond_payout::UnbondPayoutRequest as EngineUnbondPayoutRequest,
     upgrade::{UpgradeConfig, UpgradeResult},
     EngineState, Error as EngineError,
 };
 use engine_shared::{
-    logging::{self, log_duration},
+    logging::{self, log_duration, Settings},
+    metrics,
     newtypes::{Blake2bHash, CorrelationId},
+    stored_value::StoredValue,
 };
 use engine_storage::global_state::{CommitResult, StateProvider};
-use types::{bytesrepr::ToBytes, ProtocolVersion};
+use types::{bytesrepr::ToBytes, ProtocolVersion, U512};
 
 use self::{
     ipc::{
         BidStateRequest, BidStateResponse, ChainSpec_GenesisConfig, CommitRequest, CommitResponse,
-        DistributeRewardsRequest, DistributeRewardsResponse, ExecuteResponse, GenesisResponse,
-        QueryResponse, SlashRequest, SlashResponse, UnbondPayoutRequest, UnbondPayoutResponse,
-        UpgradeRequest, UpgradeResponse,
+        DistributeRewardsRequest, DistributeRewardsResponse, EngineInfo, ExecuteResponse,
+        GenesisResponse, InfoRequest, InfoResponse, LogSettings, PosParamsRequest,
+        PosParamsResponse, QueryContractBodyRequest, QueryContractBodyResponse,
+        QueryNamedKeysRequest, QueryNamedKeysResponse, QueryResponse, SetLogSettingsResponse,
+        SlashRequest, SlashResponse, UnbondPayoutRequest, UnbondPayoutResponse, UpgradeRequest,
+        UpgradeResponse,
     },
     ipc_grpc::{ExecutionEngineService, ExecutionEngineServiceServer},
-    mappings::{ParsingError, TransformMap},
+    mappings::{ContractBody, NamedKeyMap, ParsingError, TransformMap},
+    tls::TlsConfig,
 };
 
 const METRIC_DURATION_COMMIT: &str = "commit_duration";
 const METRIC_DURATION_EXEC: &str = "exec_duration";
 const METRIC_DURATION_QUERY: &str = "query_duration";
+const METRIC_DURATION_QUERY_NAMED_KEYS: &str = "query_named_keys_duration";
+const METRIC_DURATION_QUERY_CONTRACT_BODY: &str = "query_contract_body_duration";
 const METRIC_DURATION_GENESIS: &str = "genesis_duration";
 const METRIC_DURATION_UPGRADE: &str = "upgrade_duration";
+const METRIC_DURATION_DISTRIBUTE_REWARDS: &str = "distribute_rewards_duration";
+const METRIC_DURATION_INFO: &str = "info_duration";
+const METRIC_DURATION_POS_PARAMS: &str = "pos_params_duration";
+const METRIC_DURATION_SLASH: &str = "slash_duration";
+const METRIC_DURATION_UNBOND_PAYOUT: &str = "unbond_payout_duration";
+const METRIC_DEPLOYS_EXECUTED: &str = "deploys_executed";
+const METRIC_GAS_USED: &str = "gas_used";
 
 const TAG_RESPONSE_COMMIT: &str = "commit_response";
 const TAG_RESPONSE_EXEC: &str = "exec_response";
 const TAG_RESPONSE_QUERY: &str = "query_response";
+const TAG_RESPONSE_QUERY_NAMED_KEYS: &str = "query_named_keys_response";
+const TAG_RESPONSE_QUERY_CONTRACT_BODY: &str = "query_contract_body_response";
 const TAG_RESPONSE_GENESIS: &str = "genesis_response";
 const TAG_RESPONSE_UPGRADE: &str = "upgrade_response";
+const TAG_RESPONSE_DISTRIBUTE_REWARDS: &str = "distribute_rewards_response";
+const TAG_RESPONSE_INFO: &str = "info_response";
+const TAG_RESPONSE_POS_PARAMS: &str = "pos_params_response";
+const TAG_RESPONSE_SLASH: &str = "slash_response";
+const TAG_RESPONSE_UNBOND_PAYOUT: &str = "unbond_payout_response";
 
 const DEFAULT_PROTOCOL_VERSION: ProtocolVersion = ProtocolVersion::V1_0_0;
 
+/// Number of named keys per page used when a `QueryNamedKeysRequest` doesn't specify one.
+const DEFAULT_NAMED_KEYS_PAGE_SIZE: usize = 1_000;
+/// Number of bytes per chunk used when a `QueryContractBodyRequest` doesn't specify one.
+const DEFAULT_CONTRACT_BODY_CHUNK_SIZE: usize = 1024 * 1024;
+
 // Idea is that Engine will represent the core of the execution engine project.
 // It will act as an entry point for execution of Wasm binaries.
 // Proto definitions should be translated into domain objects when Engine's API
@@ -160,6 +194,126 @@ where
         SingleResponse::completed(response)
     }
 
+    fn query_named_keys(
+        &self,
+        _request_options: RequestOptions,
+        mut query_named_keys_request: QueryNamedKeysRequest,
+    ) -> StreamingResponse<QueryNamedKeysResponse> {
+        let start = Instant::now();
+        let correlation_id = CorrelationId::new();
+
+        let page_size = match query_named_keys_request.get_page_size() {
+            0 => DEFAULT_NAMED_KEYS_PAGE_SIZE,
+            page_size => page_size as usize,
+        };
+
+        let query_request = query_named_keys_request.take_query();
+        let named_keys =
+            query_stored_value(self, correlation_id, query_request).and_then(|stored_value| {
+                match stored_value {
+                    StoredValue::Account(mut account) => {
+                        Ok(mem::replace(account.named_keys_mut(), BTreeMap::new()))
+                    }
+                    StoredValue::Contract(contract) => Ok(contract.take_named_keys()),
+                    StoredValue::CLValue(_) => {
+                        Err("Queried value is neither an account nor a contract".to_string())
+                    }
+                }
+            });
+
+        let responses = match named_keys {
+            Ok(named_keys) => {
+                info!(
+                    "query_named_keys successful; correlation_id: {}",
+                    correlation_id
+                );
+                NamedKeyMap::new(named_keys)
+                    .into_pages(page_size)
+                    .into_iter()
+                    .map(|page| {
+                        let mut result = QueryNamedKeysResponse::new();
+                        result.set_success(page);
+                        result
+                    })
+                    .collect()
+            }
+            Err(log_message) => {
+                warn!("{}", log_message);
+                let mut result = QueryNamedKeysResponse::new();
+                result.set_failure(log_message);
+                vec![result]
+            }
+        };
+
+        log_duration(
+            correlation_id,
+            METRIC_DURATION_QUERY_NAMED_KEYS,
+            TAG_RESPONSE_QUERY_NAMED_KEYS,
+            start.elapsed(),
+        );
+
+        StreamingResponse::iter(responses.into_iter())
+    }
+
+    fn query_contract_body(
+        &self,
+        _request_options: RequestOptions,
+        mut query_contract_body_request: QueryContractBodyRequest,
+    ) -> StreamingResponse<QueryContractBodyResponse> {
+        let start = Instant::now();
+        let correlation_id = CorrelationId::new();
+
+        let chunk_size = match query_contract_body_request.get_chunk_size() {
+            0 => DEFAULT_CONTRACT_BODY_CHUNK_SIZE,
+            chunk_size => chunk_size as usize,
+        };
+
+        let query_request = query_contract_body_request.take_query();
+        let body =
+            query_stored_value(self, correlation_id, query_request).and_then(|stored_value| {
+                match stored_value {
+                    StoredValue::Contract(contract) => {
+                        let (body, _named_keys, _protocol_version) = contract.destructure();
+                        Ok(body)
+                    }
+                    _ => Err("Queried value is not a contract".to_string()),
+                }
+            });
+
+        let responses = match body {
+            Ok(body) => {
+                info!(
+                    "query_contract_body successful; correlation_id: {}",
+                    correlation_id
+                );
+                ContractBody::new(body)
+                    .into_chunks(chunk_size)
+                    .into_iter()
+                    .map(|chunk| {
+                        let mut result = QueryContractBodyResponse::new();
+                        result.set_success(chunk);
+                        result
+                    })
+                    .collect()
+            }
+            Err(log_message) => {
+                warn!("{}", log_message);
+                let mut result = QueryContractBodyResponse::new();
+                result.set_failure(log_message);
+                vec![result]
+            }
+        };
+
+        log_duration(
+            correlation_id,
+            METRIC_DURATION_QUERY_CONTRACT_BODY,
+            TAG_RESPONSE_QUERY_CONTRACT_BODY,
+            start.elapsed(),
+        );
+
+        StreamingResponse::iter(responses.into_iter())
+    }
+
     fn execute(
         &self,
         _request_options: RequestOptions,
@@ -177,7 +331,10 @@ where
 
         let mut exec_response = ExecuteResponse::new();
 
-        let results = match self.run_execute(correlation_id, exec_request) {
+        let ExecuteResults {
+            scheduled_call_results,
+            deploy_results: results,
+        } = match self.run_execute(correlation_id, exec_request) {
             Ok(results) => results,
             Err(error) => {
                 info!("deploy results error: RootNotFound");
@@ -192,10 +349,30 @@ where
             }
         };
 
+        // Costs which don't fit in a `u64` are reported as `u64::MAX`.
+        let max_cost = U512::from(std::u64::MAX);
+        let gas_used: f64 = results
+            .iter()
+            .map(|result| cmp::min(result.cost().value(), max_cost).as_u64() as f64)
+            .sum();
+        metrics::increment_counter(
+            METRIC_DEPLOYS_EXECUTED,
+            TAG_RESPONSE_EXEC,
+            results.len() as f64,
+        );
+        metrics::increment_counter(METRIC_GAS_USED, TAG_RESPONSE_EXEC, gas_used);
+
         let protobuf_results_iter = results.into_iter().map(Into::into);
         exec_response
             .mut_success()
             .set_deploy_results(FromIterator::from_iter(protobuf_results_iter));
+        let protobuf_scheduled_call_results_iter =
+            scheduled_call_results.into_iter().map(Into::into);
+        exec_response
+            .mut_success()
+            .set_scheduled_call_results(FromIterator::from_iter(
+                protobuf_scheduled_call_results_iter,
+            ));
         log_duration(
             correlation_id,
             METRIC_DURATION_EXEC,
@@ -407,12 +584,14 @@ where
             Ok(UpgradeResult::Success {
                 post_state_hash,
                 effect,
+                applied_migrations,
             }) => {
                 info!("upgrade successful: {}", post_state_hash);
                 let mut ret = UpgradeResponse::new();
                 let upgrade_result = ret.mut_success();
                 upgrade_result.set_post_state_hash(post_state_hash.to_vec());
                 upgrade_result.set_effect(effect.into());
+                upgrade_result.set_applied_migrations(applied_migrations as u32);
                 ret
             }
             Ok(upgrade_result) => {
@@ -443,6 +622,85 @@ where
         SingleResponse::completed(upgrade_response)
     }
 
+    fn info(
+        &self,
+        _request_options: RequestOptions,
+        _info_request: InfoRequest,
+    ) -> SingleResponse<InfoResponse> {
+        let start = Instant::now();
+        let correlation_id = CorrelationId::new();
+
+        let info = self.get_all_wasm_costs().and_then(|all_wasm_costs| {
+            let storage_stats = self.storage_stats()?;
+            Ok((all_wasm_costs, storage_stats))
+        });
+
+        let info_response = match info {
+            Ok((all_wasm_costs, storage_stats)) => {
+                let mut engine_info = EngineInfo::new();
+                engine_info.set_version(env!("CARGO_PKG_VERSION").to_string());
+                engine_info
+                    .set_protocol_versions(all_wasm_costs.into_iter().map(Into::into).collect());
+                if let Some(storage_stats) = storage_stats {
+                    engine_info.set_data_dir_size(storage_stats.data_dir_size);
+                    engine_info.set_page_size(storage_stats.page_size);
+                    engine_info.set_used_pages(storage_stats.used_pages);
+                    engine_info.set_total_pages(storage_stats.total_pages);
+                }
+                engine_info.set_turbo(self.config().turbo());
+
+                let mut ret = InfoResponse::new();
+                ret.set_success(engine_info);
+                ret
+            }
+            Err(err) => {
+                let err_msg = err.to_string();
+                warn!("{}", err_msg);
+
+                let mut ret = InfoResponse::new();
+                ret.set_failure(err_msg);
+                ret
+            }
+        };
+
+        log_duration(
+            correlation_id,
+            METRIC_DURATION_INFO,
+            TAG_RESPONSE_INFO,
+            start.elapsed(),
+        );
+
+        SingleResponse::completed(info_response)
+    }
+
+    fn set_log_settings(
+        &self,
+        _request_options: RequestOptions,
+        log_settings: LogSettings,
+    ) -> SingleResponse<SetLogSettingsResponse> {
+        let mut response = SetLogSettingsResponse::new();
+
+        let settings: Settings = match log_settings.try_into() {
+            Ok(settings) => settings,
+            Err(ParsingError(err_msg)) => {
+                warn!("{}", err_msg);
+                response.set_failure(err_msg);
+                return SingleResponse::completed(response);
+            }
+        };
+
+        if logging::reload(settings) {
+            info!("log settings changed to {:?}", settings);
+            response.set_success(settings.into());
+        } else {
+            let err_msg = "Global logger doesn't support changing settings".to_string();
+            warn!("{}", err_msg);
+            response.set_failure(err_msg);
+        }
+
+        SingleResponse::completed(response)
+    }
+
     fn bid_state(
         &self,
         _request_options: RequestOptions,
@@ -455,31 +713,305 @@ where
     fn distribute_rewards(
         &self,
         _request_options: RequestOptions,
-        _distribute_rewards_request: DistributeRewardsRequest,
+        distribute_rewards_request: DistributeRewardsRequest,
     ) -> SingleResponse<DistributeRewardsResponse> {
-        let response = DistributeRewardsResponse::new();
+        let start = Instant::now();
+        let correlation_id = CorrelationId::new();
+
+        let request: EngineDistributeRewardsRequest = match distribute_rewards_request.try_into() {
+            Ok(request) => request,
+            Err(error) => {
+                let err_msg = format!("{:?}", error);
+                warn!("{}", err_msg);
+
+                let mut response = DistributeRewardsResponse::new();
+                response.mut_error().set_message(err_msg);
+
+                log_duration(
+                    correlation_id,
+                    METRIC_DURATION_DISTRIBUTE_REWARDS,
+                    TAG_RESPONSE_DISTRIBUTE_REWARDS,
+                    start.elapsed(),
+                );
+
+                return SingleResponse::completed(response);
+            }
+        };
+        let parent_state_hash = request.parent_state_hash();
+
+        let response = match self.commit_distribute_rewards(correlation_id, request) {
+            Ok(CommitResult::Success {
+                state_root,
+                bonded_validators,
+            }) => {
+                info!("rewards distributed; new state hash is: {:?}", state_root);
+                let mut ret = DistributeRewardsResponse::new();
+                let bonds = bonded_validators.into_iter().map(Into::into).collect();
+                let commit_result = ret.mut_success();
+                commit_result.set_poststate_hash(state_root.to_vec());
+                commit_result.set_bonded_validators(bonds);
+                ret
+            }
+            Ok(CommitResult::RootNotFound) => {
+                warn!("RootNotFound");
+                let mut ret = DistributeRewardsResponse::new();
+                ret.mut_missing_parent()
+                    .set_hash(parent_state_hash.to_vec());
+                ret
+            }
+            Ok(commit_result) => {
+                let err_msg = format!("{:?}", commit_result);
+                warn!("{}", err_msg);
+
+                let mut ret = DistributeRewardsResponse::new();
+                ret.mut_error().set_message(err_msg);
+                ret
+            }
+            Err(err) => {
+                let err_msg = err.to_string();
+                warn!("{}", err_msg);
+
+                let mut ret = DistributeRewardsResponse::new();
+                ret.mut_error().set_message(err_msg);
+                ret
+            }
+        };
+
+        log_duration(
+            correlation_id,
+            METRIC_DURATION_DISTRIBUTE_REWARDS,
+            TAG_RESPONSE_DISTRIBUTE_REWARDS,
+            start.elapsed(),
+        );
+
         SingleResponse::completed(response)
     }
 
     fn slash(
         &self,
         _request_options: RequestOptions,
-        _slash_request: SlashRequest,
+        slash_request: SlashRequest,
     ) -> SingleResponse<SlashResponse> {
-        let response = SlashResponse::new();
+        let start = Instant::now();
+        let correlation_id = CorrelationId::new();
+
+        let request: EngineSlashRequest = match slash_request.try_into() {
+            Ok(request) => request,
+            Err(error) => {
+                let err_msg = format!("{:?}", error);
+                warn!("{}", err_msg);
+
+                let mut response = SlashResponse::new();
+                response.mut_error().set_message(err_msg);
+
+                log_duration(
+                    correlation_id,
+                    METRIC_DURATION_SLASH,
+                    TAG_RESPONSE_SLASH,
+                    start.elapsed(),
+                );
+
+                return SingleResponse::completed(response);
+            }
+        };
+        let parent_state_hash = request.parent_state_hash();
+
+        let response = match self.commit_slash(correlation_id, request) {
+            Ok(CommitResult::Success {
+                state_root,
+                bonded_validators,
+            }) => {
+                info!("validators slashed; new state hash is: {:?}", state_root);
+                let mut ret = SlashResponse::new();
+                let bonds = bonded_validators.into_iter().map(Into::into).collect();
+                let commit_result = ret.mut_success();
+                commit_result.set_poststate_hash(state_root.to_vec());
+                commit_result.set_bonded_validators(bonds);
+                ret
+            }
+            Ok(CommitResult::RootNotFound) => {
+                warn!("RootNotFound");
+                let mut ret = SlashResponse::new();
+                ret.mut_missing_parent()
+                    .set_hash(parent_state_hash.to_vec());
+                ret
+            }
+            Ok(commit_result) => {
+                let err_msg = format!("{:?}", commit_result);
+                warn!("{}", err_msg);
+
+                let mut ret = SlashResponse::new();
+                ret.mut_error().set_message(err_msg);
+                ret
+            }
+            Err(err) => {
+                let err_msg = err.to_string();
+                warn!("{}", err_msg);
+
+                let mut ret = SlashResponse::new();
+                ret.mut_error().set_message(err_msg);
+                ret
+            }
+        };
+
+        log_duration(
+            correlation_id,
+            METRIC_DURATION_SLASH,
+            TAG_RESPONSE_SLASH,
+            start.elapsed(),
+        );
+
         SingleResponse::completed(response)
     }
 
     fn unbond_payout(
         &self,
         _request_options: RequestOptions,
-        _unbond_payout_request: UnbondPayoutRequest,
+        unbond_payout_request: UnbondPayoutRequest,
     ) -> SingleResponse<UnbondPayoutResponse> {
-        let response = UnbondPayoutResponse::new();
+        let start = Instant::now();
+        let correlation_id = CorrelationId::new();
+
+        let request: EngineUnbondPayoutRequest = match unbond_payout_request.try_into() {
+            Ok(request) => request,
+            Err(error) => {
+                let err_msg = format!("{:?}", error);
+                warn!("{}", err_msg);
+
+                let mut response = UnbondPayoutResponse::new();
+                response.mut_error().set_message(err_msg);
+
+                log_duration(
+                    correlation_id,
+                    METRIC_DURATION_UNBOND_PAYOUT,
+                    TAG_RESPONSE_UNBOND_PAYOUT,
+                    start.elapsed(),
+                );
+
+                return SingleResponse::completed(response);
+            }
+        };
+        let parent_state_hash = request.parent_state_hash();
+
+        let response = match self.commit_unbond_payout(correlation_id, request) {
+            Ok(CommitResult::Success {
+                state_root,
+                bonded_validators,
+            }) => {
+                info!("unbonds paid out; new state hash is: {:?}", state_root);
+                let mut ret = UnbondPayoutResponse::new();
+                let bonds = bonded_validators.into_iter().map(Into::into).collect();
+                let commit_result = ret.mut_success();
+                commit_result.set_poststate_hash(state_root.to_vec());
+                commit_result.set_bonded_validators(bonds);
+                ret
+            }
+            Ok(CommitResult::RootNotFound) => {
+                warn!("RootNotFound");
+                let mut ret = UnbondPayoutResponse::new();
+                ret.mut_missing_parent()
+                    .set_hash(parent_state_hash.to_vec());
+                ret
+            }
+            Ok(commit_result) => {
+                let err_msg = format!("{:?}", commit_result);
+                warn!("{}", err_msg);
+
+                let mut ret = UnbondPayoutResponse::new();
+                ret.mut_error().set_message(err_msg);
+                ret
+            }
+            Err(err) => {
+                let err_msg = err.to_string();
+                warn!("{}", err_msg);
+
+                let mut ret = UnbondPayoutResponse::new();
+                ret.mut_error().set_message(err_msg);
+                ret
+            }
+        };
+
+        log_duration(
+            correlation_id,
+            METRIC_DURATION_UNBOND_PAYOUT,
+            TAG_RESPONSE_UNBOND_PAYOUT,
+            start.elapsed(),
+        );
+
+        SingleResponse::completed(response)
+    }
+
+    fn pos_params(
+        &self,
+        _request_options: RequestOptions,
+        mut pos_params_request: PosParamsRequest,
+    ) -> SingleResponse<PosParamsResponse> {
+        let start = Instant::now();
+        let correlation_id = CorrelationId::new();
+
+        let mut response = PosParamsResponse::new();
+        let parent_state_hash: Blake2bHash =
+            match pos_params_request.get_parent_state_hash().try_into() {
+                Err(_) => {
+                    let err_msg = "Could not parse parent state hash".to_string();
+                    warn!("{}", err_msg);
+                    response.mut_error().set_message(err_msg);
+                    return SingleResponse::completed(response);
+                }
+                Ok(hash) => hash,
+            };
+        let protocol_version = pos_params_request.take_protocol_version().into();
+
+        match self.get_pos_params(correlation_id, protocol_version, parent_state_hash) {
+            Ok(Some(pos_params)) => response.set_success(pos_params.into()),
+            Ok(None) => {
+                warn!("RootNotFound");
+                response
+                    .mut_missing_parent()
+                    .set_hash(parent_state_hash.to_vec());
+            }
+            Err(err) => {
+                let err_msg = err.to_string();
+                warn!("{}", err_msg);
+                response.mut_error().set_message(err_msg);
+            }
+        }
+
+        log_duration(
+            correlation_id,
+            METRIC_DURATION_POS_PARAMS,
+            TAG_RESPONSE_POS_PARAMS,
+            start.elapsed(),
+        );
+
         SingleResponse::completed(response)
     }
 }
 
+/// Runs `query_request` against `engine_state`, returning the found value or a failure message.
+fn query_stored_value<S>(
+    engine_state: &EngineState<S>,
+    correlation_id: CorrelationId,
+    query_request: ipc::QueryRequest,
+) -> Result<StoredValue, String>
+where
+    S: StateProvider,
+    EngineError: From<S::Error>,
+    S::Error: Into<engine_core::execution::Error> + Debug,
+{
+    let request: QueryRequest = query_request
+        .try_into()
+        .map_err(|err| format!("{:?}", err))?;
+
+    match engine_state.run_query(correlation_id, request) {
+        Ok(QueryResult::Success(value)) => Ok(value),
+        Ok(QueryResult::ValueNotFound(msg)) => Err(msg),
+        Ok(QueryResult::RootNotFound) => Err("Root not found".to_string()),
+        Ok(QueryResult::CircularReference(msg)) => Err(msg),
+        Err(err) => Err(format!("{:?}", err)),
+    }
+}
+
 // Helper method which returns single DeployResult that is set to be a
 // WasmError.
 pub fn new<E: ExecutionEngineService + Sync + Send + 'static>(
@@ -501,3 +1033,23 @@ pub fn new<E: ExecutionEngineService + Sync + Send + 'static>(
     server.add_service(ExecutionEngineServiceServer::new_service_def(e));
     server
 }
+
+/// Returns a server builder listening on the TCP `address`, which only accepts clients
+/// authenticated via mutual TLS as configured by `tls_config`.
+///
+/// Fails if `address` can't be used or TLS can't be configured.
+pub fn new_tcp<E: ExecutionEngineService + Sync + Send + 'static>(
+    address: SocketAddr,
+    tls_config: &TlsConfig,
+    thread_count: usize,
+    e: E,
+) -> tls_api::Result<ServerBuilder<tls_api_openssl::TlsAcceptor>> {
+    let acceptor = tls_config.acceptor()?;
+
+    let mut server = ServerBuilder::new();
+    server.http.set_addr(address).map_err(tls_api::Error::new)?;
+    server.http.set_tls(acceptor);
+    server.http.set_cpu_pool_threads(thread_count);
+    server.add_service(ExecutionEngineServiceServer::new_service_def(e));
+    Ok(server)
+}
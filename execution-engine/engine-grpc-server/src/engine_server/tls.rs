@@ -0,0 +1,53 @@
+//! Mutual TLS configuration for serving the engine over TCP.
+
+use std::path::PathBuf;
+
+use openssl::ssl::{SslAcceptor, SslFiletype, SslMethod, SslVerifyMode};
+use tls_api::TlsAcceptorBuilder as _;
+use tls_api_openssl::{TlsAcceptor, TlsAcceptorBuilder};
+
+const ALPN_HTTP2: &[u8] = b"h2";
+
+/// Locally supplied PEM files used to authenticate the server and its clients.
+#[derive(Clone, Debug)]
+pub struct TlsConfig {
+    cert_file: PathBuf,
+    key_file: PathBuf,
+    ca_file: PathBuf,
+}
+
+impl TlsConfig {
+    /// Creates a new `TlsConfig`.
+    ///
+    /// `cert_file` holds the server's certificate chain, `key_file` its private key, and
+    /// `ca_file` the certificate authorities which client certificates must be signed by.
+    pub fn new(cert_file: PathBuf, key_file: PathBuf, ca_file: PathBuf) -> Self {
+        TlsConfig {
+            cert_file,
+            key_file,
+            ca_file,
+        }
+    }
+
+    /// Builds an acceptor which requires every client to present a certificate signed by one of
+    /// the configured certificate authorities.
+    pub fn acceptor(&self) -> tls_api::Result<TlsAcceptor> {
+        let mut builder =
+            SslAcceptor::mozilla_intermediate(SslMethod::tls()).map_err(tls_api::Error::new)?;
+        builder
+            .set_certificate_chain_file(&self.cert_file)
+            .map_err(tls_api::Error::new)?;
+        builder
+            .set_private_key_file(&self.key_file, SslFiletype::PEM)
+            .map_err(tls_api::Error::new)?;
+        builder.check_private_key().map_err(tls_api::Error::new)?;
+        builder
+            .set_ca_file(&self.ca_file)
+            .map_err(tls_api::Error::new)?;
+        builder.set_verify(SslVerifyMode::PEER | SslVerifyMode::FAIL_IF_NO_PEER_CERT);
+
+        let mut builder = TlsAcceptorBuilder(builder);
+        builder.set_alpn_protocols(&[ALPN_HTTP2])?;
+        builder.build()
+    }
+}
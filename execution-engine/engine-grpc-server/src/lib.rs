@@ -1 +1,3 @@
+pub mod chainspec;
 pub mod engine_server;
+pub mod metrics_server;
@@ -1,23 +1,27 @@
 use std::{
     collections::BTreeMap,
     fs,
-    path::PathBuf,
+    net::{Ipv4Addr, SocketAddr},
+    path::{Path, PathBuf},
     str::FromStr,
     sync::{
         atomic::{AtomicBool, Ordering},
         Arc,
     },
+    thread,
     time::Duration,
 };
 
 use clap::{App, Arg, ArgMatches};
 use dirs::home_dir;
-use engine_core::engine_state::{EngineConfig, EngineState};
+use engine_core::engine_state::{genesis::GenesisResult, EngineConfig, EngineState};
 use lmdb::DatabaseFlags;
-use log::{error, info, Level, LevelFilter};
+use log::{error, info, warn, Level, LevelFilter};
+use signal_hook::{iterator::Signals, SIGHUP};
 
 use engine_shared::{
     logging::{self, Settings, Style},
+    newtypes::CorrelationId,
     os::get_page_size,
     socket,
 };
@@ -26,14 +30,20 @@ use engine_storage::{
     trie_store::lmdb::LmdbTrieStore,
 };
 
-use casperlabs_engine_grpc_server::engine_server;
+use casperlabs_engine_grpc_server::{
+    chainspec,
+    engine_server::{self, tls::TlsConfig},
+    metrics_server,
+};
 use engine_storage::protocol_data_store::lmdb::LmdbProtocolDataStore;
 
 // exe / proc
 const PROC_NAME: &str = "casperlabs-engine-grpc-server";
 const APP_NAME: &str = "CasperLabs Execution Engine Server";
 const SERVER_LISTENING_TEMPLATE: &str = "{listener} is listening on socket: {socket}";
+const SERVER_TCP_LISTENING_TEMPLATE: &str = "{listener} is listening on address: {address}";
 const SERVER_START_EXPECT: &str = "failed to start Execution Engine Server";
+const SERVER_TCP_EXPECT: &str = "failed to configure TCP listener for Execution Engine Server";
 
 // data-dir / lmdb
 const ARG_DATA_DIR: &str = "data-dir";
@@ -66,6 +76,21 @@ const ARG_SOCKET_HELP: &str =
     "Path to socket.  Note that this path is independent of the data directory.";
 const ARG_SOCKET_EXPECT: &str = "socket required";
 
+// tcp / tls
+const ARG_TCP_ADDRESS: &str = "tcp-address";
+const ARG_TCP_ADDRESS_VALUE: &str = "ADDRESS";
+const ARG_TCP_ADDRESS_HELP: &str = "Also listen for TCP connections on this address, e.g. \
+                                    0.0.0.0:40403.  Requires --tls-cert, --tls-key and --tls-ca.";
+const ARG_TCP_ADDRESS_EXPECT: &str = "expected valid TCP address";
+const ARG_TLS_CERT: &str = "tls-cert";
+const ARG_TLS_CERT_HELP: &str = "Path to the PEM certificate chain presented to TCP clients";
+const ARG_TLS_KEY: &str = "tls-key";
+const ARG_TLS_KEY_HELP: &str = "Path to the PEM private key of the TLS certificate";
+const ARG_TLS_CA: &str = "tls-ca";
+const ARG_TLS_CA_HELP: &str =
+    "Path to the PEM certificate authorities which TCP client certificates must be signed by";
+const ARG_TLS_FILE_VALUE: &str = "FILE";
+
 // log level
 const ARG_LOG_LEVEL: &str = "log-level";
 const ARG_LOG_LEVEL_VALUE: &str = "LEVEL";
@@ -81,6 +106,24 @@ const LOG_LEVEL_TRACE: &str = "trace";
 const ARG_LOG_METRICS: &str = "log-metrics";
 const ARG_LOG_METRICS_HELP: &str = "Enables logging of metrics regardless of log-level setting";
 
+// log config
+const ARG_LOG_CONFIG: &str = "log-config";
+const ARG_LOG_CONFIG_VALUE: &str = "FILE";
+const ARG_LOG_CONFIG_HELP: &str = "Path to a file of 'key = value' lines overriding the \
+                                   log-level, log-metrics and log-style args.  The file is \
+                                   reread on SIGHUP.";
+const LOG_CONFIG_EXPECT: &str = "could not read log config";
+const SIGHUP_HANDLE_EXPECT: &str = "Error setting SIGHUP handler";
+
+// metrics endpoint
+const ARG_METRICS_PORT: &str = "metrics-port";
+const ARG_METRICS_PORT_VALUE: &str = "PORT";
+const ARG_METRICS_PORT_HELP: &str =
+    "Serves metrics in Prometheus text format at http://127.0.0.1:PORT/metrics";
+const ARG_METRICS_PORT_EXPECT: &str = "expected valid metrics port";
+const METRICS_SERVER_START_EXPECT: &str = "failed to start metrics server";
+const METRICS_SERVER_LISTENING_TEMPLATE: &str = "metrics are served on address: {address}";
+
 // log style
 const ARG_LOG_STYLE: &str = "log-style";
 const ARG_LOG_STYLE_VALUE: &str = "STYLE";
@@ -101,6 +144,15 @@ const ARG_TURBO: &str = "turbo";
 const ARG_TURBO_SHORT: &str = "z";
 const ARG_TURBO_HELP: &str = "Turbo mode";
 
+// genesis
+const ARG_GENESIS: &str = "genesis";
+const ARG_GENESIS_VALUE: &str = "CHAINSPEC";
+const ARG_GENESIS_HELP: &str = "Runs genesis from the given TOML chainspec file into the data \
+                                directory, prints the post-state hash and exits.  The socket is \
+                                not required in this mode.";
+const CHAINSPEC_EXPECT: &str = "could not read chainspec";
+const GENESIS_EXPECT: &str = "genesis failed";
+
 // runnable
 const SIGINT_HANDLE_EXPECT: &str = "Error setting Ctrl-C handler";
 const RUNNABLE_CHECK_INTERVAL_SECONDS: u64 = 3;
@@ -110,10 +162,34 @@ fn main() {
 
     let arg_matches = get_args();
 
-    let _ = logging::initialize(get_log_settings(&arg_matches));
+    let log_settings = get_log_settings(&arg_matches);
+
+    let log_config = get_log_config(&arg_matches);
+
+    let initial_log_settings = match &log_config {
+        Some(path) => read_log_config(path, log_settings).expect(LOG_CONFIG_EXPECT),
+        None => log_settings,
+    };
+
+    let _ = logging::initialize(initial_log_settings);
+
+    if let Some(path) = log_config {
+        set_sighup_handler(path, log_settings);
+    }
+
+    if let Some(chainspec_path) = get_genesis_chainspec(&arg_matches) {
+        run_genesis(&arg_matches, &chainspec_path);
+        return;
+    }
 
     info!("starting Execution Engine Server");
 
+    let _metrics_server = get_metrics_address(&arg_matches).map(|address| {
+        let handle = metrics_server::start(&address).expect(METRICS_SERVER_START_EXPECT);
+        log_metrics_listening_message(address);
+        handle
+    });
+
     let socket = get_socket(&arg_matches);
 
     match socket.remove_file() {
@@ -129,10 +205,20 @@ fn main() {
 
     let engine_config: EngineConfig = get_engine_config(&arg_matches);
 
-    let _server = get_grpc_server(&socket, data_dir, map_size, thread_count, engine_config);
+    let engine_state = get_engine_state(data_dir, map_size, engine_config);
+
+    let tcp_listener = get_tcp_listener(&arg_matches);
+
+    let _server = get_grpc_server(&socket, thread_count, engine_state.clone());
 
     log_listening_message(&socket);
 
+    let _tcp_server = tcp_listener.map(|(address, tls_config)| {
+        let server = get_tcp_grpc_server(address, &tls_config, thread_count, engine_state);
+        log_tcp_listening_message(address);
+        server
+    });
+
     let interval = Duration::from_secs(RUNNABLE_CHECK_INTERVAL_SECONDS);
 
     let runnable = get_sigint_handle();
@@ -185,6 +271,22 @@ fn get_args() -> ArgMatches<'static> {
                 .takes_value(false)
                 .help(ARG_LOG_METRICS_HELP),
         )
+        .arg(
+            Arg::with_name(ARG_LOG_CONFIG)
+                .required(false)
+                .long(ARG_LOG_CONFIG)
+                .takes_value(true)
+                .value_name(ARG_LOG_CONFIG_VALUE)
+                .help(ARG_LOG_CONFIG_HELP),
+        )
+        .arg(
+            Arg::with_name(ARG_METRICS_PORT)
+                .required(false)
+                .long(ARG_METRICS_PORT)
+                .takes_value(true)
+                .value_name(ARG_METRICS_PORT_VALUE)
+                .help(ARG_METRICS_PORT_HELP),
+        )
         .arg(
             Arg::with_name(ARG_LOG_STYLE)
                 .required(false)
@@ -226,9 +328,53 @@ fn get_args() -> ArgMatches<'static> {
                 .short(ARG_TURBO_SHORT)
                 .help(ARG_TURBO_HELP),
         )
+        .arg(
+            Arg::with_name(ARG_TCP_ADDRESS)
+                .required(false)
+                .long(ARG_TCP_ADDRESS)
+                .takes_value(true)
+                .requires_all(&[ARG_TLS_CERT, ARG_TLS_KEY, ARG_TLS_CA])
+                .value_name(ARG_TCP_ADDRESS_VALUE)
+                .help(ARG_TCP_ADDRESS_HELP),
+        )
+        .arg(
+            Arg::with_name(ARG_TLS_CERT)
+                .required(false)
+                .long(ARG_TLS_CERT)
+                .takes_value(true)
+                .requires(ARG_TCP_ADDRESS)
+                .value_name(ARG_TLS_FILE_VALUE)
+                .help(ARG_TLS_CERT_HELP),
+        )
+        .arg(
+            Arg::with_name(ARG_TLS_KEY)
+                .required(false)
+                .long(ARG_TLS_KEY)
+                .takes_value(true)
+                .requires(ARG_TCP_ADDRESS)
+                .value_name(ARG_TLS_FILE_VALUE)
+                .help(ARG_TLS_KEY_HELP),
+        )
+        .arg(
+            Arg::with_name(ARG_TLS_CA)
+                .required(false)
+                .long(ARG_TLS_CA)
+                .takes_value(true)
+                .requires(ARG_TCP_ADDRESS)
+                .value_name(ARG_TLS_FILE_VALUE)
+                .help(ARG_TLS_CA_HELP),
+        )
+        .arg(
+            Arg::with_name(ARG_GENESIS)
+                .required(false)
+                .long(ARG_GENESIS)
+                .takes_value(true)
+                .value_name(ARG_GENESIS_VALUE)
+                .help(ARG_GENESIS_HELP),
+        )
         .arg(
             Arg::with_name(ARG_SOCKET)
-                .required(true)
+                .required_unless(ARG_GENESIS)
                 .help(ARG_SOCKET_HELP)
                 .index(1),
         )
@@ -253,6 +399,125 @@ fn get_socket(arg_matches: &ArgMatches) -> socket::Socket {
     socket::Socket::new(socket.to_owned())
 }
 
+/// Reloads log settings from the log config file whenever SIGHUP is received
+fn set_sighup_handler(log_config: PathBuf, defaults: Settings) {
+    let signals = Signals::new(&[SIGHUP]).expect(SIGHUP_HANDLE_EXPECT);
+    thread::spawn(move || {
+        for _ in signals.forever() {
+            match read_log_config(&log_config, defaults) {
+                Ok(settings) => {
+                    logging::reload(settings);
+                    info!("log settings changed to {:?}", settings);
+                }
+                Err(error) => warn!("{}: {}", LOG_CONFIG_EXPECT, error),
+            }
+        }
+    });
+}
+
+/// Gets value of genesis argument
+fn get_genesis_chainspec(arg_matches: &ArgMatches) -> Option<PathBuf> {
+    arg_matches.value_of(ARG_GENESIS).map(PathBuf::from)
+}
+
+/// Runs genesis from the chainspec into the data directory and prints the post-state hash
+fn run_genesis(arg_matches: &ArgMatches, chainspec_path: &Path) {
+    let genesis_config = chainspec::read_genesis_config(chainspec_path)
+        .unwrap_or_else(|error| panic!("{}: {}", CHAINSPEC_EXPECT, error));
+
+    let data_dir = get_data_dir(arg_matches);
+
+    let map_size = get_map_size(arg_matches);
+
+    let engine_config: EngineConfig = get_engine_config(arg_matches);
+
+    let engine_state = get_engine_state(data_dir, map_size, engine_config);
+
+    match engine_state.commit_genesis(CorrelationId::new(), genesis_config) {
+        Ok(GenesisResult::Success {
+            post_state_hash, ..
+        }) => println!("{:x}", post_state_hash),
+        Ok(genesis_result) => panic!("{}: {}", GENESIS_EXPECT, genesis_result),
+        Err(error) => panic!("{}: {}", GENESIS_EXPECT, error),
+    }
+}
+
+/// Gets value of log-config argument
+fn get_log_config(arg_matches: &ArgMatches) -> Option<PathBuf> {
+    arg_matches.value_of(ARG_LOG_CONFIG).map(PathBuf::from)
+}
+
+/// Reads log settings from the given file, falling back to `defaults` for any setting the file
+/// doesn't specify.
+///
+/// Each non-empty line not starting with '#' must be of the form `key = value`, where `key` is the
+/// name of one of the log-level, log-metrics or log-style args.
+fn read_log_config(path: &Path, defaults: Settings) -> Result<Settings, String> {
+    let contents = fs::read_to_string(path).map_err(|error| format!("{:?}", error))?;
+
+    let mut max_level = defaults.max_level();
+    let mut enable_metrics = defaults.enable_metrics();
+    let mut style = defaults.style();
+
+    for line in contents.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let mut parts = line.splitn(2, '=').map(str::trim);
+        let (key, value) = match (parts.next(), parts.next()) {
+            (Some(key), Some(value)) => (key, value),
+            _ => return Err(format!("invalid line: {}", line)),
+        };
+        match key {
+            ARG_LOG_LEVEL => {
+                max_level =
+                    parse_log_level(value).ok_or_else(|| format!("invalid {}", ARG_LOG_LEVEL))?
+            }
+            ARG_LOG_METRICS => {
+                enable_metrics = value
+                    .parse()
+                    .map_err(|_| format!("invalid {}", ARG_LOG_METRICS))?
+            }
+            ARG_LOG_STYLE => {
+                style =
+                    parse_log_style(value).ok_or_else(|| format!("invalid {}", ARG_LOG_STYLE))?
+            }
+            _ => return Err(format!("unknown key: {}", key)),
+        }
+    }
+
+    Ok(Settings::new(max_level)
+        .with_metrics_enabled(enable_metrics)
+        .with_style(style))
+}
+
+/// Gets the optional local address to serve metrics on
+fn get_metrics_address(arg_matches: &ArgMatches) -> Option<SocketAddr> {
+    let port = arg_matches
+        .value_of(ARG_METRICS_PORT)?
+        .parse()
+        .expect(ARG_METRICS_PORT_EXPECT);
+    Some(SocketAddr::from((Ipv4Addr::LOCALHOST, port)))
+}
+
+/// Gets the optional TCP address and the TLS files securing it
+fn get_tcp_listener(arg_matches: &ArgMatches) -> Option<(SocketAddr, TlsConfig)> {
+    let address = arg_matches
+        .value_of(ARG_TCP_ADDRESS)?
+        .parse()
+        .expect(ARG_TCP_ADDRESS_EXPECT);
+
+    // clap ensures the TLS args are all present whenever the TCP address is
+    let tls_file = |arg_name| PathBuf::from(arg_matches.value_of(arg_name).unwrap());
+    let tls_config = TlsConfig::new(
+        tls_file(ARG_TLS_CERT),
+        tls_file(ARG_TLS_KEY),
+        tls_file(ARG_TLS_CA),
+    );
+
+    Some((address, tls_config))
+}
+
 /// Gets value of data-dir argument
 fn get_data_dir(arg_matches: &ArgMatches) -> PathBuf {
     let mut buf = arg_matches.value_of(ARG_DATA_DIR).map_or(
@@ -296,18 +561,27 @@ fn get_engine_config(arg_matches: &ArgMatches) -> EngineConfig {
 /// Builds and returns a gRPC server.
 fn get_grpc_server(
     socket: &socket::Socket,
-    data_dir: PathBuf,
-    map_size: usize,
     thread_count: usize,
-    engine_config: EngineConfig,
+    engine_state: EngineState<LmdbGlobalState>,
 ) -> grpc::Server {
-    let engine_state = get_engine_state(data_dir, map_size, engine_config);
-
     engine_server::new(socket.as_str(), thread_count, engine_state)
         .build()
         .expect(SERVER_START_EXPECT)
 }
 
+/// Builds and returns a gRPC server listening on TCP with mutual TLS.
+fn get_tcp_grpc_server(
+    address: SocketAddr,
+    tls_config: &TlsConfig,
+    thread_count: usize,
+    engine_state: EngineState<LmdbGlobalState>,
+) -> grpc::Server {
+    engine_server::new_tcp(address, tls_config, thread_count, engine_state)
+        .expect(SERVER_TCP_EXPECT)
+        .build()
+        .expect(SERVER_START_EXPECT)
+}
+
 /// Builds and returns engine global state
 fn get_engine_state(
     data_dir: PathBuf,
@@ -339,31 +613,43 @@ fn get_engine_state(
 
 /// Builds and returns log settings
 fn get_log_settings(arg_matches: &ArgMatches) -> Settings {
-    let max_level = match arg_matches
+    let max_level = arg_matches
         .value_of(ARG_LOG_LEVEL)
-        .expect("should have default value if not explicitly set")
-    {
-        LOG_LEVEL_OFF => LevelFilter::Off,
-        LOG_LEVEL_ERROR => LevelFilter::Error,
-        LOG_LEVEL_WARN => LevelFilter::Warn,
-        LOG_LEVEL_INFO => LevelFilter::Info,
-        LOG_LEVEL_DEBUG => LevelFilter::Debug,
-        LOG_LEVEL_TRACE => LevelFilter::Trace,
-        _ => unreachable!("should validate log-level arg to match one of the options"),
-    };
+        .and_then(parse_log_level)
+        .expect("should validate log-level arg to match one of the options");
 
     let enable_metrics = arg_matches.is_present(ARG_LOG_METRICS);
 
-    let style = match arg_matches.value_of(ARG_LOG_STYLE) {
-        Some(LOG_STYLE_HUMAN_READABLE) => Style::HumanReadable,
-        _ => Style::Structured,
-    };
+    let style = arg_matches
+        .value_of(ARG_LOG_STYLE)
+        .and_then(parse_log_style)
+        .unwrap_or(Style::Structured);
 
     Settings::new(max_level)
         .with_metrics_enabled(enable_metrics)
         .with_style(style)
 }
 
+fn parse_log_level(value: &str) -> Option<LevelFilter> {
+    match value {
+        LOG_LEVEL_OFF => Some(LevelFilter::Off),
+        LOG_LEVEL_ERROR => Some(LevelFilter::Error),
+        LOG_LEVEL_WARN => Some(LevelFilter::Warn),
+        LOG_LEVEL_INFO => Some(LevelFilter::Info),
+        LOG_LEVEL_DEBUG => Some(LevelFilter::Debug),
+        LOG_LEVEL_TRACE => Some(LevelFilter::Trace),
+        _ => None,
+    }
+}
+
+fn parse_log_style(value: &str) -> Option<Style> {
+    match value {
+        LOG_STYLE_STRUCTURED => Some(Style::Structured),
+        LOG_STYLE_HUMAN_READABLE => Some(Style::HumanReadable),
+        _ => None,
+    }
+}
+
 /// Logs listening on socket message
 fn log_listening_message(socket: &socket::Socket) {
     let mut properties = BTreeMap::new();
@@ -376,3 +662,28 @@ fn log_listening_message(socket: &socket::Socket) {
         properties,
     );
 }
+
+/// Logs listening on TCP address message
+fn log_tcp_listening_message(address: SocketAddr) {
+    let mut properties = BTreeMap::new();
+    properties.insert("listener", PROC_NAME.to_owned());
+    properties.insert("address", address.to_string());
+
+    logging::log_details(
+        Level::Info,
+        (&*SERVER_TCP_LISTENING_TEMPLATE).to_string(),
+        properties,
+    );
+}
+
+/// Logs serving metrics message
+fn log_metrics_listening_message(address: SocketAddr) {
+    let mut properties = BTreeMap::new();
+    properties.insert("address", address.to_string());
+
+    logging::log_details(
+        Level::Info,
+        (&*METRICS_SERVER_LISTENING_TEMPLATE).to_string(),
+        properties,
+    );
+}
@@ -0,0 +1,69 @@
+//! A minimal HTTP server exposing the engine's in-process metrics to Prometheus.
+
+use std::{
+    net::SocketAddr,
+    thread::{self, JoinHandle},
+};
+
+use hyper::{header, rt::Future, service, Body, Method, Request, Response, Server, StatusCode};
+use log::error;
+
+use engine_shared::metrics;
+
+const METRICS_ENDPOINT: &str = "/metrics";
+const PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4";
+
+fn handle(request: Request<Body>) -> Response<Body> {
+    let mut response = Response::new(Body::empty());
+    match (request.method(), request.uri().path()) {
+        (&Method::GET, METRICS_ENDPOINT) => {
+            response.headers_mut().insert(
+                header::CONTENT_TYPE,
+                header::HeaderValue::from_static(PROMETHEUS_CONTENT_TYPE),
+            );
+            *response.body_mut() = Body::from(metrics::render());
+        }
+        _ => {
+            *response.status_mut() = StatusCode::NOT_FOUND;
+        }
+    }
+    response
+}
+
+/// Enables recording of metrics, then spawns a thread which serves them in the Prometheus text
+/// format at `/metrics` on the given address.
+pub fn start(addr: &SocketAddr) -> Result<JoinHandle<()>, hyper::Error> {
+    metrics::enable();
+    let server = Server::try_bind(addr)?
+        .serve(|| service::service_fn_ok(handle))
+        .map_err(|error| error!("metrics server error: {}", error));
+    Ok(thread::spawn(move || hyper::rt::run(server)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get(path: &str) -> Response<Body> {
+        let request = Request::get(path)
+            .body(Body::empty())
+            .expect("should build request");
+        handle(request)
+    }
+
+    #[test]
+    fn should_serve_metrics_endpoint() {
+        let response = get(METRICS_ENDPOINT);
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()[header::CONTENT_TYPE],
+            PROMETHEUS_CONTENT_TYPE
+        );
+    }
+
+    #[test]
+    fn should_not_serve_other_paths() {
+        let response = get("/other");
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
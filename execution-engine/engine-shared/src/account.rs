@@ -1,19 +1,22 @@
 mod action_thresholds;
 mod associated_keys;
+mod recovery;
 
 use std::collections::{BTreeMap, BTreeSet};
 
 use types::{
     account::{
-        ActionType, AddKeyFailure, PublicKey, RemoveKeyFailure, SetThresholdFailure,
-        UpdateKeyFailure, Weight, PUBLIC_KEY_SERIALIZED_MAX_LENGTH, WEIGHT_SERIALIZED_LENGTH,
+        ActionType, AddKeyFailure, PublicKey, RecoveryFailure, RemoveKeyFailure,
+        SetThresholdFailure, UpdateKeyFailure, Weight, PUBLIC_KEY_SERIALIZED_MAX_LENGTH,
+        WEIGHT_SERIALIZED_LENGTH,
     },
     bytesrepr::{Error, FromBytes, ToBytes, U32_SERIALIZED_LENGTH, U8_SERIALIZED_LENGTH},
-    AccessRights, Key, URef,
+    AccessRights, BlockTime, Key, URef,
 };
 
 pub use action_thresholds::ActionThresholds;
 pub use associated_keys::AssociatedKeys;
+pub use recovery::Recovery;
 
 #[derive(PartialEq, Eq, Clone, Debug)]
 pub struct Account {
@@ -22,6 +25,7 @@ pub struct Account {
     main_purse: URef,
     associated_keys: AssociatedKeys,
     action_thresholds: ActionThresholds,
+    recovery: Option<Recovery>,
 }
 
 impl Account {
@@ -38,9 +42,16 @@ impl Account {
             main_purse,
             associated_keys,
             action_thresholds,
+            recovery: None,
         }
     }
 
+    /// Sets the account's recovery settings, without any validation.
+    pub fn with_recovery(mut self, recovery: Recovery) -> Self {
+        self.recovery = Some(recovery);
+        self
+    }
+
     pub fn create(account: PublicKey, named_keys: BTreeMap<String, Key>, main_purse: URef) -> Self {
         let associated_keys = AssociatedKeys::new(account, Weight::new(1));
         let action_thresholds: ActionThresholds = Default::default();
@@ -86,6 +97,66 @@ impl Account {
         &self.action_thresholds
     }
 
+    pub fn recovery(&self) -> Option<&Recovery> {
+        self.recovery.as_ref()
+    }
+
+    /// Replaces the recovery settings, or opts out of recovery if `recovery` is `None`.
+    ///
+    /// Fails once the delay of a pending recovery request has elapsed, as the account's own keys
+    /// could otherwise use this to cancel the recovery.
+    pub fn set_recovery(
+        &mut self,
+        recovery: Option<Recovery>,
+        block_time: BlockTime,
+    ) -> Result<(), RecoveryFailure> {
+        if let Some(current_recovery) = &self.recovery {
+            if current_recovery.is_delay_elapsed(block_time) {
+                return Err(RecoveryFailure::DelayElapsed);
+            }
+        }
+        self.recovery = recovery;
+        Ok(())
+    }
+
+    /// Records one of the recovery keys' approval of a recovery, which is requested once the
+    /// approvals meet the recovery threshold.
+    pub fn request_recovery(
+        &mut self,
+        recovery_key: PublicKey,
+        block_time: BlockTime,
+    ) -> Result<(), RecoveryFailure> {
+        self.recovery
+            .as_mut()
+            .ok_or(RecoveryFailure::NotConfigured)?
+            .request(recovery_key, block_time)
+    }
+
+    /// Cancels a pending recovery request.
+    pub fn cancel_recovery(&mut self, block_time: BlockTime) -> Result<(), RecoveryFailure> {
+        self.recovery
+            .as_mut()
+            .ok_or(RecoveryFailure::NotConfigured)?
+            .cancel(block_time)
+    }
+
+    /// Completes a pending recovery request by replacing the associated keys with the recovery
+    /// keys.  Action thresholds are lowered where needed, so the recovery keys can meet them.
+    pub fn recover(
+        &mut self,
+        recovery_key: PublicKey,
+        block_time: BlockTime,
+    ) -> Result<(), RecoveryFailure> {
+        let recovery = self
+            .recovery
+            .as_mut()
+            .ok_or(RecoveryFailure::NotConfigured)?;
+        recovery.complete(recovery_key, block_time)?;
+        self.associated_keys = recovery.keys().clone();
+        self.action_thresholds = self.action_thresholds.clamped(recovery.total_keys_weight());
+        Ok(())
+    }
+
     pub fn add_associated_key(
         &mut self,
         public_key: PublicKey,
@@ -212,7 +283,8 @@ impl ToBytes for Account {
             + named_keys_size
             + purse_size
             + associated_keys_size
-            + action_thresholds_size;
+            + action_thresholds_size
+            + U8_SERIALIZED_LENGTH; // recovery tag
         if serialized_account_size >= u32::max_value() as usize {
             return Err(Error::OutOfMemory);
         }
@@ -222,6 +294,7 @@ impl ToBytes for Account {
         result.append(&mut self.main_purse.to_bytes()?);
         result.append(&mut self.associated_keys.to_bytes()?);
         result.append(&mut self.action_thresholds.to_bytes()?);
+        result.append(&mut self.recovery.to_bytes()?);
         Ok(result)
     }
 }
@@ -233,6 +306,12 @@ impl FromBytes for Account {
         let (main_purse, rem): (URef, &[u8]) = FromBytes::from_bytes(rem)?;
         let (associated_keys, rem): (AssociatedKeys, &[u8]) = FromBytes::from_bytes(rem)?;
         let (action_thresholds, rem): (ActionThresholds, &[u8]) = FromBytes::from_bytes(rem)?;
+        // Accounts stored before recovery was introduced end here.
+        let (recovery, rem): (Option<Recovery>, &[u8]) = if rem.is_empty() {
+            (None, rem)
+        } else {
+            FromBytes::from_bytes(rem)?
+        };
         Ok((
             Account {
                 public_key,
@@ -240,6 +319,7 @@ impl FromBytes for Account {
                 main_purse,
                 associated_keys,
                 action_thresholds,
+                recovery,
             },
             rem,
         ))
@@ -247,7 +327,7 @@ impl FromBytes for Account {
 }
 
 pub mod gens {
-    use proptest::prelude::*;
+    use proptest::{option, prelude::*};
 
     use types::{
         account::MAX_ASSOCIATED_KEYS,
@@ -257,6 +337,7 @@ pub mod gens {
     use super::*;
     use crate::account::{
         action_thresholds::gens::action_thresholds_arb, associated_keys::gens::associated_keys_arb,
+        recovery::gens::recovery_arb,
     };
 
     prop_compose! {
@@ -266,15 +347,20 @@ pub mod gens {
             purse in uref_arb(),
             thresholds in action_thresholds_arb(),
             mut associated_keys in associated_keys_arb(MAX_ASSOCIATED_KEYS - 1),
+            recovery in option::of(recovery_arb()),
         ) -> Account {
                 associated_keys.add_key(pub_key, Weight::new(1)).unwrap();
-                Account::new(
+                let account = Account::new(
                     pub_key,
                     urefs,
                     purse,
                     associated_keys,
                     thresholds,
-                )
+                );
+                match recovery {
+                    Some(recovery) => account.with_recovery(recovery),
+                    None => account,
+                }
         }
     }
 }
@@ -304,9 +390,10 @@ mod tests {
 
     use types::{
         account::{
-            ActionType, PublicKey, RemoveKeyFailure, SetThresholdFailure, UpdateKeyFailure, Weight,
+            ActionType, PublicKey, RecoveryFailure, RemoveKeyFailure, SetThresholdFailure,
+            UpdateKeyFailure, Weight,
         },
-        AccessRights, URef,
+        AccessRights, BlockTime, URef,
     };
 
     use super::*;
@@ -652,4 +739,72 @@ mod tests {
             .update_associated_key(key_1, Weight::new(1))
             .expect("should work");
     }
+
+    #[test]
+    fn should_hand_key_management_to_recovery_keys() {
+        let identity_key = PublicKey::ed25519_from([1; 32]);
+        let recovery_key = PublicKey::ed25519_from([2; 32]);
+        let delay = 100;
+
+        let mut account = Account::new(
+            identity_key,
+            BTreeMap::new(),
+            URef::new([0u8; 32], AccessRights::READ_ADD_WRITE),
+            AssociatedKeys::new(identity_key, Weight::new(3)),
+            ActionThresholds::new(Weight::new(1), Weight::new(3))
+                .expect("should create thresholds"),
+        );
+        assert_eq!(
+            account.request_recovery(recovery_key, BlockTime::new(0)),
+            Err(RecoveryFailure::NotConfigured)
+        );
+
+        let recovery = Recovery::new(
+            AssociatedKeys::new(recovery_key, Weight::new(2)),
+            Weight::new(2),
+            delay,
+        )
+        .expect("should create recovery");
+        account
+            .set_recovery(Some(recovery), BlockTime::new(0))
+            .expect("should set recovery");
+        account
+            .request_recovery(recovery_key, BlockTime::new(10))
+            .expect("should request recovery");
+        assert_eq!(
+            account.set_recovery(None, BlockTime::new(10 + delay)),
+            Err(RecoveryFailure::DelayElapsed)
+        );
+        account
+            .recover(recovery_key, BlockTime::new(10 + delay))
+            .expect("should recover");
+
+        let authorization_keys = BTreeSet::from_iter(vec![recovery_key]);
+        assert!(account.can_authorize(&authorization_keys));
+        assert!(account.can_manage_keys_with(&authorization_keys));
+        assert!(!account.can_authorize(&BTreeSet::from_iter(vec![identity_key])));
+        assert_eq!(*account.action_thresholds().deployment(), Weight::new(1));
+        assert_eq!(
+            *account.action_thresholds().key_management(),
+            Weight::new(2)
+        );
+    }
+
+    #[test]
+    fn should_deserialize_account_without_recovery() {
+        let public_key = PublicKey::ed25519_from([1; 32]);
+        let account = Account::create(
+            public_key,
+            BTreeMap::new(),
+            URef::new([0u8; 32], AccessRights::READ_ADD_WRITE),
+        );
+        let mut bytes = account.to_bytes().expect("should serialize");
+        // Drops the recovery tag, as accounts stored before recovery was introduced lack it.
+        assert_eq!(bytes.pop(), Some(0));
+
+        let (deserialized, rem): (Account, &[u8]) =
+            FromBytes::from_bytes(&bytes).expect("should deserialize");
+        assert_eq!(deserialized, account);
+        assert!(rem.is_empty());
+    }
 }
@@ -66,6 +66,15 @@ impl ActionThresholds {
         &self.key_management
     }
 
+    /// Returns a copy of the thresholds with each one lowered to at most `max_threshold`.
+    pub fn clamped(&self, max_threshold: Weight) -> ActionThresholds {
+        // Lowering both thresholds to the same cap keeps deployment at or below key management.
+        ActionThresholds {
+            deployment: self.deployment.min(max_threshold),
+            key_management: self.key_management.min(max_threshold),
+        }
+    }
+
     /// Unified function that takes an action type, and changes appropriate
     /// threshold defined by the [ActionType] variants.
     pub fn set_threshold(
@@ -0,0 +1,359 @@
+use types::{
+    account::{PublicKey, RecoveryFailure, Weight},
+    bytesrepr::{Error, FromBytes, ToBytes, U64_SERIALIZED_LENGTH},
+    BlockTime,
+};
+
+use super::AssociatedKeys;
+
+/// An account's opt-in recovery settings.
+///
+/// The recovery keys approve a recovery of the account one by one.  Once the weight of the
+/// approving keys meets `threshold`, the recovery is requested: the request is recorded along with
+/// the block time it was made at, and the account's own keys can cancel it until `delay`
+/// milliseconds have passed.  After that, the recovery keys can take over key management of the
+/// account.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Recovery {
+    keys: AssociatedKeys,
+    threshold: Weight,
+    delay: u64,
+    approvals: AssociatedKeys,
+    requested_at: Option<BlockTime>,
+}
+
+impl Recovery {
+    /// Creates recovery settings with no recovery requested.
+    ///
+    /// Fails if `threshold` is zero or can't be met by the weight of all of `keys`.
+    pub fn new(
+        keys: AssociatedKeys,
+        threshold: Weight,
+        delay: u64,
+    ) -> Result<Self, RecoveryFailure> {
+        if threshold == Weight::new(0) || threshold > keys.total_keys_weight() {
+            return Err(RecoveryFailure::InvalidThreshold);
+        }
+        Ok(Recovery {
+            keys,
+            threshold,
+            delay,
+            approvals: AssociatedKeys::default(),
+            requested_at: None,
+        })
+    }
+
+    /// Records the approvals of a recovery given so far, without any validation.
+    pub fn with_approvals(mut self, approvals: AssociatedKeys) -> Self {
+        self.approvals = approvals;
+        self
+    }
+
+    /// Records a recovery request made at `requested_at`.
+    pub fn with_request(mut self, requested_at: BlockTime) -> Self {
+        self.requested_at = Some(requested_at);
+        self
+    }
+
+    /// The keys, and their weights, which take over the account once recovered.
+    pub fn keys(&self) -> &AssociatedKeys {
+        &self.keys
+    }
+
+    /// The weight of recovery keys needed to request a recovery.
+    pub fn threshold(&self) -> Weight {
+        self.threshold
+    }
+
+    /// The time in milliseconds the account's own keys have to cancel a recovery request.
+    pub fn delay(&self) -> u64 {
+        self.delay
+    }
+
+    /// The recovery keys, and their weights, which have approved a recovery so far.
+    pub fn approvals(&self) -> &AssociatedKeys {
+        &self.approvals
+    }
+
+    /// The block time a pending recovery request was made at, if any.
+    pub fn requested_at(&self) -> Option<BlockTime> {
+        self.requested_at
+    }
+
+    /// Checks whether the delay of a pending recovery request has elapsed at `block_time`.
+    pub fn is_delay_elapsed(&self, block_time: BlockTime) -> bool {
+        match self.requested_at {
+            Some(requested_at) => {
+                let requested_at: u64 = requested_at.into();
+                let block_time: u64 = block_time.into();
+                requested_at.saturating_add(self.delay) <= block_time
+            }
+            None => false,
+        }
+    }
+
+    /// Records `recovery_key`'s approval of a recovery at `block_time`.  The recovery is requested
+    /// once the approvals meet the threshold.
+    pub fn request(
+        &mut self,
+        recovery_key: PublicKey,
+        block_time: BlockTime,
+    ) -> Result<(), RecoveryFailure> {
+        let weight = match self.keys.get(&recovery_key) {
+            Some(weight) => *weight,
+            None => return Err(RecoveryFailure::PermissionDenied),
+        };
+        if self.requested_at.is_some() || self.approvals.contains_key(&recovery_key) {
+            return Err(RecoveryFailure::AlreadyRequested);
+        }
+        self.approvals
+            .add_key(recovery_key, weight)
+            .map_err(|_| RecoveryFailure::MaxKeysLimit)?;
+        if self.approvals.total_keys_weight() >= self.threshold {
+            self.requested_at = Some(block_time);
+        }
+        Ok(())
+    }
+
+    /// Cancels a pending recovery request, as long as its delay hasn't elapsed at `block_time`.
+    /// Approvals which haven't met the threshold yet are discarded as well.
+    pub fn cancel(&mut self, block_time: BlockTime) -> Result<(), RecoveryFailure> {
+        if self.approvals.is_empty() {
+            return Err(RecoveryFailure::NotRequested);
+        }
+        if self.is_delay_elapsed(block_time) {
+            return Err(RecoveryFailure::DelayElapsed);
+        }
+        self.approvals = AssociatedKeys::default();
+        self.requested_at = None;
+        Ok(())
+    }
+
+    /// Completes a pending recovery request on behalf of `recovery_key`, as long as its delay has
+    /// elapsed at `block_time`.
+    pub fn complete(
+        &mut self,
+        recovery_key: PublicKey,
+        block_time: BlockTime,
+    ) -> Result<(), RecoveryFailure> {
+        if !self.keys.contains_key(&recovery_key) {
+            return Err(RecoveryFailure::PermissionDenied);
+        }
+        if self.requested_at.is_none() {
+            return Err(RecoveryFailure::NotRequested);
+        }
+        if !self.is_delay_elapsed(block_time) {
+            return Err(RecoveryFailure::DelayNotElapsed);
+        }
+        self.approvals = AssociatedKeys::default();
+        self.requested_at = None;
+        Ok(())
+    }
+
+    /// Calculates total weight of all recovery keys
+    pub fn total_keys_weight(&self) -> Weight {
+        self.keys.total_keys_weight()
+    }
+}
+
+impl ToBytes for Recovery {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut result = Vec::with_capacity(2 * U64_SERIALIZED_LENGTH);
+        result.append(&mut self.keys.to_bytes()?);
+        result.append(&mut self.threshold.to_bytes()?);
+        result.append(&mut self.delay.to_bytes()?);
+        result.append(&mut self.approvals.to_bytes()?);
+        result.append(&mut self.requested_at.to_bytes()?);
+        Ok(result)
+    }
+}
+
+impl FromBytes for Recovery {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let (keys, rem): (AssociatedKeys, &[u8]) = FromBytes::from_bytes(bytes)?;
+        let (threshold, rem): (Weight, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (delay, rem): (u64, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (approvals, rem): (AssociatedKeys, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (requested_at, rem): (Option<BlockTime>, &[u8]) = FromBytes::from_bytes(rem)?;
+        Ok((
+            Recovery {
+                keys,
+                threshold,
+                delay,
+                approvals,
+                requested_at,
+            },
+            rem,
+        ))
+    }
+}
+
+pub mod gens {
+    use proptest::{option, prelude::*};
+
+    use types::{
+        account::{Weight, MAX_ASSOCIATED_KEYS},
+        BlockTime,
+    };
+
+    use super::Recovery;
+    use crate::account::associated_keys::gens::associated_keys_arb;
+
+    pub fn recovery_arb() -> impl Strategy<Value = Recovery> {
+        (
+            associated_keys_arb(MAX_ASSOCIATED_KEYS),
+            any::<u64>(),
+            option::of(any::<u64>()),
+        )
+            .prop_filter_map(
+                "recovery keys must have weight",
+                |(keys, delay, requested_at)| {
+                    let threshold = Weight::new(keys.total_keys_weight().value() / 2 + 1);
+                    let recovery = Recovery::new(keys, threshold, delay).ok()?;
+                    match requested_at {
+                        Some(requested_at) => {
+                            let approvals = recovery.keys().clone();
+                            let recovery = recovery
+                                .with_approvals(approvals)
+                                .with_request(BlockTime::new(requested_at));
+                            Some(recovery)
+                        }
+                        None => Some(recovery),
+                    }
+                },
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RECOVERY_KEY: PublicKey = PublicKey::ed25519_from([1; 32]);
+    const OTHER_RECOVERY_KEY: PublicKey = PublicKey::ed25519_from([3; 32]);
+    const DELAY: u64 = 100;
+
+    fn recovery() -> Recovery {
+        Recovery::new(
+            AssociatedKeys::new(RECOVERY_KEY, Weight::new(1)),
+            Weight::new(1),
+            DELAY,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn should_only_accept_request_from_recovery_key() {
+        let mut recovery = recovery();
+        assert_eq!(
+            recovery.request(PublicKey::ed25519_from([2; 32]), BlockTime::new(0)),
+            Err(RecoveryFailure::PermissionDenied)
+        );
+        assert!(recovery.request(RECOVERY_KEY, BlockTime::new(10)).is_ok());
+        assert_eq!(recovery.requested_at(), Some(BlockTime::new(10)));
+        assert_eq!(
+            recovery.request(RECOVERY_KEY, BlockTime::new(20)),
+            Err(RecoveryFailure::AlreadyRequested)
+        );
+    }
+
+    #[test]
+    fn should_reject_unreachable_threshold() {
+        let keys = AssociatedKeys::new(RECOVERY_KEY, Weight::new(1));
+        assert_eq!(
+            Recovery::new(keys.clone(), Weight::new(0), DELAY),
+            Err(RecoveryFailure::InvalidThreshold)
+        );
+        assert_eq!(
+            Recovery::new(keys, Weight::new(2), DELAY),
+            Err(RecoveryFailure::InvalidThreshold)
+        );
+    }
+
+    #[test]
+    fn should_request_once_approvals_meet_threshold() {
+        let mut keys = AssociatedKeys::new(RECOVERY_KEY, Weight::new(1));
+        keys.add_key(OTHER_RECOVERY_KEY, Weight::new(1)).unwrap();
+        let mut recovery = Recovery::new(keys, Weight::new(2), DELAY).unwrap();
+
+        recovery.request(RECOVERY_KEY, BlockTime::new(10)).unwrap();
+        assert_eq!(recovery.requested_at(), None);
+        assert_eq!(
+            recovery.request(RECOVERY_KEY, BlockTime::new(20)),
+            Err(RecoveryFailure::AlreadyRequested)
+        );
+        assert_eq!(
+            recovery.complete(RECOVERY_KEY, BlockTime::new(20 + DELAY)),
+            Err(RecoveryFailure::NotRequested)
+        );
+
+        // Cancelling discards the approvals given so far.
+        let mut cancelled = recovery.clone();
+        cancelled.cancel(BlockTime::new(20)).unwrap();
+        assert!(cancelled.approvals().is_empty());
+
+        recovery
+            .request(OTHER_RECOVERY_KEY, BlockTime::new(20))
+            .unwrap();
+        assert_eq!(recovery.requested_at(), Some(BlockTime::new(20)));
+        assert!(recovery
+            .complete(RECOVERY_KEY, BlockTime::new(20 + DELAY))
+            .is_ok());
+        assert!(recovery.approvals().is_empty());
+    }
+
+    #[test]
+    fn should_cancel_only_within_delay() {
+        let mut recovery = recovery();
+        assert_eq!(
+            recovery.cancel(BlockTime::new(0)),
+            Err(RecoveryFailure::NotRequested)
+        );
+
+        recovery.request(RECOVERY_KEY, BlockTime::new(10)).unwrap();
+        assert!(recovery
+            .clone()
+            .cancel(BlockTime::new(10 + DELAY - 1))
+            .is_ok());
+        assert_eq!(
+            recovery.cancel(BlockTime::new(10 + DELAY)),
+            Err(RecoveryFailure::DelayElapsed)
+        );
+    }
+
+    #[test]
+    fn should_complete_only_after_delay() {
+        let mut recovery = recovery();
+        assert_eq!(
+            recovery.complete(RECOVERY_KEY, BlockTime::new(0)),
+            Err(RecoveryFailure::NotRequested)
+        );
+
+        recovery.request(RECOVERY_KEY, BlockTime::new(10)).unwrap();
+        assert_eq!(
+            recovery.complete(RECOVERY_KEY, BlockTime::new(10 + DELAY - 1)),
+            Err(RecoveryFailure::DelayNotElapsed)
+        );
+        assert_eq!(
+            recovery.complete(PublicKey::ed25519_from([2; 32]), BlockTime::new(10 + DELAY)),
+            Err(RecoveryFailure::PermissionDenied)
+        );
+        assert!(recovery
+            .complete(RECOVERY_KEY, BlockTime::new(10 + DELAY))
+            .is_ok());
+        assert_eq!(recovery.requested_at(), None);
+    }
+
+    #[test]
+    fn should_not_overflow_delay() {
+        let mut recovery = Recovery::new(
+            AssociatedKeys::new(RECOVERY_KEY, Weight::new(1)),
+            Weight::new(1),
+            u64::max_value(),
+        )
+        .unwrap();
+        recovery.request(RECOVERY_KEY, BlockTime::new(10)).unwrap();
+        assert!(!recovery.is_delay_elapsed(BlockTime::new(u64::max_value() - 1)));
+        assert!(recovery.is_delay_elapsed(BlockTime::new(u64::max_value())));
+    }
+}
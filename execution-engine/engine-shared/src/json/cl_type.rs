@@ -0,0 +1,281 @@
+use std::convert::TryFrom;
+
+use serde_json::{json, Value};
+use types::{CLField, CLType, CLVariant};
+
+use super::{as_single_entry, get_field, get_str, single_entry, Error, Result};
+
+const BOOL: &str = "Bool";
+const I32: &str = "I32";
+const I64: &str = "I64";
+const U8: &str = "U8";
+const U32: &str = "U32";
+const U64: &str = "U64";
+const U128: &str = "U128";
+const U256: &str = "U256";
+const U512: &str = "U512";
+const I256: &str = "I256";
+const DECIMAL: &str = "Decimal";
+const UNIT: &str = "Unit";
+const STRING: &str = "String";
+const KEY: &str = "Key";
+const UREF: &str = "URef";
+const OPTION: &str = "Option";
+const LIST: &str = "List";
+const FIXED_LIST: &str = "FixedList";
+const BYTE_ARRAY: &str = "ByteArray";
+const RESULT: &str = "Result";
+const MAP: &str = "Map";
+const TUPLE1: &str = "Tuple1";
+const TUPLE2: &str = "Tuple2";
+const TUPLE3: &str = "Tuple3";
+const ANY: &str = "Any";
+const STRUCT: &str = "Struct";
+const ENUM: &str = "Enum";
+
+const EXPECTED_CL_TYPE: &str = "a CLType";
+
+/// Renders `cl_type` as JSON.
+///
+/// Types without parameters are rendered as their name, e.g. `"U512"`, and all others as an object
+/// holding the parameters under the name, e.g. `{"Option": "U512"}`.
+pub fn cl_type_to_json(cl_type: &CLType) -> Value {
+    match cl_type {
+        CLType::Bool => json!(BOOL),
+        CLType::I32 => json!(I32),
+        CLType::I64 => json!(I64),
+        CLType::U8 => json!(U8),
+        CLType::U32 => json!(U32),
+        CLType::U64 => json!(U64),
+        CLType::U128 => json!(U128),
+        CLType::U256 => json!(U256),
+        CLType::U512 => json!(U512),
+        CLType::I256 => json!(I256),
+        CLType::Decimal => json!(DECIMAL),
+        CLType::Unit => json!(UNIT),
+        CLType::String => json!(STRING),
+        CLType::Key => json!(KEY),
+        CLType::URef => json!(UREF),
+        CLType::Option(inner) => json!({ OPTION: cl_type_to_json(inner) }),
+        CLType::List(inner) => json!({ LIST: cl_type_to_json(inner) }),
+        CLType::FixedList(inner, len) => json!({ FIXED_LIST: [cl_type_to_json(inner), len] }),
+        CLType::ByteArray(len) => json!({ BYTE_ARRAY: len }),
+        CLType::Result { ok, err } => json!({
+            RESULT: { "ok": cl_type_to_json(ok), "err": cl_type_to_json(err) }
+        }),
+        CLType::Map { key, value } => json!({
+            MAP: { "key": cl_type_to_json(key), "value": cl_type_to_json(value) }
+        }),
+        CLType::Tuple1(cl_types) => tuple_to_json(TUPLE1, cl_types),
+        CLType::Tuple2(cl_types) => tuple_to_json(TUPLE2, cl_types),
+        CLType::Tuple3(cl_types) => tuple_to_json(TUPLE3, cl_types),
+        CLType::Any => json!(ANY),
+        CLType::Struct { name, fields } => json!({
+            STRUCT: { "name": name, "fields": fields_to_json(fields) }
+        }),
+        CLType::Enum { name, variants } => {
+            let variants: Vec<Value> = variants
+                .iter()
+                .map(|variant| {
+                    json!({
+                        "tag": variant.tag,
+                        "name": variant.name,
+                        "fields": fields_to_json(&variant.fields),
+                    })
+                })
+                .collect();
+            json!({ ENUM: { "name": name, "variants": variants } })
+        }
+    }
+}
+
+/// Parses a `CLType` from the JSON produced by [`cl_type_to_json`].
+pub fn cl_type_from_json(json: &Value) -> Result<CLType> {
+    if let Some(name) = json.as_str() {
+        return match name {
+            BOOL => Ok(CLType::Bool),
+            I32 => Ok(CLType::I32),
+            I64 => Ok(CLType::I64),
+            U8 => Ok(CLType::U8),
+            U32 => Ok(CLType::U32),
+            U64 => Ok(CLType::U64),
+            U128 => Ok(CLType::U128),
+            U256 => Ok(CLType::U256),
+            U512 => Ok(CLType::U512),
+            I256 => Ok(CLType::I256),
+            DECIMAL => Ok(CLType::Decimal),
+            UNIT => Ok(CLType::Unit),
+            STRING => Ok(CLType::String),
+            KEY => Ok(CLType::Key),
+            UREF => Ok(CLType::URef),
+            ANY => Ok(CLType::Any),
+            _ => Err(Error::unexpected(EXPECTED_CL_TYPE, json)),
+        };
+    }
+
+    let (name, params) =
+        as_single_entry(json).ok_or_else(|| Error::unexpected(EXPECTED_CL_TYPE, json))?;
+    let cl_type = match name {
+        OPTION => CLType::Option(Box::new(cl_type_from_json(params)?)),
+        LIST => CLType::List(Box::new(cl_type_from_json(params)?)),
+        FIXED_LIST => match params.as_array().map(Vec::as_slice) {
+            Some([inner, len]) => {
+                let len = len
+                    .as_u64()
+                    .and_then(|len| u32::try_from(len).ok())
+                    .ok_or_else(|| Error::unexpected("a u32 length", len))?;
+                CLType::FixedList(Box::new(cl_type_from_json(inner)?), len)
+            }
+            _ => return Err(Error::unexpected("an [inner, len] array", params)),
+        },
+        BYTE_ARRAY => params
+            .as_u64()
+            .and_then(|len| u32::try_from(len).ok())
+            .map(CLType::ByteArray)
+            .ok_or_else(|| Error::unexpected("a u32 length", params))?,
+        RESULT => CLType::Result {
+            ok: Box::new(cl_type_from_json(get_field(params, "ok")?)?),
+            err: Box::new(cl_type_from_json(get_field(params, "err")?)?),
+        },
+        MAP => CLType::Map {
+            key: Box::new(cl_type_from_json(get_field(params, "key")?)?),
+            value: Box::new(cl_type_from_json(get_field(params, "value")?)?),
+        },
+        TUPLE1 => match cl_types_from_json(params)?.as_slice() {
+            [type0] => CLType::Tuple1([Box::new(type0.clone())]),
+            _ => return Err(Error::unexpected("an array of 1 CLType", params)),
+        },
+        TUPLE2 => match cl_types_from_json(params)?.as_slice() {
+            [type0, type1] => CLType::Tuple2([Box::new(type0.clone()), Box::new(type1.clone())]),
+            _ => return Err(Error::unexpected("an array of 2 CLTypes", params)),
+        },
+        TUPLE3 => match cl_types_from_json(params)?.as_slice() {
+            [type0, type1, type2] => CLType::Tuple3([
+                Box::new(type0.clone()),
+                Box::new(type1.clone()),
+                Box::new(type2.clone()),
+            ]),
+            _ => return Err(Error::unexpected("an array of 3 CLTypes", params)),
+        },
+        STRUCT => CLType::Struct {
+            name: get_str(params, "name")?.to_string(),
+            fields: fields_from_json(get_field(params, "fields")?)?,
+        },
+        ENUM => {
+            let variants = get_field(params, "variants")?;
+            let variants = variants
+                .as_array()
+                .ok_or_else(|| Error::unexpected("an array of variants", variants))?
+                .iter()
+                .map(variant_from_json)
+                .collect::<Result<_>>()?;
+            CLType::Enum {
+                name: get_str(params, "name")?.to_string(),
+                variants,
+            }
+        }
+        _ => return Err(Error::unexpected(EXPECTED_CL_TYPE, json)),
+    };
+    Ok(cl_type)
+}
+
+fn tuple_to_json(name: &str, cl_types: &[Box<CLType>]) -> Value {
+    let cl_types: Vec<Value> = cl_types
+        .iter()
+        .map(|cl_type| cl_type_to_json(cl_type))
+        .collect();
+    single_entry(name.to_string(), Value::Array(cl_types))
+}
+
+fn cl_types_from_json(json: &Value) -> Result<Vec<CLType>> {
+    json.as_array()
+        .ok_or_else(|| Error::unexpected("an array of CLTypes", json))?
+        .iter()
+        .map(cl_type_from_json)
+        .collect()
+}
+
+fn fields_to_json(fields: &[CLField]) -> Vec<Value> {
+    fields
+        .iter()
+        .map(|field| json!({ "name": field.name, "cl_type": cl_type_to_json(&field.cl_type) }))
+        .collect()
+}
+
+fn fields_from_json(json: &Value) -> Result<Vec<CLField>> {
+    json.as_array()
+        .ok_or_else(|| Error::unexpected("an array of fields", json))?
+        .iter()
+        .map(|field| {
+            let cl_type = cl_type_from_json(get_field(field, "cl_type")?)?;
+            Ok(CLField::new(get_str(field, "name")?, cl_type))
+        })
+        .collect()
+}
+
+fn variant_from_json(json: &Value) -> Result<CLVariant> {
+    let tag = get_field(json, "tag")?;
+    let tag = tag
+        .as_u64()
+        .and_then(|tag| u8::try_from(tag).ok())
+        .ok_or_else(|| Error::unexpected("a u8 tag", tag))?;
+    let fields = fields_from_json(get_field(json, "fields")?)?;
+    Ok(CLVariant::new(tag, get_str(json, "name")?, fields))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_render_nested_types() {
+        let cl_type = CLType::Map {
+            key: Box::new(CLType::String),
+            value: Box::new(CLType::Option(Box::new(CLType::U512))),
+        };
+        let expected = json!({ "Map": { "key": "String", "value": { "Option": "U512" } } });
+        assert_eq!(cl_type_to_json(&cl_type), expected);
+        assert_eq!(cl_type_from_json(&expected), Ok(cl_type));
+    }
+
+    #[test]
+    fn should_render_byte_array() {
+        let cl_type = CLType::ByteArray(32);
+        let expected = json!({ "ByteArray": 32 });
+        assert_eq!(cl_type_to_json(&cl_type), expected);
+        assert_eq!(cl_type_from_json(&expected), Ok(cl_type));
+    }
+
+    #[test]
+    fn should_round_trip_struct_and_enum() {
+        let cl_type = CLType::Enum {
+            name: String::from("Choice"),
+            variants: vec![
+                CLVariant::new(0, "Nothing", vec![]),
+                CLVariant::new(
+                    1,
+                    "Something",
+                    vec![CLField::new(
+                        "0",
+                        CLType::Struct {
+                            name: String::from("Pair"),
+                            fields: vec![
+                                CLField::new("first", CLType::Tuple1([Box::new(CLType::Key)])),
+                                CLField::new("second", CLType::FixedList(Box::new(CLType::U8), 4)),
+                            ],
+                        },
+                    )],
+                ),
+            ],
+        };
+        assert_eq!(cl_type_from_json(&cl_type_to_json(&cl_type)), Ok(cl_type));
+    }
+
+    #[test]
+    fn should_fail_to_parse_invalid_types() {
+        assert!(cl_type_from_json(&json!("U1024")).is_err());
+        assert!(cl_type_from_json(&json!({ "Tuple2": ["U8"] })).is_err());
+        assert!(cl_type_from_json(&json!({ "List": "U8", "Option": "U8" })).is_err());
+        assert!(cl_type_from_json(&json!({ "ByteArray": -1 })).is_err());
+    }
+}
@@ -0,0 +1,479 @@
+use std::{convert::TryFrom, iter};
+
+use serde_json::{json, Map, Value};
+use types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLField, CLType, CLValue, Decimal, Key, URef, I256, U128, U256, U512,
+};
+
+use super::{
+    as_single_entry, cl_type_from_json, cl_type_to_json, get_field, single_entry, Error, Result,
+};
+
+const NONE_TAG: u8 = 0;
+const SOME_TAG: u8 = 1;
+const ERR_TAG: u8 = 0;
+const OK_TAG: u8 = 1;
+
+const OK: &str = "Ok";
+const ERR: &str = "Err";
+
+/// Renders `cl_value` as an object holding its type under `"cl_type"` and its value under
+/// `"value"`.
+pub fn cl_value_to_json(cl_value: &CLValue) -> Result<Value> {
+    let bytes = cl_value.inner_bytes();
+    let value = match cl_value.cl_type() {
+        // `Any` can only be rendered at the top level, as its length is unknown.
+        CLType::Any => json!(base16::encode_lower(bytes)),
+        cl_type => {
+            let (value, remainder) = render(cl_type, bytes)?;
+            if !remainder.is_empty() {
+                return Err(bytesrepr::Error::LeftOverBytes.into());
+            }
+            value
+        }
+    };
+    Ok(json!({ "cl_type": cl_type_to_json(cl_value.cl_type()), "value": value }))
+}
+
+/// Parses a `CLValue` from the JSON produced by [`cl_value_to_json`].
+pub fn cl_value_from_json(json: &Value) -> Result<CLValue> {
+    let cl_type = cl_type_from_json(get_field(json, "cl_type")?)?;
+    let value = get_field(json, "value")?;
+    let bytes = match cl_type {
+        CLType::Any => value
+            .as_str()
+            .and_then(|hex| base16::decode(hex).ok())
+            .ok_or_else(|| Error::unexpected("a hex string", value))?,
+        _ => {
+            let mut bytes = Vec::new();
+            parse(&cl_type, value, &mut bytes)?;
+            bytes
+        }
+    };
+    Ok(CLValue::from_components(cl_type, bytes))
+}
+
+/// Deserializes a `T` from the front of `bytes` and renders it using `to_json`.
+fn render_t<T, F>(bytes: &[u8], to_json: F) -> Result<(Value, &[u8])>
+where
+    T: FromBytes,
+    F: FnOnce(T) -> Value,
+{
+    let (t, remainder) = T::from_bytes(bytes)?;
+    Ok((to_json(t), remainder))
+}
+
+/// Renders the value of type `cl_type` at the front of `bytes`, returning the rendered value and
+/// the remaining bytes.
+fn render<'a>(cl_type: &CLType, bytes: &'a [u8]) -> Result<(Value, &'a [u8])> {
+    match cl_type {
+        CLType::Bool => render_t(bytes, |value: bool| json!(value)),
+        CLType::I32 => render_t(bytes, |value: i32| json!(value)),
+        CLType::I64 => render_t(bytes, |value: i64| json!(value)),
+        CLType::U8 => render_t(bytes, |value: u8| json!(value)),
+        CLType::U32 => render_t(bytes, |value: u32| json!(value)),
+        CLType::U64 => render_t(bytes, |value: u64| json!(value)),
+        CLType::U128 => render_t(bytes, |value: U128| json!(value.to_string())),
+        CLType::U256 => render_t(bytes, |value: U256| json!(value.to_string())),
+        CLType::U512 => render_t(bytes, |value: U512| json!(value.to_string())),
+        CLType::I256 => render_t(bytes, |value: I256| json!(value.to_string())),
+        CLType::Decimal => render_t(bytes, |value: Decimal| json!(value.to_string())),
+        CLType::Unit => Ok((Value::Null, bytes)),
+        CLType::String => render_t(bytes, |value: String| json!(value)),
+        CLType::Key => render_t(bytes, |key: Key| json!(key.to_formatted_string())),
+        CLType::URef => render_t(bytes, |uref: URef| json!(uref.as_string())),
+        CLType::Option(inner) => {
+            let (tag, remainder) = u8::from_bytes(bytes)?;
+            match tag {
+                NONE_TAG => Ok((Value::Null, remainder)),
+                SOME_TAG => render(inner, remainder),
+                _ => Err(bytesrepr::Error::Formatting.into()),
+            }
+        }
+        CLType::List(inner) => {
+            let (count, remainder) = u32::from_bytes(bytes)?;
+            render_sequence(iter::repeat(&**inner).take(count as usize), remainder)
+        }
+        CLType::FixedList(inner, len) => {
+            render_sequence(iter::repeat(&**inner).take(*len as usize), bytes)
+        }
+        CLType::ByteArray(len) => {
+            let len = *len as usize;
+            if bytes.len() < len {
+                return Err(bytesrepr::Error::EarlyEndOfStream.into());
+            }
+            let (array, remainder) = bytes.split_at(len);
+            Ok((json!(base16::encode_lower(array)), remainder))
+        }
+        CLType::Result { ok, err } => {
+            let (tag, remainder) = u8::from_bytes(bytes)?;
+            let (variant, cl_type) = match tag {
+                OK_TAG => (OK, ok),
+                ERR_TAG => (ERR, err),
+                _ => return Err(bytesrepr::Error::Formatting.into()),
+            };
+            let (value, remainder) = render(cl_type, remainder)?;
+            Ok((single_entry(variant.to_string(), value), remainder))
+        }
+        CLType::Map { key, value } => {
+            let (count, mut remainder) = u32::from_bytes(bytes)?;
+            if **key == CLType::String {
+                let mut map = Map::new();
+                for _ in 0..count {
+                    let (rendered_key, rest) = String::from_bytes(remainder)?;
+                    let (rendered_value, rest) = render(value, rest)?;
+                    map.insert(rendered_key, rendered_value);
+                    remainder = rest;
+                }
+                Ok((Value::Object(map), remainder))
+            } else {
+                let mut pairs = Vec::new();
+                for _ in 0..count {
+                    let (rendered_key, rest) = render(key, remainder)?;
+                    let (rendered_value, rest) = render(value, rest)?;
+                    pairs.push(json!([rendered_key, rendered_value]));
+                    remainder = rest;
+                }
+                Ok((Value::Array(pairs), remainder))
+            }
+        }
+        CLType::Tuple1(cl_types) => render_sequence(cl_types.iter().map(AsRef::as_ref), bytes),
+        CLType::Tuple2(cl_types) => render_sequence(cl_types.iter().map(AsRef::as_ref), bytes),
+        CLType::Tuple3(cl_types) => render_sequence(cl_types.iter().map(AsRef::as_ref), bytes),
+        CLType::Any => Err(Error::UnsupportedType(CLType::Any)),
+        CLType::Struct { fields, .. } => render_fields(fields, bytes),
+        CLType::Enum { variants, .. } => {
+            let (tag, remainder) = u8::from_bytes(bytes)?;
+            let variant = variants
+                .iter()
+                .find(|variant| variant.tag == tag)
+                .ok_or(bytesrepr::Error::Formatting)?;
+            if variant.fields.is_empty() {
+                Ok((json!(variant.name), remainder))
+            } else {
+                let (fields, remainder) = render_fields(&variant.fields, remainder)?;
+                Ok((single_entry(variant.name.clone(), fields), remainder))
+            }
+        }
+    }
+}
+
+fn render_sequence<'a, 'b, I>(cl_types: I, mut bytes: &'a [u8]) -> Result<(Value, &'a [u8])>
+where
+    I: IntoIterator<Item = &'b CLType>,
+{
+    let mut elements = Vec::new();
+    for cl_type in cl_types {
+        let (element, remainder) = render(cl_type, bytes)?;
+        elements.push(element);
+        bytes = remainder;
+    }
+    Ok((Value::Array(elements), bytes))
+}
+
+fn render_fields<'a>(fields: &[CLField], mut bytes: &'a [u8]) -> Result<(Value, &'a [u8])> {
+    let mut map = Map::new();
+    for field in fields {
+        let (value, remainder) = render(&field.cl_type, bytes)?;
+        map.insert(field.name.clone(), value);
+        bytes = remainder;
+    }
+    Ok((Value::Object(map), bytes))
+}
+
+/// Converts `json` to a `T` using `from_json`, then appends the serialized `T` to `stream`.
+fn parse_t<T, F>(json: &Value, expected: &str, stream: &mut Vec<u8>, from_json: F) -> Result<()>
+where
+    T: ToBytes,
+    F: FnOnce(&Value) -> Option<T>,
+{
+    let t = from_json(json).ok_or_else(|| Error::unexpected(expected, json))?;
+    stream.append(&mut t.into_bytes()?);
+    Ok(())
+}
+
+/// Parses `json` as a value of type `cl_type`, appending its serialized form to `stream`.
+fn parse(cl_type: &CLType, json: &Value, stream: &mut Vec<u8>) -> Result<()> {
+    match cl_type {
+        CLType::Bool => parse_t(json, "a boolean", stream, Value::as_bool),
+        CLType::I32 => parse_t(json, "an i32", stream, |json| {
+            json.as_i64().and_then(|value| i32::try_from(value).ok())
+        }),
+        CLType::I64 => parse_t(json, "an i64", stream, Value::as_i64),
+        CLType::U8 => parse_t(json, "a u8", stream, |json| {
+            json.as_u64().and_then(|value| u8::try_from(value).ok())
+        }),
+        CLType::U32 => parse_t(json, "a u32", stream, |json| {
+            json.as_u64().and_then(|value| u32::try_from(value).ok())
+        }),
+        CLType::U64 => parse_t(json, "a u64", stream, Value::as_u64),
+        CLType::U128 => parse_t(json, "a decimal U128 string", stream, |json| {
+            json.as_str()
+                .and_then(|value| U128::from_dec_str(value).ok())
+        }),
+        CLType::U256 => parse_t(json, "a decimal U256 string", stream, |json| {
+            json.as_str()
+                .and_then(|value| U256::from_dec_str(value).ok())
+        }),
+        CLType::U512 => parse_t(json, "a decimal U512 string", stream, |json| {
+            json.as_str()
+                .and_then(|value| U512::from_dec_str(value).ok())
+        }),
+        CLType::I256 => parse_t(json, "a decimal I256 string", stream, |json| {
+            json.as_str().and_then(|value| value.parse::<I256>().ok())
+        }),
+        CLType::Decimal => parse_t(json, "a decimal string", stream, |json| {
+            json.as_str()
+                .and_then(|value| value.parse::<Decimal>().ok())
+        }),
+        CLType::Unit => parse_t(json, "null", stream, |json| json.as_null()),
+        CLType::String => parse_t(json, "a string", stream, |json| {
+            json.as_str().map(String::from)
+        }),
+        CLType::Key => parse_t(json, "a formatted key", stream, |json| {
+            json.as_str().and_then(Key::from_formatted_str)
+        }),
+        CLType::URef => parse_t(json, "a formatted URef", stream, |json| {
+            json.as_str().and_then(URef::from_formatted_str)
+        }),
+        CLType::Option(inner) => {
+            if json.is_null() {
+                stream.push(NONE_TAG);
+                Ok(())
+            } else {
+                stream.push(SOME_TAG);
+                parse(inner, json, stream)
+            }
+        }
+        CLType::List(inner) => {
+            let elements = as_array(json)?;
+            stream.append(&mut (elements.len() as u32).into_bytes()?);
+            parse_sequence(iter::repeat(&**inner), elements, stream)
+        }
+        CLType::FixedList(inner, len) => {
+            let elements = as_array_of_len(json, *len as usize)?;
+            parse_sequence(iter::repeat(&**inner), elements, stream)
+        }
+        CLType::ByteArray(len) => {
+            let mut array = json
+                .as_str()
+                .and_then(|hex| base16::decode(hex).ok())
+                .filter(|array| array.len() == *len as usize)
+                .ok_or_else(|| {
+                    Error::unexpected(&format!("a hex string of {} bytes", len), json)
+                })?;
+            stream.append(&mut array);
+            Ok(())
+        }
+        CLType::Result { ok, err } => {
+            let (tag, cl_type, value) = match as_single_entry(json) {
+                Some((OK, value)) => (OK_TAG, ok, value),
+                Some((ERR, value)) => (ERR_TAG, err, value),
+                _ => return Err(Error::unexpected("{\"Ok\": ...} or {\"Err\": ...}", json)),
+            };
+            stream.push(tag);
+            parse(cl_type, value, stream)
+        }
+        CLType::Map { key, value } => {
+            if **key == CLType::String {
+                let map = json
+                    .as_object()
+                    .ok_or_else(|| Error::unexpected("an object", json))?;
+                stream.append(&mut (map.len() as u32).into_bytes()?);
+                for (map_key, map_value) in map {
+                    stream.append(&mut map_key.to_bytes()?);
+                    parse(value, map_value, stream)?;
+                }
+            } else {
+                let pairs = as_array(json)?;
+                stream.append(&mut (pairs.len() as u32).into_bytes()?);
+                for pair in pairs {
+                    let pair = as_array_of_len(pair, 2)?;
+                    parse(key, &pair[0], stream)?;
+                    parse(value, &pair[1], stream)?;
+                }
+            }
+            Ok(())
+        }
+        CLType::Tuple1(cl_types) => parse_tuple(cl_types, json, stream),
+        CLType::Tuple2(cl_types) => parse_tuple(cl_types, json, stream),
+        CLType::Tuple3(cl_types) => parse_tuple(cl_types, json, stream),
+        CLType::Any => Err(Error::UnsupportedType(CLType::Any)),
+        CLType::Struct { fields, .. } => parse_fields(fields, json, stream),
+        CLType::Enum { variants, .. } => {
+            let (name, fields) = match (json.as_str(), as_single_entry(json)) {
+                (Some(name), _) => (name, None),
+                (None, Some((name, fields))) => (name, Some(fields)),
+                _ => return Err(Error::unexpected("an enum variant", json)),
+            };
+            let variant = variants
+                .iter()
+                .find(|variant| variant.name == name)
+                .ok_or_else(|| Error::unexpected("a known enum variant", json))?;
+            stream.push(variant.tag);
+            match fields {
+                Some(fields) => parse_fields(&variant.fields, fields, stream),
+                None if variant.fields.is_empty() => Ok(()),
+                None => Err(Error::unexpected("an enum variant with fields", json)),
+            }
+        }
+    }
+}
+
+fn as_array(json: &Value) -> Result<&Vec<Value>> {
+    json.as_array()
+        .ok_or_else(|| Error::unexpected("an array", json))
+}
+
+fn as_array_of_len(json: &Value, len: usize) -> Result<&Vec<Value>> {
+    match json.as_array() {
+        Some(elements) if elements.len() == len => Ok(elements),
+        _ => Err(Error::unexpected(
+            &format!("an array of {} elements", len),
+            json,
+        )),
+    }
+}
+
+fn parse_sequence<'a, I>(cl_types: I, elements: &[Value], stream: &mut Vec<u8>) -> Result<()>
+where
+    I: IntoIterator<Item = &'a CLType>,
+{
+    for (cl_type, element) in cl_types.into_iter().zip(elements) {
+        parse(cl_type, element, stream)?;
+    }
+    Ok(())
+}
+
+fn parse_tuple(cl_types: &[Box<CLType>], json: &Value, stream: &mut Vec<u8>) -> Result<()> {
+    let elements = as_array_of_len(json, cl_types.len())?;
+    parse_sequence(cl_types.iter().map(AsRef::as_ref), elements, stream)
+}
+
+fn parse_fields(fields: &[CLField], json: &Value, stream: &mut Vec<u8>) -> Result<()> {
+    let map = json
+        .as_object()
+        .ok_or_else(|| Error::unexpected("an object", json))?;
+    if map.len() != fields.len() {
+        return Err(Error::unexpected(
+            &format!("an object with {} fields", fields.len()),
+            json,
+        ));
+    }
+    for field in fields {
+        parse(&field.cl_type, get_field(json, &field.name)?, stream)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use proptest::prelude::*;
+    use types::{gens, AccessRights, CLTyped, CLVariant};
+
+    use super::*;
+
+    fn round_trip(cl_value: CLValue) {
+        let json = cl_value_to_json(&cl_value).expect("should render");
+        assert_eq!(cl_value_from_json(&json), Ok(cl_value));
+    }
+
+    #[test]
+    fn should_render_readable_values() {
+        let uref = URef::new([1; 32], AccessRights::READ_ADD_WRITE);
+        let mut map = BTreeMap::new();
+        map.insert(String::from("purse"), Key::URef(uref));
+        let cl_value = CLValue::from_t((U512::from(1_000_000_000_000u64), map)).unwrap();
+
+        let expected = json!({
+            "cl_type": cl_type_to_json(&<(U512, BTreeMap<String, Key>)>::cl_type()),
+            "value": [
+                "1000000000000",
+                { "purse": format!("uref-{}-007", "01".repeat(32)) }
+            ]
+        });
+        assert_eq!(cl_value_to_json(&cl_value), Ok(expected));
+        round_trip(cl_value);
+    }
+
+    #[test]
+    fn should_render_signed_numbers_as_strings() {
+        let rate: Decimal = "-0.025".parse().unwrap();
+        let cl_value = CLValue::from_t((I256::from(-12), rate)).unwrap();
+        let json = cl_value_to_json(&cl_value).unwrap();
+        assert_eq!(json["cl_type"], json!({ "Tuple2": ["I256", "Decimal"] }));
+        assert_eq!(json["value"], json!(["-12", "-0.025"]));
+        round_trip(cl_value);
+    }
+
+    #[test]
+    fn should_render_byte_arrays_as_hex() {
+        let cl_value = CLValue::from_t([0xabu8; 4]).unwrap();
+        let expected = json!({ "cl_type": { "ByteArray": 4 }, "value": "abababab" });
+        assert_eq!(cl_value_to_json(&cl_value), Ok(expected));
+        round_trip(cl_value);
+
+        let json = json!({ "cl_type": { "ByteArray": 4 }, "value": "ababab" });
+        assert!(cl_value_from_json(&json).is_err());
+    }
+
+    #[test]
+    fn should_render_non_string_map_keys_as_pairs() {
+        let mut map = BTreeMap::new();
+        map.insert(3u64, Some(-1i32));
+        map.insert(5u64, None);
+        let cl_value = CLValue::from_t(map).unwrap();
+        let json = cl_value_to_json(&cl_value).unwrap();
+        assert_eq!(json["value"], json!([[3, -1], [5, null]]));
+        round_trip(cl_value);
+    }
+
+    #[test]
+    fn should_render_enums_by_variant_name() {
+        let cl_type = CLType::Enum {
+            name: String::from("Choice"),
+            variants: vec![
+                CLVariant::new(0, "Nothing", vec![]),
+                CLVariant::new(1, "Something", vec![CLField::new("0", CLType::U8)]),
+            ],
+        };
+        let nothing = CLValue::from_components(cl_type.clone(), vec![0]);
+        assert_eq!(
+            cl_value_to_json(&nothing).unwrap()["value"],
+            json!("Nothing")
+        );
+        round_trip(nothing);
+
+        let something = CLValue::from_components(cl_type, vec![1, 7]);
+        assert_eq!(
+            cl_value_to_json(&something).unwrap()["value"],
+            json!({ "Something": { "0": 7 } })
+        );
+        round_trip(something);
+    }
+
+    #[test]
+    fn should_fail_on_type_mismatch() {
+        let json = json!({ "cl_type": "U8", "value": 256 });
+        assert!(cl_value_from_json(&json).is_err());
+
+        let json = json!({ "cl_type": { "Tuple2": ["Bool", "U8"] }, "value": [true] });
+        assert!(cl_value_from_json(&json).is_err());
+
+        let json = json!({ "cl_type": { "List": "Any" }, "value": [] });
+        assert_eq!(
+            cl_value_from_json(&json),
+            Err(Error::UnsupportedType(CLType::Any))
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn cl_value_round_trip(cl_value in gens::cl_value_arb()) {
+            round_trip(cl_value);
+        }
+    }
+}
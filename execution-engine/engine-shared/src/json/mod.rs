@@ -0,0 +1,107 @@
+//! Human-readable JSON representations of [`CLType`]s, [`CLValue`](types::CLValue)s and
+//! [`StoredValue`](crate::stored_value::StoredValue)s.
+//!
+//! A `CLValue` is rendered as an object holding its `CLType` under `"cl_type"` and its value under
+//! `"value"`.  The value is rendered according to its `CLType`:
+//!
+//! * `Bool`, `I32`, `I64`, `U8`, `U32` and `U64` as JSON booleans and numbers
+//! * `U128`, `U256`, `U512` and `I256` as decimal strings, e.g. `"-12"`
+//! * `Decimal` as a decimal string without trailing fractional zeros, e.g. `"-1.25"`
+//! * `Unit` as `null`
+//! * `Key` and `URef` as prefixed hex strings, e.g. `"hash-<hex>"` or `"uref-<hex>-007"`
+//! * `Option` as `null` or the contained value
+//! * `List`, `FixedList` and tuples as arrays
+//! * `Result` as `{"Ok": <value>}` or `{"Err": <value>}`
+//! * `Map` as an object if the keys are `String`s, otherwise as an array of `[key, value]` pairs
+//! * `Struct` as an object keyed by field name
+//! * `Enum` as the variant's name if it has no fields, otherwise as `{"<variant>": {<fields>}}`
+//! * `Any` as a hex string of the raw bytes, which is only supported for a top-level value
+//!
+//! Note that `Some(())` and `None` are both rendered as `null`, and so an `Option<()>` always
+//! parses as `None`.
+
+mod cl_type;
+mod cl_value;
+mod stored_value;
+
+use std::{fmt, result};
+
+use serde_json::{Map, Value};
+use types::{bytesrepr, CLType};
+
+pub use cl_type::{cl_type_from_json, cl_type_to_json};
+pub use cl_value::{cl_value_from_json, cl_value_to_json};
+pub use stored_value::{stored_value_from_json, stored_value_to_json};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The bytes of a value couldn't be serialized or deserialized.
+    Bytesrepr(bytesrepr::Error),
+    /// The JSON doesn't represent the expected type.
+    UnexpectedJson { expected: String, found: String },
+    /// Values of the given type have no JSON representation.
+    UnsupportedType(CLType),
+}
+
+impl Error {
+    fn unexpected(expected: &str, found: &Value) -> Self {
+        Error::UnexpectedJson {
+            expected: expected.to_string(),
+            found: found.to_string(),
+        }
+    }
+}
+
+impl From<bytesrepr::Error> for Error {
+    fn from(error: bytesrepr::Error) -> Self {
+        Error::Bytesrepr(error)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Bytesrepr(error) => write!(f, "{}", error),
+            Error::UnexpectedJson { expected, found } => {
+                write!(f, "expected {} but found {}", expected, found)
+            }
+            Error::UnsupportedType(cl_type) => write!(
+                f,
+                "values of type {:?} can't be represented as JSON",
+                cl_type
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+pub type Result<T> = result::Result<T, Error>;
+
+/// Returns an object holding the single entry `key: value`.
+fn single_entry(key: String, value: Value) -> Value {
+    let mut map = Map::new();
+    map.insert(key, value);
+    Value::Object(map)
+}
+
+/// Returns the key and value of `json` if it's an object holding a single entry.
+fn as_single_entry(json: &Value) -> Option<(&str, &Value)> {
+    match json.as_object() {
+        Some(map) if map.len() == 1 => map.iter().next().map(|(key, value)| (key.as_str(), value)),
+        _ => None,
+    }
+}
+
+/// Returns the value under `key` in `json`, or an error if `json` isn't an object with that key.
+fn get_field<'a>(json: &'a Value, key: &str) -> Result<&'a Value> {
+    json.get(key)
+        .ok_or_else(|| Error::unexpected(&format!("an object with field \"{}\"", key), json))
+}
+
+fn get_str<'a>(json: &'a Value, key: &str) -> Result<&'a str> {
+    let value = get_field(json, key)?;
+    value
+        .as_str()
+        .ok_or_else(|| Error::unexpected("a string", value))
+}
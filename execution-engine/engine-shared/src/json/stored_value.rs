@@ -0,0 +1,260 @@
+use std::collections::BTreeMap;
+
+use serde_json::{json, Map, Value};
+use types::{
+    account::{PublicKey, Weight},
+    BlockTime, Key, ProtocolVersion, URef,
+};
+
+use super::{
+    as_single_entry, cl_value_from_json, cl_value_to_json, get_field, get_str, single_entry, Error,
+    Result,
+};
+use crate::{
+    account::{Account, ActionThresholds, AssociatedKeys, Recovery},
+    contract::Contract,
+    stored_value::StoredValue,
+};
+
+const CL_VALUE: &str = "CLValue";
+const ACCOUNT: &str = "Account";
+const CONTRACT: &str = "Contract";
+
+/// Renders `stored_value` as an object holding the rendered value under the name of its variant,
+/// e.g. `{"Account": {...}}`.
+pub fn stored_value_to_json(stored_value: &StoredValue) -> Result<Value> {
+    let (variant, value) = match stored_value {
+        StoredValue::CLValue(cl_value) => (CL_VALUE, cl_value_to_json(cl_value)?),
+        StoredValue::Account(account) => (ACCOUNT, account_to_json(account)),
+        StoredValue::Contract(contract) => (CONTRACT, contract_to_json(contract)),
+    };
+    Ok(single_entry(variant.to_string(), value))
+}
+
+/// Parses a `StoredValue` from the JSON produced by [`stored_value_to_json`].
+pub fn stored_value_from_json(json: &Value) -> Result<StoredValue> {
+    match as_single_entry(json) {
+        Some((CL_VALUE, value)) => cl_value_from_json(value).map(StoredValue::CLValue),
+        Some((ACCOUNT, value)) => account_from_json(value).map(StoredValue::Account),
+        Some((CONTRACT, value)) => contract_from_json(value).map(StoredValue::Contract),
+        _ => Err(Error::unexpected("a stored value", json)),
+    }
+}
+
+fn account_to_json(account: &Account) -> Value {
+    json!({
+        "public_key": base16::encode_lower(account.public_key().as_bytes()),
+        "named_keys": named_keys_to_json(account.named_keys()),
+        "main_purse": account.main_purse().as_string(),
+        "associated_keys": associated_keys_to_json(account.get_associated_keys()),
+        "action_thresholds": {
+            "deployment": account.action_thresholds().deployment().value(),
+            "key_management": account.action_thresholds().key_management().value(),
+        },
+        "recovery": account.recovery().map(recovery_to_json),
+    })
+}
+
+fn account_from_json(json: &Value) -> Result<Account> {
+    let public_key = public_key_from_str(get_str(json, "public_key")?)?;
+    let named_keys = named_keys_from_json(get_field(json, "named_keys")?)?;
+    let main_purse = get_str(json, "main_purse")?;
+    let main_purse = URef::from_formatted_str(main_purse)
+        .ok_or_else(|| Error::unexpected("a formatted URef", &json!(main_purse)))?;
+
+    let associated_keys = associated_keys_from_json(get_field(json, "associated_keys")?)?;
+
+    let thresholds = get_field(json, "action_thresholds")?;
+    let action_thresholds = ActionThresholds::new(
+        weight_from_json(get_field(thresholds, "deployment")?)?,
+        weight_from_json(get_field(thresholds, "key_management")?)?,
+    )
+    .map_err(|_| Error::unexpected("valid action thresholds", thresholds))?;
+
+    let account = Account::new(
+        public_key,
+        named_keys,
+        main_purse,
+        associated_keys,
+        action_thresholds,
+    );
+    // Accounts rendered before recovery was introduced have no such field.
+    match json.get("recovery") {
+        None | Some(Value::Null) => Ok(account),
+        Some(recovery) => Ok(account.with_recovery(recovery_from_json(recovery)?)),
+    }
+}
+
+fn associated_keys_to_json<'a, I>(keys: I) -> Value
+where
+    I: Iterator<Item = (&'a PublicKey, &'a Weight)>,
+{
+    let keys: Map<String, Value> = keys
+        .map(|(public_key, weight)| {
+            (
+                base16::encode_lower(public_key.as_bytes()),
+                json!(weight.value()),
+            )
+        })
+        .collect();
+    Value::Object(keys)
+}
+
+fn associated_keys_from_json(json: &Value) -> Result<AssociatedKeys> {
+    let mut associated_keys = AssociatedKeys::default();
+    for (key, weight) in json
+        .as_object()
+        .ok_or_else(|| Error::unexpected("an object", json))?
+    {
+        let weight = weight_from_json(weight)?;
+        associated_keys
+            .add_key(public_key_from_str(key)?, weight)
+            .map_err(|_| Error::unexpected("a valid set of associated keys", json))?;
+    }
+    Ok(associated_keys)
+}
+
+fn recovery_to_json(recovery: &Recovery) -> Value {
+    json!({
+        "keys": associated_keys_to_json(recovery.keys().iter()),
+        "threshold": recovery.threshold().value(),
+        "delay": recovery.delay(),
+        "approvals": associated_keys_to_json(recovery.approvals().iter()),
+        "requested_at": recovery.requested_at().map(Into::<u64>::into),
+    })
+}
+
+fn recovery_from_json(json: &Value) -> Result<Recovery> {
+    let keys = associated_keys_from_json(get_field(json, "keys")?)?;
+    let threshold = weight_from_json(get_field(json, "threshold")?)?;
+    let delay = u64_from_json(get_field(json, "delay")?)?;
+    let approvals = associated_keys_from_json(get_field(json, "approvals")?)?;
+    let recovery = Recovery::new(keys, threshold, delay)
+        .map_err(|_| Error::unexpected("a reachable recovery threshold", json))?
+        .with_approvals(approvals);
+    match get_field(json, "requested_at")? {
+        Value::Null => Ok(recovery),
+        requested_at => Ok(recovery.with_request(BlockTime::new(u64_from_json(requested_at)?))),
+    }
+}
+
+fn contract_to_json(contract: &Contract) -> Value {
+    json!({
+        "bytes": base16::encode_lower(contract.bytes()),
+        "named_keys": named_keys_to_json(contract.named_keys()),
+        "protocol_version": contract.protocol_version().to_string(),
+    })
+}
+
+fn contract_from_json(json: &Value) -> Result<Contract> {
+    let bytes = get_str(json, "bytes")?;
+    let bytes =
+        base16::decode(bytes).map_err(|_| Error::unexpected("a hex string", &json!(bytes)))?;
+    let named_keys = named_keys_from_json(get_field(json, "named_keys")?)?;
+    let protocol_version = protocol_version_from_str(get_str(json, "protocol_version")?)?;
+    Ok(Contract::new(bytes, named_keys, protocol_version))
+}
+
+fn named_keys_to_json(named_keys: &BTreeMap<String, Key>) -> Value {
+    named_keys
+        .iter()
+        .map(|(name, key)| (name.clone(), json!(key.to_formatted_string())))
+        .collect::<Map<String, Value>>()
+        .into()
+}
+
+fn named_keys_from_json(json: &Value) -> Result<BTreeMap<String, Key>> {
+    json.as_object()
+        .ok_or_else(|| Error::unexpected("an object", json))?
+        .iter()
+        .map(|(name, key)| {
+            let key = key
+                .as_str()
+                .and_then(Key::from_formatted_str)
+                .ok_or_else(|| Error::unexpected("a formatted key", key))?;
+            Ok((name.clone(), key))
+        })
+        .collect()
+}
+
+fn public_key_from_str(input: &str) -> Result<PublicKey> {
+    base16::decode(input)
+        .ok()
+        .and_then(|bytes| PublicKey::ed25519_try_from(&bytes).ok())
+        .ok_or_else(|| Error::unexpected("a hex-encoded public key", &json!(input)))
+}
+
+fn u64_from_json(json: &Value) -> Result<u64> {
+    json.as_u64()
+        .ok_or_else(|| Error::unexpected("a u64", json))
+}
+
+fn weight_from_json(json: &Value) -> Result<Weight> {
+    match json.as_u64() {
+        Some(weight) if weight <= u64::from(u8::max_value()) => Ok(Weight::new(weight as u8)),
+        _ => Err(Error::unexpected("a u8 weight", json)),
+    }
+}
+
+/// Parses a protocol version of the form `"<major>.<minor>.<patch>"`.
+fn protocol_version_from_str(input: &str) -> Result<ProtocolVersion> {
+    let parts = input
+        .split('.')
+        .map(str::parse)
+        .collect::<std::result::Result<Vec<u32>, _>>();
+    match parts.as_ref().map(Vec::as_slice) {
+        Ok([major, minor, patch]) => Ok(ProtocolVersion::from_parts(*major, *minor, *patch)),
+        _ => Err(Error::unexpected(
+            "a \"major.minor.patch\" version",
+            &json!(input),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::stored_value::gens::stored_value_arb;
+
+    #[test]
+    fn should_render_contract() {
+        let mut named_keys = BTreeMap::new();
+        named_keys.insert(String::from("hash"), Key::Hash([2; 32]));
+        let contract = Contract::new(vec![0, 97, 115, 109], named_keys, ProtocolVersion::V1_0_0);
+        let stored_value = StoredValue::Contract(contract);
+
+        let expected = json!({
+            "Contract": {
+                "bytes": "0061736d",
+                "named_keys": { "hash": format!("hash-{}", "02".repeat(32)) },
+                "protocol_version": "1.0.0",
+            }
+        });
+        assert_eq!(stored_value_to_json(&stored_value), Ok(expected.clone()));
+        assert_eq!(stored_value_from_json(&expected), Ok(stored_value));
+    }
+
+    #[test]
+    fn should_fail_to_parse_invalid_thresholds() {
+        let json = json!({
+            "Account": {
+                "public_key": "03".repeat(32),
+                "named_keys": {},
+                "main_purse": format!("uref-{}-007", "04".repeat(32)),
+                "associated_keys": { "03".repeat(32): 1 },
+                "action_thresholds": { "deployment": 2, "key_management": 1 },
+            }
+        });
+        assert!(stored_value_from_json(&json).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn stored_value_round_trip(stored_value in stored_value_arb()) {
+            let json = stored_value_to_json(&stored_value).expect("should render");
+            prop_assert_eq!(stored_value_from_json(&json), Ok(stored_value));
+        }
+    }
+}
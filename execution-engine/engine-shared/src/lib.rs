@@ -5,7 +5,9 @@ pub mod additive_map;
 pub mod gas;
 pub mod account;
 pub mod contract;
+pub mod json;
 pub mod logging;
+pub mod metrics;
 pub mod motes;
 pub mod newtypes;
 pub mod os;
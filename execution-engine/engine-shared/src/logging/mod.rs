@@ -6,13 +6,15 @@ mod terminal_logger;
 
 use std::{
     collections::BTreeMap,
+    sync::{PoisonError, RwLock},
     time::{Duration, SystemTime, UNIX_EPOCH},
 };
 
+use lazy_static::lazy_static;
 use log::{self, Level, LevelFilter, Log, Metadata, Record, SetLoggerError};
 
 pub use self::terminal_logger::TerminalLogger;
-use crate::newtypes::CorrelationId;
+use crate::{metrics, newtypes::CorrelationId};
 pub use settings::{Settings, Style};
 
 #[doc(hidden)]
@@ -23,15 +25,44 @@ pub(crate) const MESSAGE_TEMPLATE_KEY: &str = "message_template";
 pub(crate) const DEFAULT_MESSAGE_TEMPLATE: &str = "{message}";
 pub(crate) const DEFAULT_MESSAGE_KEY: &str = "message";
 
+lazy_static! {
+    /// The global logger if it was set via `initialize`, kept so that its settings can be reloaded.
+    static ref TERMINAL_LOGGER: RwLock<Option<&'static TerminalLogger>> = RwLock::new(None);
+}
+
 /// Initializes the global logger using the given settings.
 ///
 /// The logger will write all log messages from crates prefixed with "casperlabs_" to stdout, and
-/// can also log internal metrics generated by the Execution Engine.
+/// can also log internal metrics generated by the Execution Engine.  Its settings can later be
+/// changed via [`reload`].
 ///
 /// Returns an error if the global logger has already been set in this process.
 pub fn initialize(settings: Settings) -> Result<(), SetLoggerError> {
-    let logger = Box::new(TerminalLogger::new(&settings));
-    initialize_with_logger(logger, settings)
+    let logger: &'static TerminalLogger = Box::leak(Box::new(TerminalLogger::new(&settings)));
+    log::set_logger(logger)?;
+    log::set_max_level(settings.max_level());
+    *TERMINAL_LOGGER
+        .write()
+        .unwrap_or_else(PoisonError::into_inner) = Some(logger);
+    Ok(())
+}
+
+/// Replaces the settings of the global logger, taking effect immediately.
+///
+/// Returns `false` if the global logger wasn't set up via [`initialize`], in which case nothing is
+/// changed.
+pub fn reload(settings: Settings) -> bool {
+    let maybe_logger = *TERMINAL_LOGGER
+        .read()
+        .unwrap_or_else(PoisonError::into_inner);
+    match maybe_logger {
+        Some(logger) => {
+            logger.update_settings(&settings);
+            log::set_max_level(settings.max_level());
+            true
+        }
+        None => false,
+    }
 }
 
 /// This and the `TerminalLogger` are public but undocumented to allow functional testing of this
@@ -85,7 +116,8 @@ pub fn log_details(
     logger.log(&record);
 }
 
-/// Logs the duration of a specific operation.
+/// Logs the duration of a specific operation, and records it in the in-process
+/// [`metrics`](crate::metrics) registry.
 ///
 /// # Arguments
 ///
@@ -95,9 +127,11 @@ pub fn log_details(
 /// * `duration` - in seconds
 #[inline]
 pub fn log_duration(correlation_id: CorrelationId, metric: &str, tag: &str, duration: Duration) {
+    metrics::observe_duration(metric, tag, duration);
+
     let duration_in_seconds: f64 = duration.as_secs_f64();
 
-    log_metric(
+    write_metric(
         correlation_id,
         metric,
         tag,
@@ -106,7 +140,8 @@ pub fn log_duration(correlation_id: CorrelationId, metric: &str, tag: &str, dura
     )
 }
 
-/// Logs the details of the specified metric.
+/// Logs the details of the specified metric, and sets the corresponding gauge in the in-process
+/// [`metrics`](crate::metrics) registry to its value.
 ///
 /// # Arguments
 ///
@@ -122,6 +157,19 @@ pub fn log_metric(
     tag: &str,
     metric_key: &str,
     metric_value: f64,
+) {
+    metrics::set_gauge(metric, tag, metric_value);
+
+    write_metric(correlation_id, metric, tag, metric_key, metric_value)
+}
+
+#[inline]
+fn write_metric(
+    correlation_id: CorrelationId,
+    metric: &str,
+    tag: &str,
+    metric_key: &str,
+    metric_value: f64,
 ) {
     let logger = log::logger();
 
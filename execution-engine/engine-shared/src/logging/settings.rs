@@ -1,7 +1,7 @@
 use log::LevelFilter;
 
 /// Settings used to initialize the global logger.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct Settings {
     max_level: LevelFilter,
     enable_metrics: bool,
@@ -39,21 +39,24 @@ impl Settings {
         self
     }
 
-    pub(crate) fn max_level(&self) -> LevelFilter {
+    /// Returns the verbosity level above which messages are filtered out.
+    pub fn max_level(&self) -> LevelFilter {
         self.max_level
     }
 
-    pub(crate) fn enable_metrics(&self) -> bool {
+    /// Returns `true` if metrics are logged regardless of the log-level.
+    pub fn enable_metrics(&self) -> bool {
         self.enable_metrics
     }
 
-    pub(crate) fn style(&self) -> Style {
+    /// Returns the logging style.
+    pub fn style(&self) -> Style {
         self.style
     }
 }
 
 /// The style of generated log messages.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Style {
     /// Hybrid structured log-messages, with a human-readable component followed by JSON formatted
     /// details.
@@ -1,6 +1,9 @@
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    PoisonError, RwLock,
+};
 
-use log::{Level, LevelFilter, Log, Metadata, Record};
+use log::{Level, Log, Metadata, Record};
 
 use crate::logging::{
     structured_message::{MessageId, MessageProperties, StructuredMessage, TimestampRfc3999},
@@ -10,22 +13,30 @@ use crate::logging::{
 #[doc(hidden)]
 /// Logs messages from targets with prefix "casperlabs_" or "METRIC" to stdout.
 pub struct TerminalLogger {
-    max_level: LevelFilter,
-    metrics_enabled: bool,
-    style: Style,
+    settings: RwLock<Settings>,
     next_message_id: AtomicUsize,
 }
 
 impl TerminalLogger {
     pub fn new(settings: &Settings) -> Self {
         TerminalLogger {
-            max_level: settings.max_level(),
-            metrics_enabled: settings.enable_metrics(),
-            style: settings.style(),
+            settings: RwLock::new(*settings),
             next_message_id: AtomicUsize::new(0),
         }
     }
 
+    /// Replaces the settings of this logger, taking effect from the next message logged.
+    pub fn update_settings(&self, settings: &Settings) {
+        *self
+            .settings
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = *settings;
+    }
+
+    fn settings(&self) -> Settings {
+        *self.settings.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
     pub fn prepare_log_line(&self, record: &Record) -> Option<String> {
         if !self.enabled(&record.metadata()) {
             return None;
@@ -34,7 +45,7 @@ impl TerminalLogger {
         let mut properties = MessageProperties::default();
         let _ = record.key_values().visit(&mut properties);
 
-        let log_line = match self.style {
+        let log_line = match self.settings().style() {
             Style::Structured => {
                 if record.key_values().count() == 0 {
                     properties.insert(
@@ -78,12 +89,13 @@ impl TerminalLogger {
 
 impl Log for TerminalLogger {
     fn enabled(&self, metadata: &Metadata) -> bool {
+        let settings = self.settings();
         // If the target starts "casperlabs_" it's either come from a log macro in one of our
         // crates, or via `logging::log_details`.  In this case, check the level.
         (metadata.target().starts_with(CASPERLABS_METADATA_TARGET)
-            && metadata.level() <= self.max_level)
+            && metadata.level() <= settings.max_level())
             // Otherwise, check if the target is "METRIC" and if we have metric logging enabled.
-            || (self.metrics_enabled && metadata.target() == METRIC_METADATA_TARGET)
+            || (settings.enable_metrics() && metadata.target() == METRIC_METADATA_TARGET)
     }
 
     fn log(&self, record: &Record) {
@@ -0,0 +1,212 @@
+//! An in-process registry of counters, gauges and histograms which can be rendered in the
+//! Prometheus text exposition format.
+//!
+//! Recording is disabled by default, and is a no-op until [`enable`] is called.
+
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+    time::Duration,
+};
+
+use lazy_static::lazy_static;
+
+/// Upper bounds in seconds of the buckets used for duration histograms.
+const DURATION_BUCKETS: [f64; 11] = [
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+lazy_static! {
+    static ref REGISTRY: Mutex<Registry> = Mutex::new(Registry::default());
+}
+
+#[derive(Debug, Default)]
+struct Histogram {
+    bucket_counts: [u64; DURATION_BUCKETS.len()],
+    sum: f64,
+    count: u64,
+}
+
+impl Histogram {
+    fn observe(&mut self, value: f64) {
+        for (bound, bucket_count) in DURATION_BUCKETS.iter().zip(self.bucket_counts.iter_mut()) {
+            if value <= *bound {
+                *bucket_count += 1;
+            }
+        }
+        self.sum += value;
+        self.count += 1;
+    }
+}
+
+/// Metrics keyed by metric name, then by tag.
+#[derive(Debug, Default)]
+struct Registry {
+    counters: BTreeMap<String, BTreeMap<String, f64>>,
+    gauges: BTreeMap<String, BTreeMap<String, f64>>,
+    histograms: BTreeMap<String, BTreeMap<String, Histogram>>,
+}
+
+impl Registry {
+    fn render(&self) -> String {
+        let mut ret = String::new();
+        // Writing to a `String` can't fail.
+        for (metric, series) in &self.counters {
+            let _ = writeln!(ret, "# TYPE {} counter", metric);
+            for (tag, value) in series {
+                let _ = writeln!(ret, "{}{{tag=\"{}\"}} {}", metric, tag, value);
+            }
+        }
+        for (metric, series) in &self.gauges {
+            let _ = writeln!(ret, "# TYPE {} gauge", metric);
+            for (tag, value) in series {
+                let _ = writeln!(ret, "{}{{tag=\"{}\"}} {}", metric, tag, value);
+            }
+        }
+        for (metric, series) in &self.histograms {
+            let _ = writeln!(ret, "# TYPE {} histogram", metric);
+            for (tag, histogram) in series {
+                for (bound, bucket_count) in DURATION_BUCKETS.iter().zip(&histogram.bucket_counts) {
+                    let _ = writeln!(
+                        ret,
+                        "{}_bucket{{tag=\"{}\",le=\"{}\"}} {}",
+                        metric, tag, bound, bucket_count
+                    );
+                }
+                let _ = writeln!(
+                    ret,
+                    "{}_bucket{{tag=\"{}\",le=\"+Inf\"}} {}",
+                    metric, tag, histogram.count
+                );
+                let _ = writeln!(ret, "{}_sum{{tag=\"{}\"}} {}", metric, tag, histogram.sum);
+                let _ = writeln!(
+                    ret,
+                    "{}_count{{tag=\"{}\"}} {}",
+                    metric, tag, histogram.count
+                );
+            }
+        }
+        ret
+    }
+}
+
+/// Enables recording of metrics for the rest of the life of the process.
+pub fn enable() {
+    ENABLED.store(true, Ordering::SeqCst);
+}
+
+/// Returns `true` if metrics are being recorded.
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Adds `value` to the counter identified by `metric` and `tag`.
+pub fn increment_counter(metric: &str, tag: &str, value: f64) {
+    if !is_enabled() {
+        return;
+    }
+    let mut registry = REGISTRY.lock().unwrap_or_else(|error| error.into_inner());
+    *registry
+        .counters
+        .entry(metric.to_string())
+        .or_default()
+        .entry(tag.to_string())
+        .or_default() += value;
+}
+
+/// Sets the gauge identified by `metric` and `tag` to `value`.
+pub fn set_gauge(metric: &str, tag: &str, value: f64) {
+    if !is_enabled() {
+        return;
+    }
+    let mut registry = REGISTRY.lock().unwrap_or_else(|error| error.into_inner());
+    registry
+        .gauges
+        .entry(metric.to_string())
+        .or_default()
+        .insert(tag.to_string(), value);
+}
+
+/// Records `duration` in the histogram identified by `metric` and `tag`.
+pub fn observe_duration(metric: &str, tag: &str, duration: Duration) {
+    if !is_enabled() {
+        return;
+    }
+    let mut registry = REGISTRY.lock().unwrap_or_else(|error| error.into_inner());
+    registry
+        .histograms
+        .entry(metric.to_string())
+        .or_default()
+        .entry(tag.to_string())
+        .or_default()
+        .observe(duration.as_secs_f64());
+}
+
+/// Renders all recorded metrics in the Prometheus text exposition format.
+pub fn render() -> String {
+    let registry = REGISTRY.lock().unwrap_or_else(|error| error.into_inner());
+    registry.render()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_render_counters() {
+        let mut registry = Registry::default();
+        registry
+            .counters
+            .entry("commit_reads".to_string())
+            .or_default()
+            .insert("commit".to_string(), 3.0);
+
+        assert_eq!(
+            registry.render(),
+            "# TYPE commit_reads counter\ncommit_reads{tag=\"commit\"} 3\n"
+        );
+    }
+
+    #[test]
+    fn should_render_gauges() {
+        let mut registry = Registry::default();
+        registry
+            .gauges
+            .entry("trie_store_write_puts".to_string())
+            .or_default()
+            .insert("put".to_string(), 7.0);
+
+        assert_eq!(
+            registry.render(),
+            "# TYPE trie_store_write_puts gauge\ntrie_store_write_puts{tag=\"put\"} 7\n"
+        );
+    }
+
+    #[test]
+    fn should_render_histograms() {
+        let mut histogram = Histogram::default();
+        histogram.observe(0.5);
+        histogram.observe(20.0);
+        let mut registry = Registry::default();
+        registry
+            .histograms
+            .entry("exec_duration".to_string())
+            .or_default()
+            .insert("exec_response".to_string(), histogram);
+
+        let rendered = registry.render();
+        assert!(rendered.starts_with("# TYPE exec_duration histogram\n"));
+        assert!(rendered.contains("exec_duration_bucket{tag=\"exec_response\",le=\"0.25\"} 0\n"));
+        assert!(rendered.contains("exec_duration_bucket{tag=\"exec_response\",le=\"0.5\"} 1\n"));
+        assert!(rendered.contains("exec_duration_bucket{tag=\"exec_response\",le=\"10\"} 1\n"));
+        assert!(rendered.contains("exec_duration_bucket{tag=\"exec_response\",le=\"+Inf\"} 2\n"));
+        assert!(rendered.contains("exec_duration_sum{tag=\"exec_response\"} 20.5\n"));
+        assert!(rendered.contains("exec_duration_count{tag=\"exec_response\"} 2\n"));
+    }
+}
@@ -11,7 +11,7 @@ use num::traits::{AsPrimitive, WrappingAdd};
 
 use types::{
     bytesrepr::{self, FromBytes, ToBytes},
-    CLType, CLTyped, CLValue, CLValueError, Key, U128, U256, U512,
+    CLType, CLTyped, CLValue, CLValueError, Decimal, Key, I256, U128, U256, U512,
 };
 
 use crate::{stored_value::StoredValue, TypeMismatch};
@@ -19,14 +19,17 @@ use crate::{stored_value::StoredValue, TypeMismatch};
 /// Error type for applying and combining transforms. A `TypeMismatch`
 /// occurs when a transform cannot be applied because the types are
 /// not compatible (e.g. trying to add a number to a string). An
-/// `Overflow` occurs if addition between numbers would result in the
-/// value overflowing its size in memory (e.g. if a, b are i32 and a +
-/// b > i32::MAX then a `AddInt32(a).apply(Value::Int32(b))` would
-/// cause an overflow).
+/// `Overflow` occurs if a checked addition would result in the value
+/// overflowing its type (e.g. if a, b are I256 and a + b > I256::MAX
+/// then `AddInt256(a).checked_apply(b)` fails).  Combining transforms
+/// and `apply` always wrap, so pending I256 and Decimal additions can
+/// be merged in any order, and committing them never overflows: only
+/// `checked_apply` reports `Overflow`.
 #[derive(PartialEq, Eq, Debug, Clone)]
 pub enum Error {
     Serialization(bytesrepr::Error),
     TypeMismatch(TypeMismatch),
+    Overflow,
 }
 
 impl From<TypeMismatch> for Error {
@@ -58,6 +61,8 @@ pub enum Transform {
     AddUInt128(U128),
     AddUInt256(U256),
     AddUInt512(U512),
+    AddInt256(I256),
+    AddDecimal(Decimal),
     AddKeys(BTreeMap<String, Key>),
     Failure(Error),
 }
@@ -89,6 +94,8 @@ from_try_from_impl!(u64, AddUInt64);
 from_try_from_impl!(U128, AddUInt128);
 from_try_from_impl!(U256, AddUInt256);
 from_try_from_impl!(U512, AddUInt512);
+from_try_from_impl!(I256, AddInt256);
+from_try_from_impl!(Decimal, AddDecimal);
 from_try_from_impl!(BTreeMap<String, Key>, AddKeys);
 from_try_from_impl!(Error, Failure);
 
@@ -135,7 +142,43 @@ where
     Ok(StoredValue::CLValue(CLValue::from_t(result)?))
 }
 
+/// Attempts an addition of `to_add` to `stored_value`, which must hold a value of type `X`.  `add`
+/// returns `None` on overflow.
+fn signed_addition<X>(
+    stored_value: StoredValue,
+    to_add: X,
+    add: fn(X, X) -> Option<X>,
+) -> Result<StoredValue, Error>
+where
+    X: CLTyped + ToBytes + FromBytes,
+{
+    let cl_value = CLValue::try_from(stored_value)?;
+    let x: X = cl_value.into_t()?;
+    let result = add(x, to_add).ok_or(Error::Overflow)?;
+    Ok(StoredValue::CLValue(CLValue::from_t(result)?))
+}
+
 impl Transform {
+    /// Applies `self` to `stored_value` like [`Transform::apply`], except that I256 and Decimal
+    /// additions fail with [`Error::Overflow`] rather than wrapping.
+    ///
+    /// This is used while executing, where each addition is checked against the current value;
+    /// the transforms which are committed are sums of such additions, and so may wrap in
+    /// intermediate steps without the final value having overflowed.
+    pub fn checked_apply(self, stored_value: StoredValue) -> Result<StoredValue, Error> {
+        match self {
+            Transform::AddInt256(to_add) => {
+                signed_addition(stored_value, to_add, I256::checked_add)
+            }
+            Transform::AddDecimal(to_add) => {
+                signed_addition(stored_value, to_add, Decimal::checked_add)
+            }
+            transform => transform.apply(stored_value),
+        }
+    }
+
+    /// Applies `self` to `stored_value`.  All numeric additions wrap, so this never returns
+    /// [`Error::Overflow`]; it is used when committing effects to global state.
     pub fn apply(self, stored_value: StoredValue) -> Result<StoredValue, Error> {
         match self {
             Transform::Identity => Ok(stored_value),
@@ -145,6 +188,14 @@ impl Transform {
             Transform::AddUInt128(to_add) => wrapping_addition(stored_value, to_add),
             Transform::AddUInt256(to_add) => wrapping_addition(stored_value, to_add),
             Transform::AddUInt512(to_add) => wrapping_addition(stored_value, to_add),
+            Transform::AddInt256(to_add) => {
+                signed_addition(stored_value, to_add, |x: I256, y| Some(x.wrapping_add(y)))
+            }
+            Transform::AddDecimal(to_add) => {
+                signed_addition(stored_value, to_add, |x: Decimal, y| {
+                    Some(x.wrapping_add(y))
+                })
+            }
             Transform::AddKeys(mut keys) => match stored_value {
                 StoredValue::Contract(mut contract) => {
                     contract.named_keys_append(&mut keys);
@@ -197,6 +248,29 @@ where
     }
 }
 
+/// Combines signed numeric `Transform`s into a single `Transform` using wrapping addition.  Unlike
+/// the unsigned transforms, these only combine with their own variant.
+fn signed_transform_addition<T>(
+    i: T,
+    b: Transform,
+    expected: &str,
+    wrapping_add: fn(T, T) -> T,
+) -> Transform
+where
+    T: Into<Transform> + TryFrom<Transform, Error = String>,
+{
+    match T::try_from(b) {
+        Err(b_type) => Transform::Failure(
+            TypeMismatch {
+                expected: String::from(expected),
+                found: b_type,
+            }
+            .into(),
+        ),
+        Ok(j) => wrapping_add(i, j).into(),
+    }
+}
+
 impl Add for Transform {
     type Output = Transform;
 
@@ -237,6 +311,12 @@ impl Add for Transform {
             (Transform::AddUInt128(i), b) => wrapped_transform_addition(i, b, "U128"),
             (Transform::AddUInt256(i), b) => wrapped_transform_addition(i, b, "U256"),
             (Transform::AddUInt512(i), b) => wrapped_transform_addition(i, b, "U512"),
+            (Transform::AddInt256(i), b) => {
+                signed_transform_addition(i, b, "I256", I256::wrapping_add)
+            }
+            (Transform::AddDecimal(i), b) => {
+                signed_transform_addition(i, b, "Decimal", Decimal::wrapping_add)
+            }
             (Transform::AddKeys(mut ks1), b) => match b {
                 Transform::AddKeys(mut ks2) => {
                     ks1.append(&mut ks2);
@@ -270,6 +350,7 @@ impl Default for Transform {
 
 pub mod gens {
     use proptest::{collection::vec, prelude::*};
+    use types::gens::{decimal_arb, i256_arb};
 
     use super::Transform;
     use crate::stored_value::gens::stored_value_arb;
@@ -291,6 +372,8 @@ pub mod gens {
                 buf.copy_from_slice(&u);
                 Transform::AddUInt512(buf.into())
             }),
+            i256_arb().prop_map(Transform::AddInt256),
+            decimal_arb().prop_map(Transform::AddDecimal),
         ]
     }
 }
@@ -422,6 +505,110 @@ mod tests {
         uint_overflow_test::<U512>();
     }
 
+    #[test]
+    fn i256_overflow() {
+        let one = I256::one();
+        let max_value = StoredValue::CLValue(CLValue::from_t(I256::MAX).unwrap());
+        let min_value = StoredValue::CLValue(CLValue::from_t(I256::MIN).unwrap());
+
+        assert_eq!(
+            Transform::AddInt256(one).checked_apply(max_value.clone()),
+            Err(Error::Overflow)
+        );
+        assert_eq!(
+            Transform::AddInt256(one.checked_neg().unwrap()).checked_apply(min_value.clone()),
+            Err(Error::Overflow)
+        );
+        assert_eq!(
+            Transform::AddInt256(I256::MIN).checked_apply(max_value.clone()),
+            Ok(StoredValue::CLValue(
+                CLValue::from_t(I256::from(-1)).unwrap()
+            ))
+        );
+        assert_eq!(Transform::AddInt256(one).apply(max_value), Ok(min_value));
+
+        assert_eq!(
+            Transform::AddInt256(I256::MAX) + Transform::AddInt256(one),
+            Transform::AddInt256(I256::MIN)
+        );
+        assert_eq!(
+            Transform::AddInt256(I256::MIN) + Transform::AddInt256(I256::MAX),
+            Transform::AddInt256(I256::from(-1))
+        );
+    }
+
+    #[test]
+    fn decimal_addition() {
+        let half: Decimal = "0.5".parse().unwrap();
+        let minus_quarter: Decimal = "-0.25".parse().unwrap();
+        let value = StoredValue::CLValue(CLValue::from_t(half).unwrap());
+
+        assert_eq!(
+            Transform::AddDecimal(minus_quarter).apply(value),
+            Ok(StoredValue::CLValue(
+                CLValue::from_t("0.25".parse::<Decimal>().unwrap()).unwrap()
+            ))
+        );
+        assert_eq!(
+            Transform::AddDecimal(half) + Transform::AddDecimal(minus_quarter),
+            Transform::AddDecimal(minus_quarter) + Transform::AddDecimal(half)
+        );
+        let max_value = StoredValue::CLValue(CLValue::from_t(Decimal::MAX).unwrap());
+        assert_eq!(
+            Transform::AddDecimal(half).checked_apply(max_value),
+            Err(Error::Overflow)
+        );
+    }
+
+    #[test]
+    fn signed_additions_should_commit_the_final_value() {
+        // -5 + MAX + 3 only overflows in the intermediate sum of the additions
+        let value = StoredValue::CLValue(CLValue::from_t(I256::from(-5)).unwrap());
+        let first = Transform::AddInt256(I256::MAX);
+        let second = Transform::AddInt256(I256::from(3));
+        let expected = I256::MAX.checked_sub(I256::from(2)).unwrap();
+
+        let in_order = (first.clone() + second.clone()).apply(value.clone());
+        let reversed = (second.clone() + first.clone()).apply(value.clone());
+        let stepwise = first
+            .checked_apply(value)
+            .and_then(|value| second.checked_apply(value));
+        let expected = Ok(StoredValue::CLValue(CLValue::from_t(expected).unwrap()));
+        assert_eq!(in_order, expected);
+        assert_eq!(reversed, expected);
+        assert_eq!(stepwise, expected);
+    }
+
+    #[test]
+    fn checked_additions_should_not_mix_with_other_types() {
+        fn assert_apply_yields_type_mismatch(transform: Transform, stored_value: StoredValue) {
+            match transform.apply(stored_value) {
+                Err(Error::TypeMismatch(_)) => (),
+                _ => panic!("applying transform should yield TypeMismatch error"),
+            }
+        }
+
+        fn assert_sum_yields_type_mismatch(transform: Transform) {
+            match transform {
+                Transform::Failure(Error::TypeMismatch(_)) => (),
+                _ => panic!("adding transforms should yield TypeMismatch failure"),
+            }
+        }
+
+        let i256_value = StoredValue::CLValue(CLValue::from_t(I256::one()).unwrap());
+        let decimal_value = StoredValue::CLValue(CLValue::from_t(Decimal::one()).unwrap());
+
+        assert_apply_yields_type_mismatch(Transform::AddInt32(1), i256_value.clone());
+        assert_apply_yields_type_mismatch(Transform::AddDecimal(Decimal::one()), i256_value);
+        assert_apply_yields_type_mismatch(Transform::AddInt256(I256::one()), decimal_value);
+
+        assert_sum_yields_type_mismatch(Transform::AddInt256(I256::one()) + Transform::AddInt32(1));
+        assert_sum_yields_type_mismatch(Transform::AddInt32(1) + Transform::AddInt256(I256::one()));
+        assert_sum_yields_type_mismatch(
+            Transform::AddUInt256(ONE_U256) + Transform::AddDecimal(Decimal::one()),
+        );
+    }
+
     #[test]
     fn addition_between_mismatched_types_should_fail() {
         fn assert_yields_type_mismatch_error(stored_value: StoredValue) {
@@ -0,0 +1,34 @@
+use log::{Level, LevelFilter, Metadata};
+
+use casperlabs_engine_shared::logging::{self, Settings};
+
+const LOG_MSG_TARGET: &str = "casperlabs_logging";
+const METRIC_TARGET: &str = "METRIC";
+
+fn is_enabled(target: &str, level: Level) -> bool {
+    let metadata = Metadata::builder().target(target).level(level).build();
+    log::logger().enabled(&metadata)
+}
+
+#[test]
+fn should_reload_settings() {
+    assert!(!logging::reload(Settings::new(LevelFilter::Trace)));
+
+    logging::initialize(Settings::new(LevelFilter::Off)).expect("should initialize");
+    assert_eq!(log::max_level(), LevelFilter::Off);
+    assert!(!is_enabled(LOG_MSG_TARGET, Level::Error));
+    assert!(!is_enabled(METRIC_TARGET, Level::Info));
+
+    assert!(logging::reload(
+        Settings::new(LevelFilter::Trace).with_metrics_enabled(true)
+    ));
+    assert_eq!(log::max_level(), LevelFilter::Trace);
+    assert!(is_enabled(LOG_MSG_TARGET, Level::Trace));
+    assert!(is_enabled(METRIC_TARGET, Level::Info));
+
+    assert!(logging::reload(Settings::new(LevelFilter::Warn)));
+    assert_eq!(log::max_level(), LevelFilter::Warn);
+    assert!(is_enabled(LOG_MSG_TARGET, Level::Warn));
+    assert!(!is_enabled(LOG_MSG_TARGET, Level::Info));
+    assert!(!is_enabled(METRIC_TARGET, Level::Info));
+}
@@ -1,4 +1,4 @@
-use std::sync;
+use std::{io, sync};
 
 use failure::Fail;
 use lmdb as lmdb_external;
@@ -17,6 +17,9 @@ pub enum Error {
 
     #[fail(display = "Another thread panicked while holding a lock")]
     Poison,
+
+    #[fail(display = "I/O error: {:?}", _0)]
+    Io(io::ErrorKind),
 }
 
 impl wasmi::HostError for Error {}
@@ -10,7 +10,7 @@ use types::{Key, ProtocolVersion};
 
 use crate::{
     error::{self, in_memory},
-    global_state::{commit, CommitResult, StateProvider, StateReader},
+    global_state::{commit, CommitResult, StateProvider, StateReader, StorageStats},
     protocol_data::ProtocolData,
     protocol_data_store::in_memory::InMemoryProtocolDataStore,
     store::Store,
@@ -194,6 +194,17 @@ impl StateProvider for InMemoryGlobalState {
         Ok(result)
     }
 
+    fn get_all_protocol_data(&self) -> Result<Vec<(ProtocolVersion, ProtocolData)>, Self::Error> {
+        let txn = self.environment.create_read_txn()?;
+        let result = self.protocol_data_store.get_all(&txn)?;
+        txn.commit()?;
+        Ok(result)
+    }
+
+    fn storage_stats(&self) -> Result<Option<StorageStats>, Self::Error> {
+        Ok(None)
+    }
+
     fn empty_root(&self) -> Blake2bHash {
         self.empty_root_hash
     }
@@ -10,7 +10,7 @@ use types::{Key, ProtocolVersion};
 
 use crate::{
     error,
-    global_state::{commit, CommitResult, StateProvider, StateReader},
+    global_state::{commit, CommitResult, StateProvider, StateReader, StorageStats},
     protocol_data::ProtocolData,
     protocol_data_store::lmdb::LmdbProtocolDataStore,
     store::Store,
@@ -22,6 +22,7 @@ use crate::{
     },
 };
 
+#[derive(Clone)]
 pub struct LmdbGlobalState {
     pub environment: Arc<LmdbEnvironment>,
     pub trie_store: Arc<LmdbTrieStore>,
@@ -154,6 +155,17 @@ impl StateProvider for LmdbGlobalState {
         Ok(result)
     }
 
+    fn get_all_protocol_data(&self) -> Result<Vec<(ProtocolVersion, ProtocolData)>, Self::Error> {
+        let txn = self.environment.create_read_txn()?;
+        let result = self.protocol_data_store.get_all(&txn)?;
+        txn.commit()?;
+        Ok(result)
+    }
+
+    fn storage_stats(&self) -> Result<Option<StorageStats>, Self::Error> {
+        Ok(Some(self.environment.stats()?))
+    }
+
     fn empty_root(&self) -> Blake2bHash {
         self.empty_root_hash
     }
@@ -80,10 +80,26 @@ impl From<transform::Error> for CommitResult {
                 CommitResult::TypeMismatch(type_mismatch)
             }
             transform::Error::Serialization(error) => CommitResult::Serialization(error),
+            // Only `Transform::checked_apply` reports overflows; committed transforms are
+            // applied with `Transform::apply`, which wraps.
+            transform::Error::Overflow => unreachable!("committed additions wrap"),
         }
     }
 }
 
+/// Size and page usage of the storage backing a [`StateProvider`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct StorageStats {
+    /// Total size in bytes of the files in the data directory.
+    pub data_dir_size: u64,
+    /// Size in bytes of a single page.
+    pub page_size: u64,
+    /// Number of pages currently in use.
+    pub used_pages: u64,
+    /// Maximum number of pages available.
+    pub total_pages: u64,
+}
+
 pub trait StateProvider {
     type Error;
     type Reader: StateReader<Key, StoredValue, Error = Self::Error>;
@@ -111,6 +127,13 @@ pub trait StateProvider {
         protocol_version: ProtocolVersion,
     ) -> Result<Option<ProtocolData>, Self::Error>;
 
+    /// Returns all stored protocol data along with their protocol versions.
+    fn get_all_protocol_data(&self) -> Result<Vec<(ProtocolVersion, ProtocolData)>, Self::Error>;
+
+    /// Returns size and page usage of the underlying storage, or `None` if it isn't backed by
+    /// files on disk.
+    fn storage_stats(&self) -> Result<Option<StorageStats>, Self::Error>;
+
     fn empty_root(&self) -> Blake2bHash;
 }
 
@@ -1,11 +1,101 @@
 use engine_wasm_prep::wasm_costs::{WasmCosts, WASM_COSTS_SERIALIZED_LENGTH};
 use types::{
-    bytesrepr::{self, FromBytes, ToBytes},
-    AccessRights, URef, UREF_SERIALIZED_LENGTH,
+    bytesrepr::{
+        self, FromBytes, ToBytes, U32_SERIALIZED_LENGTH, U64_SERIALIZED_LENGTH,
+        U8_SERIALIZED_LENGTH,
+    },
+    AccessRights, BlockTime, URef, UREF_SERIALIZED_LENGTH,
 };
 
-const PROTOCOL_DATA_SERIALIZED_LENGTH: usize =
-    WASM_COSTS_SERIALIZED_LENGTH + UREF_SERIALIZED_LENGTH + UREF_SERIALIZED_LENGTH;
+const ACTIVATION_POINT_SERIALIZED_LENGTH: usize = U8_SERIALIZED_LENGTH + U64_SERIALIZED_LENGTH;
+
+const PROTOCOL_DATA_SERIALIZED_LENGTH: usize = WASM_COSTS_SERIALIZED_LENGTH
+    + UREF_SERIALIZED_LENGTH
+    + UREF_SERIALIZED_LENGTH
+    + U32_SERIALIZED_LENGTH
+    + ACTIVATION_POINT_SERIALIZED_LENGTH;
+
+const RANK_TAG: u8 = 0;
+const BLOCK_TIME_TAG: u8 = 1;
+
+/// The maximum depth of nested contract calls used where none is configured.
+pub const DEFAULT_MAX_CALL_DEPTH: u32 = 32;
+
+/// The first block a protocol version applies to, given either by rank (i.e. block height) or by
+/// block time.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ActivationPoint {
+    Rank(u64),
+    BlockTime(BlockTime),
+}
+
+/// Active from the first block on.
+impl Default for ActivationPoint {
+    fn default() -> Self {
+        ActivationPoint::Rank(0)
+    }
+}
+
+impl ActivationPoint {
+    /// Returns `true` if a block with the given rank and block time is at or past this point.
+    pub fn is_reached(&self, rank: u64, block_time: BlockTime) -> bool {
+        match self {
+            ActivationPoint::Rank(activation_rank) => rank >= *activation_rank,
+            ActivationPoint::BlockTime(activation_time) => block_time >= *activation_time,
+        }
+    }
+
+    /// Returns `true` if a protocol version activating at this point may follow one activating at
+    /// `previous`.  Points of the same kind may not go back.  Points of different kinds can't be
+    /// ordered, so only the genesis point, i.e. rank zero, may be followed by a point of either
+    /// kind.
+    pub fn can_follow(&self, previous: &ActivationPoint) -> bool {
+        match (previous, self) {
+            (ActivationPoint::Rank(previous_rank), ActivationPoint::Rank(rank)) => {
+                rank >= previous_rank
+            }
+            (ActivationPoint::BlockTime(previous_time), ActivationPoint::BlockTime(time)) => {
+                time >= previous_time
+            }
+            (ActivationPoint::Rank(0), ActivationPoint::BlockTime(_)) => true,
+            _ => false,
+        }
+    }
+}
+
+impl ToBytes for ActivationPoint {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut ret: Vec<u8> = Vec::with_capacity(ACTIVATION_POINT_SERIALIZED_LENGTH);
+        match self {
+            ActivationPoint::Rank(rank) => {
+                ret.push(RANK_TAG);
+                ret.append(&mut rank.to_bytes()?);
+            }
+            ActivationPoint::BlockTime(block_time) => {
+                ret.push(BLOCK_TIME_TAG);
+                ret.append(&mut block_time.to_bytes()?);
+            }
+        }
+        Ok(ret)
+    }
+}
+
+impl FromBytes for ActivationPoint {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (tag, rem): (u8, &[u8]) = FromBytes::from_bytes(bytes)?;
+        match tag {
+            RANK_TAG => {
+                let (rank, rem) = FromBytes::from_bytes(rem)?;
+                Ok((ActivationPoint::Rank(rank), rem))
+            }
+            BLOCK_TIME_TAG => {
+                let (block_time, rem) = FromBytes::from_bytes(rem)?;
+                Ok((ActivationPoint::BlockTime(block_time), rem))
+            }
+            _ => Err(bytesrepr::Error::Formatting),
+        }
+    }
+}
 
 /// Represents a protocol's data. Intended to be associated with a given protocol version.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -13,6 +103,8 @@ pub struct ProtocolData {
     wasm_costs: WasmCosts,
     mint: URef,
     proof_of_stake: URef,
+    max_call_depth: u32,
+    activation_point: ActivationPoint,
 }
 
 /// Provides a default instance with non existing urefs and empty costs table.
@@ -25,20 +117,35 @@ impl Default for ProtocolData {
             wasm_costs: WasmCosts::default(),
             mint: URef::new([0; 32], AccessRights::READ),
             proof_of_stake: URef::new([0; 32], AccessRights::READ),
+            max_call_depth: DEFAULT_MAX_CALL_DEPTH,
+            activation_point: ActivationPoint::default(),
         }
     }
 }
 
 impl ProtocolData {
     /// Creates a new [`ProtocolData`] value from a given [`WasmCosts`] value.
-    pub fn new(wasm_costs: WasmCosts, mint: URef, proof_of_stake: URef) -> Self {
+    pub fn new(
+        wasm_costs: WasmCosts,
+        mint: URef,
+        proof_of_stake: URef,
+        max_call_depth: u32,
+    ) -> Self {
         ProtocolData {
             wasm_costs,
             mint,
             proof_of_stake,
+            max_call_depth,
+            activation_point: ActivationPoint::default(),
         }
     }
 
+    /// Sets the point from which the protocol version applies to executed blocks.
+    pub fn with_activation_point(mut self, activation_point: ActivationPoint) -> Self {
+        self.activation_point = activation_point;
+        self
+    }
+
     /// Gets the [`WasmCosts`] value from a given [`ProtocolData`] value.
     pub fn wasm_costs(&self) -> &WasmCosts {
         &self.wasm_costs
@@ -52,6 +159,17 @@ impl ProtocolData {
         self.proof_of_stake
     }
 
+    /// Gets the maximum number of stored contracts which may be executing at once, i.e. the
+    /// maximum depth of nested `call_contract` calls.
+    pub fn max_call_depth(&self) -> u32 {
+        self.max_call_depth
+    }
+
+    /// Gets the point from which the protocol version applies to executed blocks.
+    pub fn activation_point(&self) -> ActivationPoint {
+        self.activation_point
+    }
+
     /// Retrieves all valid system contracts stored in protocol version
     pub fn system_contracts(&self) -> Vec<URef> {
         let mut vec = Vec::with_capacity(2);
@@ -71,6 +189,8 @@ impl ToBytes for ProtocolData {
         ret.append(&mut self.wasm_costs.to_bytes()?);
         ret.append(&mut self.mint.to_bytes()?);
         ret.append(&mut self.proof_of_stake.to_bytes()?);
+        ret.append(&mut self.max_call_depth.to_bytes()?);
+        ret.append(&mut self.activation_point.to_bytes()?);
         Ok(ret)
     }
 }
@@ -80,11 +200,24 @@ impl FromBytes for ProtocolData {
         let (wasm_costs, rem): (WasmCosts, &[u8]) = FromBytes::from_bytes(bytes)?;
         let (mint_reference, rem): (URef, &[u8]) = FromBytes::from_bytes(rem)?;
         let (proof_of_stake_reference, rem): (URef, &[u8]) = FromBytes::from_bytes(rem)?;
+        // Protocol data stored before these fields were added ends here, so they're defaulted.
+        let (max_call_depth, rem): (u32, &[u8]) = if rem.is_empty() {
+            (DEFAULT_MAX_CALL_DEPTH, rem)
+        } else {
+            FromBytes::from_bytes(rem)?
+        };
+        let (activation_point, rem): (ActivationPoint, &[u8]) = if rem.is_empty() {
+            (ActivationPoint::default(), rem)
+        } else {
+            FromBytes::from_bytes(rem)?
+        };
         Ok((
             ProtocolData {
                 wasm_costs,
                 mint: mint_reference,
                 proof_of_stake: proof_of_stake_reference,
+                max_call_depth,
+                activation_point,
             },
             rem,
         ))
@@ -93,23 +226,34 @@ impl FromBytes for ProtocolData {
 
 #[cfg(test)]
 pub(crate) mod gens {
-    use proptest::prop_compose;
+    use proptest::{arbitrary::any, prop_compose, prop_oneof, strategy::Strategy};
 
     use engine_wasm_prep::wasm_costs::gens as wasm_costs_gens;
-    use types::gens;
+    use types::{gens, BlockTime};
 
-    use super::ProtocolData;
+    use super::{ActivationPoint, ProtocolData};
+
+    pub fn activation_point_arb() -> impl Strategy<Value = ActivationPoint> {
+        prop_oneof![
+            any::<u64>().prop_map(ActivationPoint::Rank),
+            any::<u64>().prop_map(|time| ActivationPoint::BlockTime(BlockTime::new(time))),
+        ]
+    }
 
     prop_compose! {
         pub fn protocol_data_arb()(
             wasm_costs in wasm_costs_gens::wasm_costs_arb(),
             mint in gens::uref_arb(),
             proof_of_stake in gens::uref_arb(),
+            max_call_depth in any::<u32>(),
+            activation_point in activation_point_arb(),
         ) -> ProtocolData {
             ProtocolData {
                 wasm_costs,
                 mint,
                 proof_of_stake,
+                max_call_depth,
+                activation_point,
             }
         }
     }
@@ -120,9 +264,12 @@ mod tests {
     use proptest::proptest;
 
     use engine_wasm_prep::wasm_costs::WasmCosts;
-    use types::{bytesrepr, AccessRights, URef};
+    use types::{
+        bytesrepr::{self, ToBytes},
+        AccessRights, BlockTime, URef,
+    };
 
-    use super::{gens, ProtocolData};
+    use super::{gens, ActivationPoint, ProtocolData, DEFAULT_MAX_CALL_DEPTH};
 
     fn wasm_costs_mock() -> WasmCosts {
         WasmCosts {
@@ -160,25 +307,67 @@ mod tests {
             let costs = wasm_costs_mock();
             let mint_reference = URef::new([0u8; 32], AccessRights::READ_ADD_WRITE);
             let proof_of_stake_reference = URef::new([1u8; 32], AccessRights::READ_ADD_WRITE);
-            ProtocolData::new(costs, mint_reference, proof_of_stake_reference)
+            ProtocolData::new(
+                costs,
+                mint_reference,
+                proof_of_stake_reference,
+                DEFAULT_MAX_CALL_DEPTH,
+            )
         };
         let free = {
             let costs = wasm_costs_free();
             let mint_reference = URef::new([0u8; 32], AccessRights::READ_ADD_WRITE);
             let proof_of_stake_reference = URef::new([1u8; 32], AccessRights::READ_ADD_WRITE);
-            ProtocolData::new(costs, mint_reference, proof_of_stake_reference)
+            ProtocolData::new(
+                costs,
+                mint_reference,
+                proof_of_stake_reference,
+                DEFAULT_MAX_CALL_DEPTH,
+            )
+            .with_activation_point(ActivationPoint::BlockTime(BlockTime::new(1_000)))
         };
         bytesrepr::test_serialization_roundtrip(&mock);
         bytesrepr::test_serialization_roundtrip(&free);
     }
 
+    #[test]
+    fn should_default_fields_missing_from_stored_data() {
+        let mint_reference = URef::new([0u8; 32], AccessRights::READ_ADD_WRITE);
+        let proof_of_stake_reference = URef::new([1u8; 32], AccessRights::READ_ADD_WRITE);
+        let mut bytes = wasm_costs_mock().to_bytes().unwrap();
+        bytes.append(&mut mint_reference.to_bytes().unwrap());
+        bytes.append(&mut proof_of_stake_reference.to_bytes().unwrap());
+
+        let expected = ProtocolData::new(
+            wasm_costs_mock(),
+            mint_reference,
+            proof_of_stake_reference,
+            DEFAULT_MAX_CALL_DEPTH,
+        );
+        assert_eq!(bytesrepr::deserialize(bytes.clone()), Ok(expected));
+
+        bytes.append(&mut 7u32.to_bytes().unwrap());
+        let expected = ProtocolData::new(
+            wasm_costs_mock(),
+            mint_reference,
+            proof_of_stake_reference,
+            7,
+        );
+        assert_eq!(bytesrepr::deserialize(bytes), Ok(expected));
+    }
+
     #[test]
     fn should_return_all_system_contracts() {
         let mint_reference = URef::new([197u8; 32], AccessRights::READ_ADD_WRITE);
         let proof_of_stake_reference = URef::new([198u8; 32], AccessRights::READ_ADD_WRITE);
         let protocol_data = {
             let costs = wasm_costs_mock();
-            ProtocolData::new(costs, mint_reference, proof_of_stake_reference)
+            ProtocolData::new(
+                costs,
+                mint_reference,
+                proof_of_stake_reference,
+                DEFAULT_MAX_CALL_DEPTH,
+            )
         };
 
         let actual = {
@@ -200,7 +389,12 @@ mod tests {
         let proof_of_stake_reference = URef::new([0u8; 32], AccessRights::READ);
         let protocol_data = {
             let costs = wasm_costs_mock();
-            ProtocolData::new(costs, mint_reference, proof_of_stake_reference)
+            ProtocolData::new(
+                costs,
+                mint_reference,
+                proof_of_stake_reference,
+                DEFAULT_MAX_CALL_DEPTH,
+            )
         };
 
         let actual = {
@@ -213,6 +407,23 @@ mod tests {
         assert_eq!(actual[0], mint_reference);
     }
 
+    #[test]
+    fn should_compare_activation_points() {
+        let rank = ActivationPoint::Rank(10);
+        assert!(!rank.is_reached(9, BlockTime::new(100)));
+        assert!(rank.is_reached(10, BlockTime::new(0)));
+        assert!(rank.can_follow(&ActivationPoint::Rank(9)));
+        assert!(rank.can_follow(&rank));
+        assert!(!ActivationPoint::Rank(9).can_follow(&rank));
+
+        let time = ActivationPoint::BlockTime(BlockTime::new(100));
+        assert!(!time.is_reached(10, BlockTime::new(99)));
+        assert!(time.is_reached(0, BlockTime::new(100)));
+        assert!(time.can_follow(&ActivationPoint::default()));
+        assert!(!time.can_follow(&rank));
+        assert!(!rank.can_follow(&time));
+    }
+
     proptest! {
         #[test]
         fn should_serialize_and_deserialize_with_arbitrary_values(
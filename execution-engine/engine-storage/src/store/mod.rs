@@ -31,6 +31,23 @@ pub trait Store<K, V> {
         }
     }
 
+    fn get_all<T>(&self, txn: &T) -> Result<Vec<(K, V)>, Self::Error>
+    where
+        T: Readable<Handle = Self::Handle>,
+        K: FromBytes,
+        V: FromBytes,
+        Self::Error: From<T::Error>,
+    {
+        let handle = self.handle();
+        let mut ret = Vec::new();
+        for (key_bytes, value_bytes) in txn.read_all(handle)? {
+            let key = bytesrepr::deserialize(key_bytes)?;
+            let value = bytesrepr::deserialize(value_bytes)?;
+            ret.push((key, value));
+        }
+        Ok(ret)
+    }
+
     fn put<T>(&self, txn: &mut T, key: &K, value: &V) -> Result<(), Self::Error>
     where
         T: Writable<Handle = Self::Handle>,
@@ -47,3 +47,23 @@ where
     };
     Ok(Iterator::eq(items.values(), values.iter()))
 }
+
+// should be moved to the `store` module
+pub fn get_all_succeeds<'a, K, V, X, S>(
+    transaction_source: &'a X,
+    store: &S,
+    items: BTreeMap<K, V>,
+) -> Result<bool, S::Error>
+where
+    K: ToBytes + FromBytes + Ord,
+    V: ToBytes + FromBytes + PartialEq,
+    X: TransactionSource<'a, Handle = S::Handle>,
+    S: Store<K, V>,
+    S::Error: From<X::Error>,
+{
+    let mut txn: X::ReadWriteTransaction = transaction_source.create_read_write_txn()?;
+    store.put_many(&mut txn, items.iter())?;
+    let all_items: BTreeMap<K, V> = store.get_all(&txn)?.into_iter().collect();
+    txn.commit()?;
+    Ok(all_items == items)
+}
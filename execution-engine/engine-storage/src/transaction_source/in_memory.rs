@@ -18,6 +18,15 @@ type BytesMap = HashMap<Vec<u8>, Vec<u8>>;
 
 type PoisonError<'a> = sync::PoisonError<MutexGuard<'a, HashMap<Option<String>, BytesMap>>>;
 
+/// Returns the contents of `sub_view` as key-value pairs ordered by key.
+fn sorted_pairs(sub_view: Option<&BytesMap>) -> Vec<(Vec<u8>, Vec<u8>)> {
+    let mut ret: Vec<(Vec<u8>, Vec<u8>)> = sub_view
+        .map(|sub_view| sub_view.clone().into_iter().collect())
+        .unwrap_or_default();
+    ret.sort();
+    ret
+}
+
 /// A read transaction for the in-memory trie store.
 pub struct InMemoryReadTransaction {
     view: HashMap<Option<String>, BytesMap>,
@@ -52,6 +61,10 @@ impl Readable for InMemoryReadTransaction {
         };
         Ok(sub_view.get(&key.to_vec()).cloned())
     }
+
+    fn read_all(&self, handle: Self::Handle) -> Result<Vec<(Vec<u8>, Vec<u8>)>, Self::Error> {
+        Ok(sorted_pairs(self.view.get(&handle)))
+    }
 }
 
 /// A read-write transaction for the in-memory trie store.
@@ -97,6 +110,10 @@ impl<'a> Readable for InMemoryReadWriteTransaction<'a> {
         };
         Ok(sub_view.get(&key.to_vec()).cloned())
     }
+
+    fn read_all(&self, handle: Self::Handle) -> Result<Vec<(Vec<u8>, Vec<u8>)>, Self::Error> {
+        Ok(sorted_pairs(self.view.get(&handle)))
+    }
 }
 
 impl<'a> Writable for InMemoryReadWriteTransaction<'a> {
@@ -1,13 +1,27 @@
-use std::path::PathBuf;
+use std::{fs, os::raw::c_int, path::PathBuf, ptr};
 
-use lmdb::{self, Database, Environment, RoTransaction, RwTransaction, WriteFlags};
+use lmdb::{self, Cursor, Database, Environment, RoTransaction, RwTransaction, WriteFlags};
+use lmdb_sys::{mdb_env_info, mdb_env_stat, MDB_envinfo, MDB_stat, MDB_SUCCESS};
 
 use crate::{
     error,
+    global_state::StorageStats,
     transaction_source::{Readable, Transaction, TransactionSource, Writable},
     MAX_DBS,
 };
 
+fn read_all<T: lmdb::Transaction>(
+    txn: &T,
+    handle: Database,
+) -> Result<Vec<(Vec<u8>, Vec<u8>)>, lmdb::Error> {
+    let mut cursor = txn.open_ro_cursor(handle)?;
+    let ret = cursor
+        .iter_start()
+        .map(|(key, value)| (key.to_vec(), value.to_vec()))
+        .collect();
+    Ok(ret)
+}
+
 impl<'a> Transaction for RoTransaction<'a> {
     type Error = lmdb::Error;
 
@@ -26,6 +40,10 @@ impl<'a> Readable for RoTransaction<'a> {
             Err(e) => Err(e),
         }
     }
+
+    fn read_all(&self, handle: Self::Handle) -> Result<Vec<(Vec<u8>, Vec<u8>)>, Self::Error> {
+        read_all(self, handle)
+    }
 }
 
 impl<'a> Transaction for RwTransaction<'a> {
@@ -46,6 +64,10 @@ impl<'a> Readable for RwTransaction<'a> {
             Err(e) => Err(e),
         }
     }
+
+    fn read_all(&self, handle: Self::Handle) -> Result<Vec<(Vec<u8>, Vec<u8>)>, Self::Error> {
+        read_all(self, handle)
+    }
 }
 
 impl<'a> Writable for RwTransaction<'a> {
@@ -81,6 +103,55 @@ impl LmdbEnvironment {
     pub fn env(&self) -> &Environment {
         &self.env
     }
+
+    /// Returns the on-disk size and the page usage of the environment.
+    pub fn stats(&self) -> Result<StorageStats, error::Error> {
+        let mut stat = MDB_stat {
+            ms_psize: 0,
+            ms_depth: 0,
+            ms_branch_pages: 0,
+            ms_leaf_pages: 0,
+            ms_overflow_pages: 0,
+            ms_entries: 0,
+        };
+        let mut info = MDB_envinfo {
+            me_mapaddr: ptr::null_mut(),
+            me_mapsize: 0,
+            me_last_pgno: 0,
+            me_last_txnid: 0,
+            me_maxreaders: 0,
+            me_numreaders: 0,
+        };
+        // Safe as `self.env` is a valid open environment for as long as `self` lives, and both
+        // out-parameters are fully initialized structs owned by this stack frame.
+        unsafe {
+            lmdb_result(mdb_env_stat(self.env.env(), &mut stat))?;
+            lmdb_result(mdb_env_info(self.env.env(), &mut info))?;
+        }
+
+        let page_size = u64::from(stat.ms_psize);
+        let data_dir_size = fs::read_dir(&self.path)
+            .and_then(|entries| {
+                entries.try_fold(0, |size, entry| Ok(size + entry?.metadata()?.len()))
+            })
+            .map_err(|error| error::Error::Io(error.kind()))?;
+
+        Ok(StorageStats {
+            data_dir_size,
+            page_size,
+            // Page numbers are zero-based.
+            used_pages: info.me_last_pgno as u64 + 1,
+            total_pages: info.me_mapsize as u64 / page_size,
+        })
+    }
+}
+
+fn lmdb_result(error_code: c_int) -> Result<(), lmdb::Error> {
+    if error_code == MDB_SUCCESS {
+        Ok(())
+    } else {
+        Err(lmdb::Error::from_err_code(error_code))
+    }
 }
 
 impl<'a> TransactionSource<'a> for LmdbEnvironment {
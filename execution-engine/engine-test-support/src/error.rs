@@ -1,6 +1,6 @@
 use std::result;
 
-use engine_shared::TypeMismatch;
+use engine_shared::{json, TypeMismatch};
 use types::CLValueError;
 
 /// The error type returned by any casperlabs-engine-test-support operation.
@@ -31,5 +31,13 @@ impl From<TypeMismatch> for Error {
     }
 }
 
+impl From<json::Error> for Error {
+    fn from(error: json::Error) -> Self {
+        Error {
+            inner: error.to_string(),
+        }
+    }
+}
+
 /// A specialized `std::result::Result` for this crate.
 pub type Result<T> = result::Result<T, Error>;
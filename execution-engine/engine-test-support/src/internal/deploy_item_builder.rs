@@ -5,7 +5,7 @@ use engine_core::{
     engine_state::{deploy_item::DeployItem, executable_deploy_item::ExecutableDeployItem},
     DeployHash,
 };
-use types::{account::PublicKey, bytesrepr::ToBytes, URef};
+use types::{account::PublicKey, bytesrepr::ToBytes, NamedArg, URef};
 
 use crate::internal::utils;
 
@@ -35,8 +35,11 @@ impl DeployItemBuilder {
 
     pub fn with_payment_bytes(mut self, module_bytes: Vec<u8>, args: impl ArgsParser) -> Self {
         let args = Self::serialize_args(args);
-        self.deploy_item.payment_code =
-            Some(ExecutableDeployItem::ModuleBytes { module_bytes, args });
+        self.deploy_item.payment_code = Some(ExecutableDeployItem::ModuleBytes {
+            module_bytes,
+            args,
+            named_args: Vec::new(),
+        });
         self
     }
 
@@ -47,8 +50,11 @@ impl DeployItemBuilder {
 
     pub fn with_stored_payment_hash(mut self, hash: Vec<u8>, args: impl ArgsParser) -> Self {
         let args = Self::serialize_args(args);
-        self.deploy_item.payment_code =
-            Some(ExecutableDeployItem::StoredContractByHash { hash, args });
+        self.deploy_item.payment_code = Some(ExecutableDeployItem::StoredContractByHash {
+            hash,
+            args,
+            named_args: Vec::new(),
+        });
         self
     }
 
@@ -61,6 +67,7 @@ impl DeployItemBuilder {
         self.deploy_item.payment_code = Some(ExecutableDeployItem::StoredContractByURef {
             uref: uref_addr,
             args,
+            named_args: Vec::new(),
         });
         self
     }
@@ -70,6 +77,7 @@ impl DeployItemBuilder {
         self.deploy_item.payment_code = Some(ExecutableDeployItem::StoredContractByURef {
             uref: uref.addr().to_vec(),
             args,
+            named_args: Vec::new(),
         });
         self
     }
@@ -79,14 +87,18 @@ impl DeployItemBuilder {
         self.deploy_item.payment_code = Some(ExecutableDeployItem::StoredContractByName {
             name: uref_name.to_owned(),
             args,
+            named_args: Vec::new(),
         });
         self
     }
 
     pub fn with_session_bytes(mut self, module_bytes: Vec<u8>, args: impl ArgsParser) -> Self {
         let args = Self::serialize_args(args);
-        self.deploy_item.session_code =
-            Some(ExecutableDeployItem::ModuleBytes { module_bytes, args });
+        self.deploy_item.session_code = Some(ExecutableDeployItem::ModuleBytes {
+            module_bytes,
+            args,
+            named_args: Vec::new(),
+        });
         self
     }
 
@@ -97,8 +109,11 @@ impl DeployItemBuilder {
 
     pub fn with_stored_session_hash(mut self, hash: Vec<u8>, args: impl ArgsParser) -> Self {
         let args = Self::serialize_args(args);
-        self.deploy_item.session_code =
-            Some(ExecutableDeployItem::StoredContractByHash { hash, args });
+        self.deploy_item.session_code = Some(ExecutableDeployItem::StoredContractByHash {
+            hash,
+            args,
+            named_args: Vec::new(),
+        });
         self
     }
 
@@ -111,6 +126,7 @@ impl DeployItemBuilder {
         self.deploy_item.session_code = Some(ExecutableDeployItem::StoredContractByURef {
             uref: uref_addr,
             args,
+            named_args: Vec::new(),
         });
         self
     }
@@ -120,6 +136,7 @@ impl DeployItemBuilder {
         self.deploy_item.session_code = Some(ExecutableDeployItem::StoredContractByURef {
             uref: uref.addr().to_vec(),
             args,
+            named_args: Vec::new(),
         });
         self
     }
@@ -129,10 +146,31 @@ impl DeployItemBuilder {
         self.deploy_item.session_code = Some(ExecutableDeployItem::StoredContractByName {
             name: uref_name.to_owned(),
             args,
+            named_args: Vec::new(),
         });
         self
     }
 
+    pub fn with_payment_named_args(mut self, named_args: Vec<NamedArg>) -> Self {
+        *self
+            .deploy_item
+            .payment_code
+            .as_mut()
+            .expect("payment code should be set before its named args")
+            .named_args_mut() = named_args;
+        self
+    }
+
+    pub fn with_session_named_args(mut self, named_args: Vec<NamedArg>) -> Self {
+        *self
+            .deploy_item
+            .session_code
+            .as_mut()
+            .expect("session code should be set before its named args")
+            .named_args_mut() = named_args;
+        self
+    }
+
     pub fn with_authorization_keys<T: Clone + Into<PublicKey>>(
         mut self,
         authorization_keys: &[T],
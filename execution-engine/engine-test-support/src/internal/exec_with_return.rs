@@ -1,4 +1,9 @@
-use std::{cell::RefCell, collections::BTreeSet, convert::TryInto, rc::Rc};
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, BTreeSet},
+    convert::TryInto,
+    rc::Rc,
+};
 
 use contract::args_parser::ArgsParser;
 use engine_core::{
@@ -6,13 +11,16 @@ use engine_core::{
         executable_deploy_item::ExecutableDeployItem, execution_effect::ExecutionEffect,
         EngineConfig, EngineState,
     },
-    execution::{self, AddressGenerator},
+    execution::{self, AddressGenerator, CallStack},
     runtime::{self, Runtime},
     runtime_context::RuntimeContext,
 };
 use engine_grpc_server::engine_server::ipc_grpc::ExecutionEngineService;
 use engine_shared::{gas::Gas, newtypes::CorrelationId};
-use engine_storage::{global_state::StateProvider, protocol_data::ProtocolData};
+use engine_storage::{
+    global_state::StateProvider,
+    protocol_data::{ProtocolData, DEFAULT_MAX_CALL_DEPTH},
+};
 use engine_wasm_prep::Preprocessor;
 use types::{
     account::PublicKey, bytesrepr::FromBytes, BlockTime, CLTyped, CLValue, Key, Phase,
@@ -84,7 +92,7 @@ where
     let protocol_data = {
         let mint = builder.get_mint_contract_uref();
         let pos = builder.get_mint_contract_uref();
-        ProtocolData::new(*DEFAULT_WASM_COSTS, mint, pos)
+        ProtocolData::new(*DEFAULT_WASM_COSTS, mint, pos, DEFAULT_MAX_CALL_DEPTH)
     };
 
     let context = RuntimeContext::new(
@@ -92,6 +100,7 @@ where
         &mut named_keys,
         access_rights,
         arguments,
+        BTreeMap::new(),
         BTreeSet::new(),
         &account,
         base_key,
@@ -105,12 +114,14 @@ where
         correlation_id,
         phase,
         protocol_data,
+        CallStack::new(),
     );
 
     let wasm_bytes = utils::read_wasm_file_bytes(wasm_file);
     let deploy_item = ExecutableDeployItem::ModuleBytes {
         module_bytes: wasm_bytes,
         args: Vec::new(),
+        named_args: Vec::new(),
     };
 
     let wasm_costs = *DEFAULT_WASM_COSTS;
@@ -38,11 +38,22 @@ impl ExecuteRequestBuilder {
         self
     }
 
+    pub fn with_rank(mut self, rank: u64) -> Self {
+        self.execute_request.rank = Some(rank);
+        self
+    }
+
     pub fn with_protocol_version(mut self, protocol_version: ProtocolVersion) -> Self {
         self.execute_request.protocol_version = protocol_version;
         self
     }
 
+    /// Runs the scheduled calls which are due at the block time ahead of the deploys.
+    pub fn with_scheduled_calls(mut self) -> Self {
+        self.execute_request.run_scheduled_calls = true;
+        self
+    }
+
     pub fn build(self) -> ExecuteRequest {
         self.execute_request
     }
@@ -11,7 +11,9 @@ use num_traits::identities::Zero;
 
 use engine_core::engine_state::genesis::{GenesisAccount, GenesisConfig};
 use engine_shared::{motes::Motes, test_utils};
+use engine_storage::protocol_data::DEFAULT_MAX_CALL_DEPTH;
 use engine_wasm_prep::wasm_costs::WasmCosts;
+use proof_of_stake::PosParams;
 use types::{account::PublicKey, ProtocolVersion, U512};
 
 use super::{DEFAULT_ACCOUNT_ADDR, DEFAULT_ACCOUNT_INITIAL_BALANCE};
@@ -66,6 +68,8 @@ lazy_static! {
             pos_installer_bytes,
             DEFAULT_ACCOUNTS.clone(),
             *DEFAULT_WASM_COSTS,
+            DEFAULT_MAX_CALL_DEPTH,
+            PosParams::default(),
         )
     };
 }
@@ -1,11 +1,13 @@
+use engine_core::engine_state::{migration::Migration, upgrade::ActivationPoint};
 use engine_grpc_server::engine_server::{
     ipc::{
-        ChainSpec_ActivationPoint, ChainSpec_CostTable_WasmCosts, ChainSpec_UpgradePoint,
-        DeployCode, UpgradeRequest,
+        ChainSpec_ActivationPoint, ChainSpec_CostTable_WasmCosts, ChainSpec_Migration,
+        ChainSpec_ProofOfStakeParams, ChainSpec_UpgradePoint, DeployCode, UpgradeRequest,
     },
     state,
 };
 use engine_wasm_prep::wasm_costs::WasmCosts;
+use proof_of_stake::PosParams;
 use types::ProtocolVersion;
 
 pub struct UpgradeRequestBuilder {
@@ -14,6 +16,9 @@ pub struct UpgradeRequestBuilder {
     new_protocol_version: state::ProtocolVersion,
     upgrade_installer: DeployCode,
     new_costs: Option<ChainSpec_CostTable_WasmCosts>,
+    new_max_call_depth: u32,
+    new_pos_params: Option<ChainSpec_ProofOfStakeParams>,
+    migrations: Vec<ChainSpec_Migration>,
     activation_point: ChainSpec_ActivationPoint,
 }
 
@@ -58,12 +63,23 @@ impl UpgradeRequestBuilder {
         self
     }
 
-    pub fn with_activation_point(mut self, rank: u64) -> Self {
-        self.activation_point = {
-            let mut ret = ChainSpec_ActivationPoint::new();
-            ret.set_rank(rank);
-            ret
-        };
+    pub fn with_new_max_call_depth(mut self, max_call_depth: u32) -> Self {
+        self.new_max_call_depth = max_call_depth;
+        self
+    }
+
+    pub fn with_new_pos_params(mut self, pos_params: PosParams) -> Self {
+        self.new_pos_params = Some(pos_params.into());
+        self
+    }
+
+    pub fn with_migration(mut self, migration: Migration) -> Self {
+        self.migrations.push(migration.into());
+        self
+    }
+
+    pub fn with_activation_point(mut self, activation_point: ActivationPoint) -> Self {
+        self.activation_point = activation_point.into();
         self
     }
 
@@ -79,6 +95,11 @@ impl UpgradeRequestBuilder {
                 upgrade_point.set_new_costs(cost_table);
             }
         }
+        upgrade_point.set_new_max_call_depth(self.new_max_call_depth);
+        if let Some(new_pos_params) = self.new_pos_params {
+            upgrade_point.set_new_pos_params(new_pos_params);
+        }
+        upgrade_point.set_migrations(self.migrations.into());
         upgrade_point.set_protocol_version(self.new_protocol_version);
         upgrade_point.set_upgrade_installer(self.upgrade_installer);
 
@@ -97,6 +118,9 @@ impl Default for UpgradeRequestBuilder {
             new_protocol_version: Default::default(),
             upgrade_installer: Default::default(),
             new_costs: None,
+            new_max_call_depth: 0,
+            new_pos_params: None,
+            migrations: Vec::new(),
             activation_point: Default::default(),
         }
     }
@@ -14,6 +14,8 @@ use engine_shared::{
     account::Account, additive_map::AdditiveMap, gas::Gas, stored_value::StoredValue,
     transform::Transform,
 };
+use engine_storage::protocol_data::DEFAULT_MAX_CALL_DEPTH;
+use proof_of_stake::PosParams;
 use types::Key;
 
 use crate::internal::{
@@ -116,6 +118,8 @@ pub fn create_genesis_config(accounts: Vec<GenesisAccount>) -> GenesisConfig {
         proof_of_stake_installer_bytes,
         accounts,
         wasm_costs,
+        DEFAULT_MAX_CALL_DEPTH,
+        PosParams::default(),
     )
 }
 
@@ -1,5 +1,5 @@
 use std::{
-    collections::HashMap,
+    collections::{BTreeMap, HashMap},
     convert::{TryFrom, TryInto},
     ffi::OsStr,
     fs,
@@ -14,15 +14,18 @@ use log::LevelFilter;
 
 use engine_core::{
     engine_state::{
-        execute_request::ExecuteRequest, execution_result::ExecutionResult, genesis::GenesisConfig,
+        execute_request::{ExecuteRequest, ExecuteResults},
+        execution_result::ExecutionResult,
+        genesis::{GenesisConfig, POS_STAKES},
         EngineConfig, EngineState, SYSTEM_ACCOUNT_ADDR,
     },
     execution,
 };
 use engine_grpc_server::engine_server::{
     ipc::{
-        CommitRequest, CommitResponse, GenesisResponse, QueryRequest, UpgradeRequest,
-        UpgradeResponse,
+        CommitRequest, CommitResponse, DistributeRewardsRequest, DistributeRewardsResponse,
+        GenesisResponse, PosParamsRequest, QueryRequest, SlashRequest, SlashResponse,
+        UnbondPayoutRequest, UnbondPayoutResponse, UpgradeRequest, UpgradeResponse,
     },
     ipc_grpc::ExecutionEngineService,
     mappings::{MappingError, TransformMap},
@@ -45,10 +48,11 @@ use engine_storage::{
     transaction_source::lmdb::LmdbEnvironment,
     trie_store::lmdb::LmdbTrieStore,
 };
+use proof_of_stake::PosParams;
 use types::{
     account::PublicKey,
     bytesrepr::{self, ToBytes},
-    CLValue, Key, URef, U512,
+    CLValue, Key, ProtocolVersion, URef, U512,
 };
 
 use crate::internal::utils;
@@ -71,6 +75,8 @@ pub struct WasmTestBuilder<S> {
     engine_state: Rc<EngineState<S>>,
     /// [`ExecutionResult`] is wrapped in [`Rc`] to work around a missing [`Clone`] implementation
     exec_responses: Vec<Vec<Rc<ExecutionResult>>>,
+    /// Results of the scheduled calls run ahead of the deploys of each exec call
+    scheduled_call_responses: Vec<Vec<Rc<ExecutionResult>>>,
     upgrade_responses: Vec<UpgradeResponse>,
     genesis_hash: Option<Vec<u8>>,
     post_state_hash: Option<Vec<u8>>,
@@ -110,6 +116,7 @@ impl Default for InMemoryWasmTestBuilder {
         WasmTestBuilder {
             engine_state: Rc::new(engine_state),
             exec_responses: Vec::new(),
+            scheduled_call_responses: Vec::new(),
             upgrade_responses: Vec::new(),
             genesis_hash: None,
             post_state_hash: None,
@@ -130,6 +137,7 @@ impl<S> Clone for WasmTestBuilder<S> {
         WasmTestBuilder {
             engine_state: Rc::clone(&self.engine_state),
             exec_responses: self.exec_responses.clone(),
+            scheduled_call_responses: self.scheduled_call_responses.clone(),
             upgrade_responses: self.upgrade_responses.clone(),
             genesis_hash: self.genesis_hash.clone(),
             post_state_hash: self.post_state_hash.clone(),
@@ -197,6 +205,7 @@ impl LmdbWasmTestBuilder {
         WasmTestBuilder {
             engine_state: Rc::new(engine_state),
             exec_responses: Vec::new(),
+            scheduled_call_responses: Vec::new(),
             upgrade_responses: Vec::new(),
             genesis_hash: None,
             post_state_hash: None,
@@ -257,6 +266,7 @@ impl LmdbWasmTestBuilder {
         WasmTestBuilder {
             engine_state: Rc::new(engine_state),
             exec_responses: Vec::new(),
+            scheduled_call_responses: Vec::new(),
             upgrade_responses: Vec::new(),
             genesis_hash: None,
             post_state_hash: Some(post_state_hash),
@@ -292,6 +302,7 @@ where
         WasmTestBuilder {
             engine_state: result.0.engine_state,
             exec_responses: Vec::new(),
+            scheduled_call_responses: Vec::new(),
             upgrade_responses: Vec::new(),
             genesis_hash: result.0.genesis_hash,
             post_state_hash: result.0.post_state_hash,
@@ -392,26 +403,44 @@ where
             .run_execute(CorrelationId::new(), exec_request);
         assert!(exec_response.is_ok());
         // Parse deploy results
-        let execution_results = exec_response.as_ref().unwrap();
+        let ExecuteResults {
+            scheduled_call_results,
+            deploy_results,
+        } = exec_response.unwrap();
         // Cache transformations
         self.transforms.extend(
-            execution_results
+            deploy_results
                 .iter()
                 .map(|res| res.effect().transforms.clone()),
         );
+        self.scheduled_call_responses
+            .push(scheduled_call_results.into_iter().map(Rc::new).collect());
         self.exec_responses
-            .push(exec_response.unwrap().into_iter().map(Rc::new).collect());
+            .push(deploy_results.into_iter().map(Rc::new).collect());
         self
     }
 
-    /// Commit effects of previous exec call on the latest post-state hash.
+    /// Commit effects of previous exec call on the latest post-state hash, preceded by those of the
+    /// scheduled calls it ran.
     pub fn commit(&mut self) -> &mut Self {
         let prestate_hash = self
             .post_state_hash
             .clone()
             .expect("Should have genesis hash");
 
-        let effects = self.transforms.last().cloned().unwrap_or_default();
+        let mut effects = AdditiveMap::new();
+        let scheduled_call_effects = self
+            .scheduled_call_responses
+            .last()
+            .into_iter()
+            .flatten()
+            .map(|result| result.effect().transforms.clone());
+        let deploy_effects = self.transforms.last().cloned();
+        for transforms in scheduled_call_effects.chain(deploy_effects) {
+            for (key, transform) in transforms {
+                effects.insert_add(key, transform);
+            }
+        }
 
         self.commit_effects(prestate_hash, effects)
     }
@@ -483,6 +512,105 @@ where
         self
     }
 
+    /// Runs a distribute rewards request on the latest post-state hash and, if it succeeds,
+    /// overwrites the cached post state hash with the new one.
+    pub fn distribute_rewards_with_request(
+        &mut self,
+        distribute_rewards_request: &mut DistributeRewardsRequest,
+    ) -> DistributeRewardsResponse {
+        let hash = self
+            .post_state_hash
+            .clone()
+            .expect("expected post_state_hash");
+        distribute_rewards_request.set_parent_state_hash(hash);
+
+        let distribute_rewards_response = self
+            .engine_state
+            .distribute_rewards(RequestOptions::new(), distribute_rewards_request.clone())
+            .wait_drop_metadata()
+            .expect("should distribute rewards");
+
+        if distribute_rewards_response.has_success() {
+            let commit_success = distribute_rewards_response.get_success();
+            self.post_state_hash = Some(commit_success.get_poststate_hash().to_vec());
+            let bonded_validators = commit_success
+                .get_bonded_validators()
+                .iter()
+                .cloned()
+                .map(TryInto::try_into)
+                .collect::<Result<HashMap<PublicKey, U512>, MappingError>>()
+                .unwrap();
+            self.bonded_validators.push(bonded_validators);
+        }
+
+        distribute_rewards_response
+    }
+
+    /// Runs a slash request on the latest post-state hash and, if it succeeds, overwrites the
+    /// cached post state hash with the new one.
+    pub fn slash_with_request(&mut self, slash_request: &mut SlashRequest) -> SlashResponse {
+        let hash = self
+            .post_state_hash
+            .clone()
+            .expect("expected post_state_hash");
+        slash_request.set_parent_state_hash(hash);
+
+        let slash_response = self
+            .engine_state
+            .slash(RequestOptions::new(), slash_request.clone())
+            .wait_drop_metadata()
+            .expect("should slash");
+
+        if slash_response.has_success() {
+            let commit_success = slash_response.get_success();
+            self.post_state_hash = Some(commit_success.get_poststate_hash().to_vec());
+            let bonded_validators = commit_success
+                .get_bonded_validators()
+                .iter()
+                .cloned()
+                .map(TryInto::try_into)
+                .collect::<Result<HashMap<PublicKey, U512>, MappingError>>()
+                .unwrap();
+            self.bonded_validators.push(bonded_validators);
+        }
+
+        slash_response
+    }
+
+    /// Runs an unbond payout request on the latest post-state hash and, if it succeeds, overwrites
+    /// the cached post state hash with the new one.
+    pub fn unbond_payout_with_request(
+        &mut self,
+        unbond_payout_request: &mut UnbondPayoutRequest,
+    ) -> UnbondPayoutResponse {
+        let hash = self
+            .post_state_hash
+            .clone()
+            .expect("expected post_state_hash");
+        unbond_payout_request.set_parent_state_hash(hash);
+
+        let unbond_payout_response = self
+            .engine_state
+            .unbond_payout(RequestOptions::new(), unbond_payout_request.clone())
+            .wait_drop_metadata()
+            .expect("should pay out unbonds");
+
+        if unbond_payout_response.has_success() {
+            let commit_success = unbond_payout_response.get_success();
+            self.post_state_hash = Some(commit_success.get_poststate_hash().to_vec());
+            let bonded_validators = commit_success
+                .get_bonded_validators()
+                .iter()
+                .cloned()
+                .map(TryInto::try_into)
+                .collect::<Result<HashMap<PublicKey, U512>, MappingError>>()
+                .unwrap();
+            self.bonded_validators.push(bonded_validators);
+        }
+
+        unbond_payout_response
+    }
+
     /// Expects a successful run and caches transformations
     pub fn expect_success(&mut self) -> &mut Self {
         // Check first result, as only first result is interesting for a simple test
@@ -571,6 +699,12 @@ where
         self.exec_responses.len()
     }
 
+    /// Returns the results of the scheduled calls run ahead of the deploys of the exec call at
+    /// `index`.
+    pub fn get_scheduled_call_response(&self, index: usize) -> Option<&Vec<Rc<ExecutionResult>>> {
+        self.scheduled_call_responses.get(index)
+    }
+
     pub fn get_upgrade_response(&self, index: usize) -> Option<&UpgradeResponse> {
         self.upgrade_responses.get(index)
     }
@@ -589,6 +723,40 @@ where
             .expect("should find PoS URef")
     }
 
+    /// Returns the stakes of the bonded validators, as stored by the PoS contract.
+    pub fn get_pos_stakes(&self) -> BTreeMap<PublicKey, U512> {
+        let pos_contract: Key = self
+            .pos_contract_uref
+            .expect("should have pos contract uref")
+            .into();
+        let stored_value = self
+            .query(None, pos_contract, &[POS_STAKES])
+            .expect("should find PoS stakes");
+        CLValue::try_from(stored_value)
+            .expect("should have CLValue")
+            .into_t()
+            .expect("should convert stakes")
+    }
+
+    /// Returns the PoS contract's parameters at the current post state hash, as reported over IPC.
+    pub fn get_pos_params(&self, protocol_version: ProtocolVersion) -> PosParams {
+        let mut request = PosParamsRequest::new();
+        request.set_parent_state_hash(self.get_post_state_hash());
+        request.set_protocol_version(protocol_version.into());
+        let mut response = self
+            .engine_state
+            .pos_params(RequestOptions::new(), request)
+            .wait_drop_metadata()
+            .expect("should get pos params response");
+        if !response.has_success() {
+            panic!("expected pos params success but received {:?}", response);
+        }
+        response
+            .take_success()
+            .try_into()
+            .expect("should convert pos params")
+    }
+
     pub fn get_purse_balance(&self, purse: URef) -> U512 {
         let mint = self.get_mint_contract_uref();
         let purse_addr = purse.addr();
@@ -0,0 +1,66 @@
+use std::{fs, path::Path, str::FromStr};
+
+use contract::args_parser::ArgsParser;
+use engine_shared::json;
+use types::{CLValue, CLValueError};
+
+use crate::{Error, Result};
+
+/// Contract arguments parsed from JSON, which can be passed anywhere an [`ArgsParser`] is
+/// expected.
+///
+/// The JSON must be an array with one entry per argument, each being an object holding the
+/// argument's type under `"cl_type"` and its value under `"value"`, e.g.
+///
+/// ```json
+/// [
+///     { "cl_type": "U512", "value": "1000000000" },
+///     { "cl_type": "Key", "value": "hash-0101010101010101010101010101010101010101010101010101010101010101" }
+/// ]
+/// ```
+///
+/// Large integers (`U128`, `U256` and `U512`) are given as decimal strings, and keys and
+/// [`URef`](types::URef)s in their prefixed hex form.  Arguments are parsed from a string with
+/// [`str::parse`], or read from a file with [`JsonArgs::from_file`].
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct JsonArgs {
+    args: Vec<CLValue>,
+}
+
+impl JsonArgs {
+    /// Parses the arguments from the JSON file at `path`.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<JsonArgs> {
+        let input = fs::read_to_string(path.as_ref()).map_err(|error| {
+            Error::from(format!(
+                "failed to read {}: {}",
+                path.as_ref().display(),
+                error
+            ))
+        })?;
+        input.parse()
+    }
+}
+
+impl FromStr for JsonArgs {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<JsonArgs> {
+        let json: serde_json::Value =
+            serde_json::from_str(input).map_err(|error| Error::from(error.to_string()))?;
+        let args = json
+            .as_array()
+            .ok_or_else(|| {
+                Error::from(format!("expected an array of arguments but found {}", json))
+            })?
+            .iter()
+            .map(|arg| json::cl_value_from_json(arg).map_err(Error::from))
+            .collect::<Result<_>>()?;
+        Ok(JsonArgs { args })
+    }
+}
+
+impl ArgsParser for JsonArgs {
+    fn parse(self) -> std::result::Result<Vec<CLValue>, CLValueError> {
+        Ok(self.args)
+    }
+}
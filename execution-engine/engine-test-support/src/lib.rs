@@ -69,15 +69,17 @@ mod error;
 // This module is not intended to be used by third party crates.
 #[doc(hidden)]
 pub mod internal;
+mod json_args;
 mod session;
 mod test_context;
 mod value;
 
 pub use code::Code;
 pub use error::{Error, Result};
+pub use json_args::JsonArgs;
 pub use session::{Session, SessionBuilder};
 pub use test_context::{TestContext, TestContextBuilder};
-pub use types::account::PublicKey;
+pub use types::{account::PublicKey, NamedArg};
 pub use value::Value;
 
 /// The address of a [`URef`](types::URef) (unforgeable reference) on the network.
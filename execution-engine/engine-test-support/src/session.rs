@@ -8,7 +8,7 @@ use crate::{
     internal::{
         DeployItemBuilder, ExecuteRequestBuilder, DEFAULT_PAYMENT, STANDARD_PAYMENT_CONTRACT,
     },
-    Code, PublicKey,
+    Code, NamedArg, PublicKey,
 };
 
 /// A single session, i.e. a single request to execute a single deploy within the test context.
@@ -49,6 +49,16 @@ impl SessionBuilder {
         self
     }
 
+    /// Returns `self` with the provided named session args set.
+    ///
+    /// These are available to the session code via `runtime::get_named_arg()`.  If no positional
+    /// session args were provided, the values of the named args are also available by position in
+    /// the order given here.
+    pub fn with_named_args(mut self, named_args: Vec<NamedArg>) -> Self {
+        self.di_builder = self.di_builder.with_session_named_args(named_args);
+        self
+    }
+
     /// Returns `self` with the provided payment code and args set.
     pub fn with_payment_code(mut self, code: Code, args: impl ArgsParser) -> Self {
         self.di_builder = match code {
@@ -1,6 +1,6 @@
 use std::convert::TryFrom;
 
-use engine_shared::stored_value::StoredValue;
+use engine_shared::{json, stored_value::StoredValue};
 use types::{
     bytesrepr::{FromBytes, ToBytes},
     CLTyped, CLValue,
@@ -33,4 +33,15 @@ impl Value {
         let cl_value = CLValue::try_from(self.inner)?;
         Ok(cl_value.into_t()?)
     }
+
+    /// Renders `self` as human-readable JSON.
+    pub fn to_json(&self) -> Result<serde_json::Value> {
+        Ok(json::stored_value_to_json(&self.inner)?)
+    }
+
+    /// Parses a `Value` from the JSON produced by [`Value::to_json`].
+    pub fn from_json(json: &serde_json::Value) -> Result<Value> {
+        let inner = json::stored_value_from_json(json)?;
+        Ok(Value { inner })
+    }
 }
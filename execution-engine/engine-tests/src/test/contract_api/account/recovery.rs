@@ -0,0 +1,304 @@
+use lazy_static::lazy_static;
+
+use engine_test_support::{
+    internal::{
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG,
+        DEFAULT_PAYMENT,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{
+    account::{PublicKey, RecoveryFailure, Weight},
+    ApiError, BlockTime, U512,
+};
+
+const CONTRACT_ACCOUNT_RECOVERY: &str = "account_recovery.wasm";
+const CONTRACT_TRANSFER_PURSE_TO_ACCOUNT: &str = "transfer_purse_to_account.wasm";
+const SET_RECOVERY: &str = "set_recovery";
+const REQUEST_RECOVERY: &str = "request_recovery";
+const CANCEL_RECOVERY: &str = "cancel_recovery";
+const RECOVER_ACCOUNT: &str = "recover_account";
+const ACCOUNT_1_ADDR: PublicKey = PublicKey::ed25519_from([1u8; 32]);
+const ACCOUNT_2_ADDR: PublicKey = PublicKey::ed25519_from([2u8; 32]);
+const REQUESTED_AT: u64 = 1_000;
+const DELAY: u64 = 500;
+
+lazy_static! {
+    static ref ACCOUNT_1_INITIAL_FUND: U512 = *DEFAULT_PAYMENT * 10;
+}
+
+/// Runs the account recovery contract from `account` at `block_time`, returning the engine's
+/// error message on failure.
+fn call_account_recovery(
+    builder: &mut InMemoryWasmTestBuilder,
+    account: PublicKey,
+    block_time: u64,
+    method_name: &str,
+) -> Result<(), String> {
+    let exec_request = ExecuteRequestBuilder::standard(
+        account,
+        CONTRACT_ACCOUNT_RECOVERY,
+        (method_name, DEFAULT_ACCOUNT_ADDR, DELAY),
+    )
+    .with_block_time(block_time)
+    .build();
+    builder.exec(exec_request).commit();
+
+    if !builder.is_error() {
+        return Ok(());
+    }
+
+    let response = builder
+        .get_exec_response(builder.get_exec_responses_count() - 1)
+        .expect("should have a response");
+    Err(utils::get_error_message(response))
+}
+
+fn assert_failure(result: Result<(), String>, failure: RecoveryFailure) {
+    let message = result.expect_err("should fail");
+    let expected = format!("Revert({})", u32::from(ApiError::User(failure as u16)));
+    assert!(message.contains(&expected), "{}", message);
+}
+
+/// Funds accounts 1 and 2.
+fn fund_accounts() -> InMemoryWasmTestBuilder {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+
+    for account in &[ACCOUNT_1_ADDR, ACCOUNT_2_ADDR] {
+        let exec_request = ExecuteRequestBuilder::standard(
+            DEFAULT_ACCOUNT_ADDR,
+            CONTRACT_TRANSFER_PURSE_TO_ACCOUNT,
+            (*account, *ACCOUNT_1_INITIAL_FUND),
+        )
+        .build();
+        builder.exec(exec_request).expect_success().commit();
+    }
+
+    builder
+}
+
+/// Funds accounts 1 and 2 and makes account 1 the only recovery key of the default account.
+fn setup() -> InMemoryWasmTestBuilder {
+    let mut builder = fund_accounts();
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_ACCOUNT_RECOVERY,
+        (SET_RECOVERY, ACCOUNT_1_ADDR, DELAY, 1u8),
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    builder
+}
+
+#[ignore]
+#[test]
+fn should_recover_account_after_delay() {
+    let mut builder = setup();
+
+    call_account_recovery(&mut builder, ACCOUNT_1_ADDR, REQUESTED_AT, REQUEST_RECOVERY)
+        .expect("should request recovery");
+
+    let account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    let recovery = account.recovery().expect("should have recovery");
+    assert_eq!(recovery.requested_at(), Some(BlockTime::new(REQUESTED_AT)));
+
+    assert_failure(
+        call_account_recovery(
+            &mut builder,
+            ACCOUNT_1_ADDR,
+            REQUESTED_AT + DELAY - 1,
+            RECOVER_ACCOUNT,
+        ),
+        RecoveryFailure::DelayNotElapsed,
+    );
+
+    call_account_recovery(
+        &mut builder,
+        ACCOUNT_1_ADDR,
+        REQUESTED_AT + DELAY,
+        RECOVER_ACCOUNT,
+    )
+    .expect("should recover account");
+
+    let account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    assert_eq!(
+        account.get_associated_key_weight(ACCOUNT_1_ADDR),
+        Some(&Weight::new(1))
+    );
+    assert_eq!(
+        account.get_associated_key_weight(DEFAULT_ACCOUNT_ADDR),
+        None
+    );
+    assert_eq!(account.recovery().and_then(|r| r.requested_at()), None);
+}
+
+#[ignore]
+#[test]
+fn should_cancel_recovery_within_delay() {
+    let mut builder = setup();
+
+    call_account_recovery(&mut builder, ACCOUNT_1_ADDR, REQUESTED_AT, REQUEST_RECOVERY)
+        .expect("should request recovery");
+    call_account_recovery(
+        &mut builder,
+        DEFAULT_ACCOUNT_ADDR,
+        REQUESTED_AT + DELAY - 1,
+        CANCEL_RECOVERY,
+    )
+    .expect("should cancel recovery");
+
+    assert_failure(
+        call_account_recovery(
+            &mut builder,
+            ACCOUNT_1_ADDR,
+            REQUESTED_AT + DELAY,
+            RECOVER_ACCOUNT,
+        ),
+        RecoveryFailure::NotRequested,
+    );
+
+    let account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    assert_eq!(
+        account.get_associated_key_weight(DEFAULT_ACCOUNT_ADDR),
+        Some(&Weight::new(1))
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_cancel_recovery_after_delay() {
+    let mut builder = setup();
+
+    call_account_recovery(&mut builder, ACCOUNT_1_ADDR, REQUESTED_AT, REQUEST_RECOVERY)
+        .expect("should request recovery");
+
+    assert_failure(
+        call_account_recovery(
+            &mut builder,
+            DEFAULT_ACCOUNT_ADDR,
+            REQUESTED_AT + DELAY,
+            CANCEL_RECOVERY,
+        ),
+        RecoveryFailure::DelayElapsed,
+    );
+}
+
+#[ignore]
+#[test]
+fn should_only_accept_recovery_request_from_recovery_key() {
+    let mut builder = setup();
+
+    assert_failure(
+        call_account_recovery(
+            &mut builder,
+            DEFAULT_ACCOUNT_ADDR,
+            REQUESTED_AT,
+            REQUEST_RECOVERY,
+        ),
+        RecoveryFailure::PermissionDenied,
+    );
+    assert_failure(
+        call_account_recovery(
+            &mut builder,
+            ACCOUNT_1_ADDR,
+            REQUESTED_AT + DELAY,
+            RECOVER_ACCOUNT,
+        ),
+        RecoveryFailure::NotRequested,
+    );
+}
+
+#[ignore]
+#[test]
+fn should_request_recovery_once_approvals_meet_threshold() {
+    let mut builder = fund_accounts();
+
+    // Either recovery key alone can't meet the threshold of 2.
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_ACCOUNT_RECOVERY,
+        (SET_RECOVERY, ACCOUNT_1_ADDR, DELAY, 2u8, ACCOUNT_2_ADDR),
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    call_account_recovery(&mut builder, ACCOUNT_1_ADDR, REQUESTED_AT, REQUEST_RECOVERY)
+        .expect("should approve recovery");
+    let account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    assert_eq!(account.recovery().and_then(|r| r.requested_at()), None);
+    assert_failure(
+        call_account_recovery(
+            &mut builder,
+            ACCOUNT_1_ADDR,
+            REQUESTED_AT + DELAY,
+            RECOVER_ACCOUNT,
+        ),
+        RecoveryFailure::NotRequested,
+    );
+
+    call_account_recovery(
+        &mut builder,
+        ACCOUNT_2_ADDR,
+        REQUESTED_AT + DELAY,
+        REQUEST_RECOVERY,
+    )
+    .expect("should request recovery");
+    let account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    assert_eq!(
+        account.recovery().and_then(|r| r.requested_at()),
+        Some(BlockTime::new(REQUESTED_AT + DELAY))
+    );
+
+    call_account_recovery(
+        &mut builder,
+        ACCOUNT_1_ADDR,
+        REQUESTED_AT + 2 * DELAY,
+        RECOVER_ACCOUNT,
+    )
+    .expect("should recover account");
+    let account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    assert_eq!(
+        account.get_associated_key_weight(ACCOUNT_2_ADDR),
+        Some(&Weight::new(1))
+    );
+    assert_eq!(
+        account.get_associated_key_weight(DEFAULT_ACCOUNT_ADDR),
+        None
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_set_unreachable_recovery_threshold() {
+    let mut builder = fund_accounts();
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_ACCOUNT_RECOVERY,
+        (SET_RECOVERY, ACCOUNT_1_ADDR, DELAY, 2u8),
+    )
+    .build();
+    builder.exec(exec_request).commit();
+    let response = builder
+        .get_exec_response(builder.get_exec_responses_count() - 1)
+        .expect("should have a response");
+    assert_failure(
+        Err(utils::get_error_message(response)),
+        RecoveryFailure::InvalidThreshold,
+    );
+}
@@ -0,0 +1,106 @@
+use engine_test_support::{
+    internal::{
+        utils, DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder,
+        DEFAULT_GENESIS_CONFIG, DEFAULT_PAYMENT, STANDARD_PAYMENT_CONTRACT,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{bytesrepr::ToBytes, ApiError, CLTyped, CLValue, NamedArg, U512};
+
+#[derive(Debug)]
+#[repr(u16)]
+enum GetNamedArgContractError {
+    MissingGreeting = 0,
+    MissingAmount,
+    InvalidGreeting,
+    InvalidAmount,
+}
+
+const CONTRACT_GET_NAMED_ARG: &str = "get_named_arg.wasm";
+const ARG_GREETING: &str = "greeting";
+const ARG_AMOUNT: &str = "amount";
+const GREETING_VALUE: &str = "Hello, world!";
+const AMOUNT_VALUE: u64 = 42;
+
+fn named_arg<T: CLTyped + ToBytes>(name: &str, value: T) -> NamedArg {
+    NamedArg::new(
+        name.to_string(),
+        CLValue::from_t(value).expect("should create CLValue"),
+    )
+}
+
+/// Calls get_named_arg contract and returns Ok(()) in case no error, or String which is the error
+/// message returned by the engine
+fn call_get_named_arg(named_args: Vec<NamedArg>) -> Result<(), String> {
+    let deploy = DeployItemBuilder::new()
+        .with_address(DEFAULT_ACCOUNT_ADDR)
+        .with_session_code(CONTRACT_GET_NAMED_ARG, ())
+        .with_session_named_args(named_args)
+        .with_payment_code(STANDARD_PAYMENT_CONTRACT, (*DEFAULT_PAYMENT,))
+        .with_authorization_keys(&[DEFAULT_ACCOUNT_ADDR])
+        .with_deploy_hash([1; 32])
+        .build();
+    let exec_request = ExecuteRequestBuilder::new().push_deploy(deploy).build();
+
+    let result = InMemoryWasmTestBuilder::default()
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .commit()
+        .finish();
+
+    if !result.builder().is_error() {
+        return Ok(());
+    }
+
+    let response = result
+        .builder()
+        .get_exec_response(0)
+        .expect("should have a response");
+
+    Err(utils::get_error_message(response))
+}
+
+fn expected_revert(error: GetNamedArgContractError) -> String {
+    format!("Revert({})", u32::from(ApiError::User(error as u16)))
+}
+
+#[ignore]
+#[test]
+fn should_use_passed_named_arguments() {
+    // The order of named args is irrelevant other than for positional access.
+    call_get_named_arg(vec![
+        named_arg(ARG_GREETING, String::from(GREETING_VALUE)),
+        named_arg(ARG_AMOUNT, U512::from(AMOUNT_VALUE)),
+    ])
+    .expect("should call get_named_arg with valid args");
+}
+
+#[ignore]
+#[test]
+fn should_revert_with_missing_named_arg() {
+    assert!(call_get_named_arg(vec![])
+        .expect_err("should fail")
+        .contains(&expected_revert(GetNamedArgContractError::MissingGreeting)));
+    assert!(
+        call_get_named_arg(vec![named_arg(ARG_GREETING, String::from(GREETING_VALUE))])
+            .expect_err("should fail")
+            .contains(&expected_revert(GetNamedArgContractError::MissingAmount))
+    );
+}
+
+#[ignore]
+#[test]
+fn should_revert_with_invalid_named_arg() {
+    assert!(call_get_named_arg(vec![
+        named_arg(ARG_GREETING, U512::from(AMOUNT_VALUE)),
+        named_arg(ARG_AMOUNT, U512::from(AMOUNT_VALUE)),
+    ])
+    .expect_err("should fail")
+    .contains(&expected_revert(GetNamedArgContractError::InvalidGreeting)));
+    assert!(call_get_named_arg(vec![
+        named_arg(ARG_GREETING, String::from(GREETING_VALUE)),
+        named_arg(ARG_AMOUNT, String::from("this is expected to be U512")),
+    ])
+    .expect_err("should fail")
+    .contains(&expected_revert(GetNamedArgContractError::InvalidAmount)));
+}
@@ -2,13 +2,17 @@ mod account;
 mod create_purse;
 mod get_arg;
 mod get_blocktime;
+mod get_call_stack;
 mod get_caller;
+mod get_named_arg;
 mod get_phase;
 mod list_named_keys;
 mod local_state;
 mod main_purse;
 mod mint_purse;
+mod reentrancy;
 mod revert;
+mod schedule_call;
 mod subcall;
 mod transfer;
 mod transfer_purse_to_account;
@@ -0,0 +1,142 @@
+use engine_core::engine_state::upgrade::ActivationPoint;
+use engine_storage::protocol_data::DEFAULT_MAX_CALL_DEPTH;
+use engine_test_support::{
+    internal::{
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, UpgradeRequestBuilder,
+        DEFAULT_GENESIS_CONFIG,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{ApiError, ProtocolVersion};
+
+const CONTRACT_REENTRANCY: &str = "reentrancy.wasm";
+const PROTOCOL_VERSION: ProtocolVersion = ProtocolVersion::V1_0_0;
+const DEFAULT_ACTIVATION_RANK: u64 = 1;
+const DEFAULT_ACTIVATION_POINT: ActivationPoint = ActivationPoint::Rank(DEFAULT_ACTIVATION_RANK);
+
+/// Runs the reentrancy contract, which makes `depth + 1` nested calls to the same stored
+/// contract, and returns the error message of the deploy if it failed.
+fn run_reentrancy(
+    builder: &mut InMemoryWasmTestBuilder,
+    protocol_version: ProtocolVersion,
+    depth: u32,
+    non_reentrant: bool,
+) -> Option<String> {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_REENTRANCY,
+        (depth, non_reentrant),
+    )
+    .with_protocol_version(protocol_version)
+    .with_rank(DEFAULT_ACTIVATION_RANK)
+    .build();
+    builder.exec(exec_request).commit();
+
+    let response = builder
+        .get_exec_response(builder.get_exec_responses_count() - 1)
+        .expect("should have a response");
+    if builder.is_error() {
+        Some(utils::get_error_message(response))
+    } else {
+        None
+    }
+}
+
+#[ignore]
+#[test]
+fn should_allow_reentry_up_to_max_call_depth() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+
+    let error = run_reentrancy(
+        &mut builder,
+        PROTOCOL_VERSION,
+        DEFAULT_MAX_CALL_DEPTH - 1,
+        false,
+    );
+    assert_eq!(error, None);
+}
+
+#[ignore]
+#[test]
+fn should_fail_beyond_max_call_depth() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+
+    let error = run_reentrancy(
+        &mut builder,
+        PROTOCOL_VERSION,
+        DEFAULT_MAX_CALL_DEPTH,
+        false,
+    )
+    .expect("should fail");
+    assert!(
+        error.contains(&format!("CallDepthExceeded({})", DEFAULT_MAX_CALL_DEPTH)),
+        "{}",
+        error
+    );
+}
+
+#[ignore]
+#[test]
+fn should_refuse_reentry_into_non_reentrant_contract() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+
+    // A single call doesn't re-enter the contract.
+    let error = run_reentrancy(&mut builder, PROTOCOL_VERSION, 0, true);
+    assert_eq!(error, None);
+
+    let error = run_reentrancy(&mut builder, PROTOCOL_VERSION, 1, true).expect("should fail");
+    assert!(
+        error.contains(&format!("Revert({})", u32::from(ApiError::Reentrancy))),
+        "{}",
+        error
+    );
+}
+
+#[ignore]
+#[test]
+fn should_use_max_call_depth_from_upgrade() {
+    const NEW_MAX_CALL_DEPTH: u32 = 3;
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+
+    let sem_ver = PROTOCOL_VERSION.value();
+    let new_protocol_version =
+        ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor, sem_ver.patch + 1);
+
+    let mut upgrade_request = UpgradeRequestBuilder::new()
+        .with_current_protocol_version(PROTOCOL_VERSION)
+        .with_new_protocol_version(new_protocol_version)
+        .with_new_max_call_depth(NEW_MAX_CALL_DEPTH)
+        .with_activation_point(DEFAULT_ACTIVATION_POINT)
+        .build();
+    builder.upgrade_with_upgrade_request(&mut upgrade_request);
+    let upgrade_response = builder
+        .get_upgrade_response(0)
+        .expect("should have response");
+    assert!(upgrade_response.has_success(), "expected success");
+
+    let error = run_reentrancy(
+        &mut builder,
+        new_protocol_version,
+        NEW_MAX_CALL_DEPTH - 1,
+        false,
+    );
+    assert_eq!(error, None);
+
+    let error = run_reentrancy(
+        &mut builder,
+        new_protocol_version,
+        NEW_MAX_CALL_DEPTH,
+        false,
+    )
+    .expect("should fail");
+    assert!(
+        error.contains(&format!("CallDepthExceeded({})", NEW_MAX_CALL_DEPTH)),
+        "{}",
+        error
+    );
+}
@@ -0,0 +1,343 @@
+use engine_core::engine_state::{
+    scheduled_call::{CallContext, ScheduledCall, MIN_ESCROW, SCHEDULED_CALL_PREFIX},
+    CONV_RATE, SYSTEM_ACCOUNT_ADDR,
+};
+use engine_shared::{motes::Motes, stored_value::StoredValue};
+use engine_test_support::{
+    internal::{
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG,
+        DEFAULT_PAYMENT,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{ApiError, BlockTime, Key, U512};
+
+const CONTRACT_SCHEDULE_CALL: &str = "schedule_call.wasm";
+const CONTRACT_DO_NOTHING: &str = "do_nothing.wasm";
+const INSTALL: &str = "install";
+const SCHEDULE: &str = "schedule";
+const SCHEDULE_FROM_CONTRACT: &str = "schedule_from_contract";
+const SCHEDULED_CONTRACT_KEY: &str = "scheduled_contract";
+const SCHEDULER_CONTRACT_KEY: &str = "scheduler_contract";
+const FAILING_CONTRACT_KEY: &str = "failing_contract";
+const SCHEDULED_CALL_RAN_KEY: &str = "scheduled_call_ran";
+const DUE: u64 = 1_000;
+
+/// Installs the contract to be called, returning the key under which it's stored.
+fn install() -> (InMemoryWasmTestBuilder, Key) {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_SCHEDULE_CALL, (INSTALL,))
+            .build();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    let contract = named_key(&builder, SCHEDULED_CONTRACT_KEY);
+    (builder, contract)
+}
+
+fn named_key(builder: &InMemoryWasmTestBuilder, name: &str) -> Key {
+    builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(name)
+        .cloned()
+        .expect("should have named key")
+}
+
+/// Installs the contract to be called and schedules a call to it, due at `DUE`.
+fn setup() -> InMemoryWasmTestBuilder {
+    let (mut builder, contract) = install();
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_SCHEDULE_CALL,
+        (SCHEDULE, contract, DUE, *DEFAULT_PAYMENT),
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    builder
+}
+
+/// Executes a deploy which does nothing at `block_time`, running any scheduled calls due by then.
+fn run_block(builder: &mut InMemoryWasmTestBuilder, block_time: u64) {
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_DO_NOTHING, ())
+            .with_block_time(block_time)
+            .with_scheduled_calls()
+            .build();
+    builder.exec(exec_request).expect_success().commit();
+}
+
+fn scheduled_calls(builder: &InMemoryWasmTestBuilder) -> Vec<ScheduledCall> {
+    builder
+        .get_account(SYSTEM_ACCOUNT_ADDR)
+        .expect("should have system account")
+        .named_keys()
+        .iter()
+        .filter(|(name, _)| name.starts_with(SCHEDULED_CALL_PREFIX))
+        .map(|(_, key)| match builder.query(None, *key, &[]) {
+            Ok(StoredValue::CLValue(cl_value)) => cl_value.into_t().expect("should be a call"),
+            _ => panic!("should have scheduled call"),
+        })
+        .collect()
+}
+
+fn last_scheduled_call_results_count(builder: &InMemoryWasmTestBuilder) -> usize {
+    builder
+        .get_scheduled_call_response(builder.get_exec_responses_count() - 1)
+        .expect("should have scheduled call response")
+        .len()
+}
+
+#[ignore]
+#[test]
+fn should_escrow_payment_for_scheduled_call() {
+    let builder = setup();
+
+    let calls = scheduled_calls(&builder);
+    assert_eq!(calls.len(), 1);
+    let call = &calls[0];
+    assert_eq!(call.account(), DEFAULT_ACCOUNT_ADDR);
+    assert_eq!(call.due(), BlockTime::new(DUE));
+    assert_eq!(
+        builder.get_purse_balance(call.escrow_purse()),
+        *DEFAULT_PAYMENT
+    );
+}
+
+#[ignore]
+#[test]
+fn should_run_scheduled_call_once_due() {
+    let mut builder = setup();
+
+    run_block(&mut builder, DUE - 1);
+    assert_eq!(last_scheduled_call_results_count(&builder), 0);
+    assert_eq!(scheduled_calls(&builder).len(), 1);
+
+    run_block(&mut builder, DUE);
+    let response = builder
+        .get_scheduled_call_response(builder.get_exec_responses_count() - 1)
+        .expect("should have scheduled call response");
+    assert_eq!(response.len(), 1);
+    assert!(!response[0].is_failure(), "{:?}", response[0]);
+    assert!(scheduled_calls(&builder).is_empty());
+
+    let ran_at = match builder.query(
+        None,
+        Key::Account(DEFAULT_ACCOUNT_ADDR),
+        &[SCHEDULED_CALL_RAN_KEY],
+    ) {
+        Ok(StoredValue::CLValue(cl_value)) => cl_value.into_t::<u64>().expect("should be u64"),
+        _ => panic!("scheduled call should have run"),
+    };
+    assert_eq!(ran_at, DUE);
+
+    run_block(&mut builder, DUE + 1);
+    assert_eq!(last_scheduled_call_results_count(&builder), 0);
+}
+
+#[ignore]
+#[test]
+fn should_only_run_scheduled_calls_when_requested() {
+    let mut builder = setup();
+
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_DO_NOTHING, ())
+            .with_block_time(DUE)
+            .build();
+    builder.exec(exec_request).expect_success().commit();
+    assert_eq!(last_scheduled_call_results_count(&builder), 0);
+    assert_eq!(scheduled_calls(&builder).len(), 1);
+
+    run_block(&mut builder, DUE);
+    assert_eq!(last_scheduled_call_results_count(&builder), 1);
+    assert!(scheduled_calls(&builder).is_empty());
+}
+
+#[ignore]
+#[test]
+fn should_refund_unspent_escrow() {
+    let mut builder = setup();
+
+    let escrow_purse = scheduled_calls(&builder)[0].escrow_purse();
+    let main_purse = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .main_purse();
+    let balance_before = builder.get_purse_balance(main_purse);
+
+    run_block(&mut builder, DUE);
+
+    let index = builder.get_exec_responses_count() - 1;
+    let scheduled_call_cost = builder
+        .get_scheduled_call_response(index)
+        .expect("should have scheduled call response")[0]
+        .cost();
+    let deploy_cost = utils::get_success_result(
+        builder
+            .get_exec_response(index)
+            .expect("should have exec response"),
+    )
+    .cost();
+    let spent = Motes::from_gas(scheduled_call_cost, CONV_RATE).expect("should have motes")
+        + Motes::from_gas(deploy_cost, CONV_RATE).expect("should have motes");
+
+    assert_eq!(builder.get_purse_balance(escrow_purse), U512::zero());
+    assert_eq!(
+        builder.get_purse_balance(main_purse),
+        balance_before + *DEFAULT_PAYMENT - spent.value()
+    );
+}
+
+#[ignore]
+#[test]
+fn should_take_failing_call_off_schedule() {
+    let (mut builder, _) = install();
+    let failing = named_key(&builder, FAILING_CONTRACT_KEY);
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_SCHEDULE_CALL,
+        (SCHEDULE, failing, DUE, *DEFAULT_PAYMENT),
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+    let escrow_purse = scheduled_calls(&builder)[0].escrow_purse();
+
+    run_block(&mut builder, DUE);
+    let response = builder
+        .get_scheduled_call_response(builder.get_exec_responses_count() - 1)
+        .expect("should have scheduled call response");
+    assert_eq!(response.len(), 1);
+    assert!(response[0].is_failure());
+    assert!(scheduled_calls(&builder).is_empty());
+    assert_eq!(builder.get_purse_balance(escrow_purse), U512::zero());
+
+    run_block(&mut builder, DUE + 1);
+    assert_eq!(last_scheduled_call_results_count(&builder), 0);
+}
+
+#[ignore]
+#[test]
+fn should_not_schedule_call_to_missing_contract() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_SCHEDULE_CALL,
+        (SCHEDULE, Key::Hash([255; 32]), DUE, *DEFAULT_PAYMENT),
+    )
+    .build();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .commit();
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("should have a response");
+    let message = utils::get_error_message(response);
+    let expected = format!("Revert({})", u32::from(ApiError::InvalidArgument));
+    assert!(message.contains(&expected), "{}", message);
+    assert!(scheduled_calls(&builder).is_empty());
+}
+
+#[ignore]
+#[test]
+fn should_not_schedule_call_below_minimum_escrow() {
+    let (mut builder, contract) = install();
+
+    let amount = U512::from(MIN_ESCROW) - 1;
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_SCHEDULE_CALL,
+        (SCHEDULE, contract, DUE, amount),
+    )
+    .build();
+    builder.exec(exec_request).commit();
+
+    let response = builder
+        .get_exec_response(1)
+        .expect("should have a response");
+    let message = utils::get_error_message(response);
+    let expected = format!("Revert({})", u32::from(ApiError::InvalidArgument));
+    assert!(message.contains(&expected), "{}", message);
+    assert!(scheduled_calls(&builder).is_empty());
+}
+
+#[ignore]
+#[test]
+fn should_run_call_scheduled_by_contract_in_its_context() {
+    let (mut builder, _) = install();
+    let scheduler = named_key(&builder, SCHEDULER_CONTRACT_KEY);
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_SCHEDULE_CALL,
+        (SCHEDULE_FROM_CONTRACT, scheduler, DUE, *DEFAULT_PAYMENT),
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    let calls = scheduled_calls(&builder);
+    assert_eq!(calls.len(), 1);
+    let call = &calls[0];
+    assert_eq!(call.context(), CallContext::Contract);
+    assert_eq!(call.contract(), scheduler);
+    let refund_purse = call.refund_purse();
+    assert_eq!(builder.get_purse_balance(refund_purse), U512::zero());
+
+    run_block(&mut builder, DUE);
+    let response = builder
+        .get_scheduled_call_response(builder.get_exec_responses_count() - 1)
+        .expect("should have scheduled call response");
+    assert_eq!(response.len(), 1);
+    assert!(!response[0].is_failure(), "{:?}", response[0]);
+    let spent = Motes::from_gas(response[0].cost(), CONV_RATE).expect("should have motes");
+
+    let ran_at = match builder.query(None, scheduler, &[SCHEDULED_CALL_RAN_KEY]) {
+        Ok(StoredValue::CLValue(cl_value)) => cl_value.into_t::<u64>().expect("should be u64"),
+        _ => panic!("scheduled call should have run in the contract's context"),
+    };
+    assert_eq!(ran_at, DUE);
+    assert!(builder
+        .query(
+            None,
+            Key::Account(DEFAULT_ACCOUNT_ADDR),
+            &[SCHEDULED_CALL_RAN_KEY]
+        )
+        .is_err());
+
+    assert_eq!(
+        builder.get_purse_balance(refund_purse),
+        *DEFAULT_PAYMENT - spent.value()
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_schedule_call_to_another_contract_from_contract() {
+    let (mut builder, contract) = install();
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_SCHEDULE_CALL,
+        (SCHEDULE_FROM_CONTRACT, contract, DUE, *DEFAULT_PAYMENT),
+    )
+    .build();
+    builder.exec(exec_request).commit();
+
+    let response = builder
+        .get_exec_response(1)
+        .expect("should have a response");
+    let message = utils::get_error_message(response);
+    assert!(message.contains("InvalidContext"), "{}", message);
+    assert!(scheduled_calls(&builder).is_empty());
+}
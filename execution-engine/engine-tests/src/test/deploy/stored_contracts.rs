@@ -13,7 +13,8 @@ use engine_test_support::{
 use types::{account::PublicKey, Key, ProtocolVersion, U512};
 
 const ACCOUNT_1_ADDR: PublicKey = PublicKey::ed25519_from([42u8; 32]);
-const DEFAULT_ACTIVATION_POINT: ActivationPoint = 1;
+const DEFAULT_ACTIVATION_RANK: u64 = 1;
+const DEFAULT_ACTIVATION_POINT: ActivationPoint = ActivationPoint::Rank(DEFAULT_ACTIVATION_RANK);
 const DO_NOTHING_NAME: &str = "do_nothing";
 const DO_NOTHING_STORED_CONTRACT_NAME: &str = "do_nothing_stored";
 const MODIFIED_MINT_UPGRADER_CONTRACT_NAME: &str = "modified_mint_upgrader.wasm";
@@ -888,6 +889,7 @@ fn should_fail_payment_stored_at_named_key_with_incompatible_major_version() {
         ExecuteRequestBuilder::new()
             .push_deploy(deploy)
             .with_protocol_version(new_protocol_version)
+            .with_rank(DEFAULT_ACTIVATION_RANK)
             .build()
     };
 
@@ -969,6 +971,7 @@ fn should_fail_payment_stored_at_hash_with_incompatible_major_version() {
         ExecuteRequestBuilder::new()
             .push_deploy(deploy)
             .with_protocol_version(new_protocol_version)
+            .with_rank(DEFAULT_ACTIVATION_RANK)
             .build()
     };
 
@@ -1051,6 +1054,7 @@ fn should_fail_payment_stored_at_uref_with_incompatible_major_version() {
         ExecuteRequestBuilder::new()
             .push_deploy(deploy)
             .with_protocol_version(new_protocol_version)
+            .with_rank(DEFAULT_ACTIVATION_RANK)
             .build()
     };
 
@@ -1132,6 +1136,7 @@ fn should_fail_session_stored_at_named_key_with_incompatible_major_version() {
         ExecuteRequestBuilder::new()
             .push_deploy(deploy)
             .with_protocol_version(new_protocol_version)
+            .with_rank(DEFAULT_ACTIVATION_RANK)
             .build()
     };
 
@@ -1213,6 +1218,7 @@ fn should_fail_session_stored_at_hash_with_incompatible_major_version() {
         ExecuteRequestBuilder::new()
             .push_deploy(deploy)
             .with_protocol_version(new_protocol_version)
+            .with_rank(DEFAULT_ACTIVATION_RANK)
             .build()
     };
 
@@ -1295,6 +1301,7 @@ fn should_fail_session_stored_at_uref_with_incompatible_major_version() {
         ExecuteRequestBuilder::new()
             .push_deploy(deploy)
             .with_protocol_version(new_protocol_version)
+            .with_rank(DEFAULT_ACTIVATION_RANK)
             .build()
     };
 
@@ -1344,6 +1351,7 @@ fn should_execute_stored_payment_and_session_code_with_new_major_version() {
         (STORE_AT_HASH.to_string(),),
     )
     .with_protocol_version(new_protocol_version)
+    .with_rank(DEFAULT_ACTIVATION_RANK)
     .build();
 
     let exec_request_2 = ExecuteRequestBuilder::standard(
@@ -1352,6 +1360,7 @@ fn should_execute_stored_payment_and_session_code_with_new_major_version() {
         (STORE_AT_UREF.to_string(),),
     )
     .with_protocol_version(new_protocol_version)
+    .with_rank(DEFAULT_ACTIVATION_RANK)
     .build();
 
     // store both contracts
@@ -1403,6 +1412,7 @@ fn should_execute_stored_payment_and_session_code_with_new_major_version() {
         ExecuteRequestBuilder::new()
             .push_deploy(deploy)
             .with_protocol_version(new_protocol_version)
+            .with_rank(DEFAULT_ACTIVATION_RANK)
             .build()
     };
 
@@ -0,0 +1,197 @@
+use engine_core::engine_state::upgrade::ActivationPoint;
+use engine_test_support::{
+    internal::{
+        ExecuteRequestBuilder, InMemoryWasmTestBuilder, UpgradeRequestBuilder,
+        DEFAULT_GENESIS_CONFIG, DEFAULT_WASM_COSTS,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use engine_wasm_prep::wasm_costs::WasmCosts;
+use types::{BlockTime, ProtocolVersion};
+
+const PROTOCOL_VERSION: ProtocolVersion = ProtocolVersion::V1_0_0;
+const ACTIVATION_RANK: u64 = 5;
+const ACTIVATION_BLOCK_TIME: u64 = 1_000;
+const DO_NOTHING_WASM: &str = "do_nothing.wasm";
+
+fn new_protocol_version() -> ProtocolVersion {
+    let sem_ver = PROTOCOL_VERSION.value();
+    ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor, sem_ver.patch + 1)
+}
+
+fn get_upgraded_wasm_costs() -> WasmCosts {
+    WasmCosts {
+        regular: DEFAULT_WASM_COSTS.regular * 2,
+        div: DEFAULT_WASM_COSTS.div * 2,
+        mul: DEFAULT_WASM_COSTS.mul * 2,
+        mem: DEFAULT_WASM_COSTS.mem * 2,
+        ..*DEFAULT_WASM_COSTS
+    }
+}
+
+fn setup(activation_point: ActivationPoint) -> InMemoryWasmTestBuilder {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&*DEFAULT_GENESIS_CONFIG);
+
+    let mut upgrade_request = UpgradeRequestBuilder::new()
+        .with_current_protocol_version(PROTOCOL_VERSION)
+        .with_new_protocol_version(new_protocol_version())
+        .with_new_costs(get_upgraded_wasm_costs())
+        .with_activation_point(activation_point)
+        .build();
+    builder.upgrade_with_upgrade_request(&mut upgrade_request);
+
+    let upgrade_response = builder
+        .get_upgrade_response(0)
+        .expect("should have response");
+    assert!(upgrade_response.has_success(), "{:?}", upgrade_response);
+    builder
+}
+
+fn exec_do_nothing(
+    builder: &mut InMemoryWasmTestBuilder,
+    rank: u64,
+    block_time: u64,
+    protocol_version: ProtocolVersion,
+) -> Result<(), String> {
+    let exec_request = ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, DO_NOTHING_WASM, ())
+        .with_rank(rank)
+        .with_block_time(block_time)
+        .with_protocol_version(protocol_version)
+        .build();
+    builder.exec(exec_request).commit();
+    if builder.is_error() {
+        let index = builder.get_exec_responses_count() - 1;
+        Err(builder
+            .exec_error_message(index)
+            .expect("should have exec error"))
+    } else {
+        Ok(())
+    }
+}
+
+fn assert_inactive(result: Result<(), String>) {
+    let message = result.expect_err("should reject inactive protocol version");
+    assert!(message.contains("InactiveProtocolVersion"), "{}", message);
+}
+
+#[ignore]
+#[test]
+fn should_activate_protocol_version_at_rank() {
+    let mut builder = setup(ActivationPoint::Rank(ACTIVATION_RANK));
+
+    assert_inactive(exec_do_nothing(
+        &mut builder,
+        ACTIVATION_RANK - 1,
+        0,
+        new_protocol_version(),
+    ));
+    exec_do_nothing(&mut builder, ACTIVATION_RANK - 1, 0, PROTOCOL_VERSION)
+        .expect("old version should still be active");
+
+    exec_do_nothing(&mut builder, ACTIVATION_RANK, 0, new_protocol_version())
+        .expect("new version should be active");
+    assert_inactive(exec_do_nothing(
+        &mut builder,
+        ACTIVATION_RANK,
+        0,
+        PROTOCOL_VERSION,
+    ));
+}
+
+#[ignore]
+#[test]
+fn should_require_rank_once_activating_by_rank() {
+    let mut builder = setup(ActivationPoint::Rank(ACTIVATION_RANK));
+
+    let exec_request = ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, DO_NOTHING_WASM, ())
+        .with_protocol_version(new_protocol_version())
+        .build();
+    builder.exec(exec_request).commit();
+    let message = builder
+        .exec_error_message(0)
+        .expect("should have exec error");
+    assert!(message.contains("MissingRank"), "{}", message);
+}
+
+#[ignore]
+#[test]
+fn should_activate_protocol_version_at_block_time() {
+    let mut builder = setup(ActivationPoint::BlockTime(BlockTime::new(
+        ACTIVATION_BLOCK_TIME,
+    )));
+
+    assert_inactive(exec_do_nothing(
+        &mut builder,
+        ACTIVATION_RANK,
+        ACTIVATION_BLOCK_TIME - 1,
+        new_protocol_version(),
+    ));
+    exec_do_nothing(
+        &mut builder,
+        ACTIVATION_RANK,
+        ACTIVATION_BLOCK_TIME,
+        new_protocol_version(),
+    )
+    .expect("new version should be active");
+}
+
+#[ignore]
+#[test]
+fn should_use_wasm_costs_of_active_protocol_version() {
+    let mut builder = setup(ActivationPoint::Rank(ACTIVATION_RANK));
+
+    exec_do_nothing(
+        &mut builder,
+        ACTIVATION_RANK - 1,
+        0,
+        ProtocolVersion::default(),
+    )
+    .expect("should pick the old version");
+    exec_do_nothing(&mut builder, ACTIVATION_RANK, 0, ProtocolVersion::default())
+        .expect("should pick the new version");
+
+    let old_cost = builder.exec_costs(0)[0];
+    let new_cost = builder.exec_costs(1)[0];
+    assert!(new_cost > old_cost, "{:?} <= {:?}", new_cost, old_cost);
+}
+
+#[ignore]
+#[test]
+fn should_not_schedule_upgrade_before_current_activation_point() {
+    let mut builder = setup(ActivationPoint::Rank(ACTIVATION_RANK));
+
+    let newer_protocol_version = ProtocolVersion::from_parts(1, 0, 2);
+    let mut upgrade_request = UpgradeRequestBuilder::new()
+        .with_current_protocol_version(new_protocol_version())
+        .with_new_protocol_version(newer_protocol_version)
+        .with_activation_point(ActivationPoint::Rank(ACTIVATION_RANK - 1))
+        .build();
+    builder.upgrade_with_upgrade_request(&mut upgrade_request);
+
+    let upgrade_response = builder
+        .get_upgrade_response(1)
+        .expect("should have response");
+    assert!(!upgrade_response.has_success(), "expected failure");
+}
+
+#[ignore]
+#[test]
+fn should_not_schedule_upgrade_by_another_kind_of_activation_point() {
+    let mut builder = setup(ActivationPoint::Rank(ACTIVATION_RANK));
+
+    let newer_protocol_version = ProtocolVersion::from_parts(1, 0, 2);
+    let mut upgrade_request = UpgradeRequestBuilder::new()
+        .with_current_protocol_version(new_protocol_version())
+        .with_new_protocol_version(newer_protocol_version)
+        .with_activation_point(ActivationPoint::BlockTime(BlockTime::new(
+            ACTIVATION_BLOCK_TIME,
+        )))
+        .build();
+    builder.upgrade_with_upgrade_request(&mut upgrade_request);
+
+    let upgrade_response = builder
+        .get_upgrade_response(1)
+        .expect("should have response");
+    assert!(!upgrade_response.has_success(), "expected failure");
+}
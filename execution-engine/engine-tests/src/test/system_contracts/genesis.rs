@@ -1,14 +1,29 @@
+use contract::args_parser::ArgsParser;
 use engine_core::engine_state::{
-    genesis::{GenesisAccount, GenesisConfig},
+    genesis::{GenesisAccount, GenesisConfig, GenesisInstaller},
     SYSTEM_ACCOUNT_ADDR,
 };
-use engine_shared::{motes::Motes, stored_value::StoredValue};
-use engine_test_support::internal::{utils, InMemoryWasmTestBuilder, DEFAULT_WASM_COSTS};
-use types::{account::PublicKey, Key, ProtocolVersion, U512};
+use engine_shared::{
+    account::{ActionThresholds, AssociatedKeys},
+    motes::Motes,
+    stored_value::StoredValue,
+};
+use engine_storage::protocol_data::DEFAULT_MAX_CALL_DEPTH;
+use engine_test_support::internal::{
+    utils, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG, DEFAULT_WASM_COSTS,
+};
+use proof_of_stake::PosParams;
+use types::{
+    account::{PublicKey, Weight},
+    bytesrepr::ToBytes,
+    Key, ProtocolVersion, U512,
+};
 
 const MINT_INSTALL: &str = "mint_install.wasm";
 const POS_INSTALL: &str = "pos_install.wasm";
 const BAD_INSTALL: &str = "standard_payment.wasm";
+const DO_NOTHING_STORED_INSTALL: &str = "do_nothing_stored.wasm";
+const DO_NOTHING_STORED_KEY: &str = "do_nothing_stored";
 
 const CHAIN_NAME: &str = "Jeremiah";
 const TIMESTAMP: u64 = 0;
@@ -59,6 +74,8 @@ fn should_run_genesis() {
         pos_installer_bytes,
         accounts,
         wasm_costs,
+        DEFAULT_MAX_CALL_DEPTH,
+        PosParams::default(),
     );
 
     let mut builder = InMemoryWasmTestBuilder::default();
@@ -141,6 +158,8 @@ fn should_fail_if_bad_mint_install_contract_is_provided() {
             pos_installer_bytes,
             accounts,
             wasm_costs,
+            DEFAULT_MAX_CALL_DEPTH,
+            PosParams::default(),
         )
     };
 
@@ -189,6 +208,8 @@ fn should_fail_if_bad_pos_install_contract_is_provided() {
             pos_installer_bytes,
             accounts,
             wasm_costs,
+            DEFAULT_MAX_CALL_DEPTH,
+            PosParams::default(),
         )
     };
 
@@ -196,3 +217,110 @@ fn should_fail_if_bad_pos_install_contract_is_provided() {
 
     builder.run_genesis(&genesis_config);
 }
+
+fn do_nothing_stored_installer(destination: &str) -> GenesisInstaller {
+    let installer_bytes = utils::read_wasm_file_bytes(DO_NOTHING_STORED_INSTALL);
+    let args = ArgsParser::parse((destination,))
+        .expect("args should convert to `Vec<CLValue>`")
+        .into_bytes()
+        .expect("args should serialize");
+    GenesisInstaller::new(installer_bytes, args)
+}
+
+#[ignore]
+#[test]
+fn should_run_genesis_installers_as_system_account() {
+    let mut genesis_config = DEFAULT_GENESIS_CONFIG.clone();
+    genesis_config.push_installer(do_nothing_stored_installer("hash"));
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&genesis_config);
+
+    let system_account = builder
+        .get_account(SYSTEM_ACCOUNT_ADDR)
+        .expect("system account should exist");
+    let contract_key = *system_account
+        .named_keys()
+        .get(DO_NOTHING_STORED_KEY)
+        .expect("installer should put its key under the system account");
+    assert!(
+        match contract_key {
+            Key::Hash(_) => true,
+            _ => false,
+        },
+        "unexpected key: {:?}",
+        contract_key
+    );
+
+    if let Ok(StoredValue::Contract(_)) = builder.query(None, contract_key, &[]) {
+        // Contract exists at the installed hash
+    } else {
+        panic!("contract not found at installed hash");
+    }
+}
+
+#[ignore]
+#[should_panic]
+#[test]
+fn should_fail_if_genesis_installer_reverts() {
+    let mut genesis_config = DEFAULT_GENESIS_CONFIG.clone();
+    genesis_config.push_installer(do_nothing_stored_installer("nowhere"));
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&genesis_config);
+}
+
+#[ignore]
+#[test]
+fn should_create_genesis_account_with_associated_keys() {
+    let mut associated_keys = AssociatedKeys::new(ACCOUNT_1_ADDR, Weight::new(1));
+    associated_keys
+        .add_key(ACCOUNT_2_ADDR, Weight::new(1))
+        .expect("should add key");
+    let action_thresholds =
+        ActionThresholds::new(Weight::new(1), Weight::new(2)).expect("should create thresholds");
+    let account_1 = GenesisAccount::new(
+        ACCOUNT_1_ADDR,
+        Motes::new(ACCOUNT_1_BALANCE.into()),
+        Motes::zero(),
+    )
+    .with_associated_keys(associated_keys, action_thresholds.clone());
+
+    let mut genesis_config = DEFAULT_GENESIS_CONFIG.clone();
+    genesis_config.push_account(account_1);
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&genesis_config);
+
+    let account_1 = builder
+        .get_account(ACCOUNT_1_ADDR)
+        .expect("account 1 should exist");
+    assert_eq!(account_1.action_thresholds(), &action_thresholds);
+    assert_eq!(
+        account_1.get_associated_key_weight(ACCOUNT_2_ADDR),
+        Some(&Weight::new(1))
+    );
+}
+
+#[ignore]
+#[should_panic]
+#[test]
+fn should_fail_if_genesis_account_cannot_meet_its_thresholds() {
+    let action_thresholds =
+        ActionThresholds::new(Weight::new(1), Weight::new(2)).expect("should create thresholds");
+    let account_1 = GenesisAccount::new(
+        ACCOUNT_1_ADDR,
+        Motes::new(ACCOUNT_1_BALANCE.into()),
+        Motes::zero(),
+    )
+    .with_associated_keys(
+        AssociatedKeys::new(ACCOUNT_1_ADDR, Weight::new(1)),
+        action_thresholds,
+    );
+
+    let mut genesis_config = DEFAULT_GENESIS_CONFIG.clone();
+    genesis_config.push_account(account_1);
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&genesis_config);
+}
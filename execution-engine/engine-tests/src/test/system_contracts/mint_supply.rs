@@ -0,0 +1,141 @@
+use std::convert::TryFrom;
+
+use engine_test_support::{
+    internal::{utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{account::PublicKey, system_contract_errors::mint, ApiError, CLValue, Key, U512};
+
+const CONTRACT_MINT_AUTHORITY: &str = "mint_authority.wasm";
+const CONTRACT_TRANSFER_TO_ACCOUNT: &str = "transfer_to_account_u512.wasm";
+const SYSTEM_ADDR: PublicKey = PublicKey::ed25519_from([0u8; 32]);
+const TRANSFER_AMOUNT: u64 = 250_000_000 + 1000;
+const TOTAL_SUPPLY_KEY: &str = "total_supply";
+const PURSE_KEY: &str = "purse";
+
+fn get_total_supply(builder: &InMemoryWasmTestBuilder) -> U512 {
+    let mint_key = Key::URef(builder.get_mint_contract_uref());
+    let stored_value = builder
+        .query(None, mint_key, &[TOTAL_SUPPLY_KEY])
+        .expect("should have total supply");
+    CLValue::try_from(stored_value)
+        .expect("should have CLValue")
+        .into_t()
+        .expect("should convert successfully")
+}
+
+fn run_mint_authority(
+    builder: &mut InMemoryWasmTestBuilder,
+    account: PublicKey,
+    mint_amount: u64,
+    burn_amount: u64,
+    use_own_authority: bool,
+) -> Option<String> {
+    let exec_request = ExecuteRequestBuilder::standard(
+        account,
+        CONTRACT_MINT_AUTHORITY,
+        (
+            U512::from(mint_amount),
+            U512::from(burn_amount),
+            use_own_authority,
+        ),
+    )
+    .build();
+    builder.exec(exec_request).commit();
+
+    let response = builder
+        .get_exec_response(builder.get_exec_responses_count() - 1)
+        .expect("should have a response");
+    if builder.is_error() {
+        Some(utils::get_error_message(response))
+    } else {
+        None
+    }
+}
+
+fn assert_invalid_authority(error: Option<String>) {
+    let error = error.expect("should fail");
+    let expected = ApiError::from(mint::Error::InvalidAuthority);
+    assert!(
+        error.contains(&format!("Revert({})", u32::from(expected))),
+        "{}",
+        error
+    );
+}
+
+#[ignore]
+#[test]
+fn should_track_total_supply_from_genesis() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+
+    let expected_total_supply = DEFAULT_GENESIS_CONFIG
+        .accounts()
+        .iter()
+        .fold(U512::zero(), |total, account| {
+            total + account.balance().value() + account.bonded_amount().value()
+        });
+    assert_eq!(get_total_supply(&builder), expected_total_supply);
+}
+
+#[ignore]
+#[test]
+fn should_mint_and_burn_tokens_with_mint_authority() {
+    const MINT_AMOUNT: u64 = 100;
+    const BURN_AMOUNT: u64 = 40;
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_TO_ACCOUNT,
+        (SYSTEM_ADDR, U512::from(TRANSFER_AMOUNT)),
+    )
+    .build();
+    builder.exec(exec_request).commit().expect_success();
+
+    let total_supply_before = get_total_supply(&builder);
+
+    let error = run_mint_authority(&mut builder, SYSTEM_ADDR, MINT_AMOUNT, BURN_AMOUNT, false);
+    assert_eq!(error, None);
+
+    let system_account = builder
+        .get_account(SYSTEM_ADDR)
+        .expect("should have system account");
+    let purse = system_account
+        .named_keys()
+        .get(PURSE_KEY)
+        .and_then(Key::as_uref)
+        .cloned()
+        .expect("should have purse");
+    let expected_amount = U512::from(MINT_AMOUNT - BURN_AMOUNT);
+    assert_eq!(builder.get_purse_balance(purse), expected_amount);
+    assert_eq!(
+        get_total_supply(&builder),
+        total_supply_before + expected_amount
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_hand_out_mint_authority_to_user_accounts() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+
+    let error = run_mint_authority(&mut builder, DEFAULT_ACCOUNT_ADDR, 100, 0, false);
+    assert_invalid_authority(error);
+}
+
+#[ignore]
+#[test]
+fn should_not_mint_tokens_without_mint_authority() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+
+    let total_supply_before = get_total_supply(&builder);
+
+    let error = run_mint_authority(&mut builder, DEFAULT_ACCOUNT_ADDR, 100, 0, true);
+    assert_invalid_authority(error);
+    assert_eq!(get_total_supply(&builder), total_supply_before);
+}
@@ -1,7 +1,10 @@
+mod activation_point;
 mod genesis;
 mod mint_install;
+mod mint_supply;
 mod pos_install;
 mod proof_of_stake;
 mod system_contract_urefs_access_rights;
 mod system_contracts_access;
 mod upgrade;
+mod upgrade_migrations;
@@ -1,6 +1,9 @@
 use std::collections::BTreeMap;
 
-use engine_core::engine_state::EngineConfig;
+use engine_core::engine_state::{
+    genesis::{POS_BONDING_PURSE, POS_PAYMENT_PURSE, POS_REWARDS_PURSE, POS_TREASURY_PURSE},
+    EngineConfig,
+};
 use engine_test_support::{
     internal::{
         exec_with_return, ExecuteRequestBuilder, WasmTestBuilder, DEFAULT_BLOCK_TIME,
@@ -8,6 +11,7 @@ use engine_test_support::{
     },
     DEFAULT_ACCOUNT_ADDR,
 };
+use proof_of_stake::PosParams;
 use types::{account::PublicKey, AccessRights, Key, URef, U512};
 
 const CONTRACT_TRANSFER_TO_ACCOUNT: &str = "transfer_to_account_u512.wasm";
@@ -16,12 +20,9 @@ const SYSTEM_ADDR: PublicKey = PublicKey::ed25519_from([0u8; 32]);
 const DEPLOY_HASH_2: [u8; 32] = [2u8; 32];
 const N_VALIDATORS: u8 = 5;
 
-// one named_key for each validator and three for the purses
-const EXPECTED_KNOWN_KEYS_LEN: usize = (N_VALIDATORS as usize) + 3;
-
-const POS_BONDING_PURSE: &str = "pos_bonding_purse";
-const POS_PAYMENT_PURSE: &str = "pos_payment_purse";
-const POS_REWARDS_PURSE: &str = "pos_rewards_purse";
+// four named_keys for the purses, one for the mint authority, one for the stakes, two for the
+// bonding queues and one for the parameters
+const EXPECTED_KNOWN_KEYS_LEN: usize = 9;
 
 #[ignore]
 #[test]
@@ -59,7 +60,7 @@ fn should_run_pos_install_contract() {
         "pos_install.wasm",
         DEFAULT_BLOCK_TIME,
         DEPLOY_HASH_2,
-        (mint_uref, genesis_validators),
+        (mint_uref, genesis_validators, PosParams::default()),
         vec![mint_uref],
     )
     .expect("should run successfully");
@@ -98,6 +99,13 @@ fn should_run_pos_install_contract() {
 
     let rewards_purse_balance = builder.get_purse_balance(rewards_purse);
     assert_eq!(rewards_purse_balance, U512::zero());
+
+    // treasury purse has correct balance
+    let treasury_purse = get_purse(named_keys, POS_TREASURY_PURSE)
+        .expect("should find treasury purse in named_keys");
+
+    let treasury_purse_balance = builder.get_purse_balance(treasury_purse);
+    assert_eq!(treasury_purse_balance, U512::zero());
 }
 
 fn get_purse(named_keys: &BTreeMap<String, Key>, name: &str) -> Option<URef> {
@@ -0,0 +1,229 @@
+use std::convert::TryFrom;
+
+use engine_core::engine_state::genesis::{GenesisAccount, POS_BONDING_PURSE};
+use engine_grpc_server::engine_server::ipc::DistributeRewardsRequest;
+use engine_shared::motes::Motes;
+use engine_test_support::{
+    internal::{
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNTS,
+        DEFAULT_PROTOCOL_VERSION,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{account::PublicKey, system_contract_errors::pos, ApiError, CLValue, Key, URef, U512};
+
+const CONTRACT_POS_BONDING: &str = "pos_bonding.wasm";
+const VALIDATOR_ADDR: PublicKey = PublicKey::ed25519_from([42; 32]);
+const NON_VALIDATOR_ADDR: PublicKey = PublicKey::ed25519_from([43; 32]);
+const VALIDATOR_STAKE: u64 = 50_000;
+const DELEGATED_STAKE: u64 = 50_000;
+const TOTAL_SUPPLY_KEY: &str = "total_supply";
+
+const TEST_DELEGATE: &str = "delegate";
+const TEST_UNDELEGATE: &str = "undelegate";
+
+// Rates are given in millionths: 1% inflation, 10% commission
+const RATE_DENOMINATOR: u64 = 1_000_000;
+const INFLATION_RATE: u64 = 10_000;
+const COMMISSION_RATE: u64 = 100_000;
+
+fn setup() -> InMemoryWasmTestBuilder {
+    let accounts = {
+        let mut tmp: Vec<GenesisAccount> = DEFAULT_ACCOUNTS.clone();
+        tmp.push(GenesisAccount::new(
+            VALIDATOR_ADDR,
+            Motes::new(VALIDATOR_STAKE.into()) * Motes::new(2.into()),
+            Motes::new(VALIDATOR_STAKE.into()),
+        ));
+        tmp
+    };
+    let genesis_config = utils::create_genesis_config(accounts);
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&genesis_config);
+    builder
+}
+
+fn get_bonding_purse_balance(builder: &InMemoryWasmTestBuilder) -> U512 {
+    let purse: URef = builder
+        .get_pos_contract()
+        .named_keys()
+        .get(POS_BONDING_PURSE)
+        .and_then(Key::as_uref)
+        .cloned()
+        .expect("should have bonding purse");
+    builder.get_purse_balance(purse)
+}
+
+fn get_total_supply(builder: &InMemoryWasmTestBuilder) -> U512 {
+    let mint_key = Key::URef(builder.get_mint_contract_uref());
+    let stored_value = builder
+        .query(None, mint_key, &[TOTAL_SUPPLY_KEY])
+        .expect("should have total supply");
+    CLValue::try_from(stored_value)
+        .expect("should have CLValue")
+        .into_t()
+        .expect("should convert successfully")
+}
+
+fn get_stake(builder: &InMemoryWasmTestBuilder, addr: PublicKey) -> U512 {
+    builder
+        .get_pos_stakes()
+        .get(&addr)
+        .cloned()
+        .expect("should be bonded")
+}
+
+fn assert_revert(builder: &InMemoryWasmTestBuilder, error: pos::Error) {
+    let response = builder
+        .get_exec_response(0)
+        .expect("should have a response")
+        .to_owned();
+    let error_message = utils::get_error_message(response);
+    let expected = format!("Revert({})", u32::from(ApiError::from(error)));
+    assert!(error_message.contains(&expected), "{}", error_message);
+}
+
+#[ignore]
+#[test]
+fn should_delegate_and_undelegate() {
+    let mut builder = setup();
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_POS_BONDING,
+        (
+            String::from(TEST_DELEGATE),
+            VALIDATOR_ADDR,
+            U512::from(DELEGATED_STAKE),
+        ),
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    // The delegated stake counts towards the validator's weight.
+    assert_eq!(
+        get_stake(&builder, VALIDATOR_ADDR),
+        U512::from(VALIDATOR_STAKE + DELEGATED_STAKE)
+    );
+    assert_eq!(
+        get_bonding_purse_balance(&builder),
+        U512::from(VALIDATOR_STAKE + DELEGATED_STAKE)
+    );
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_POS_BONDING,
+        (String::from(TEST_UNDELEGATE), VALIDATOR_ADDR, None::<U512>),
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    assert_eq!(
+        get_stake(&builder, VALIDATOR_ADDR),
+        U512::from(VALIDATOR_STAKE)
+    );
+    assert_eq!(
+        get_bonding_purse_balance(&builder),
+        U512::from(VALIDATOR_STAKE)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_fail_delegating_to_non_validator() {
+    let mut builder = setup();
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_POS_BONDING,
+        (
+            String::from(TEST_DELEGATE),
+            NON_VALIDATOR_ADDR,
+            U512::from(DELEGATED_STAKE),
+        ),
+    )
+    .build();
+    builder.exec(exec_request).commit();
+
+    assert_revert(&builder, pos::Error::NotBonded);
+}
+
+#[ignore]
+#[test]
+fn should_fail_undelegating_without_delegating_first() {
+    let mut builder = setup();
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_POS_BONDING,
+        (
+            String::from(TEST_UNDELEGATE),
+            VALIDATOR_ADDR,
+            Some(U512::from(DELEGATED_STAKE)),
+        ),
+    )
+    .build();
+    builder.exec(exec_request).commit();
+
+    assert_revert(&builder, pos::Error::NotDelegated);
+}
+
+#[ignore]
+#[test]
+fn should_restake_delegator_rewards() {
+    let mut builder = setup();
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_POS_BONDING,
+        (
+            String::from(TEST_DELEGATE),
+            VALIDATOR_ADDR,
+            U512::from(DELEGATED_STAKE),
+        ),
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    let total_supply = get_total_supply(&builder);
+    let mut request = DistributeRewardsRequest::new();
+    request.set_protocol_version((*DEFAULT_PROTOCOL_VERSION).into());
+    request.set_era_id(1);
+    request.set_inflation_rate(INFLATION_RATE);
+    request.set_commission_rate(COMMISSION_RATE);
+    let response = builder.distribute_rewards_with_request(&mut request);
+    assert!(response.has_success(), "{:?}", response);
+
+    // The validator is the only one bonded, so the whole inflation goes to them and their
+    // delegator, split by their stakes.
+    let inflation = total_supply * INFLATION_RATE / RATE_DENOMINATOR;
+    let total_stake = U512::from(VALIDATOR_STAKE + DELEGATED_STAKE);
+    let delegator_share = inflation * DELEGATED_STAKE / total_stake;
+    let own_share = inflation - delegator_share;
+    let delegator_restaked = delegator_share - delegator_share * COMMISSION_RATE / RATE_DENOMINATOR;
+    let validator_restaked = own_share - own_share * COMMISSION_RATE / RATE_DENOMINATOR;
+
+    assert_eq!(
+        get_stake(&builder, VALIDATOR_ADDR),
+        total_stake + delegator_restaked + validator_restaked
+    );
+    assert_eq!(
+        get_bonding_purse_balance(&builder),
+        total_stake + delegator_restaked + validator_restaked
+    );
+
+    // Undelegating withdraws the delegator's restaked reward as well.
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_POS_BONDING,
+        (String::from(TEST_UNDELEGATE), VALIDATOR_ADDR, None::<U512>),
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    assert_eq!(
+        get_stake(&builder, VALIDATOR_ADDR),
+        U512::from(VALIDATOR_STAKE) + validator_restaked
+    );
+}
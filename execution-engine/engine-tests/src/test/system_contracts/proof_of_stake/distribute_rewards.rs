@@ -0,0 +1,166 @@
+use std::convert::TryFrom;
+
+use engine_core::engine_state::genesis::{GenesisAccount, POS_BONDING_PURSE};
+use engine_grpc_server::engine_server::ipc::{DistributeRewardsRequest, DistributeRewardsResponse};
+use engine_shared::motes::Motes;
+use engine_test_support::internal::{
+    utils, InMemoryWasmTestBuilder, DEFAULT_ACCOUNTS, DEFAULT_PROTOCOL_VERSION,
+};
+use types::{account::PublicKey, system_contract_errors::pos, ApiError, CLValue, Key, URef, U512};
+
+const VALIDATOR_1_ADDR: PublicKey = PublicKey::ed25519_from([42; 32]);
+const VALIDATOR_2_ADDR: PublicKey = PublicKey::ed25519_from([43; 32]);
+const VALIDATOR_BALANCE: u64 = 1_000_000;
+const VALIDATOR_1_STAKE: u64 = 300_000;
+const VALIDATOR_2_STAKE: u64 = 100_000;
+const TOTAL_SUPPLY_KEY: &str = "total_supply";
+
+// Rates are given in millionths: 1% inflation, 10% commission
+const RATE_DENOMINATOR: u64 = 1_000_000;
+const INFLATION_RATE: u64 = 10_000;
+const COMMISSION_RATE: u64 = 100_000;
+
+fn setup() -> InMemoryWasmTestBuilder {
+    let accounts = {
+        let mut tmp: Vec<GenesisAccount> = DEFAULT_ACCOUNTS.clone();
+        for (addr, stake) in &[
+            (VALIDATOR_1_ADDR, VALIDATOR_1_STAKE),
+            (VALIDATOR_2_ADDR, VALIDATOR_2_STAKE),
+        ] {
+            tmp.push(GenesisAccount::new(
+                *addr,
+                Motes::new(VALIDATOR_BALANCE.into()),
+                Motes::new((*stake).into()),
+            ));
+        }
+        tmp
+    };
+    let genesis_config = utils::create_genesis_config(accounts);
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&genesis_config);
+    builder
+}
+
+fn distribute_rewards(
+    builder: &mut InMemoryWasmTestBuilder,
+    era_id: u64,
+    inflation_rate: u64,
+    commission_rate: u64,
+) -> DistributeRewardsResponse {
+    let mut request = DistributeRewardsRequest::new();
+    request.set_protocol_version((*DEFAULT_PROTOCOL_VERSION).into());
+    request.set_era_id(era_id);
+    request.set_inflation_rate(inflation_rate);
+    request.set_commission_rate(commission_rate);
+    builder.distribute_rewards_with_request(&mut request)
+}
+
+fn assert_revert(response: &DistributeRewardsResponse, error: pos::Error) {
+    assert!(response.has_error(), "expected error: {:?}", response);
+    let message = response.get_error().get_message();
+    let expected = format!("Revert({})", u32::from(ApiError::from(error)));
+    assert!(message.contains(&expected), "{}", message);
+}
+
+fn get_total_supply(builder: &InMemoryWasmTestBuilder) -> U512 {
+    let mint_key = Key::URef(builder.get_mint_contract_uref());
+    let stored_value = builder
+        .query(None, mint_key, &[TOTAL_SUPPLY_KEY])
+        .expect("should have total supply");
+    CLValue::try_from(stored_value)
+        .expect("should have CLValue")
+        .into_t()
+        .expect("should convert successfully")
+}
+
+fn get_main_purse_balance(builder: &InMemoryWasmTestBuilder, addr: PublicKey) -> U512 {
+    let account = builder.get_account(addr).expect("should have account");
+    builder.get_purse_balance(account.main_purse())
+}
+
+fn get_bonding_purse_balance(builder: &InMemoryWasmTestBuilder) -> U512 {
+    let purse: URef = builder
+        .get_pos_contract()
+        .named_keys()
+        .get(POS_BONDING_PURSE)
+        .and_then(Key::as_uref)
+        .cloned()
+        .expect("should have bonding purse");
+    builder.get_purse_balance(purse)
+}
+
+fn get_stake(builder: &InMemoryWasmTestBuilder, addr: PublicKey) -> U512 {
+    builder
+        .get_pos_stakes()
+        .get(&addr)
+        .cloned()
+        .expect("should be bonded")
+}
+
+#[ignore]
+#[test]
+fn should_distribute_inflation_to_bonded_validators() {
+    let mut builder = setup();
+    let total_supply_before = get_total_supply(&builder);
+
+    let response = distribute_rewards(&mut builder, 1, INFLATION_RATE, COMMISSION_RATE);
+    assert!(response.has_success(), "{:?}", response);
+
+    let inflation = total_supply_before * INFLATION_RATE / RATE_DENOMINATOR;
+    assert_eq!(get_total_supply(&builder), total_supply_before + inflation);
+
+    let total_stake = U512::from(VALIDATOR_1_STAKE + VALIDATOR_2_STAKE);
+    let mut total_restaked = U512::zero();
+    for (addr, stake) in &[
+        (VALIDATOR_1_ADDR, VALIDATOR_1_STAKE),
+        (VALIDATOR_2_ADDR, VALIDATOR_2_STAKE),
+    ] {
+        let share = inflation * *stake / total_stake;
+        let commission = share * COMMISSION_RATE / RATE_DENOMINATOR;
+        let restaked = share - commission;
+        total_restaked += restaked;
+
+        assert_eq!(
+            get_main_purse_balance(&builder, *addr),
+            U512::from(VALIDATOR_BALANCE) + commission
+        );
+        assert_eq!(get_stake(&builder, *addr), U512::from(*stake) + restaked);
+    }
+    assert_eq!(
+        get_bonding_purse_balance(&builder),
+        total_stake + total_restaked
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_reward_era_twice() {
+    let mut builder = setup();
+
+    let response = distribute_rewards(&mut builder, 2, INFLATION_RATE, COMMISSION_RATE);
+    assert!(response.has_success(), "{:?}", response);
+
+    let total_supply = get_total_supply(&builder);
+
+    let response = distribute_rewards(&mut builder, 2, INFLATION_RATE, COMMISSION_RATE);
+    assert_revert(&response, pos::Error::EraAlreadyRewarded);
+    let response = distribute_rewards(&mut builder, 1, INFLATION_RATE, COMMISSION_RATE);
+    assert_revert(&response, pos::Error::EraAlreadyRewarded);
+    assert_eq!(get_total_supply(&builder), total_supply);
+
+    let response = distribute_rewards(&mut builder, 3, INFLATION_RATE, COMMISSION_RATE);
+    assert!(response.has_success(), "{:?}", response);
+}
+
+#[ignore]
+#[test]
+fn should_reject_rates_above_one() {
+    let mut builder = setup();
+
+    let response = distribute_rewards(&mut builder, 1, RATE_DENOMINATOR + 1, COMMISSION_RATE);
+    assert_revert(&response, pos::Error::InvalidRewardRate);
+
+    let response = distribute_rewards(&mut builder, 1, INFLATION_RATE, RATE_DENOMINATOR + 1);
+    assert_revert(&response, pos::Error::InvalidRewardRate);
+}
@@ -1,5 +1,9 @@
 mod bonding;
 mod commit_validators;
+mod delegation;
+mod distribute_rewards;
 mod finalize_payment;
 mod get_payment_purse;
+mod params;
 mod refund_purse;
+mod slashing;
@@ -0,0 +1,224 @@
+use std::convert::TryFrom;
+
+use engine_core::engine_state::{
+    genesis::{GenesisAccount, GenesisConfig},
+    upgrade::ActivationPoint,
+};
+use engine_grpc_server::engine_server::ipc::UnbondPayoutRequest;
+use engine_shared::{motes::Motes, stored_value::StoredValue};
+use engine_test_support::{
+    internal::{
+        ExecuteRequestBuilder, InMemoryWasmTestBuilder, UpgradeRequestBuilder, DEFAULT_ACCOUNTS,
+        DEFAULT_GENESIS_CONFIG, DEFAULT_PROTOCOL_VERSION,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use proof_of_stake::PosParams;
+use types::{account::PublicKey, CLValue, ProtocolVersion, U512};
+
+const CONTRACT_POS_BONDING: &str = "pos_bonding.wasm";
+const CONTRACT_POS_GET_PARAMS: &str = "pos_get_params.wasm";
+const PROTOCOL_VERSION: ProtocolVersion = ProtocolVersion::V1_0_0;
+const DEFAULT_ACTIVATION_POINT: ActivationPoint = ActivationPoint::Rank(1);
+const GENESIS_VALIDATOR_STAKE: u64 = 50_000;
+const ACCOUNT_STAKE: u64 = 1_000;
+const POS_PARAMS_KEY: &str = "pos_params";
+const TEST_BOND: &str = "bond";
+const BOND_BLOCK_TIME: u64 = 10_000;
+
+fn custom_pos_params() -> PosParams {
+    PosParams {
+        bond_delay: 1_000,
+        unbond_delay: 2_000,
+        max_bond_len: 10,
+        max_unbond_len: 20,
+        max_spread: U512::from(1_000_000),
+        max_increase: U512::from(500_000),
+        max_decrease: U512::from(400_000),
+        max_rel_increase: 100_000,
+        max_rel_decrease: 200_000,
+    }
+}
+
+fn genesis_config_with_pos_params(pos_params: PosParams) -> GenesisConfig {
+    let mut accounts: Vec<GenesisAccount> = DEFAULT_ACCOUNTS.clone();
+    accounts.push(GenesisAccount::new(
+        PublicKey::ed25519_from([42; 32]),
+        Motes::new(GENESIS_VALIDATOR_STAKE.into()) * Motes::new(2.into()),
+        Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+    ));
+    GenesisConfig::new(
+        DEFAULT_GENESIS_CONFIG.name().to_string(),
+        DEFAULT_GENESIS_CONFIG.timestamp(),
+        DEFAULT_GENESIS_CONFIG.protocol_version(),
+        DEFAULT_GENESIS_CONFIG.mint_installer_bytes().to_vec(),
+        DEFAULT_GENESIS_CONFIG
+            .proof_of_stake_installer_bytes()
+            .to_vec(),
+        accounts,
+        DEFAULT_GENESIS_CONFIG.wasm_costs(),
+        DEFAULT_GENESIS_CONFIG.max_call_depth(),
+        pos_params,
+    )
+}
+
+/// Runs the `pos-get-params` contract and returns the parameters it read from the PoS contract.
+fn get_pos_params_from_contract(builder: &mut InMemoryWasmTestBuilder) -> PosParams {
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_POS_GET_PARAMS, ()).build();
+    builder.exec(exec_request).expect_success().commit();
+
+    let account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    let key = *account
+        .named_keys()
+        .get(POS_PARAMS_KEY)
+        .expect("should have pos params key");
+    let stored_value: StoredValue = builder
+        .query(None, key, &[])
+        .expect("should have pos params");
+    CLValue::try_from(stored_value)
+        .expect("should have CLValue")
+        .into_t()
+        .expect("should convert pos params")
+}
+
+#[ignore]
+#[test]
+fn should_use_default_pos_params() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+
+    assert_eq!(
+        builder.get_pos_params(PROTOCOL_VERSION),
+        PosParams::default()
+    );
+    assert_eq!(
+        get_pos_params_from_contract(&mut builder),
+        PosParams::default()
+    );
+}
+
+#[ignore]
+#[test]
+fn should_store_pos_params_from_genesis() {
+    let genesis_config = genesis_config_with_pos_params(custom_pos_params());
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&genesis_config);
+
+    assert_eq!(
+        builder.get_pos_params(PROTOCOL_VERSION),
+        custom_pos_params()
+    );
+    assert_eq!(
+        get_pos_params_from_contract(&mut builder),
+        custom_pos_params()
+    );
+}
+
+#[ignore]
+#[test]
+fn should_apply_bond_delay_from_genesis() {
+    let genesis_config = genesis_config_with_pos_params(custom_pos_params());
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&genesis_config);
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_POS_BONDING,
+        (String::from(TEST_BOND), U512::from(ACCOUNT_STAKE)),
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    // The bond stays in the queue until the bonding delay has passed.
+    assert_eq!(builder.get_pos_stakes().get(&DEFAULT_ACCOUNT_ADDR), None);
+}
+
+/// Runs the `step` system call of the PoS contract at the given block time.
+fn unbond_payout(builder: &mut InMemoryWasmTestBuilder, block_time: u64) {
+    let mut unbond_payout_request = UnbondPayoutRequest::new();
+    unbond_payout_request.set_protocol_version((*DEFAULT_PROTOCOL_VERSION).into());
+    unbond_payout_request.set_block_time(block_time);
+    let unbond_payout_response = builder.unbond_payout_with_request(&mut unbond_payout_request);
+    assert!(unbond_payout_response.has_success(), "expected success");
+}
+
+#[ignore]
+#[test]
+fn should_apply_bond_on_unbond_payout_once_delay_passed() {
+    let genesis_config = genesis_config_with_pos_params(custom_pos_params());
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&genesis_config);
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_POS_BONDING,
+        (String::from(TEST_BOND), U512::from(ACCOUNT_STAKE)),
+    )
+    .with_block_time(BOND_BLOCK_TIME)
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    let bond_delay = custom_pos_params().bond_delay;
+
+    unbond_payout(&mut builder, BOND_BLOCK_TIME + bond_delay - 1);
+    assert_eq!(builder.get_pos_stakes().get(&DEFAULT_ACCOUNT_ADDR), None);
+
+    unbond_payout(&mut builder, BOND_BLOCK_TIME + bond_delay);
+    assert_eq!(
+        builder.get_pos_stakes().get(&DEFAULT_ACCOUNT_ADDR),
+        Some(&U512::from(ACCOUNT_STAKE))
+    );
+}
+
+#[ignore]
+#[test]
+fn should_update_pos_params_on_upgrade() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+
+    let sem_ver = PROTOCOL_VERSION.value();
+    let new_protocol_version =
+        ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor, sem_ver.patch + 1);
+
+    let mut upgrade_request = UpgradeRequestBuilder::new()
+        .with_current_protocol_version(PROTOCOL_VERSION)
+        .with_new_protocol_version(new_protocol_version)
+        .with_new_pos_params(custom_pos_params())
+        .with_activation_point(DEFAULT_ACTIVATION_POINT)
+        .build();
+    builder.upgrade_with_upgrade_request(&mut upgrade_request);
+    let upgrade_response = builder
+        .get_upgrade_response(0)
+        .expect("should have response");
+    assert!(upgrade_response.has_success(), "expected success");
+
+    assert_eq!(
+        builder.get_pos_params(new_protocol_version),
+        custom_pos_params()
+    );
+
+    // An upgrade without new parameters leaves them unchanged.
+    let newer_protocol_version =
+        ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor, sem_ver.patch + 2);
+    let mut upgrade_request = UpgradeRequestBuilder::new()
+        .with_current_protocol_version(new_protocol_version)
+        .with_new_protocol_version(newer_protocol_version)
+        .with_activation_point(DEFAULT_ACTIVATION_POINT)
+        .build();
+    builder.upgrade_with_upgrade_request(&mut upgrade_request);
+    let upgrade_response = builder
+        .get_upgrade_response(1)
+        .expect("should have response");
+    assert!(upgrade_response.has_success(), "expected success");
+
+    assert_eq!(
+        builder.get_pos_params(newer_protocol_version),
+        custom_pos_params()
+    );
+}
@@ -0,0 +1,293 @@
+use engine_core::engine_state::genesis::{
+    GenesisAccount, GenesisConfig, POS_BONDING_PURSE, POS_TREASURY_PURSE,
+};
+use engine_grpc_server::engine_server::ipc::{
+    DistributeRewardsRequest, DistributeRewardsResponse, SlashRequest, SlashResponse,
+    UnbondPayoutRequest,
+};
+use engine_shared::motes::Motes;
+use engine_test_support::{
+    internal::{
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNTS,
+        DEFAULT_PROTOCOL_VERSION,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use proof_of_stake::{PosParams, Slash, SlashAmount};
+use types::{account::PublicKey, system_contract_errors::pos, ApiError, Key, URef, U512};
+
+const CONTRACT_POS_BONDING: &str = "pos_bonding.wasm";
+const TEST_BOND: &str = "bond";
+const TEST_UNBOND: &str = "unbond";
+
+const VALIDATOR_1_ADDR: PublicKey = PublicKey::ed25519_from([42; 32]);
+const VALIDATOR_2_ADDR: PublicKey = PublicKey::ed25519_from([43; 32]);
+const VALIDATOR_BALANCE: u64 = 1_000_000;
+const VALIDATOR_1_STAKE: u64 = 300_000;
+const VALIDATOR_2_STAKE: u64 = 100_000;
+const DEFAULT_ACCOUNT_STAKE: u64 = 50_000;
+const BOND_DELAY: u64 = 1_000;
+const BLOCK_TIME: u64 = 10_000;
+
+// Rates are given in millionths
+const RATE_DENOMINATOR: u64 = 1_000_000;
+
+fn setup() -> InMemoryWasmTestBuilder {
+    setup_with_pos_params(PosParams::default())
+}
+
+fn setup_with_pos_params(pos_params: PosParams) -> InMemoryWasmTestBuilder {
+    let accounts = {
+        let mut tmp: Vec<GenesisAccount> = DEFAULT_ACCOUNTS.clone();
+        for (addr, stake) in &[
+            (VALIDATOR_1_ADDR, VALIDATOR_1_STAKE),
+            (VALIDATOR_2_ADDR, VALIDATOR_2_STAKE),
+        ] {
+            tmp.push(GenesisAccount::new(
+                *addr,
+                Motes::new(VALIDATOR_BALANCE.into()),
+                Motes::new((*stake).into()),
+            ));
+        }
+        tmp
+    };
+    let default_config = utils::create_genesis_config(accounts);
+    let genesis_config = GenesisConfig::new(
+        default_config.name().to_string(),
+        default_config.timestamp(),
+        default_config.protocol_version(),
+        default_config.mint_installer_bytes().to_vec(),
+        default_config.proof_of_stake_installer_bytes().to_vec(),
+        default_config.accounts().to_vec(),
+        default_config.wasm_costs(),
+        default_config.max_call_depth(),
+        pos_params,
+    );
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&genesis_config);
+    builder
+}
+
+fn slash(
+    builder: &mut InMemoryWasmTestBuilder,
+    validator: PublicKey,
+    slash: Slash,
+) -> SlashResponse {
+    slash_at(builder, validator, slash, 0)
+}
+
+fn slash_at(
+    builder: &mut InMemoryWasmTestBuilder,
+    validator: PublicKey,
+    slash: Slash,
+    block_time: u64,
+) -> SlashResponse {
+    let mut request = SlashRequest::new();
+    request.set_protocol_version((*DEFAULT_PROTOCOL_VERSION).into());
+    request.set_block_time(block_time);
+    request.mut_slashes().push((validator, slash).into());
+    builder.slash_with_request(&mut request)
+}
+
+fn distribute_rewards(
+    builder: &mut InMemoryWasmTestBuilder,
+    era_id: u64,
+) -> DistributeRewardsResponse {
+    let mut request = DistributeRewardsRequest::new();
+    request.set_protocol_version((*DEFAULT_PROTOCOL_VERSION).into());
+    request.set_era_id(era_id);
+    builder.distribute_rewards_with_request(&mut request)
+}
+
+fn bond_default_account(builder: &mut InMemoryWasmTestBuilder) {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_POS_BONDING,
+        (String::from(TEST_BOND), U512::from(DEFAULT_ACCOUNT_STAKE)),
+    )
+    .build();
+    builder.exec(exec_request).commit();
+}
+
+/// Runs the `step` system call of the PoS contract at the given block time.
+fn unbond_payout(builder: &mut InMemoryWasmTestBuilder, block_time: u64) {
+    let mut request = UnbondPayoutRequest::new();
+    request.set_protocol_version((*DEFAULT_PROTOCOL_VERSION).into());
+    request.set_block_time(block_time);
+    let response = builder.unbond_payout_with_request(&mut request);
+    assert!(response.has_success(), "{:?}", response);
+}
+
+fn assert_revert(message: &str, error: pos::Error) {
+    let expected = format!("Revert({})", u32::from(ApiError::from(error)));
+    assert!(message.contains(&expected), "{}", message);
+}
+
+fn get_pos_purse_balance(builder: &InMemoryWasmTestBuilder, purse_name: &str) -> U512 {
+    let purse: URef = builder
+        .get_pos_contract()
+        .named_keys()
+        .get(purse_name)
+        .and_then(Key::as_uref)
+        .cloned()
+        .expect("should have purse");
+    builder.get_purse_balance(purse)
+}
+
+fn get_stake(builder: &InMemoryWasmTestBuilder, addr: PublicKey) -> Option<U512> {
+    builder.get_pos_stakes().get(&addr).cloned()
+}
+
+#[ignore]
+#[test]
+fn should_slash_fixed_amount_into_treasury() {
+    let mut builder = setup();
+    let slashed = U512::from(50_000);
+
+    let response = slash(
+        &mut builder,
+        VALIDATOR_1_ADDR,
+        Slash::new(SlashAmount::Fixed(slashed), 0),
+    );
+    assert!(response.has_success(), "{:?}", response);
+
+    assert_eq!(
+        get_stake(&builder, VALIDATOR_1_ADDR),
+        Some(U512::from(VALIDATOR_1_STAKE) - slashed)
+    );
+    assert_eq!(
+        get_stake(&builder, VALIDATOR_2_ADDR),
+        Some(U512::from(VALIDATOR_2_STAKE))
+    );
+    assert_eq!(get_pos_purse_balance(&builder, POS_TREASURY_PURSE), slashed);
+    assert_eq!(
+        get_pos_purse_balance(&builder, POS_BONDING_PURSE),
+        U512::from(VALIDATOR_1_STAKE + VALIDATOR_2_STAKE) - slashed
+    );
+}
+
+#[ignore]
+#[test]
+fn should_slash_proportional_amount() {
+    let mut builder = setup();
+    let rate = RATE_DENOMINATOR / 4;
+
+    let response = slash(
+        &mut builder,
+        VALIDATOR_2_ADDR,
+        Slash::new(SlashAmount::Rate(rate), 0),
+    );
+    assert!(response.has_success(), "{:?}", response);
+
+    let slashed = U512::from(VALIDATOR_2_STAKE * rate / RATE_DENOMINATOR);
+    assert_eq!(
+        get_stake(&builder, VALIDATOR_2_ADDR),
+        Some(U512::from(VALIDATOR_2_STAKE) - slashed)
+    );
+    assert_eq!(get_pos_purse_balance(&builder, POS_TREASURY_PURSE), slashed);
+}
+
+#[ignore]
+#[test]
+fn should_keep_jailed_validator_unbonded_until_era() {
+    let mut builder = setup();
+    bond_default_account(&mut builder);
+    assert!(!builder.is_error(), "{:?}", builder.exec_error_message(0));
+    assert_eq!(
+        get_stake(&builder, DEFAULT_ACCOUNT_ADDR),
+        Some(U512::from(DEFAULT_ACCOUNT_STAKE))
+    );
+
+    let response = slash(
+        &mut builder,
+        DEFAULT_ACCOUNT_ADDR,
+        Slash::new(SlashAmount::Rate(RATE_DENOMINATOR / 10), 2),
+    );
+    assert!(response.has_success(), "{:?}", response);
+    assert_eq!(get_stake(&builder, DEFAULT_ACCOUNT_ADDR), None);
+    assert_eq!(
+        get_pos_purse_balance(&builder, POS_TREASURY_PURSE),
+        U512::from(DEFAULT_ACCOUNT_STAKE / 10)
+    );
+
+    // Rewarding era 1 doesn't release the validator yet.
+    let response = distribute_rewards(&mut builder, 1);
+    assert!(response.has_success(), "{:?}", response);
+    bond_default_account(&mut builder);
+    assert!(builder.is_error());
+    let message = builder
+        .exec_error_message(1)
+        .expect("should have exec error");
+    assert_revert(&message, pos::Error::ValidatorJailed);
+
+    let response = distribute_rewards(&mut builder, 2);
+    assert!(response.has_success(), "{:?}", response);
+    bond_default_account(&mut builder);
+    assert!(!builder.is_error(), "{:?}", builder.exec_error_message(2));
+    assert_eq!(
+        get_stake(&builder, DEFAULT_ACCOUNT_ADDR),
+        Some(U512::from(DEFAULT_ACCOUNT_STAKE))
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_bond_jailed_validator_from_pending_bond() {
+    let mut builder = setup_with_pos_params(PosParams {
+        bond_delay: BOND_DELAY,
+        unbond_delay: BOND_DELAY * 2,
+        ..Default::default()
+    });
+
+    // The validator unbonds and bonds again, so that they're only in the queues.
+    let unbond_request = ExecuteRequestBuilder::standard(
+        VALIDATOR_1_ADDR,
+        CONTRACT_POS_BONDING,
+        (String::from(TEST_UNBOND), None::<U512>),
+    )
+    .with_block_time(BLOCK_TIME)
+    .build();
+    let bond_request = ExecuteRequestBuilder::standard(
+        VALIDATOR_1_ADDR,
+        CONTRACT_POS_BONDING,
+        (String::from(TEST_BOND), U512::from(VALIDATOR_2_STAKE)),
+    )
+    .with_block_time(BLOCK_TIME)
+    .build();
+    builder.exec(unbond_request).expect_success().commit();
+    builder.exec(bond_request).expect_success().commit();
+    assert_eq!(get_stake(&builder, VALIDATOR_1_ADDR), None);
+
+    let response = slash_at(
+        &mut builder,
+        VALIDATOR_1_ADDR,
+        Slash::new(SlashAmount::Rate(0), 2),
+        BLOCK_TIME,
+    );
+    assert!(response.has_success(), "{:?}", response);
+
+    unbond_payout(&mut builder, BLOCK_TIME + BOND_DELAY);
+    assert_eq!(get_stake(&builder, VALIDATOR_1_ADDR), None);
+}
+
+#[ignore]
+#[test]
+fn should_reject_slash_rate_above_one() {
+    let mut builder = setup();
+
+    let response = slash(
+        &mut builder,
+        VALIDATOR_1_ADDR,
+        Slash::new(SlashAmount::Rate(RATE_DENOMINATOR + 1), 0),
+    );
+    assert!(response.has_error(), "expected error: {:?}", response);
+    assert_revert(
+        response.get_error().get_message(),
+        pos::Error::InvalidSlashRate,
+    );
+    assert_eq!(
+        get_stake(&builder, VALIDATOR_1_ADDR),
+        Some(U512::from(VALIDATOR_1_STAKE))
+    );
+}
@@ -14,7 +14,8 @@ use types::ProtocolVersion;
 use types::{CLValue, Key, U512};
 
 const PROTOCOL_VERSION: ProtocolVersion = ProtocolVersion::V1_0_0;
-const DEFAULT_ACTIVATION_POINT: ActivationPoint = 1;
+const DEFAULT_ACTIVATION_RANK: u64 = 1;
+const DEFAULT_ACTIVATION_POINT: ActivationPoint = ActivationPoint::Rank(DEFAULT_ACTIVATION_RANK);
 const MODIFIED_SYSTEM_UPGRADER_CONTRACT_NAME: &str = "modified_system_upgrader.wasm";
 #[cfg(not(feature = "turbo"))]
 const MODIFIED_MINT_CALLER_CONTRACT_NAME: &str = "modified_mint_caller.wasm";
@@ -115,6 +116,7 @@ fn should_upgrade_system_contract() {
             (U512::from(PAYMENT_AMOUNT),),
         )
         .with_protocol_version(new_protocol_version)
+        .with_rank(DEFAULT_ACTIVATION_RANK)
         .build()
     };
 
@@ -194,6 +196,7 @@ fn should_upgrade_system_contract_on_patch_bump() {
             (U512::from(PAYMENT_AMOUNT),),
         )
         .with_protocol_version(new_protocol_version)
+        .with_rank(DEFAULT_ACTIVATION_RANK)
         .build()
     };
 
@@ -273,6 +276,7 @@ fn should_upgrade_system_contract_on_minor_bump() {
             (U512::from(PAYMENT_AMOUNT),),
         )
         .with_protocol_version(new_protocol_version)
+        .with_rank(DEFAULT_ACTIVATION_RANK)
         .build()
     };
 
@@ -437,6 +441,7 @@ fn should_upgrade_system_contract_and_wasm_costs_major() {
             (U512::from(PAYMENT_AMOUNT),),
         )
         .with_protocol_version(new_protocol_version)
+        .with_rank(DEFAULT_ACTIVATION_RANK)
         .build()
     };
 
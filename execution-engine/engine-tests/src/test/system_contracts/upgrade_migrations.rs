@@ -0,0 +1,206 @@
+use std::convert::TryFrom;
+
+use engine_core::engine_state::{
+    genesis::POS_PARAMS, migration::Migration, upgrade::ActivationPoint, SYSTEM_ACCOUNT_ADDR,
+};
+use engine_grpc_server::engine_server::ipc::UpgradeRequest;
+use engine_shared::account::ActionThresholds;
+use engine_test_support::{
+    internal::{
+        ExecuteRequestBuilder, InMemoryWasmTestBuilder, UpgradeRequestBuilder,
+        DEFAULT_GENESIS_CONFIG,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use proof_of_stake::PosParams;
+use types::{account::Weight, CLValue, Key, ProtocolVersion, U512};
+
+const PROTOCOL_VERSION: ProtocolVersion = ProtocolVersion::V1_0_0;
+const DEFAULT_ACTIVATION_POINT: ActivationPoint = ActivationPoint::Rank(1);
+const DO_NOTHING_STORED_INSTALL: &str = "do_nothing_stored.wasm";
+const DO_NOTHING_STORED_KEY: &str = "do_nothing_stored";
+const MOVED_KEY: &str = "moved_do_nothing_stored";
+
+fn new_protocol_version() -> ProtocolVersion {
+    let sem_ver = PROTOCOL_VERSION.value();
+    ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor, sem_ver.patch + 1)
+}
+
+fn setup() -> InMemoryWasmTestBuilder {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, DO_NOTHING_STORED_INSTALL, ("hash",))
+            .build();
+    builder.exec(exec_request).expect_success().commit();
+    builder
+}
+
+fn upgrade_request(migrations: Vec<Migration>) -> UpgradeRequest {
+    migrations
+        .into_iter()
+        .fold(UpgradeRequestBuilder::new(), |builder, migration| {
+            builder.with_migration(migration)
+        })
+        .with_current_protocol_version(PROTOCOL_VERSION)
+        .with_new_protocol_version(new_protocol_version())
+        .with_activation_point(DEFAULT_ACTIVATION_POINT)
+        .build()
+}
+
+fn pos_params_key(builder: &InMemoryWasmTestBuilder) -> Key {
+    builder.get_pos_contract().named_keys()[POS_PARAMS]
+}
+
+fn custom_pos_params() -> PosParams {
+    PosParams {
+        max_spread: U512::from(1_000_000),
+        ..PosParams::default()
+    }
+}
+
+#[ignore]
+#[test]
+fn should_apply_migrations_on_upgrade() {
+    let mut builder = setup();
+    let contract_key = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()[DO_NOTHING_STORED_KEY];
+
+    let mut upgrade_request = upgrade_request(vec![
+        Migration::WriteValue {
+            key: pos_params_key(&builder),
+            value: CLValue::from_t(custom_pos_params()).expect("should create CLValue"),
+        },
+        Migration::MoveNamedKey {
+            from: Key::Account(DEFAULT_ACCOUNT_ADDR),
+            from_name: DO_NOTHING_STORED_KEY.to_string(),
+            to: Key::Account(SYSTEM_ACCOUNT_ADDR),
+            to_name: MOVED_KEY.to_string(),
+        },
+    ]);
+    builder.upgrade_with_upgrade_request(&mut upgrade_request);
+
+    let upgrade_response = builder
+        .get_upgrade_response(0)
+        .expect("should have response");
+    assert!(upgrade_response.has_success(), "{:?}", upgrade_response);
+    assert_eq!(upgrade_response.get_success().get_applied_migrations(), 2);
+
+    assert_eq!(
+        builder.get_pos_params(new_protocol_version()),
+        custom_pos_params()
+    );
+    let default_account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    assert!(!default_account
+        .named_keys()
+        .contains_key(DO_NOTHING_STORED_KEY));
+    let system_account = builder
+        .get_account(SYSTEM_ACCOUNT_ADDR)
+        .expect("should have system account");
+    assert_eq!(
+        system_account.named_keys().get(MOVED_KEY),
+        Some(&contract_key)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_put_named_value_on_upgrade() {
+    const TOTAL_SUPPLY_KEY: &str = "total_supply";
+
+    let mut builder = setup();
+    let total_supply = U512::from(1_000_000);
+
+    let mut upgrade_request = upgrade_request(vec![Migration::PutNamedValue {
+        key: Key::Account(DEFAULT_ACCOUNT_ADDR),
+        name: TOTAL_SUPPLY_KEY.to_string(),
+        value: CLValue::from_t(total_supply).expect("should create CLValue"),
+    }]);
+    builder.upgrade_with_upgrade_request(&mut upgrade_request);
+
+    let upgrade_response = builder
+        .get_upgrade_response(0)
+        .expect("should have response");
+    assert!(upgrade_response.has_success(), "{:?}", upgrade_response);
+
+    let stored_value = builder
+        .query(
+            None,
+            Key::Account(DEFAULT_ACCOUNT_ADDR),
+            &[TOTAL_SUPPLY_KEY],
+        )
+        .expect("should have value");
+    let cl_value = CLValue::try_from(stored_value).expect("should have CLValue");
+    assert_eq!(
+        cl_value.into_t::<U512>().expect("should be U512"),
+        total_supply
+    );
+}
+
+#[ignore]
+#[test]
+fn should_reject_migration_with_mismatched_type() {
+    let mut builder = setup();
+
+    let mut upgrade_request = upgrade_request(vec![Migration::WriteValue {
+        key: pos_params_key(&builder),
+        value: CLValue::from_t(1u64).expect("should create CLValue"),
+    }]);
+    builder.upgrade_with_upgrade_request(&mut upgrade_request);
+
+    let upgrade_response = builder
+        .get_upgrade_response(0)
+        .expect("should have response");
+    assert!(
+        upgrade_response.has_failed_deploy(),
+        "{:?}",
+        upgrade_response
+    );
+    let message = &upgrade_response.get_failed_deploy().message;
+    assert!(message.starts_with("Invalid migration 0"), "{}", message);
+}
+
+#[ignore]
+#[test]
+fn should_report_index_of_invalid_migration() {
+    let mut builder = setup();
+
+    // The default account's only key has weight 1, so it can't meet a threshold of 2.
+    let mut upgrade_request = upgrade_request(vec![
+        Migration::MoveNamedKey {
+            from: Key::Account(DEFAULT_ACCOUNT_ADDR),
+            from_name: DO_NOTHING_STORED_KEY.to_string(),
+            to: Key::Account(DEFAULT_ACCOUNT_ADDR),
+            to_name: MOVED_KEY.to_string(),
+        },
+        Migration::SetActionThresholds {
+            account: DEFAULT_ACCOUNT_ADDR,
+            action_thresholds: ActionThresholds::new(Weight::new(1), Weight::new(2))
+                .expect("should create thresholds"),
+        },
+    ]);
+    builder.upgrade_with_upgrade_request(&mut upgrade_request);
+
+    let upgrade_response = builder
+        .get_upgrade_response(0)
+        .expect("should have response");
+    assert!(
+        upgrade_response.has_failed_deploy(),
+        "{:?}",
+        upgrade_response
+    );
+    let message = &upgrade_response.get_failed_deploy().message;
+    assert!(message.starts_with("Invalid migration 1"), "{}", message);
+
+    // The failed upgrade doesn't register the new protocol version.
+    let maybe_protocol_data = builder
+        .get_engine_state()
+        .get_protocol_data(new_protocol_version())
+        .expect("should read protocol data");
+    assert_eq!(maybe_protocol_data, None);
+}
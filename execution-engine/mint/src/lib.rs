@@ -10,6 +10,8 @@ use types::{account::PublicKey, system_contract_errors::mint::Error, Key, URef,
 pub use crate::{runtime_provider::RuntimeProvider, storage_provider::StorageProvider};
 
 const SYSTEM_ACCOUNT: PublicKey = PublicKey::ed25519_from([0; 32]);
+const TOTAL_SUPPLY_KEY: &str = "total_supply";
+const MINT_AUTHORITY_KEY: &str = "mint_authority";
 
 pub trait Mint: RuntimeProvider + StorageProvider {
     fn mint(&mut self, initial_balance: U512) -> Result<URef, Error> {
@@ -28,9 +30,119 @@ pub trait Mint: RuntimeProvider + StorageProvider {
         // store association between purse id and balance uref
         self.write_local(purse_key.addr(), balance_uref);
 
+        if !initial_balance.is_zero() {
+            let total_supply = self
+                .total_supply()?
+                .checked_add(initial_balance)
+                .ok_or(Error::SupplyOverflow)?;
+            self.write_total_supply(total_supply)?;
+        }
+
         Ok(purse_key)
     }
 
+    /// Returns the total amount of tokens in existence, i.e. the sum of the balances of all purses.
+    ///
+    /// Chains which predate tracking the supply have to seed it when upgrading, via a
+    /// `PutNamedValue` migration adding `total_supply` to the mint's named keys.
+    fn total_supply(&mut self) -> Result<U512, Error> {
+        match self.get_key(TOTAL_SUPPLY_KEY) {
+            Some(key) => {
+                let uref: URef = TryFrom::<Key>::try_from(key).map_err(|_| Error::InvalidURef)?;
+                Ok(self.read(uref)?.unwrap_or_default())
+            }
+            None => Ok(U512::zero()),
+        }
+    }
+
+    /// Writes a new total supply, creating the URef holding it on first use.
+    fn write_total_supply(&mut self, total_supply: U512) -> Result<(), Error> {
+        match self.get_key(TOTAL_SUPPLY_KEY) {
+            Some(key) => {
+                let uref: URef = TryFrom::<Key>::try_from(key).map_err(|_| Error::InvalidURef)?;
+                self.write(uref, total_supply)
+            }
+            None => {
+                let uref = self.new_uref(total_supply);
+                self.put_key(TOTAL_SUPPLY_KEY, uref.into());
+                Ok(())
+            }
+        }
+    }
+
+    /// Returns the URef whose holder is allowed to call `mint_tokens` and `burn`, creating it on
+    /// first use.
+    ///
+    /// Only the system account may obtain the authority; it is handed to PoS at genesis.
+    fn authority(&mut self) -> Result<URef, Error> {
+        if self.get_caller() != SYSTEM_ACCOUNT {
+            return Err(Error::InvalidAuthority);
+        }
+        match self.get_key(MINT_AUTHORITY_KEY) {
+            Some(key) => TryFrom::<Key>::try_from(key).map_err(|_| Error::InvalidURef),
+            None => {
+                let authority: URef = self.new_uref(());
+                self.put_key(MINT_AUTHORITY_KEY, authority.into());
+                Ok(authority)
+            }
+        }
+    }
+
+    /// Checks that `authority` is a writeable copy of the mint authority URef.
+    fn check_authority(&mut self, authority: URef) -> Result<(), Error> {
+        match self.get_key(MINT_AUTHORITY_KEY) {
+            Some(Key::URef(expected))
+                if expected.addr() == authority.addr() && authority.is_writeable() =>
+            {
+                Ok(())
+            }
+            _ => Err(Error::InvalidAuthority),
+        }
+    }
+
+    /// Creates `amount` new tokens in `purse`, increasing the total supply accordingly.
+    fn mint_tokens(&mut self, authority: URef, purse: URef, amount: U512) -> Result<(), Error> {
+        self.check_authority(authority)?;
+        if !purse.is_addable() {
+            return Err(Error::InvalidAccessRights);
+        }
+        let balance_uref: URef = match self.read_local(&purse.addr())? {
+            Some(key) => TryFrom::<Key>::try_from(key).map_err(|_| Error::InvalidAccessRights)?,
+            None => return Err(Error::DestNotFound),
+        };
+        let total_supply = self
+            .total_supply()?
+            .checked_add(amount)
+            .ok_or(Error::SupplyOverflow)?;
+        self.add(balance_uref, amount)?;
+        self.write_total_supply(total_supply)
+    }
+
+    /// Destroys `amount` tokens held in `purse`, decreasing the total supply accordingly.
+    fn burn(&mut self, authority: URef, purse: URef, amount: U512) -> Result<(), Error> {
+        self.check_authority(authority)?;
+        if !purse.is_writeable() {
+            return Err(Error::InvalidAccessRights);
+        }
+        let balance_uref: URef = match self.read_local(&purse.addr())? {
+            Some(key) => TryFrom::<Key>::try_from(key).map_err(|_| Error::InvalidAccessRights)?,
+            None => return Err(Error::SourceNotFound),
+        };
+        let balance: U512 = match self.read(balance_uref)? {
+            Some(balance) => balance,
+            None => return Err(Error::SourceNotFound),
+        };
+        if amount > balance {
+            return Err(Error::InsufficientFunds);
+        }
+        let total_supply = self
+            .total_supply()?
+            .checked_sub(amount)
+            .ok_or(Error::SupplyOverflow)?;
+        self.write(balance_uref, balance - amount)?;
+        self.write_total_supply(total_supply)
+    }
+
     fn balance(&mut self, purse: URef) -> Result<Option<U512>, Error> {
         let balance_uref: URef = match self.read_local(&purse.addr())? {
             Some(key) => TryFrom::<Key>::try_from(key).map_err(|_| Error::InvalidAccessRights)?,
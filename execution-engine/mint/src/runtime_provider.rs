@@ -4,4 +4,6 @@ pub trait RuntimeProvider {
     fn get_caller(&self) -> PublicKey;
 
     fn put_key(&mut self, name: &str, key: Key);
+
+    fn get_key(&self, name: &str) -> Option<Key>;
 }
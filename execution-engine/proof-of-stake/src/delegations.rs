@@ -0,0 +1,317 @@
+use alloc::{boxed::Box, collections::BTreeMap, vec::Vec};
+use core::result;
+
+use types::{
+    account::PublicKey,
+    bytesrepr::{self, FromBytes, ToBytes},
+    system_contract_errors::pos::{Error, Result},
+    BlockTime, CLType, CLTyped, U512,
+};
+
+/// The delegations map, assigning to each validator the amount of motes each of their delegators
+/// has staked with them.
+///
+/// The delegated amounts are included in the validators' entries in the
+/// [`Stakes`](crate::Stakes), so they count towards the validators' weights and the bonding and
+/// unbonding limits.
+#[derive(Clone, Debug, Default, PartialEq, FromBytes, ToBytes, CLTyped)]
+pub struct Delegations(pub BTreeMap<PublicKey, BTreeMap<PublicKey, U512>>);
+
+impl Delegations {
+    /// Returns the delegators of the given validator with their delegated amounts, if any.
+    pub fn delegators(&self, validator: &PublicKey) -> Option<&BTreeMap<PublicKey, U512>> {
+        self.0.get(validator)
+    }
+
+    /// Returns the amount the given delegator has delegated to the given validator.
+    pub fn delegation(&self, validator: &PublicKey, delegator: &PublicKey) -> U512 {
+        self.delegators(validator)
+            .and_then(|delegators| delegators.get(delegator))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Returns the total amount delegated to the given validator.
+    pub fn total_delegated(&self, validator: &PublicKey) -> U512 {
+        self.delegators(validator)
+            .map(|delegators| {
+                delegators
+                    .values()
+                    .fold(U512::zero(), |sum, amount| sum + amount)
+            })
+            .unwrap_or_default()
+    }
+
+    /// Adds `amount` to the delegator's stake with the validator.
+    pub fn delegate(&mut self, validator: &PublicKey, delegator: &PublicKey, amount: U512) {
+        self.0
+            .entry(*validator)
+            .or_default()
+            .entry(*delegator)
+            .and_modify(|x| *x += amount)
+            .or_insert(amount);
+    }
+
+    /// If `maybe_amount` is `None`, removes the delegator's whole stake with the validator,
+    /// otherwise subtracts the given amount.
+    ///
+    /// Returns the amount that was actually subtracted, or an error if the delegator has not
+    /// delegated to the validator or if the amount exceeds their delegated stake.
+    pub fn undelegate(
+        &mut self,
+        validator: &PublicKey,
+        delegator: &PublicKey,
+        maybe_amount: Option<U512>,
+    ) -> Result<U512> {
+        let delegators = self.0.get_mut(validator).ok_or(Error::NotDelegated)?;
+        let stake = delegators.get_mut(delegator).ok_or(Error::NotDelegated)?;
+        let amount = maybe_amount.unwrap_or(*stake);
+        if amount > *stake {
+            return Err(Error::UnbondTooLarge);
+        }
+        *stake -= amount;
+        if stake.is_zero() {
+            delegators.remove(delegator);
+        }
+        if delegators.is_empty() {
+            self.0.remove(validator);
+        }
+        Ok(amount)
+    }
+
+    /// Removes all delegations to the given validator and returns them.
+    pub fn remove_validator(&mut self, validator: &PublicKey) -> BTreeMap<PublicKey, U512> {
+        self.0.remove(validator).unwrap_or_default()
+    }
+}
+
+/// A pending entry in the delegation or undelegation queue.
+#[derive(Clone, Copy, Debug, PartialEq, FromBytes, ToBytes, CLTyped)]
+pub struct DelegationQueueEntry {
+    /// The account that is delegating or undelegating.
+    pub delegator: PublicKey,
+    /// The validator the stake is delegated to.
+    pub validator: PublicKey,
+    /// The amount by which to change the delegated stake.
+    pub amount: U512,
+    /// The timestamp when the request was made.
+    pub timestamp: BlockTime,
+}
+
+impl DelegationQueueEntry {
+    fn new(
+        delegator: PublicKey,
+        validator: PublicKey,
+        amount: U512,
+        timestamp: BlockTime,
+    ) -> DelegationQueueEntry {
+        DelegationQueueEntry {
+            delegator,
+            validator,
+            amount,
+            timestamp,
+        }
+    }
+}
+
+/// A queue of delegation or undelegation requests, sorted by timestamp in ascending order.
+#[derive(Clone, Default)]
+pub struct DelegationQueue(pub Vec<DelegationQueueEntry>);
+
+impl DelegationQueue {
+    /// Pushes a new entry to the end of the queue.
+    ///
+    /// Returns an error if the delegator already has a request for the same validator in the
+    /// queue.
+    pub fn push(
+        &mut self,
+        delegator: PublicKey,
+        validator: PublicKey,
+        amount: U512,
+        timestamp: BlockTime,
+    ) -> Result<()> {
+        if self
+            .0
+            .iter()
+            .any(|entry| entry.delegator == delegator && entry.validator == validator)
+        {
+            return Err(Error::MultipleRequests);
+        }
+        if let Some(entry) = self.0.last() {
+            if entry.timestamp > timestamp {
+                return Err(Error::TimeWentBackwards);
+            }
+        }
+        self.0.push(DelegationQueueEntry::new(
+            delegator, validator, amount, timestamp,
+        ));
+        Ok(())
+    }
+
+    /// Pushes a new entry to the end of the queue, merging in the amount of any request the
+    /// delegator already has in the queue for the same validator.  The merged amount is only due
+    /// after the new request's delay.
+    pub fn push_merged(
+        &mut self,
+        delegator: PublicKey,
+        validator: PublicKey,
+        amount: U512,
+        timestamp: BlockTime,
+    ) -> Result<()> {
+        let mut merged_amount = amount;
+        if let Some(index) = self
+            .0
+            .iter()
+            .position(|entry| entry.delegator == delegator && entry.validator == validator)
+        {
+            merged_amount += self.0[index].amount;
+            self.0.remove(index);
+        }
+        self.push(delegator, validator, merged_amount, timestamp)
+    }
+
+    /// Returns all queue entries at least as old as the specified timestamp.
+    pub fn pop_due(&mut self, timestamp: BlockTime) -> Vec<DelegationQueueEntry> {
+        let (older_than, rest) = self
+            .0
+            .iter()
+            .partition(|entry| entry.timestamp <= timestamp);
+        self.0 = rest;
+        older_than
+    }
+}
+
+impl FromBytes for DelegationQueue {
+    fn from_bytes(bytes: &[u8]) -> result::Result<(Self, &[u8]), bytesrepr::Error> {
+        let (len, mut bytes) = u64::from_bytes(bytes)?;
+        let mut queue = Vec::new();
+        for _ in 0..len {
+            let (entry, rest) = DelegationQueueEntry::from_bytes(bytes)?;
+            bytes = rest;
+            queue.push(entry);
+        }
+        Ok((DelegationQueue(queue), bytes))
+    }
+}
+
+impl ToBytes for DelegationQueue {
+    fn to_bytes(&self) -> result::Result<Vec<u8>, bytesrepr::Error> {
+        let mut bytes = (self.0.len() as u64).to_bytes()?;
+        for entry in &self.0 {
+            bytes.append(&mut entry.to_bytes()?);
+        }
+        Ok(bytes)
+    }
+}
+
+impl CLTyped for DelegationQueue {
+    fn cl_type() -> CLType {
+        CLType::List(Box::new(DelegationQueueEntry::cl_type()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use types::{account::PublicKey, system_contract_errors::pos::Error, BlockTime, U512};
+
+    use super::{DelegationQueue, Delegations};
+
+    const VALIDATOR: [u8; 32] = [1; 32];
+    const DELEGATOR1: [u8; 32] = [2; 32];
+    const DELEGATOR2: [u8; 32] = [3; 32];
+
+    #[test]
+    fn test_delegate_and_undelegate() {
+        let validator = PublicKey::ed25519_from(VALIDATOR);
+        let delegator1 = PublicKey::ed25519_from(DELEGATOR1);
+        let delegator2 = PublicKey::ed25519_from(DELEGATOR2);
+        let mut delegations = Delegations::default();
+
+        delegations.delegate(&validator, &delegator1, U512::from(10));
+        delegations.delegate(&validator, &delegator2, U512::from(20));
+        delegations.delegate(&validator, &delegator1, U512::from(5));
+        assert_eq!(
+            delegations.delegation(&validator, &delegator1),
+            U512::from(15)
+        );
+        assert_eq!(delegations.total_delegated(&validator), U512::from(35));
+
+        assert_eq!(
+            Err(Error::UnbondTooLarge),
+            delegations.undelegate(&validator, &delegator1, Some(U512::from(16)))
+        );
+        assert_eq!(
+            Ok(U512::from(4)),
+            delegations.undelegate(&validator, &delegator1, Some(U512::from(4)))
+        );
+        assert_eq!(
+            Ok(U512::from(11)),
+            delegations.undelegate(&validator, &delegator1, None)
+        );
+        assert_eq!(
+            Err(Error::NotDelegated),
+            delegations.undelegate(&validator, &delegator1, None)
+        );
+        assert_eq!(
+            Ok(U512::from(20)),
+            delegations.undelegate(&validator, &delegator2, None)
+        );
+        assert_eq!(delegations, Delegations::default());
+    }
+
+    #[test]
+    fn test_push_to_queue() {
+        let validator = PublicKey::ed25519_from(VALIDATOR);
+        let delegator1 = PublicKey::ed25519_from(DELEGATOR1);
+        let delegator2 = PublicKey::ed25519_from(DELEGATOR2);
+        let mut queue = DelegationQueue::default();
+
+        assert_eq!(
+            Ok(()),
+            queue.push(delegator1, validator, U512::from(5), BlockTime::new(100))
+        );
+        assert_eq!(
+            Ok(()),
+            queue.push(delegator2, validator, U512::from(5), BlockTime::new(101))
+        );
+        // A delegator may delegate to several validators at once, but only once to each.
+        assert_eq!(
+            Ok(()),
+            queue.push(delegator1, delegator2, U512::from(5), BlockTime::new(101))
+        );
+        assert_eq!(
+            Err(Error::MultipleRequests),
+            queue.push(delegator1, validator, U512::from(5), BlockTime::new(102))
+        );
+        assert_eq!(
+            Err(Error::TimeWentBackwards),
+            queue.push(validator, delegator1, U512::from(5), BlockTime::new(100))
+        );
+        assert_eq!(1, queue.pop_due(BlockTime::new(100)).len());
+        assert_eq!(2, queue.0.len());
+    }
+
+    #[test]
+    fn test_push_merged_to_queue() {
+        let validator = PublicKey::ed25519_from(VALIDATOR);
+        let delegator1 = PublicKey::ed25519_from(DELEGATOR1);
+        let delegator2 = PublicKey::ed25519_from(DELEGATOR2);
+        let mut queue = DelegationQueue::default();
+
+        queue
+            .push(delegator1, validator, U512::from(5), BlockTime::new(100))
+            .expect("should push");
+        queue
+            .push(delegator2, validator, U512::from(5), BlockTime::new(101))
+            .expect("should push");
+        assert_eq!(
+            Ok(()),
+            queue.push_merged(delegator1, validator, U512::from(7), BlockTime::new(102))
+        );
+        assert_eq!(2, queue.0.len());
+        let entry = queue.0[1];
+        assert_eq!(entry.delegator, delegator1);
+        assert_eq!(entry.amount, U512::from(12));
+        assert_eq!(entry.timestamp, BlockTime::new(102));
+    }
+}
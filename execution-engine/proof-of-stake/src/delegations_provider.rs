@@ -0,0 +1,24 @@
+use crate::{
+    delegations::{DelegationQueue, Delegations},
+    Result,
+};
+
+pub trait DelegationsProvider {
+    /// Reads the delegated stakes.
+    fn read_delegations(&mut self) -> Result<Delegations>;
+
+    /// Writes the delegated stakes.
+    fn write_delegations(&mut self, delegations: Delegations) -> Result<()>;
+
+    /// Reads delegation queue.
+    fn read_delegating(&mut self) -> Result<DelegationQueue>;
+
+    /// Reads undelegation queue.
+    fn read_undelegating(&mut self) -> Result<DelegationQueue>;
+
+    /// Writes delegation queue.
+    fn write_delegating(&mut self, queue: DelegationQueue) -> Result<()>;
+
+    /// Writes undelegation queue.
+    fn write_undelegating(&mut self, queue: DelegationQueue) -> Result<()>;
+}
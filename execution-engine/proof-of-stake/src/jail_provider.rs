@@ -0,0 +1,9 @@
+use crate::{slashing::Jail, Result};
+
+pub trait JailProvider {
+    /// Reads the jailed validators.
+    fn read_jail(&mut self) -> Result<Jail>;
+
+    /// Writes the jailed validators.
+    fn write_jail(&mut self, jail: Jail) -> Result<()>;
+}
@@ -2,28 +2,57 @@
 
 extern crate alloc;
 
+mod delegations;
+mod delegations_provider;
+mod jail_provider;
 mod mint_provider;
+mod params;
+mod params_provider;
 mod queue;
 mod queue_provider;
+mod rewards;
+mod rewards_provider;
 mod runtime_provider;
+mod slashing;
 mod stakes;
 mod stakes_provider;
 
+use alloc::collections::BTreeMap;
 use core::marker::Sized;
 
 use types::{
     account::PublicKey,
     system_contract_errors::pos::{Error, Result},
-    AccessRights, TransferredTo, URef, U512,
+    AccessRights, URef, U512,
 };
 
 pub use crate::{
-    mint_provider::MintProvider, queue::Queue, queue_provider::QueueProvider,
-    runtime_provider::RuntimeProvider, stakes::Stakes, stakes_provider::StakesProvider,
+    delegations::{DelegationQueue, DelegationQueueEntry, Delegations},
+    delegations_provider::DelegationsProvider,
+    jail_provider::JailProvider,
+    mint_provider::MintProvider,
+    params::PosParams,
+    params_provider::ParamsProvider,
+    queue::Queue,
+    queue_provider::QueueProvider,
+    rewards::{EraRewards, ValidatorReward, RATE_DENOMINATOR},
+    rewards_provider::RewardsProvider,
+    runtime_provider::RuntimeProvider,
+    slashing::{Jail, Slash, SlashAmount},
+    stakes::Stakes,
+    stakes_provider::StakesProvider,
 };
 
 pub trait ProofOfStake:
-    MintProvider + QueueProvider + RuntimeProvider + StakesProvider + Sized
+    DelegationsProvider
+    + JailProvider
+    + MintProvider
+    + ParamsProvider
+    + QueueProvider
+    + RewardsProvider
+    + RuntimeProvider
+    + StakesProvider
+    + Sized
 {
     fn bond(&mut self, validator: PublicKey, amount: U512, source: URef) -> Result<()> {
         if amount.is_zero() {
@@ -37,28 +66,64 @@ pub trait ProofOfStake:
             .map_err(|_| Error::BondTransferFailed)?;
         internal::bond(self, amount, validator, timestamp)?;
 
-        // TODO: Remove this and set nonzero delays once the system calls `step` in each block.
-        let unbonds = internal::step(self, timestamp)?;
-        for entry in unbonds {
-            let _: TransferredTo = self
-                .transfer_purse_to_account(source, entry.validator, entry.amount)
-                .map_err(|_| Error::BondTransferFailed)?;
-        }
-        Ok(())
+        // TODO: Remove this and set nonzero delays once the node calls `step` in each block.
+        internal::step_and_pay_out(self, timestamp)
     }
 
     fn unbond(&mut self, validator: PublicKey, maybe_amount: Option<U512>) -> Result<()> {
-        let pos_purse = internal::get_bonding_purse(self)?;
         let timestamp = self.get_block_time();
         internal::unbond(self, maybe_amount, validator, timestamp)?;
 
-        // TODO: Remove this and set nonzero delays once the system calls `step` in each block.
-        let unbonds = internal::step(self, timestamp)?;
-        for entry in unbonds {
-            self.transfer_purse_to_account(pos_purse, entry.validator, entry.amount)
-                .map_err(|_| Error::UnbondTransferFailed)?;
+        // TODO: Remove this and set nonzero delays once the node calls `step` in each block.
+        internal::step_and_pay_out(self, timestamp)
+    }
+
+    /// Delegates `amount` from the purse `source` to the bonded `validator`, on behalf of
+    /// `delegator`.
+    fn delegate(
+        &mut self,
+        delegator: PublicKey,
+        validator: PublicKey,
+        amount: U512,
+        source: URef,
+    ) -> Result<()> {
+        if amount.is_zero() {
+            return Err(Error::BondTooSmall);
         }
-        Ok(())
+        let pos_purse = internal::get_bonding_purse(self)?;
+        let timestamp = self.get_block_time();
+        self.transfer_purse_to_purse(source, pos_purse, amount)
+            .map_err(|_| Error::BondTransferFailed)?;
+        internal::delegate(self, amount, delegator, validator, timestamp)?;
+
+        // TODO: Remove this and set nonzero delays once the node calls `step` in each block.
+        internal::step_and_pay_out(self, timestamp)
+    }
+
+    /// Withdraws `delegator`'s stake delegated to `validator`. If `maybe_amount` is `None`, the
+    /// whole delegated stake is withdrawn.
+    fn undelegate(
+        &mut self,
+        delegator: PublicKey,
+        validator: PublicKey,
+        maybe_amount: Option<U512>,
+    ) -> Result<()> {
+        let timestamp = self.get_block_time();
+        internal::undelegate(self, maybe_amount, delegator, validator, timestamp)?;
+
+        // TODO: Remove this and set nonzero delays once the node calls `step` in each block.
+        internal::step_and_pay_out(self, timestamp)
+    }
+
+    /// Applies all bonding, unbonding, delegation and undelegation requests which are due at the
+    /// current block time, and pays out the released stake from the bonding purse.  Only the system
+    /// account may call this.
+    fn step(&mut self) -> Result<()> {
+        if self.get_caller() != internal::SYSTEM_ACCOUNT {
+            return Err(Error::SystemFunctionCalledByUserAccount);
+        }
+        let timestamp = self.get_block_time();
+        internal::step_and_pay_out(self, timestamp)
     }
 
     fn get_payment_purse(&self) -> Result<URef> {
@@ -82,10 +147,48 @@ pub trait ProofOfStake:
     fn finalize_payment(&mut self, amount_spent: U512, account: PublicKey) -> Result<()> {
         internal::finalize_payment(self, amount_spent, account)
     }
+
+    /// Mints `inflation_rate` millionths of the total supply into the rewards purse and pays out
+    /// the whole rewards purse to the bonded validators in proportion to their stakes.
+    ///
+    /// Delegators receive their share of their validator's reward, minus `commission_rate`
+    /// millionths which go to the validator, as additional delegated stake.  Of the validator's
+    /// own share, `commission_rate` millionths are transferred to the validator's account together
+    /// with the commission, and the rest is added to their stake.  Rewards can be distributed at
+    /// most once per era, and only for eras later than the last rewarded one.
+    fn distribute_rewards(
+        &mut self,
+        era_id: u64,
+        inflation_rate: u64,
+        commission_rate: u64,
+    ) -> Result<()> {
+        internal::distribute_rewards(self, era_id, inflation_rate, commission_rate)
+    }
+
+    /// Returns the rewards distributed for the most recently rewarded era, if any.
+    fn get_era_rewards(&mut self) -> Result<Option<EraRewards>> {
+        self.read_era_rewards()
+    }
+
+    /// Returns the parameters governing bonding, unbonding and the stake limits.
+    fn get_params(&mut self) -> Result<PosParams> {
+        self.read_params()
+    }
+
+    /// Burns the given amounts from the validators' own stakes and pending unbonds, moving them
+    /// from the bonding purse to the treasury purse.
+    ///
+    /// Validators whose sentence ends in a later era than the last rewarded one are jailed: they
+    /// are unbonded, their delegations are released, and they can't bond again until rewards have
+    /// been distributed for that era.
+    fn slash(&mut self, slashes: BTreeMap<PublicKey, Slash>) -> Result<()> {
+        let timestamp = self.get_block_time();
+        internal::slash(self, slashes, timestamp)
+    }
 }
 
 mod internal {
-    use alloc::vec::Vec;
+    use alloc::{collections::BTreeMap, vec::Vec};
 
     use types::{
         account::PublicKey,
@@ -94,12 +197,21 @@ mod internal {
     };
 
     use crate::{
-        mint_provider::MintProvider, queue::QueueEntry, queue_provider::QueueProvider,
-        runtime_provider::RuntimeProvider, stakes_provider::StakesProvider,
+        delegations_provider::DelegationsProvider,
+        jail_provider::JailProvider,
+        mint_provider::MintProvider,
+        params_provider::ParamsProvider,
+        queue_provider::QueueProvider,
+        rewards::{EraRewards, RATE_DENOMINATOR},
+        rewards_provider::RewardsProvider,
+        runtime_provider::RuntimeProvider,
+        slashing::{Slash, SlashAmount},
+        stakes::Stakes,
+        stakes_provider::StakesProvider,
     };
 
     /// Account used to run system functions (in particular `finalize_payment`).
-    const SYSTEM_ACCOUNT: PublicKey = PublicKey::ed25519_from([0u8; 32]);
+    pub const SYSTEM_ACCOUNT: PublicKey = PublicKey::ed25519_from([0u8; 32]);
 
     /// The uref name where the PoS purse is stored. It contains all staked motes, and all unbonded
     /// motes that are yet to be paid out.
@@ -111,98 +223,397 @@ mod internal {
     /// The uref name where the PoS holds validator earnings before distributing them.
     const REWARDS_PURSE_KEY: &str = "pos_rewards_purse";
 
+    /// The uref name where the PoS collects the motes slashed from validators.
+    const TREASURY_PURSE_KEY: &str = "pos_treasury_purse";
+
     /// The uref name where the PoS will refund unused payment back to the user. The uref this name
     /// corresponds to is set by the user.
     const REFUND_PURSE_KEY: &str = "pos_refund_purse";
 
-    /// The time from a bonding request until the bond becomes effective and part of the stake.
-    const BOND_DELAY: u64 = 0;
-
-    /// The time from an unbonding request until the stakes are paid out.
-    const UNBOND_DELAY: u64 = 0;
+    /// The uref name where the PoS holds the mint authority, which allows it to mint inflation.
+    const MINT_AUTHORITY_KEY: &str = "mint_authority";
 
-    /// The maximum number of pending bonding requests.
-    const MAX_BOND_LEN: usize = 100;
+    /// Returns the stakes as they will be once all pending bonding and delegation requests have
+    /// been applied.
+    fn read_pending_stakes<P: DelegationsProvider + QueueProvider + StakesProvider>(
+        provider: &mut P,
+    ) -> Result<Stakes> {
+        let mut stakes = provider.read()?;
+        for entry in &provider.read_bonding()?.0 {
+            stakes.bond(&entry.validator, entry.amount);
+        }
+        for entry in &provider.read_delegating()?.0 {
+            stakes.bond(&entry.validator, entry.amount);
+        }
+        Ok(stakes)
+    }
 
-    /// The maximum number of pending unbonding requests.
-    const MAX_UNBOND_LEN: usize = 1000;
+    /// Returns the most recently rewarded era, which is the era jail sentences are measured
+    /// against.
+    fn current_era<P: RewardsProvider>(provider: &mut P) -> Result<u64> {
+        Ok(provider
+            .read_era_rewards()?
+            .map_or(0, |era_rewards| era_rewards.era_id))
+    }
 
     /// Enqueues the deploy's creator for becoming a validator. The bond `amount` is paid from the
     /// purse `source`.
-    pub fn bond<P: QueueProvider + StakesProvider>(
+    pub fn bond<
+        P: DelegationsProvider
+            + JailProvider
+            + ParamsProvider
+            + QueueProvider
+            + RewardsProvider
+            + StakesProvider,
+    >(
         provider: &mut P,
         amount: U512,
         validator: PublicKey,
         timestamp: BlockTime,
     ) -> Result<()> {
-        let mut queue = provider.read_bonding();
-        if queue.0.len() >= MAX_BOND_LEN {
+        let era_id = current_era(provider)?;
+        if provider.read_jail()?.is_jailed(&validator, era_id) {
+            return Err(Error::ValidatorJailed);
+        }
+
+        let params = provider.read_params()?;
+        let mut queue = provider.read_bonding()?;
+        if queue.0.len() as u64 >= params.max_bond_len {
             return Err(Error::TooManyEventsInQueue);
         }
 
-        let mut stakes = provider.read()?;
         // Simulate applying all earlier bonds. The modified stakes are not written.
-        for entry in &queue.0 {
-            stakes.bond(&entry.validator, entry.amount);
-        }
-        stakes.validate_bonding(&validator, amount)?;
+        let stakes = read_pending_stakes(provider)?;
+        stakes.validate_bonding(&params, &validator, amount)?;
 
         queue.push(validator, amount, timestamp)?;
-        provider.write_bonding(queue);
+        provider.write_bonding(queue)?;
         Ok(())
     }
 
     /// Enqueues the deploy's creator for unbonding. Their vote weight as a validator is decreased
-    /// immediately, but the funds will only be released after a delay. If `maybe_amount` is `None`,
-    /// all funds are enqueued for withdrawal, terminating the validator status.
-    pub fn unbond<P: QueueProvider + StakesProvider>(
+    /// immediately, but the funds will only be released after a delay. If `maybe_amount` is `None`
+    /// or equal to the validator's own stake, all funds are enqueued for withdrawal, terminating
+    /// the validator status, and all stake delegated to the validator is enqueued for withdrawal,
+    /// too.
+    pub fn unbond<P: DelegationsProvider + ParamsProvider + QueueProvider + StakesProvider>(
         provider: &mut P,
         maybe_amount: Option<U512>,
         validator: PublicKey,
         timestamp: BlockTime,
     ) -> Result<()> {
-        let mut queue = provider.read_unbonding();
-        if queue.0.len() >= MAX_UNBOND_LEN {
+        let params = provider.read_params()?;
+        let mut queue = provider.read_unbonding()?;
+        if queue.0.len() as u64 >= params.max_unbond_len {
             return Err(Error::TooManyEventsInQueue);
         }
 
         let mut stakes = provider.read()?;
-        let payout = stakes.unbond(&validator, maybe_amount)?;
-        provider.write(&stakes);
+        let mut delegations = provider.read_delegations()?;
+        let delegated = delegations.total_delegated(&validator);
+        let own_stake = stakes
+            .0
+            .get(&validator)
+            .ok_or(Error::NotBonded)?
+            .saturating_sub(delegated);
+
+        let payout = match maybe_amount {
+            Some(amount) if amount > own_stake => return Err(Error::UnbondTooLarge),
+            Some(amount) if amount < own_stake => {
+                stakes.unbond(&params, &validator, Some(amount))?
+            }
+            _ => {
+                // The limits are checked against the validator's whole weight, including the
+                // delegated stake, which is released together with the validator's own.
+                let weight = stakes.unbond(&params, &validator, None)?;
+                let mut undelegating = provider.read_undelegating()?;
+                for (delegator, amount) in delegations.remove_validator(&validator) {
+                    undelegating.push_merged(delegator, validator, amount, timestamp)?;
+                }
+                if undelegating.0.len() as u64 > params.max_unbond_len {
+                    return Err(Error::TooManyEventsInQueue);
+                }
+                provider.write_undelegating(undelegating)?;
+                provider.write_delegations(delegations)?;
+                weight - delegated
+            }
+        };
+        provider.write(&stakes)?;
         // TODO: Make sure the destination is valid and the amount can be paid. The actual payment
         // will be made later, after the unbonding delay. contract_api::transfer_dry_run(POS_PURSE,
         // dest, amount)?;
         queue.push(validator, payout, timestamp)?;
-        provider.write_unbonding(queue);
+        provider.write_unbonding(queue)?;
         Ok(())
     }
 
-    /// Removes all due requests from the queues and applies them.
-    pub fn step<P: QueueProvider + StakesProvider>(
+    /// Enqueues a delegation of `amount` by `delegator` to the bonded `validator`. The delegated
+    /// stake counts towards the validator's weight and is subject to the same limits as a bond.
+    pub fn delegate<P: DelegationsProvider + ParamsProvider + QueueProvider + StakesProvider>(
         provider: &mut P,
+        amount: U512,
+        delegator: PublicKey,
+        validator: PublicKey,
+        timestamp: BlockTime,
+    ) -> Result<()> {
+        let params = provider.read_params()?;
+        let mut queue = provider.read_delegating()?;
+        if queue.0.len() as u64 >= params.max_bond_len {
+            return Err(Error::TooManyEventsInQueue);
+        }
+
+        if !provider.read()?.0.contains_key(&validator) {
+            return Err(Error::NotBonded);
+        }
+
+        // Simulate applying all earlier bonds and delegations. The modified stakes are not written.
+        let stakes = read_pending_stakes(provider)?;
+        stakes.validate_bonding(&params, &validator, amount)?;
+
+        queue.push(delegator, validator, amount, timestamp)?;
+        provider.write_delegating(queue)?;
+        Ok(())
+    }
+
+    /// Enqueues the withdrawal of stake `delegator` delegated to `validator`. The validator's vote
+    /// weight is decreased immediately, but the funds will only be released after a delay. If
+    /// `maybe_amount` is `None`, the whole delegated stake is withdrawn.
+    pub fn undelegate<P: DelegationsProvider + ParamsProvider + StakesProvider>(
+        provider: &mut P,
+        maybe_amount: Option<U512>,
+        delegator: PublicKey,
+        validator: PublicKey,
         timestamp: BlockTime,
-    ) -> Result<Vec<QueueEntry>> {
-        let mut bonding_queue = provider.read_bonding();
-        let mut unbonding_queue = provider.read_unbonding();
+    ) -> Result<()> {
+        let params = provider.read_params()?;
+        let mut queue = provider.read_undelegating()?;
+        if queue.0.len() as u64 >= params.max_unbond_len {
+            return Err(Error::TooManyEventsInQueue);
+        }
+
+        let mut delegations = provider.read_delegations()?;
+        let payout = delegations.undelegate(&validator, &delegator, maybe_amount)?;
+        let mut stakes = provider.read()?;
+        // The validator's own stake is never zero, so this only decreases their weight.
+        stakes.unbond(&params, &validator, Some(payout))?;
+        provider.write(&stakes)?;
+        provider.write_delegations(delegations)?;
 
-        let bonds = bonding_queue.pop_due(timestamp.saturating_sub(BlockTime::new(BOND_DELAY)));
-        let unbonds =
-            unbonding_queue.pop_due(timestamp.saturating_sub(BlockTime::new(UNBOND_DELAY)));
+        queue.push(delegator, validator, payout, timestamp)?;
+        provider.write_undelegating(queue)?;
+        Ok(())
+    }
 
-        if !unbonds.is_empty() {
-            provider.write_unbonding(unbonding_queue);
+    /// Removes all due requests from the queues and applies them.
+    ///
+    /// Returns the accounts to be paid out, with the amounts.  Delegations to validators that are
+    /// not bonded anymore are refunded.
+    pub fn step<P: DelegationsProvider + ParamsProvider + QueueProvider + StakesProvider>(
+        provider: &mut P,
+        timestamp: BlockTime,
+    ) -> Result<Vec<(PublicKey, U512)>> {
+        let params = provider.read_params()?;
+        let mut bonding_queue = provider.read_bonding()?;
+        let mut unbonding_queue = provider.read_unbonding()?;
+        let mut delegating_queue = provider.read_delegating()?;
+        let mut undelegating_queue = provider.read_undelegating()?;
+
+        let bond_timestamp = timestamp.saturating_sub(BlockTime::new(params.bond_delay));
+        let unbond_timestamp = timestamp.saturating_sub(BlockTime::new(params.unbond_delay));
+        let bonds = bonding_queue.pop_due(bond_timestamp);
+        let unbonds = unbonding_queue.pop_due(unbond_timestamp);
+        let delegations_due = delegating_queue.pop_due(bond_timestamp);
+        let undelegations_due = undelegating_queue.pop_due(unbond_timestamp);
+
+        let mut payouts: Vec<(PublicKey, U512)> = unbonds
+            .into_iter()
+            .map(|entry| (entry.validator, entry.amount))
+            .chain(
+                undelegations_due
+                    .iter()
+                    .map(|entry| (entry.delegator, entry.amount)),
+            )
+            .collect();
+
+        if !payouts.is_empty() {
+            provider.write_unbonding(unbonding_queue)?;
+            provider.write_undelegating(undelegating_queue)?;
         }
 
-        if !bonds.is_empty() {
-            provider.write_bonding(bonding_queue);
+        if !bonds.is_empty() || !delegations_due.is_empty() {
+            provider.write_bonding(bonding_queue)?;
+            provider.write_delegating(delegating_queue)?;
             let mut stakes = provider.read()?;
             for entry in bonds {
                 stakes.bond(&entry.validator, entry.amount);
             }
-            provider.write(&stakes);
+            if !delegations_due.is_empty() {
+                let mut delegations = provider.read_delegations()?;
+                for entry in delegations_due {
+                    if stakes.0.contains_key(&entry.validator) {
+                        stakes.bond(&entry.validator, entry.amount);
+                        delegations.delegate(&entry.validator, &entry.delegator, entry.amount);
+                    } else {
+                        payouts.push((entry.delegator, entry.amount));
+                    }
+                }
+                provider.write_delegations(delegations)?;
+            }
+            provider.write(&stakes)?;
         }
 
-        Ok(unbonds)
+        Ok(payouts)
+    }
+
+    /// Runs [`step`] and pays the returned payouts from the bonding purse.
+    pub fn step_and_pay_out<
+        P: DelegationsProvider
+            + MintProvider
+            + ParamsProvider
+            + QueueProvider
+            + RuntimeProvider
+            + StakesProvider,
+    >(
+        provider: &mut P,
+        timestamp: BlockTime,
+    ) -> Result<()> {
+        let bonding_purse = get_bonding_purse(provider)?;
+        for (recipient, amount) in step(provider, timestamp)? {
+            provider
+                .transfer_purse_to_account(bonding_purse, recipient, amount)
+                .map_err(|_| Error::UnbondTransferFailed)?;
+        }
+        Ok(())
+    }
+
+    /// Slashes the given validators and jails those whose sentence hasn't been served yet.
+    ///
+    /// Each slash is taken from the validator's own bonded stake first and from their pending
+    /// unbond after that; delegated stake is not slashed. A jailed validator, or one left without
+    /// any own stake, is removed from the stakes: the rest of their stake and any pending bond are
+    /// enqueued for unbonding, and their delegations for undelegation. A jailed validator who is
+    /// only about to bond has their pending bond enqueued for unbonding too.
+    pub fn slash<
+        P: DelegationsProvider
+            + JailProvider
+            + MintProvider
+            + QueueProvider
+            + RewardsProvider
+            + RuntimeProvider
+            + StakesProvider,
+    >(
+        provider: &mut P,
+        slashes: BTreeMap<PublicKey, Slash>,
+        timestamp: BlockTime,
+    ) -> Result<()> {
+        let caller = provider.get_caller();
+        if caller != SYSTEM_ACCOUNT {
+            return Err(Error::SystemFunctionCalledByUserAccount);
+        }
+        for slash in slashes.values() {
+            if let SlashAmount::Rate(rate) = slash.amount {
+                if rate > RATE_DENOMINATOR {
+                    return Err(Error::InvalidSlashRate);
+                }
+            }
+        }
+
+        let era_id = current_era(provider)?;
+        let mut stakes = provider.read()?;
+        let mut delegations = provider.read_delegations()?;
+        let mut bonding_queue = provider.read_bonding()?;
+        let mut unbonding_queue = provider.read_unbonding()?;
+        let mut undelegating_queue = provider.read_undelegating()?;
+        let mut jail = provider.read_jail()?;
+        jail.release(era_id);
+
+        let mut total_slashed = U512::zero();
+        for (validator, slash) in slashes {
+            let unbonding = unbonding_queue
+                .0
+                .iter()
+                .position(|entry| entry.validator == validator);
+            let unbonding_amount = unbonding
+                .map(|index| unbonding_queue.0[index].amount)
+                .unwrap_or_default();
+            let own_stake = match stakes.0.get(&validator) {
+                Some(stake) => stake.saturating_sub(delegations.total_delegated(&validator)),
+                None if unbonding.is_some() => U512::zero(),
+                None => return Err(Error::NotBonded),
+            };
+
+            let slashed = slash.amount.of(own_stake + unbonding_amount);
+            let from_stake = slashed.min(own_stake);
+            let own_stake = own_stake - from_stake;
+            if let Some(stake) = stakes.0.get_mut(&validator) {
+                *stake -= from_stake;
+            }
+            if let Some(index) = unbonding {
+                let entry = &mut unbonding_queue.0[index];
+                entry.amount -= slashed - from_stake;
+                if entry.amount.is_zero() {
+                    unbonding_queue.0.remove(index);
+                }
+            }
+            total_slashed += slashed;
+
+            let jailed = slash.jailed_until_era > era_id;
+            if jailed {
+                jail.jail(&validator, slash.jailed_until_era);
+            }
+            let removed = stakes.0.contains_key(&validator) && (jailed || own_stake.is_zero());
+            if removed {
+                if stakes.0.len() == 1 {
+                    return Err(Error::CannotUnbondLastValidator);
+                }
+                stakes.0.remove(&validator);
+                for (delegator, amount) in delegations.remove_validator(&validator) {
+                    undelegating_queue.push_merged(delegator, validator, amount, timestamp)?;
+                }
+            } else if !jailed {
+                continue;
+            }
+            // The rest of the stake, if the validator was removed, is merged with any pending
+            // unbond and pending bond, and paid out after a full unbonding delay.  A jailed
+            // validator's pending bond is unbonded even if they weren't bonded yet, since it would
+            // bond them before their sentence ends.
+            let mut payout = if removed { own_stake } else { U512::zero() };
+            bonding_queue.0.retain(|entry| {
+                if entry.validator == validator {
+                    payout += entry.amount;
+                    false
+                } else {
+                    true
+                }
+            });
+            unbonding_queue.0.retain(|entry| {
+                if entry.validator == validator {
+                    payout += entry.amount;
+                    false
+                } else {
+                    true
+                }
+            });
+            if !payout.is_zero() {
+                unbonding_queue.push(validator, payout, timestamp)?;
+            }
+        }
+
+        if !total_slashed.is_zero() {
+            let bonding_purse = get_bonding_purse(provider)?;
+            let treasury_purse = get_treasury_purse(provider)?;
+            provider
+                .transfer_purse_to_purse(bonding_purse, treasury_purse, total_slashed)
+                .map_err(|_| Error::FailedTransferToTreasuryPurse)?;
+        }
+
+        provider.write(&stakes)?;
+        provider.write_delegations(delegations)?;
+        provider.write_bonding(bonding_queue)?;
+        provider.write_unbonding(unbonding_queue)?;
+        provider.write_undelegating(undelegating_queue)?;
+        provider.write_jail(jail)?;
+        Ok(())
     }
 
     /// Attempts to look up a purse from the named_keys
@@ -234,6 +645,11 @@ mod internal {
         get_purse::<R>(runtime_provider, REWARDS_PURSE_KEY).map_err(PurseLookupError::rewards)
     }
 
+    /// Returns the purse for holding slashed motes
+    pub fn get_treasury_purse<R: RuntimeProvider>(runtime_provider: &R) -> Result<URef> {
+        get_purse::<R>(runtime_provider, TREASURY_PURSE_KEY).map_err(PurseLookupError::treasury)
+    }
+
     /// Sets the purse where refunds (excess funds not spent to pay for computation) will be sent.
     /// Note that if this function is never called, the default location is the main purse of the
     /// deployer's account.
@@ -308,6 +724,84 @@ mod internal {
         Ok(())
     }
 
+    /// Pays out the rewards purse, topped up with the era's inflation, to the bonded validators
+    /// and their delegators.
+    pub fn distribute_rewards<
+        P: DelegationsProvider + MintProvider + RewardsProvider + RuntimeProvider + StakesProvider,
+    >(
+        provider: &mut P,
+        era_id: u64,
+        inflation_rate: u64,
+        commission_rate: u64,
+    ) -> Result<()> {
+        let caller = provider.get_caller();
+        if caller != SYSTEM_ACCOUNT {
+            return Err(Error::SystemFunctionCalledByUserAccount);
+        }
+        if inflation_rate > RATE_DENOMINATOR || commission_rate > RATE_DENOMINATOR {
+            return Err(Error::InvalidRewardRate);
+        }
+        if let Some(last_era_rewards) = provider.read_era_rewards()? {
+            if era_id <= last_era_rewards.era_id {
+                return Err(Error::EraAlreadyRewarded);
+            }
+        }
+
+        let rewards_purse = get_rewards_purse(provider)?;
+        let bonding_purse = get_bonding_purse(provider)?;
+
+        let inflation = provider.total_supply() * inflation_rate / RATE_DENOMINATOR;
+        if !inflation.is_zero() {
+            let mint_authority = get_purse::<P>(provider, MINT_AUTHORITY_KEY)
+                .map_err(|_| Error::MintAuthorityNotFound)?;
+            provider
+                .mint_tokens(mint_authority, rewards_purse, inflation)
+                .map_err(|_| Error::FailedToMintRewards)?;
+        }
+
+        let pool = provider
+            .balance(rewards_purse)
+            .ok_or(Error::RewardsPurseBalanceNotFound)?;
+        let mut stakes = provider.read()?;
+        let mut delegations = provider.read_delegations()?;
+        let era_rewards = EraRewards::new(
+            era_id,
+            inflation,
+            &stakes,
+            &delegations,
+            pool,
+            commission_rate,
+        );
+
+        for (validator, reward) in &era_rewards.rewards {
+            if !reward.commission.is_zero() {
+                provider
+                    .transfer_purse_to_account(rewards_purse, *validator, reward.commission)
+                    .map_err(|_| Error::FailedTransferFromRewardsPurse)?;
+            }
+            if !reward.restaked.is_zero() {
+                provider
+                    .transfer_purse_to_purse(rewards_purse, bonding_purse, reward.restaked)
+                    .map_err(|_| Error::FailedTransferFromRewardsPurse)?;
+                stakes.bond(validator, reward.restaked);
+            }
+            for (delegator, restaked) in &reward.delegators {
+                if !restaked.is_zero() {
+                    provider
+                        .transfer_purse_to_purse(rewards_purse, bonding_purse, *restaked)
+                        .map_err(|_| Error::FailedTransferFromRewardsPurse)?;
+                    stakes.bond(validator, *restaked);
+                    delegations.delegate(validator, delegator, *restaked);
+                }
+            }
+        }
+
+        provider.write(&stakes)?;
+        provider.write_delegations(delegations)?;
+        provider.write_era_rewards(era_rewards)?;
+        Ok(())
+    }
+
     pub fn refund_to_account<M: MintProvider>(
         mint_provider: &mut M,
         payment_purse: URef,
@@ -324,44 +818,166 @@ mod internal {
     mod tests {
         extern crate std;
 
-        use std::{cell::RefCell, iter, thread_local};
+        use std::{cell::RefCell, iter, thread_local, vec};
 
-        use types::{account::PublicKey, system_contract_errors::pos::Result, BlockTime, U512};
+        use types::{
+            account::PublicKey,
+            system_contract_errors::pos::{Error, Result},
+            AccessRights, BlockTime, Key, Phase, TransferResult, TransferredTo, URef, U512,
+        };
 
-        use super::{bond, step, unbond, BOND_DELAY, UNBOND_DELAY};
+        use super::{bond, delegate, slash, step, unbond, undelegate, SYSTEM_ACCOUNT};
         use crate::{
-            queue::Queue, queue_provider::QueueProvider, stakes::Stakes,
+            delegations::{DelegationQueue, Delegations},
+            delegations_provider::DelegationsProvider,
+            jail_provider::JailProvider,
+            mint_provider::MintProvider,
+            params::PosParams,
+            params_provider::ParamsProvider,
+            queue::Queue,
+            queue_provider::QueueProvider,
+            rewards::EraRewards,
+            rewards_provider::RewardsProvider,
+            runtime_provider::RuntimeProvider,
+            slashing::{Jail, Slash, SlashAmount},
+            stakes::Stakes,
             stakes_provider::StakesProvider,
         };
 
         const KEY1: [u8; 32] = [1; 32];
         const KEY2: [u8; 32] = [2; 32];
+        const KEY3: [u8; 32] = [3; 32];
+
+        const BOND_DELAY: u64 = 5;
+        const UNBOND_DELAY: u64 = 3;
 
         thread_local! {
+            static PARAMS: RefCell<PosParams> = RefCell::new(PosParams {
+                bond_delay: BOND_DELAY,
+                unbond_delay: UNBOND_DELAY,
+                ..Default::default()
+            });
             static BONDING: RefCell<Queue> = RefCell::new(Queue(Default::default()));
             static UNBONDING: RefCell<Queue> = RefCell::new(Queue(Default::default()));
+            static DELEGATIONS: RefCell<Delegations> = RefCell::new(Default::default());
+            static DELEGATING: RefCell<DelegationQueue> = RefCell::new(Default::default());
+            static UNDELEGATING: RefCell<DelegationQueue> = RefCell::new(Default::default());
             static STAKES: RefCell<Stakes> = RefCell::new(
                 Stakes(iter::once((PublicKey::ed25519_from(KEY1), U512::from(1_000))).collect())
             );
+            static JAIL: RefCell<Jail> = RefCell::new(Default::default());
+            static ERA_REWARDS: RefCell<Option<EraRewards>> = RefCell::new(None);
+            static TREASURY: RefCell<U512> = RefCell::new(U512::zero());
         }
 
         struct Provider;
 
         impl QueueProvider for Provider {
-            fn read_bonding(&mut self) -> Queue {
-                BONDING.with(|b| b.borrow().clone())
+            fn read_bonding(&mut self) -> Result<Queue> {
+                Ok(BONDING.with(|b| b.borrow().clone()))
             }
 
-            fn read_unbonding(&mut self) -> Queue {
-                UNBONDING.with(|ub| ub.borrow().clone())
+            fn read_unbonding(&mut self) -> Result<Queue> {
+                Ok(UNBONDING.with(|ub| ub.borrow().clone()))
             }
 
-            fn write_bonding(&mut self, queue: Queue) {
+            fn write_bonding(&mut self, queue: Queue) -> Result<()> {
                 BONDING.with(|b| b.replace(queue));
+                Ok(())
             }
 
-            fn write_unbonding(&mut self, queue: Queue) {
+            fn write_unbonding(&mut self, queue: Queue) -> Result<()> {
                 UNBONDING.with(|ub| ub.replace(queue));
+                Ok(())
+            }
+        }
+
+        impl JailProvider for Provider {
+            fn read_jail(&mut self) -> Result<Jail> {
+                Ok(JAIL.with(|j| j.borrow().clone()))
+            }
+
+            fn write_jail(&mut self, jail: Jail) -> Result<()> {
+                JAIL.with(|j| j.replace(jail));
+                Ok(())
+            }
+        }
+
+        impl MintProvider for Provider {
+            fn transfer_purse_to_account(
+                &mut self,
+                _source: URef,
+                _target: PublicKey,
+                _amount: U512,
+            ) -> TransferResult {
+                Ok(TransferredTo::ExistingAccount)
+            }
+
+            /// Only slashing moves motes between purses, so all transfers go to the treasury.
+            fn transfer_purse_to_purse(
+                &mut self,
+                _source: URef,
+                _target: URef,
+                amount: U512,
+            ) -> core::result::Result<(), ()> {
+                TREASURY.with(|t| *t.borrow_mut() += amount);
+                Ok(())
+            }
+
+            fn balance(&mut self, _purse: URef) -> Option<U512> {
+                Some(U512::zero())
+            }
+
+            fn total_supply(&mut self) -> U512 {
+                U512::zero()
+            }
+
+            fn mint_tokens(
+                &mut self,
+                _authority: URef,
+                _purse: URef,
+                _amount: U512,
+            ) -> core::result::Result<(), ()> {
+                Ok(())
+            }
+        }
+
+        impl ParamsProvider for Provider {
+            fn read_params(&mut self) -> Result<PosParams> {
+                Ok(PARAMS.with(|p| *p.borrow()))
+            }
+        }
+
+        impl RewardsProvider for Provider {
+            fn read_era_rewards(&mut self) -> Result<Option<EraRewards>> {
+                Ok(ERA_REWARDS.with(|r| r.borrow().clone()))
+            }
+
+            fn write_era_rewards(&mut self, era_rewards: EraRewards) -> Result<()> {
+                ERA_REWARDS.with(|r| r.replace(Some(era_rewards)));
+                Ok(())
+            }
+        }
+
+        impl RuntimeProvider for Provider {
+            fn get_key(&self, _name: &str) -> Option<Key> {
+                Some(Key::URef(URef::new([0; 32], AccessRights::READ_ADD_WRITE)))
+            }
+
+            fn put_key(&mut self, _name: &str, _key: Key) {}
+
+            fn remove_key(&mut self, _name: &str) {}
+
+            fn get_phase(&self) -> Phase {
+                Phase::System
+            }
+
+            fn get_block_time(&self) -> BlockTime {
+                BlockTime::new(0)
+            }
+
+            fn get_caller(&self) -> PublicKey {
+                SYSTEM_ACCOUNT
             }
         }
 
@@ -370,8 +986,38 @@ mod internal {
                 STAKES.with(|s| Ok(s.borrow().clone()))
             }
 
-            fn write(&mut self, stakes: &Stakes) {
+            fn write(&mut self, stakes: &Stakes) -> Result<()> {
                 STAKES.with(|s| s.replace(stakes.clone()));
+                Ok(())
+            }
+        }
+
+        impl DelegationsProvider for Provider {
+            fn read_delegations(&mut self) -> Result<Delegations> {
+                Ok(DELEGATIONS.with(|d| d.borrow().clone()))
+            }
+
+            fn write_delegations(&mut self, delegations: Delegations) -> Result<()> {
+                DELEGATIONS.with(|d| d.replace(delegations));
+                Ok(())
+            }
+
+            fn read_delegating(&mut self) -> Result<DelegationQueue> {
+                Ok(DELEGATING.with(|d| d.borrow().clone()))
+            }
+
+            fn read_undelegating(&mut self) -> Result<DelegationQueue> {
+                Ok(UNDELEGATING.with(|ud| ud.borrow().clone()))
+            }
+
+            fn write_delegating(&mut self, queue: DelegationQueue) -> Result<()> {
+                DELEGATING.with(|d| d.replace(queue));
+                Ok(())
+            }
+
+            fn write_undelegating(&mut self, queue: DelegationQueue) -> Result<()> {
+                UNDELEGATING.with(|ud| ud.replace(queue));
+                Ok(())
             }
         }
 
@@ -416,5 +1062,377 @@ mod internal {
             step::<Provider>(&mut provider, BlockTime::new(2 + UNBOND_DELAY)).expect("step 3");
             assert_stakes(&[(KEY1, 500), (KEY2, 500)]);
         }
+
+        #[test]
+        fn test_delegate_undelegate() {
+            let mut provider = Provider;
+            let validator = PublicKey::ed25519_from(KEY1);
+            let delegator = PublicKey::ed25519_from(KEY3);
+
+            assert_eq!(
+                Err(Error::NotBonded),
+                delegate(
+                    &mut provider,
+                    U512::from(100),
+                    delegator,
+                    PublicKey::ed25519_from(KEY2),
+                    BlockTime::new(1),
+                )
+            );
+
+            delegate(
+                &mut provider,
+                U512::from(500),
+                delegator,
+                validator,
+                BlockTime::new(1),
+            )
+            .expect("delegate to validator 1");
+            let payouts = step(&mut provider, BlockTime::new(1 + BOND_DELAY)).expect("step 1");
+            assert!(payouts.is_empty());
+            // The delegated stake counts towards the validator's weight.
+            assert_stakes(&[(KEY1, 1_500)]);
+            assert_eq!(
+                U512::from(500),
+                provider
+                    .read_delegations()
+                    .expect("should read delegations")
+                    .delegation(&validator, &delegator)
+            );
+
+            undelegate(
+                &mut provider,
+                Some(U512::from(200)),
+                delegator,
+                validator,
+                BlockTime::new(2),
+            )
+            .expect("partly undelegate");
+            assert_stakes(&[(KEY1, 1_300)]);
+            let payouts = step(&mut provider, BlockTime::new(2 + UNBOND_DELAY)).expect("step 2");
+            assert_eq!(vec![(delegator, U512::from(200))], payouts);
+
+            assert_eq!(
+                Err(Error::NotDelegated),
+                undelegate(
+                    &mut provider,
+                    None,
+                    PublicKey::ed25519_from(KEY2),
+                    validator,
+                    BlockTime::new(3),
+                )
+            );
+        }
+
+        #[test]
+        fn test_unbond_validator_releases_delegations() {
+            let mut provider = Provider;
+            let validator = PublicKey::ed25519_from(KEY2);
+            let delegator = PublicKey::ed25519_from(KEY3);
+
+            bond(&mut provider, U512::from(500), validator, BlockTime::new(1))
+                .expect("bond validator 2");
+            step(&mut provider, BlockTime::new(1 + BOND_DELAY)).expect("step 1");
+            delegate(
+                &mut provider,
+                U512::from(300),
+                delegator,
+                validator,
+                BlockTime::new(2),
+            )
+            .expect("delegate to validator 2");
+            step(&mut provider, BlockTime::new(2 + BOND_DELAY)).expect("step 2");
+            assert_stakes(&[(KEY1, 1_000), (KEY2, 800)]);
+
+            // Validator 2 can't unbond more than their own stake.
+            assert_eq!(
+                Err(Error::UnbondTooLarge),
+                unbond(
+                    &mut provider,
+                    Some(U512::from(501)),
+                    validator,
+                    BlockTime::new(3),
+                )
+            );
+
+            unbond(&mut provider, None, validator, BlockTime::new(3)).expect("unbond validator 2");
+            assert_stakes(&[(KEY1, 1_000)]);
+            assert_eq!(
+                Delegations::default(),
+                provider
+                    .read_delegations()
+                    .expect("should read delegations")
+            );
+            let payouts = step(&mut provider, BlockTime::new(3 + UNBOND_DELAY)).expect("step 3");
+            assert_eq!(
+                vec![(validator, U512::from(500)), (delegator, U512::from(300))],
+                payouts
+            );
+        }
+
+        #[test]
+        fn test_unbond_validator_merges_pending_undelegations() {
+            let mut provider = Provider;
+            let validator = PublicKey::ed25519_from(KEY2);
+            let delegator = PublicKey::ed25519_from(KEY3);
+
+            bond(&mut provider, U512::from(500), validator, BlockTime::new(1))
+                .expect("bond validator 2");
+            step(&mut provider, BlockTime::new(1 + BOND_DELAY)).expect("step 1");
+            delegate(
+                &mut provider,
+                U512::from(300),
+                delegator,
+                validator,
+                BlockTime::new(1),
+            )
+            .expect("delegate to validator 2");
+            step(&mut provider, BlockTime::new(1 + BOND_DELAY)).expect("step 2");
+            undelegate(
+                &mut provider,
+                Some(U512::from(100)),
+                delegator,
+                validator,
+                BlockTime::new(2),
+            )
+            .expect("partly undelegate");
+
+            unbond(&mut provider, None, validator, BlockTime::new(3)).expect("unbond validator 2");
+            let undelegating = provider
+                .read_undelegating()
+                .expect("should read undelegating");
+            assert_eq!(1, undelegating.0.len());
+            assert_eq!(U512::from(300), undelegating.0[0].amount);
+            let payouts = step(&mut provider, BlockTime::new(3 + UNBOND_DELAY)).expect("step 3");
+            assert_eq!(
+                vec![(validator, U512::from(500)), (delegator, U512::from(300))],
+                payouts
+            );
+        }
+
+        #[test]
+        fn test_jail_validator_merges_pending_undelegations() {
+            let mut provider = Provider;
+            let validator = PublicKey::ed25519_from(KEY2);
+            let delegator = PublicKey::ed25519_from(KEY3);
+
+            bond(&mut provider, U512::from(500), validator, BlockTime::new(1))
+                .expect("bond validator 2");
+            step(&mut provider, BlockTime::new(1 + BOND_DELAY)).expect("step 1");
+            delegate(
+                &mut provider,
+                U512::from(300),
+                delegator,
+                validator,
+                BlockTime::new(1),
+            )
+            .expect("delegate to validator 2");
+            step(&mut provider, BlockTime::new(1 + BOND_DELAY)).expect("step 2");
+            undelegate(
+                &mut provider,
+                Some(U512::from(100)),
+                delegator,
+                validator,
+                BlockTime::new(2),
+            )
+            .expect("partly undelegate");
+
+            let slashes = iter::once((validator, Slash::new(SlashAmount::Rate(100_000), 2)));
+            slash(&mut provider, slashes.collect(), BlockTime::new(3)).expect("slash and jail");
+            let undelegating = provider
+                .read_undelegating()
+                .expect("should read undelegating");
+            assert_eq!(1, undelegating.0.len());
+            assert_eq!(U512::from(300), undelegating.0[0].amount);
+            let payouts = step(&mut provider, BlockTime::new(3 + UNBOND_DELAY)).expect("step 3");
+            assert_eq!(
+                vec![(validator, U512::from(450)), (delegator, U512::from(300))],
+                payouts
+            );
+        }
+
+        #[test]
+        fn test_jail_unbonding_validator_with_pending_bond() {
+            let mut provider = Provider;
+            let validator = PublicKey::ed25519_from(KEY2);
+
+            bond(&mut provider, U512::from(500), validator, BlockTime::new(1))
+                .expect("bond validator 2");
+            step(&mut provider, BlockTime::new(1 + BOND_DELAY)).expect("step 1");
+            unbond(&mut provider, None, validator, BlockTime::new(2)).expect("unbond validator 2");
+            bond(&mut provider, U512::from(200), validator, BlockTime::new(2))
+                .expect("bond validator 2 again");
+
+            let slashes = iter::once((validator, Slash::new(SlashAmount::Rate(0), 2)));
+            slash(&mut provider, slashes.collect(), BlockTime::new(3)).expect("jail");
+
+            // The pending bond is unbonded together with the pending unbond, rather than bonding
+            // the jailed validator.
+            assert!(provider
+                .read_bonding()
+                .expect("should read bonding")
+                .0
+                .is_empty());
+            let payouts = step(&mut provider, BlockTime::new(3 + BOND_DELAY)).expect("step 2");
+            assert_stakes(&[(KEY1, 1_000)]);
+            assert_eq!(vec![(validator, U512::from(700))], payouts);
+        }
+
+        #[test]
+        fn test_queue_length_limits() {
+            let mut provider = Provider;
+            PARAMS.with(|p| {
+                let mut params = p.borrow_mut();
+                params.max_bond_len = 1;
+                params.max_unbond_len = 0;
+            });
+
+            bond(
+                &mut provider,
+                U512::from(500),
+                PublicKey::ed25519_from(KEY2),
+                BlockTime::new(1),
+            )
+            .expect("bond validator 2");
+            assert_eq!(
+                Err(Error::TooManyEventsInQueue),
+                bond(
+                    &mut provider,
+                    U512::from(500),
+                    PublicKey::ed25519_from(KEY3),
+                    BlockTime::new(1),
+                )
+            );
+            assert_eq!(
+                Err(Error::TooManyEventsInQueue),
+                unbond(
+                    &mut provider,
+                    Some(U512::from(100)),
+                    PublicKey::ed25519_from(KEY1),
+                    BlockTime::new(1),
+                )
+            );
+        }
+
+        #[test]
+        fn test_slash_stake_and_pending_unbond() {
+            let mut provider = Provider;
+            let validator = PublicKey::ed25519_from(KEY2);
+
+            bond(&mut provider, U512::from(500), validator, BlockTime::new(1))
+                .expect("bond validator 2");
+            step(&mut provider, BlockTime::new(1 + BOND_DELAY)).expect("step 1");
+            unbond(
+                &mut provider,
+                Some(U512::from(100)),
+                validator,
+                BlockTime::new(2),
+            )
+            .expect("partly unbond validator 2");
+
+            // 20% of the 400 bonded plus 100 unbonding motes are taken from the stake.
+            let slashes = iter::once((validator, Slash::new(SlashAmount::Rate(200_000), 0)));
+            slash(&mut provider, slashes.collect(), BlockTime::new(3)).expect("slash by rate");
+            assert_stakes(&[(KEY1, 1_000), (KEY2, 300)]);
+            assert_eq!(
+                U512::from(100),
+                provider.read_unbonding().expect("should read unbonding").0[0].amount
+            );
+
+            // The fixed amount exceeds the stake, so the rest is taken from the pending unbond,
+            // and the validator is removed without being jailed.
+            let slashes = iter::once((
+                validator,
+                Slash::new(SlashAmount::Fixed(U512::from(350)), 0),
+            ));
+            slash(&mut provider, slashes.collect(), BlockTime::new(3)).expect("slash fixed");
+            assert_stakes(&[(KEY1, 1_000)]);
+            assert_eq!(
+                U512::from(50),
+                provider.read_unbonding().expect("should read unbonding").0[0].amount
+            );
+            assert_eq!(U512::from(450), TREASURY.with(|t| *t.borrow()));
+            assert!(!provider
+                .read_jail()
+                .expect("should read jail")
+                .is_jailed(&validator, 0));
+
+            assert_eq!(
+                Err(Error::InvalidSlashRate),
+                slash(
+                    &mut provider,
+                    iter::once((validator, Slash::new(SlashAmount::Rate(1_000_001), 0))).collect(),
+                    BlockTime::new(3),
+                )
+            );
+            assert_eq!(
+                Err(Error::NotBonded),
+                slash(
+                    &mut provider,
+                    iter::once((
+                        PublicKey::ed25519_from(KEY3),
+                        Slash::new(SlashAmount::Rate(1), 0)
+                    ))
+                    .collect(),
+                    BlockTime::new(3),
+                )
+            );
+        }
+
+        #[test]
+        fn test_jail_validator() {
+            let mut provider = Provider;
+            let validator = PublicKey::ed25519_from(KEY2);
+            let delegator = PublicKey::ed25519_from(KEY3);
+
+            bond(&mut provider, U512::from(500), validator, BlockTime::new(1))
+                .expect("bond validator 2");
+            step(&mut provider, BlockTime::new(1 + BOND_DELAY)).expect("step 1");
+            delegate(
+                &mut provider,
+                U512::from(300),
+                delegator,
+                validator,
+                BlockTime::new(2),
+            )
+            .expect("delegate to validator 2");
+            step(&mut provider, BlockTime::new(2 + BOND_DELAY)).expect("step 2");
+
+            let slashes = iter::once((validator, Slash::new(SlashAmount::Rate(100_000), 2)));
+            slash(&mut provider, slashes.collect(), BlockTime::new(3)).expect("slash and jail");
+
+            // Only the validator's own stake is slashed; the rest of it and the delegations are
+            // released.
+            assert_stakes(&[(KEY1, 1_000)]);
+            assert_eq!(U512::from(50), TREASURY.with(|t| *t.borrow()));
+            let payouts = step(&mut provider, BlockTime::new(3 + UNBOND_DELAY)).expect("step 3");
+            assert_eq!(
+                vec![(validator, U512::from(450)), (delegator, U512::from(300))],
+                payouts
+            );
+
+            assert_eq!(
+                Err(Error::ValidatorJailed),
+                bond(&mut provider, U512::from(500), validator, BlockTime::new(4))
+            );
+            ERA_REWARDS.with(|r| {
+                r.replace(Some(EraRewards {
+                    era_id: 2,
+                    ..Default::default()
+                }))
+            });
+            bond(&mut provider, U512::from(500), validator, BlockTime::new(4))
+                .expect("bond after the sentence ended");
+
+            // The last bonded validator can't be jailed.
+            let slashes = iter::once((
+                PublicKey::ed25519_from(KEY1),
+                Slash::new(SlashAmount::Rate(0), 3),
+            ));
+            assert_eq!(
+                Err(Error::CannotUnbondLastValidator),
+                slash(&mut provider, slashes.collect(), BlockTime::new(5))
+            );
+        }
     }
 }
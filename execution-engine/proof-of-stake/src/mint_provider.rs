@@ -16,4 +16,8 @@ pub trait MintProvider {
     ) -> Result<(), ()>;
 
     fn balance(&mut self, purse: URef) -> Option<U512>;
+
+    fn total_supply(&mut self) -> U512;
+
+    fn mint_tokens(&mut self, authority: URef, purse: URef, amount: U512) -> Result<(), ()>;
 }
@@ -0,0 +1,92 @@
+use types::{
+    bytesrepr::{FromBytes, ToBytes},
+    CLTyped, U512,
+};
+
+/// The parameters governing bonding, unbonding and the stake limits of the proof-of-stake
+/// contract.
+///
+/// They are set in the genesis config, stored in the contract's state and can be changed by a
+/// protocol upgrade.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FromBytes, ToBytes, CLTyped)]
+pub struct PosParams {
+    /// The time from a bonding request until the bond becomes effective and part of the stake.
+    pub bond_delay: u64,
+    /// The time from an unbonding request until the stakes are paid out.
+    pub unbond_delay: u64,
+    /// The maximum number of pending bonding requests.
+    pub max_bond_len: u64,
+    /// The maximum number of pending unbonding requests.
+    pub max_unbond_len: u64,
+    /// The maximum difference between the largest and the smallest stakes.
+    pub max_spread: U512,
+    /// The maximum increase of stakes in a single bonding request.
+    pub max_increase: U512,
+    /// The maximum decrease of stakes in a single unbonding request.
+    pub max_decrease: U512,
+    /// The maximum increase of stakes in millionths of the total stakes in a single bonding
+    /// request.
+    pub max_rel_increase: u64,
+    /// The maximum decrease of stakes in millionths of the total stakes in a single unbonding
+    /// request.
+    pub max_rel_decrease: u64,
+}
+
+impl PosParams {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        bond_delay: u64,
+        unbond_delay: u64,
+        max_bond_len: u64,
+        max_unbond_len: u64,
+        max_spread: U512,
+        max_increase: U512,
+        max_decrease: U512,
+        max_rel_increase: u64,
+        max_rel_decrease: u64,
+    ) -> Self {
+        PosParams {
+            bond_delay,
+            unbond_delay,
+            max_bond_len,
+            max_unbond_len,
+            max_spread,
+            max_increase,
+            max_decrease,
+            max_rel_increase,
+            max_rel_decrease,
+        }
+    }
+}
+
+impl Default for PosParams {
+    fn default() -> Self {
+        PosParams {
+            bond_delay: 0,
+            unbond_delay: 0,
+            max_bond_len: 100,
+            max_unbond_len: 1000,
+            // TODO: Should this be a percentage instead?
+            // TODO: Pick a reasonable value.
+            max_spread: U512::MAX,
+            max_increase: U512::MAX,
+            max_decrease: U512::MAX,
+            max_rel_increase: 1_000_000_000,
+            max_rel_decrease: 900_000,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use types::{bytesrepr, U512};
+
+    use super::PosParams;
+
+    #[test]
+    fn serialization_roundtrip() {
+        let params = PosParams::new(1, 2, 3, 4, U512::from(5), U512::MAX, U512::zero(), 6, 7);
+        bytesrepr::test_serialization_roundtrip(&params);
+        bytesrepr::test_serialization_roundtrip(&PosParams::default());
+    }
+}
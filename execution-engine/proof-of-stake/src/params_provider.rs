@@ -0,0 +1,6 @@
+use crate::{params::PosParams, Result};
+
+pub trait ParamsProvider {
+    /// Reads the proof-of-stake parameters.
+    fn read_params(&mut self) -> Result<PosParams>;
+}
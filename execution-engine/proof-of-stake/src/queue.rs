@@ -9,7 +9,7 @@ use types::{
 };
 
 /// A pending entry in the bonding or unbonding queue.
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, FromBytes, ToBytes)]
 pub struct QueueEntry {
     /// The validator who is bonding or unbonding.
     pub validator: PublicKey,
@@ -30,29 +30,6 @@ impl QueueEntry {
     }
 }
 
-impl FromBytes for QueueEntry {
-    fn from_bytes(bytes: &[u8]) -> result::Result<(Self, &[u8]), bytesrepr::Error> {
-        let (validator, bytes) = PublicKey::from_bytes(bytes)?;
-        let (amount, bytes) = U512::from_bytes(bytes)?;
-        let (timestamp, bytes) = BlockTime::from_bytes(bytes)?;
-        let entry = QueueEntry {
-            validator,
-            amount,
-            timestamp,
-        };
-        Ok((entry, bytes))
-    }
-}
-
-impl ToBytes for QueueEntry {
-    fn to_bytes(&self) -> result::Result<Vec<u8>, bytesrepr::Error> {
-        Ok((self.validator.to_bytes()?.into_iter())
-            .chain(self.amount.to_bytes()?)
-            .chain(self.timestamp.to_bytes()?)
-            .collect())
-    }
-}
-
 impl CLTyped for QueueEntry {
     fn cl_type() -> CLType {
         CLType::Any
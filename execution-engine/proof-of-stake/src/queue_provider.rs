@@ -1,15 +1,15 @@
-use crate::queue::Queue;
+use crate::{queue::Queue, Result};
 
 pub trait QueueProvider {
     /// Reads bonding queue.
-    fn read_bonding(&mut self) -> Queue;
+    fn read_bonding(&mut self) -> Result<Queue>;
 
     /// Reads unbonding queue.
-    fn read_unbonding(&mut self) -> Queue;
+    fn read_unbonding(&mut self) -> Result<Queue>;
 
     /// Writes bonding queue.
-    fn write_bonding(&mut self, queue: Queue);
+    fn write_bonding(&mut self, queue: Queue) -> Result<()>;
 
     /// Writes unbonding queue.
-    fn write_unbonding(&mut self, queue: Queue);
+    fn write_unbonding(&mut self, queue: Queue) -> Result<()>;
 }
@@ -0,0 +1,230 @@
+use alloc::collections::BTreeMap;
+
+use types::{
+    account::PublicKey,
+    bytesrepr::{FromBytes, ToBytes},
+    CLTyped, U512,
+};
+
+use crate::{delegations::Delegations, stakes::Stakes};
+
+/// The denominator of the inflation and commission rates, i.e. rates are given in millionths.
+pub const RATE_DENOMINATOR: u64 = 1_000_000;
+
+/// The reward paid to a single validator and their delegators for an era.
+#[derive(Clone, Debug, Default, PartialEq, FromBytes, ToBytes, CLTyped)]
+pub struct ValidatorReward {
+    /// The part of the reward paid out to the validator's account.
+    pub commission: U512,
+    /// The part of the reward added to the validator's own stake.
+    pub restaked: U512,
+    /// The parts of the reward added to each delegator's delegated stake.
+    pub delegators: BTreeMap<PublicKey, U512>,
+}
+
+impl ValidatorReward {
+    /// Returns the whole reward of the validator and their delegators.
+    pub fn total(&self) -> U512 {
+        self.delegators
+            .values()
+            .fold(self.commission + self.restaked, |total, reward| {
+                total + reward
+            })
+    }
+}
+
+/// The rewards distributed for the most recently rewarded era.
+#[derive(Clone, Debug, Default, PartialEq, FromBytes, ToBytes, CLTyped)]
+pub struct EraRewards {
+    /// The era the rewards were distributed for.
+    pub era_id: u64,
+    /// The amount minted into the rewards purse as inflation for the era.
+    pub inflation: U512,
+    /// The reward of each bonded validator.
+    pub rewards: BTreeMap<PublicKey, ValidatorReward>,
+}
+
+impl EraRewards {
+    /// Splits `pool` between the bonded validators in proportion to their stakes, and each
+    /// validator's share between the validator and their delegators in proportion to their
+    /// delegated stakes.
+    ///
+    /// Of each delegator's share, `commission_rate` millionths are paid to the validator as
+    /// commission and the rest is restaked.  Of the validator's own share, `commission_rate`
+    /// millionths are paid out too and the rest is restaked.  Any remainder left by rounding down
+    /// is not distributed.
+    pub fn new(
+        era_id: u64,
+        inflation: U512,
+        stakes: &Stakes,
+        delegations: &Delegations,
+        pool: U512,
+        commission_rate: u64,
+    ) -> EraRewards {
+        let total_bonds = stakes.total_bonds();
+        let rewards = stakes
+            .iter()
+            .map(|(validator, stake)| {
+                let share = if total_bonds.is_zero() {
+                    U512::zero()
+                } else {
+                    pool * *stake / total_bonds
+                };
+                let mut reward = ValidatorReward::default();
+                let mut own_share = share;
+                for (delegator, delegated) in
+                    delegations.delegators(validator).into_iter().flatten()
+                {
+                    let delegator_share = share * *delegated / *stake;
+                    let commission = delegator_share * commission_rate / RATE_DENOMINATOR;
+                    own_share -= delegator_share;
+                    reward.commission += commission;
+                    reward
+                        .delegators
+                        .insert(*delegator, delegator_share - commission);
+                }
+                let commission = own_share * commission_rate / RATE_DENOMINATOR;
+                reward.commission += commission;
+                reward.restaked = own_share - commission;
+                (*validator, reward)
+            })
+            .collect();
+        EraRewards {
+            era_id,
+            inflation,
+            rewards,
+        }
+    }
+
+    /// Returns the sum of all validators' rewards.
+    pub fn total(&self) -> U512 {
+        self.rewards
+            .values()
+            .fold(U512::zero(), |total, reward| total + reward.total())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{collections::BTreeMap, vec};
+
+    use types::{account::PublicKey, U512};
+
+    use super::{EraRewards, ValidatorReward, RATE_DENOMINATOR};
+    use crate::{delegations::Delegations, stakes::Stakes};
+
+    const KEY1: [u8; 32] = [1; 32];
+    const KEY2: [u8; 32] = [2; 32];
+
+    fn new_stakes(stakes: &[([u8; 32], u64)]) -> Stakes {
+        Stakes(
+            stakes
+                .iter()
+                .map(|(key, amount)| (PublicKey::ed25519_from(*key), U512::from(*amount)))
+                .collect(),
+        )
+    }
+
+    fn reward(commission: u64, restaked: u64) -> ValidatorReward {
+        ValidatorReward {
+            commission: U512::from(commission),
+            restaked: U512::from(restaked),
+            delegators: BTreeMap::new(),
+        }
+    }
+
+    #[test]
+    fn should_split_pool_in_proportion_to_stakes() {
+        let stakes = new_stakes(&[(KEY1, 300), (KEY2, 100)]);
+        let era_rewards = EraRewards::new(
+            1,
+            U512::zero(),
+            &stakes,
+            &Delegations::default(),
+            U512::from(1_000),
+            100_000,
+        );
+
+        let expected: BTreeMap<PublicKey, ValidatorReward> = vec![
+            (PublicKey::ed25519_from(KEY1), reward(75, 675)),
+            (PublicKey::ed25519_from(KEY2), reward(25, 225)),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(era_rewards.rewards, expected);
+        assert_eq!(era_rewards.total(), U512::from(1_000));
+    }
+
+    #[test]
+    fn should_round_shares_down() {
+        let stakes = new_stakes(&[(KEY1, 1), (KEY2, 2)]);
+        let era_rewards = EraRewards::new(
+            1,
+            U512::zero(),
+            &stakes,
+            &Delegations::default(),
+            U512::from(100),
+            0,
+        );
+
+        assert_eq!(
+            era_rewards.rewards[&PublicKey::ed25519_from(KEY1)],
+            reward(0, 33)
+        );
+        assert_eq!(
+            era_rewards.rewards[&PublicKey::ed25519_from(KEY2)],
+            reward(0, 66)
+        );
+        assert_eq!(era_rewards.total(), U512::from(99));
+    }
+
+    #[test]
+    fn should_pay_out_everything_at_full_commission() {
+        let stakes = new_stakes(&[(KEY1, 1), (KEY2, 1)]);
+        let era_rewards = EraRewards::new(
+            1,
+            U512::zero(),
+            &stakes,
+            &Delegations::default(),
+            U512::from(10),
+            RATE_DENOMINATOR,
+        );
+
+        assert!(era_rewards
+            .rewards
+            .values()
+            .all(|reward| reward.restaked.is_zero() && reward.commission == U512::from(5)));
+    }
+
+    #[test]
+    fn should_split_validator_share_with_delegators() {
+        // KEY1 has 100 of their own stake and 300 delegated by KEY2.
+        let stakes = new_stakes(&[(KEY1, 400)]);
+        let mut delegations = Delegations::default();
+        delegations.delegate(
+            &PublicKey::ed25519_from(KEY1),
+            &PublicKey::ed25519_from(KEY2),
+            U512::from(300),
+        );
+        let era_rewards = EraRewards::new(
+            1,
+            U512::zero(),
+            &stakes,
+            &delegations,
+            U512::from(1_000),
+            100_000,
+        );
+
+        // The delegator's share is 750, of which 75 go to the validator as commission.  Of the
+        // validator's own share of 250, 25 are paid out too.
+        let mut expected = reward(100, 225);
+        expected
+            .delegators
+            .insert(PublicKey::ed25519_from(KEY2), U512::from(675));
+        assert_eq!(
+            era_rewards.rewards[&PublicKey::ed25519_from(KEY1)],
+            expected
+        );
+        assert_eq!(era_rewards.total(), U512::from(1_000));
+    }
+}
@@ -0,0 +1,9 @@
+use crate::{rewards::EraRewards, Result};
+
+pub trait RewardsProvider {
+    /// Reads the rewards of the most recently rewarded era, if any.
+    fn read_era_rewards(&mut self) -> Result<Option<EraRewards>>;
+
+    /// Writes the rewards of the most recently rewarded era.
+    fn write_era_rewards(&mut self, era_rewards: EraRewards) -> Result<()>;
+}
@@ -0,0 +1,129 @@
+use alloc::collections::BTreeMap;
+
+use types::{
+    account::PublicKey,
+    bytesrepr::{FromBytes, ToBytes},
+    CLTyped, U512,
+};
+
+use crate::rewards::RATE_DENOMINATOR;
+
+/// The amount to be slashed from a validator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FromBytes, ToBytes, CLTyped)]
+pub enum SlashAmount {
+    /// A fixed amount of motes.
+    Fixed(U512),
+    /// A share of the validator's own bonded and unbonding stake, in millionths.
+    Rate(u64),
+}
+
+impl SlashAmount {
+    /// Returns the amount to slash from a validator whose own bonded and unbonding stake add up
+    /// to `total`. The result never exceeds `total`.
+    pub fn of(&self, total: U512) -> U512 {
+        match *self {
+            SlashAmount::Fixed(amount) => amount.min(total),
+            SlashAmount::Rate(rate) => (total * rate / RATE_DENOMINATOR).min(total),
+        }
+    }
+}
+
+/// A penalty imposed on a single validator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FromBytes, ToBytes, CLTyped)]
+pub struct Slash {
+    /// The amount to be burned from the validator's stake and pending unbonds.
+    pub amount: SlashAmount,
+    /// The era until which the validator is jailed. If it is not later than the current era, the
+    /// validator stays bonded with the rest of their stake.
+    pub jailed_until_era: u64,
+}
+
+impl Slash {
+    pub fn new(amount: SlashAmount, jailed_until_era: u64) -> Self {
+        Slash {
+            amount,
+            jailed_until_era,
+        }
+    }
+}
+
+/// The jailed validators, with the era until which each of them is barred from bonding again.
+///
+/// A validator is released once rewards have been distributed for that era.
+#[derive(Clone, Debug, Default, PartialEq, FromBytes, ToBytes, CLTyped)]
+pub struct Jail(pub BTreeMap<PublicKey, u64>);
+
+impl Jail {
+    /// Jails the validator until the given era. If they are jailed already, the later era applies.
+    pub fn jail(&mut self, validator: &PublicKey, until_era: u64) {
+        self.0
+            .entry(*validator)
+            .and_modify(|era| *era = (*era).max(until_era))
+            .or_insert(until_era);
+    }
+
+    /// Returns whether the validator is barred from bonding in the given era.
+    pub fn is_jailed(&self, validator: &PublicKey, era_id: u64) -> bool {
+        self.0
+            .get(validator)
+            .map_or(false, |until_era| era_id < *until_era)
+    }
+
+    /// Removes all validators whose sentence has been served by the given era.
+    pub fn release(&mut self, era_id: u64) {
+        self.0.retain(|_, until_era| era_id < *until_era);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use types::{account::PublicKey, bytesrepr, U512};
+
+    use super::{Jail, Slash, SlashAmount};
+
+    const KEY1: [u8; 32] = [1; 32];
+    const KEY2: [u8; 32] = [2; 32];
+
+    #[test]
+    fn test_slash_amount() {
+        let total = U512::from(1_000);
+        assert_eq!(
+            U512::from(300),
+            SlashAmount::Fixed(U512::from(300)).of(total)
+        );
+        assert_eq!(total, SlashAmount::Fixed(U512::from(3_000)).of(total));
+        assert_eq!(U512::from(250), SlashAmount::Rate(250_000).of(total));
+        assert_eq!(total, SlashAmount::Rate(2_000_000).of(total));
+    }
+
+    #[test]
+    fn test_jail() {
+        let val1 = PublicKey::ed25519_from(KEY1);
+        let val2 = PublicKey::ed25519_from(KEY2);
+        let mut jail = Jail::default();
+        jail.jail(&val1, 3);
+        jail.jail(&val2, 2);
+        // A shorter sentence doesn't release a validator early.
+        jail.jail(&val1, 1);
+
+        assert!(jail.is_jailed(&val1, 2));
+        assert!(jail.is_jailed(&val2, 1));
+        assert!(!jail.is_jailed(&val2, 2));
+
+        jail.release(2);
+        assert_eq!(Some(&3), jail.0.get(&val1));
+        assert_eq!(None, jail.0.get(&val2));
+    }
+
+    #[test]
+    fn serialization_roundtrip() {
+        let mut jail = Jail::default();
+        jail.jail(&PublicKey::ed25519_from(KEY1), 7);
+        bytesrepr::test_serialization_roundtrip(&jail);
+        bytesrepr::test_serialization_roundtrip(&Slash::new(SlashAmount::Rate(5), 3));
+        bytesrepr::test_serialization_roundtrip(&Slash::new(
+            SlashAmount::Fixed(U512::from(100)),
+            0,
+        ));
+    }
+}
@@ -1,38 +1,20 @@
-use alloc::{
-    collections::{
-        btree_map::{Iter, Values},
-        BTreeMap,
-    },
-    format,
-    string::String,
+use alloc::collections::{
+    btree_map::{Iter, Values},
+    BTreeMap,
 };
 
 use types::{
     account::PublicKey,
+    bytesrepr::{FromBytes, ToBytes},
     system_contract_errors::pos::{Error, Result},
-    U512,
+    CLType, CLTyped, U512,
 };
 
-/// The maximum difference between the largest and the smallest stakes.
-// TODO: Should this be a percentage instead?
-// TODO: Pick a reasonable value.
-const MAX_SPREAD: U512 = U512::MAX;
-
-/// The maximum increase of stakes in a single bonding request.
-const MAX_INCREASE: U512 = U512::MAX;
-
-/// The maximum decrease of stakes in a single unbonding request.
-const MAX_DECREASE: U512 = U512::MAX;
-
-/// The maximum increase of stakes in millionths of the total stakes in a single bonding request.
-const MAX_REL_INCREASE: u64 = 1_000_000_000;
-
-/// The maximum decrease of stakes in millionths of the total stakes in a single unbonding request.
-const MAX_REL_DECREASE: u64 = 900_000;
+use crate::params::PosParams;
 
 /// The stakes map, assigning the staked amount of motes to each bonded
 /// validator.
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, FromBytes, ToBytes)]
 pub struct Stakes(pub BTreeMap<PublicKey, U512>);
 
 impl Stakes {
@@ -48,14 +30,6 @@ impl Stakes {
         self.0.values()
     }
 
-    pub fn strings(&self) -> impl Iterator<Item = String> + '_ {
-        self.iter().map(|(public_key, balance)| {
-            let key_bytes = public_key.as_bytes();
-            let hex_key = base16::encode_lower(&key_bytes);
-            format!("v_{}_{}", hex_key, balance)
-        })
-    }
-
     pub fn total_bonds(&self) -> U512 {
         self.values().fold(U512::zero(), |x, y| x + y)
     }
@@ -69,12 +43,19 @@ impl Stakes {
     /// * unbonding the specified amount is not allowed,
     /// * tries to unbond last validator,
     /// * validator was not bonded.
-    pub fn unbond(&mut self, validator: &PublicKey, maybe_amount: Option<U512>) -> Result<U512> {
+    pub fn unbond(
+        &mut self,
+        params: &PosParams,
+        validator: &PublicKey,
+        maybe_amount: Option<U512>,
+    ) -> Result<U512> {
         let min = self
             .max_without(validator)
             .unwrap_or_else(U512::zero)
-            .saturating_sub(MAX_SPREAD);
-        let max_decrease = MAX_DECREASE.min(self.sum() * MAX_REL_DECREASE / 1_000_000);
+            .saturating_sub(params.max_spread);
+        let max_decrease = params
+            .max_decrease
+            .min(self.sum() * params.max_rel_decrease / 1_000_000);
 
         if let Some(amount) = maybe_amount {
             // The minimum stake value to not violate the maximum spread.
@@ -118,20 +99,27 @@ impl Stakes {
     }
 
     /// Returns an error if bonding the specified amount is not allowed.
-    pub fn validate_bonding(&self, validator: &PublicKey, amount: U512) -> Result<()> {
+    pub fn validate_bonding(
+        &self,
+        params: &PosParams,
+        validator: &PublicKey,
+        amount: U512,
+    ) -> Result<()> {
         let max = self
             .min_without(validator)
             .unwrap_or(U512::MAX)
-            .saturating_add(MAX_SPREAD);
+            .saturating_add(params.max_spread);
         let min = self
             .max_without(validator)
             .unwrap_or_else(U512::zero)
-            .saturating_sub(MAX_SPREAD);
+            .saturating_sub(params.max_spread);
         let stake = self.0.get(validator).map(|s| *s + amount).unwrap_or(amount);
         if stake > max || stake < min {
             return Err(Error::SpreadTooHigh);
         }
-        let max_increase = MAX_INCREASE.min(self.sum() * MAX_REL_INCREASE / 1_000_000);
+        let max_increase = params
+            .max_increase
+            .min(self.sum() * params.max_rel_increase / 1_000_000);
         if (stake.is_zero() && amount > min.saturating_add(max_increase))
             || (!stake.is_zero() && amount > max_increase)
         {
@@ -168,11 +156,18 @@ impl Stakes {
     }
 }
 
+impl CLTyped for Stakes {
+    fn cl_type() -> CLType {
+        BTreeMap::<PublicKey, U512>::cl_type()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use types::{account::PublicKey, system_contract_errors::pos::Error, U512};
 
     use super::Stakes;
+    use crate::params::PosParams;
 
     const KEY1: [u8; 32] = [1; 32];
     const KEY2: [u8; 32] = [2; 32];
@@ -188,10 +183,11 @@ mod tests {
 
     #[test]
     fn test_bond() {
+        let params = PosParams::default();
         let mut stakes = new_stakes(&[(KEY2, 100)]);
         assert_eq!(
             Ok(()),
-            stakes.validate_bonding(&PublicKey::ed25519_from(KEY1), U512::from(5))
+            stakes.validate_bonding(&params, &PublicKey::ed25519_from(KEY1), U512::from(5))
         );
         stakes.bond(&PublicKey::ed25519_from(KEY1), U512::from(5));
         assert_eq!(new_stakes(&[(KEY1, 5), (KEY2, 100)]), stakes);
@@ -199,10 +195,11 @@ mod tests {
 
     #[test]
     fn test_bond_existing() {
+        let params = PosParams::default();
         let mut stakes = new_stakes(&[(KEY1, 50), (KEY2, 100)]);
         assert_eq!(
             Ok(()),
-            stakes.validate_bonding(&PublicKey::ed25519_from(KEY1), U512::from(4))
+            stakes.validate_bonding(&params, &PublicKey::ed25519_from(KEY1), U512::from(4))
         );
         stakes.bond(&PublicKey::ed25519_from(KEY1), U512::from(4));
         assert_eq!(new_stakes(&[(KEY1, 54), (KEY2, 100)]), stakes);
@@ -210,21 +207,24 @@ mod tests {
 
     #[test]
     fn test_bond_too_much_rel() {
+        let params = PosParams::default();
         let stakes = new_stakes(&[(KEY1, 1_000), (KEY2, 1_000)]);
         let total = 1_000 + 1_000;
         assert_eq!(
             Err(Error::BondTooLarge),
             stakes.validate_bonding(
+                &params,
                 &PublicKey::ed25519_from(KEY1),
-                U512::from(super::MAX_REL_INCREASE * total / 1_000_000 + 1)
+                U512::from(params.max_rel_increase * total / 1_000_000 + 1)
             ),
             "Successfully bonded more than the maximum amount."
         );
         assert_eq!(
             Ok(()),
             stakes.validate_bonding(
+                &params,
                 &PublicKey::ed25519_from(KEY1),
-                U512::from(super::MAX_REL_INCREASE * total / 1_000_000)
+                U512::from(params.max_rel_increase * total / 1_000_000)
             ),
             "Failed to bond the maximum amount."
         );
@@ -232,50 +232,56 @@ mod tests {
 
     #[test]
     fn test_unbond() {
+        let params = PosParams::default();
         let mut stakes = new_stakes(&[(KEY1, 5), (KEY2, 100)]);
         assert_eq!(
             Ok(U512::from(5)),
-            stakes.unbond(&PublicKey::ed25519_from(KEY1), None)
+            stakes.unbond(&params, &PublicKey::ed25519_from(KEY1), None)
         );
         assert_eq!(new_stakes(&[(KEY2, 100)]), stakes);
     }
 
     #[test]
     fn test_unbond_last_validator() {
+        let params = PosParams::default();
         let mut stakes = new_stakes(&[(KEY1, 5)]);
         assert_eq!(
             Err(Error::CannotUnbondLastValidator),
-            stakes.unbond(&PublicKey::ed25519_from(KEY1), None)
+            stakes.unbond(&params, &PublicKey::ed25519_from(KEY1), None)
         );
     }
 
     #[test]
     fn test_partially_unbond() {
+        let params = PosParams::default();
         let mut stakes = new_stakes(&[(KEY1, 50)]);
         assert_eq!(
             Ok(U512::from(4)),
-            stakes.unbond(&PublicKey::ed25519_from(KEY1), Some(U512::from(4)))
+            stakes.unbond(&params, &PublicKey::ed25519_from(KEY1), Some(U512::from(4)))
         );
         assert_eq!(new_stakes(&[(KEY1, 46)]), stakes);
     }
 
     #[test]
     fn test_unbond_too_much_rel() {
+        let params = PosParams::default();
         let mut stakes = new_stakes(&[(KEY1, 999), (KEY2, 1)]);
         let total = 999 + 1;
         assert_eq!(
             Err(Error::UnbondTooLarge),
             stakes.unbond(
+                &params,
                 &PublicKey::ed25519_from(KEY1),
-                Some(U512::from(super::MAX_REL_DECREASE * total / 1_000_000 + 1))
+                Some(U512::from(params.max_rel_decrease * total / 1_000_000 + 1))
             ),
             "Successfully unbonded more than the maximum amount."
         );
         assert_eq!(
-            Ok(U512::from(super::MAX_REL_DECREASE * total / 1_000_000)),
+            Ok(U512::from(params.max_rel_decrease * total / 1_000_000)),
             stakes.unbond(
+                &params,
                 &PublicKey::ed25519_from(KEY1),
-                Some(U512::from(super::MAX_REL_DECREASE * total / 1_000_000))
+                Some(U512::from(params.max_rel_decrease * total / 1_000_000))
             ),
             "Failed to unbond the maximum amount."
         );
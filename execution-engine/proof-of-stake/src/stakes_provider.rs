@@ -4,5 +4,5 @@ use crate::{stakes::Stakes, Result};
 pub trait StakesProvider {
     fn read(&self) -> Result<Stakes>;
 
-    fn write(&mut self, stakes: &Stakes);
+    fn write(&mut self, stakes: &Stakes) -> Result<()>;
 }
@@ -9,7 +9,7 @@ EE_DIR="$(cd "$(dirname "${BASH_SOURCE[0]}")" >/dev/null 2>&1 && pwd)"
 # should remain ordered from least-dependent to most.
 #
 # Note: 'cargo-casperlabs' is treated specially since it needs '--allow-dirty' passed to the publish call
-PACKAGE_DIRS=( types contract engine-wasm-prep engine-shared engine-storage engine-core engine-grpc-server engine-test-support )
+PACKAGE_DIRS=( types-derive types contract engine-wasm-prep engine-shared engine-storage engine-core engine-grpc-server engine-test-support )
 
 run_curl() {
     set +e
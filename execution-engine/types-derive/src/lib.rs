@@ -0,0 +1,296 @@
+//! Derive macros for the [`ToBytes`], [`FromBytes`] and [`CLTyped`] traits of `casperlabs-types`.
+//!
+//! These are normally used via the re-exports in `casperlabs-types` with its `derive` feature
+//! enabled, which expects the types crate to be available under the name `types`, as it is
+//! throughout the CasperLabs workspace.
+//!
+//! # Encoding
+//!
+//! A struct is encoded as the concatenation of its fields in declaration order, i.e. identically
+//! to a tuple of the same field types.  A unit struct is encoded as zero bytes, like `()`.
+//!
+//! An enum is encoded as a single `u8` tag followed by the fields of the variant, again in
+//! declaration order.  The tag is the variant's discriminant: the explicit discriminant if one is
+//! given, otherwise one more than that of the previous variant, starting from zero.  Enums with
+//! more than 256 variants are rejected.
+//!
+//! The generated code only relies on `core` and `alloc`, and so can be used in `no_std` contracts.
+//!
+//! [`ToBytes`]: ../casperlabs_types/bytesrepr/trait.ToBytes.html
+//! [`FromBytes`]: ../casperlabs_types/bytesrepr/trait.FromBytes.html
+//! [`CLTyped`]: ../casperlabs_types/trait.CLTyped.html
+
+#![doc(html_root_url = "https://docs.rs/casperlabs-types-derive/0.1.0")]
+#![doc(
+    html_favicon_url = "https://raw.githubusercontent.com/CasperLabs/CasperLabs/dev/images/CasperLabs_Logo_Favicon_RGB_50px.png",
+    html_logo_url = "https://raw.githubusercontent.com/CasperLabs/CasperLabs/dev/images/CasperLabs_Logo_Symbol_RGB.png",
+    test(attr(forbid(warnings)))
+)]
+#![warn(missing_docs)]
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, parse_quote, spanned::Spanned, Data, DataEnum, DeriveInput, Error, Expr,
+    ExprLit, Fields, Generics, Ident, Lit, Path,
+};
+
+/// Derives `types::bytesrepr::ToBytes`.
+#[proc_macro_derive(ToBytes)]
+pub fn derive_to_bytes(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_to_bytes(input)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
+
+/// Derives `types::bytesrepr::FromBytes`.
+#[proc_macro_derive(FromBytes)]
+pub fn derive_from_bytes(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_from_bytes(input)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
+
+/// Derives `types::CLTyped`.
+///
+/// Structs are described as `CLType::Struct` and enums as `CLType::Enum`, named after the type,
+/// its fields and its variants.  Fields of tuple structs and tuple variants are named by their
+/// index.
+#[proc_macro_derive(CLTyped)]
+pub fn derive_cl_typed(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_cl_typed(input)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
+
+fn expand_to_bytes(input: DeriveInput) -> Result<TokenStream2, Error> {
+    let name = &input.ident;
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let (pattern, bindings) = destructure(quote!(#name), &data.fields);
+            quote! {
+                let #pattern = self;
+                #(result.append(&mut ::types::bytesrepr::ToBytes::to_bytes(#bindings)?);)*
+            }
+        }
+        Data::Enum(data) => {
+            let tags = variant_tags(data)?;
+            let arms = data.variants.iter().zip(tags).map(|(variant, tag)| {
+                let variant_name = &variant.ident;
+                let (pattern, bindings) =
+                    destructure(quote!(#name::#variant_name), &variant.fields);
+                quote! {
+                    #pattern => {
+                        result.append(&mut ::types::bytesrepr::ToBytes::to_bytes(&#tag)?);
+                        #(result.append(&mut ::types::bytesrepr::ToBytes::to_bytes(#bindings)?);)*
+                    }
+                }
+            });
+            if data.variants.is_empty() {
+                quote!(match *self {})
+            } else {
+                quote! {
+                    match self {
+                        #(#arms)*
+                    }
+                }
+            }
+        }
+        Data::Union(data) => return Err(unsupported_union(data.union_token.span)),
+    };
+
+    let generics = add_trait_bounds(&input.generics, parse_quote!(::types::bytesrepr::ToBytes));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    Ok(wrap(quote! {
+        impl #impl_generics ::types::bytesrepr::ToBytes for #name #ty_generics #where_clause {
+            fn to_bytes(
+                &self,
+            ) -> ::core::result::Result<alloc::vec::Vec<u8>, ::types::bytesrepr::Error> {
+                let mut result = alloc::vec::Vec::new();
+                #body
+                ::core::result::Result::Ok(result)
+            }
+        }
+    }))
+}
+
+fn expand_from_bytes(input: DeriveInput) -> Result<TokenStream2, Error> {
+    let name = &input.ident;
+    let body = match &input.data {
+        Data::Struct(data) => parse_fields(quote!(#name), &data.fields),
+        Data::Enum(data) => {
+            let tags = variant_tags(data)?;
+            let arms = data.variants.iter().zip(tags).map(|(variant, tag)| {
+                let variant_name = &variant.ident;
+                let parse = parse_fields(quote!(#name::#variant_name), &variant.fields);
+                quote! {
+                    #tag => { #parse }
+                }
+            });
+            quote! {
+                let (tag, bytes): (u8, &[u8]) = ::types::bytesrepr::FromBytes::from_bytes(bytes)?;
+                match tag {
+                    #(#arms)*
+                    _ => ::core::result::Result::Err(::types::bytesrepr::Error::Formatting),
+                }
+            }
+        }
+        Data::Union(data) => return Err(unsupported_union(data.union_token.span)),
+    };
+
+    let generics = add_trait_bounds(&input.generics, parse_quote!(::types::bytesrepr::FromBytes));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    Ok(wrap(quote! {
+        impl #impl_generics ::types::bytesrepr::FromBytes for #name #ty_generics #where_clause {
+            fn from_bytes(
+                bytes: &[u8],
+            ) -> ::core::result::Result<(Self, &[u8]), ::types::bytesrepr::Error> {
+                #body
+            }
+        }
+    }))
+}
+
+fn expand_cl_typed(input: DeriveInput) -> Result<TokenStream2, Error> {
+    let name = input.ident.to_string();
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let fields = cl_fields(&data.fields);
+            quote! {
+                ::types::CLType::Struct {
+                    name: alloc::string::String::from(#name),
+                    fields: alloc::vec![#(#fields),*],
+                }
+            }
+        }
+        Data::Enum(data) => {
+            let tags = variant_tags(data)?;
+            let variants = data.variants.iter().zip(tags).map(|(variant, tag)| {
+                let variant_name = variant.ident.to_string();
+                let fields = cl_fields(&variant.fields);
+                quote! {
+                    ::types::CLVariant::new(#tag, #variant_name, alloc::vec![#(#fields),*])
+                }
+            });
+            quote! {
+                ::types::CLType::Enum {
+                    name: alloc::string::String::from(#name),
+                    variants: alloc::vec![#(#variants),*],
+                }
+            }
+        }
+        Data::Union(data) => return Err(unsupported_union(data.union_token.span)),
+    };
+
+    let name = &input.ident;
+    let generics = add_trait_bounds(&input.generics, parse_quote!(::types::CLTyped));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    Ok(wrap(quote! {
+        impl #impl_generics ::types::CLTyped for #name #ty_generics #where_clause {
+            fn cl_type() -> ::types::CLType {
+                #body
+            }
+        }
+    }))
+}
+
+/// Returns expressions constructing a `CLField` for each of `fields`.
+fn cl_fields(fields: &Fields) -> Vec<TokenStream2> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            let field_name = match &field.ident {
+                Some(ident) => ident.to_string(),
+                None => index.to_string(),
+            };
+            let field_type = &field.ty;
+            quote! {
+                ::types::CLField::new(#field_name, <#field_type as ::types::CLTyped>::cl_type())
+            }
+        })
+        .collect()
+}
+
+/// Wraps the generated impl in an anonymous const so that `alloc` can be referenced without
+/// requiring the deriving crate to declare it.
+fn wrap(item: TokenStream2) -> TokenStream2 {
+    quote! {
+        #[allow(unused_mut, unused_qualifications, unused_variables)]
+        const _: () = {
+            extern crate alloc;
+            #item
+        };
+    }
+}
+
+/// Returns a pattern binding each of `fields` by reference, along with the bound identifiers.
+fn destructure(path: TokenStream2, fields: &Fields) -> (TokenStream2, Vec<Ident>) {
+    let bindings: Vec<Ident> = (0..fields.len())
+        .map(|index| format_ident!("field_{}", index))
+        .collect();
+    let pattern = match fields {
+        Fields::Named(named) => {
+            let names = named.named.iter().map(|field| &field.ident);
+            quote!(#path { #(#names: #bindings),* })
+        }
+        Fields::Unnamed(_) => quote!(#path ( #(#bindings),* )),
+        Fields::Unit => quote!(#path),
+    };
+    (pattern, bindings)
+}
+
+/// Returns statements parsing each of `fields` in order from `bytes`, then constructing `path`.
+fn parse_fields(path: TokenStream2, fields: &Fields) -> TokenStream2 {
+    let (constructor, bindings) = destructure(path, fields);
+    quote! {
+        #(let (#bindings, bytes) = ::types::bytesrepr::FromBytes::from_bytes(bytes)?;)*
+        ::core::result::Result::Ok((#constructor, bytes))
+    }
+}
+
+/// Returns the `u8` tag of each variant, following the rules Rust uses for discriminants.
+fn variant_tags(data: &DataEnum) -> Result<Vec<u8>, Error> {
+    let mut tags = Vec::with_capacity(data.variants.len());
+    let mut next_tag = Some(0u8);
+    for variant in &data.variants {
+        let tag = match &variant.discriminant {
+            Some((
+                _,
+                Expr::Lit(ExprLit {
+                    lit: Lit::Int(int), ..
+                }),
+            )) => int.base10_parse::<u8>()?,
+            Some((_, expr)) => {
+                return Err(Error::new(
+                    expr.span(),
+                    "discriminant must be an integer literal",
+                ))
+            }
+            None => next_tag.ok_or_else(|| {
+                Error::new(variant.span(), "discriminant doesn't fit in a `u8` tag")
+            })?,
+        };
+        tags.push(tag);
+        next_tag = tag.checked_add(1);
+    }
+    Ok(tags)
+}
+
+fn add_trait_bounds(generics: &Generics, bound: Path) -> Generics {
+    let mut generics = generics.clone();
+    for type_param in generics.type_params_mut() {
+        type_param.bounds.push(parse_quote!(#bound));
+    }
+    generics
+}
+
+fn unsupported_union(span: Span) -> Error {
+    Error::new(span, "unions are not supported")
+}
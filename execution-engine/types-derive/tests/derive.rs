@@ -0,0 +1,191 @@
+use proptest::prelude::*;
+
+use types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLField, CLType, CLTyped, CLValue, CLVariant, Key, U512,
+};
+
+#[derive(Debug, PartialEq, FromBytes, ToBytes, CLTyped)]
+struct Unit;
+
+#[derive(Debug, PartialEq, FromBytes, ToBytes, CLTyped)]
+struct Named {
+    amount: U512,
+    name: String,
+    flag: bool,
+}
+
+#[derive(Debug, PartialEq, FromBytes, ToBytes, CLTyped)]
+struct Generic<T>(T, u64);
+
+#[derive(Debug, PartialEq, FromBytes, ToBytes, CLTyped)]
+enum Fieldless {
+    First,
+    Second,
+    Fifth = 5,
+    Sixth,
+}
+
+#[derive(Debug, PartialEq, FromBytes, ToBytes, CLTyped)]
+enum WithData {
+    Empty,
+    Tuple(u32, Option<String>),
+    Struct { key: Key, values: Vec<u8> },
+}
+
+fn round_trip<T: FromBytes + ToBytes + PartialEq + std::fmt::Debug>(value: &T) {
+    let serialized = value.to_bytes().expect("should serialize");
+    let deserialized: T = bytesrepr::deserialize(serialized).expect("should deserialize");
+    assert_eq!(*value, deserialized);
+}
+
+#[test]
+fn unit_struct_should_encode_as_unit() {
+    assert_eq!(Unit.to_bytes().unwrap(), ().to_bytes().unwrap());
+    round_trip(&Unit);
+    let expected = CLType::Struct {
+        name: String::from("Unit"),
+        fields: vec![],
+    };
+    assert_eq!(Unit::cl_type(), expected);
+}
+
+#[test]
+fn fieldless_enum_should_encode_discriminant() {
+    assert_eq!(Fieldless::First.to_bytes().unwrap(), vec![0]);
+    assert_eq!(Fieldless::Second.to_bytes().unwrap(), vec![1]);
+    assert_eq!(Fieldless::Fifth.to_bytes().unwrap(), vec![5]);
+    assert_eq!(Fieldless::Sixth.to_bytes().unwrap(), vec![6]);
+    round_trip(&Fieldless::Sixth);
+    assert_eq!(
+        bytesrepr::deserialize::<Fieldless>(vec![2]),
+        Err(bytesrepr::Error::Formatting)
+    );
+}
+
+#[test]
+fn struct_cl_type_should_name_fields() {
+    let expected = CLType::Struct {
+        name: String::from("Named"),
+        fields: vec![
+            CLField::new("amount", CLType::U512),
+            CLField::new("name", CLType::String),
+            CLField::new("flag", CLType::Bool),
+        ],
+    };
+    assert_eq!(Named::cl_type(), expected);
+
+    let expected = CLType::Struct {
+        name: String::from("Generic"),
+        fields: vec![
+            CLField::new("0", CLType::Key),
+            CLField::new("1", CLType::U64),
+        ],
+    };
+    assert_eq!(Generic::<Key>::cl_type(), expected);
+}
+
+#[test]
+fn enum_cl_type_should_name_variants() {
+    let expected = CLType::Enum {
+        name: String::from("Fieldless"),
+        variants: vec![
+            CLVariant::new(0, "First", vec![]),
+            CLVariant::new(1, "Second", vec![]),
+            CLVariant::new(5, "Fifth", vec![]),
+            CLVariant::new(6, "Sixth", vec![]),
+        ],
+    };
+    assert_eq!(Fieldless::cl_type(), expected);
+
+    let expected = CLType::Enum {
+        name: String::from("WithData"),
+        variants: vec![
+            CLVariant::new(0, "Empty", vec![]),
+            CLVariant::new(
+                1,
+                "Tuple",
+                vec![
+                    CLField::new("0", CLType::U32),
+                    CLField::new("1", CLType::Option(Box::new(CLType::String))),
+                ],
+            ),
+            CLVariant::new(
+                2,
+                "Struct",
+                vec![
+                    CLField::new("key", CLType::Key),
+                    CLField::new("values", CLType::List(Box::new(CLType::U8))),
+                ],
+            ),
+        ],
+    };
+    assert_eq!(WithData::cl_type(), expected);
+}
+
+#[test]
+fn derived_values_should_type_check() {
+    let value = Named {
+        amount: U512::from(3),
+        name: String::from("abc"),
+        flag: true,
+    };
+    let cl_value = CLValue::from_t(value).unwrap();
+    assert!(cl_value.clone().into_t::<(U512, String, bool)>().is_err());
+    assert_eq!(
+        cl_value.into_t::<Named>().unwrap(),
+        Named {
+            amount: U512::from(3),
+            name: String::from("abc"),
+            flag: true,
+        }
+    );
+}
+
+#[test]
+fn should_fail_on_early_end_of_stream() {
+    let mut bytes = WithData::Tuple(1, None).to_bytes().unwrap();
+    bytes.pop();
+    assert_eq!(
+        bytesrepr::deserialize::<WithData>(bytes),
+        Err(bytesrepr::Error::EarlyEndOfStream)
+    );
+}
+
+proptest! {
+    #[test]
+    fn named_struct_should_encode_as_tuple(amount in any::<u64>(), name in ".*", flag: bool) {
+        let amount = U512::from(amount);
+        let value = Named { amount, name: name.clone(), flag };
+        prop_assert_eq!(value.to_bytes().unwrap(), (amount, name, flag).to_bytes().unwrap());
+        round_trip(&value);
+    }
+
+    #[test]
+    fn generic_struct_should_encode_as_tuple(first: u32, second: u64) {
+        let value = Generic(first, second);
+        prop_assert_eq!(value.to_bytes().unwrap(), (first, second).to_bytes().unwrap());
+        round_trip(&value);
+    }
+
+    #[test]
+    fn enum_should_encode_tag_then_fields(
+        number: u32,
+        maybe_string in proptest::option::of(".*"),
+        values: Vec<u8>,
+    ) {
+        let tuple = WithData::Tuple(number, maybe_string.clone());
+        let expected = (1u8, number, maybe_string).to_bytes().unwrap();
+        prop_assert_eq!(tuple.to_bytes().unwrap(), expected);
+        round_trip(&tuple);
+
+        let key = Key::Hash([number as u8; 32]);
+        let structure = WithData::Struct { key, values: values.clone() };
+        let expected = (2u8, key, values).to_bytes().unwrap();
+        prop_assert_eq!(structure.to_bytes().unwrap(), expected);
+        round_trip(&structure);
+
+        prop_assert_eq!(WithData::Empty.to_bytes().unwrap(), vec![0]);
+        round_trip(&WithData::Empty);
+    }
+}
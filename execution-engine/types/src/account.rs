@@ -372,6 +372,64 @@ impl TryFrom<i32> for UpdateKeyFailure {
     }
 }
 
+/// Errors that can occur while configuring or going through an account's recovery.
+#[derive(PartialEq, Eq, Fail, Debug)]
+#[repr(i32)]
+pub enum RecoveryFailure {
+    /// Caller doesn't have sufficient permissions, or isn't one of the account's recovery keys.
+    #[fail(display = "Unable to change account recovery due to insufficient permissions")]
+    PermissionDenied = 1,
+    /// The account hasn't designated any recovery keys.
+    #[fail(display = "Account has no recovery keys")]
+    NotConfigured = 2,
+    /// The recovery keys given would exceed [`MAX_ASSOCIATED_KEYS`].
+    #[fail(display = "Unable to set recovery keys because maximum amount of keys is reached")]
+    MaxKeysLimit = 3,
+    /// A recovery request has already been recorded for the account.
+    #[fail(display = "Account recovery has already been requested")]
+    AlreadyRequested = 4,
+    /// No recovery request has been recorded for the account.
+    #[fail(display = "Account recovery has not been requested")]
+    NotRequested = 5,
+    /// The recovery delay hasn't elapsed yet, so the recovery keys can't take over.
+    #[fail(display = "Account recovery delay has not elapsed yet")]
+    DelayNotElapsed = 6,
+    /// The recovery delay has elapsed, so the request can no longer be cancelled.
+    #[fail(display = "Account recovery delay has already elapsed")]
+    DelayElapsed = 7,
+    /// The recovery threshold is zero, or the weight of all the recovery keys can't meet it.
+    #[fail(display = "Unable to set recovery keys because their threshold can't be met")]
+    InvalidThreshold = 8,
+}
+
+// This conversion is not intended to be used by third party crates.
+#[doc(hidden)]
+impl TryFrom<i32> for RecoveryFailure {
+    type Error = TryFromIntError;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            d if d == RecoveryFailure::PermissionDenied as i32 => {
+                Ok(RecoveryFailure::PermissionDenied)
+            }
+            d if d == RecoveryFailure::NotConfigured as i32 => Ok(RecoveryFailure::NotConfigured),
+            d if d == RecoveryFailure::MaxKeysLimit as i32 => Ok(RecoveryFailure::MaxKeysLimit),
+            d if d == RecoveryFailure::AlreadyRequested as i32 => {
+                Ok(RecoveryFailure::AlreadyRequested)
+            }
+            d if d == RecoveryFailure::NotRequested as i32 => Ok(RecoveryFailure::NotRequested),
+            d if d == RecoveryFailure::DelayNotElapsed as i32 => {
+                Ok(RecoveryFailure::DelayNotElapsed)
+            }
+            d if d == RecoveryFailure::DelayElapsed as i32 => Ok(RecoveryFailure::DelayElapsed),
+            d if d == RecoveryFailure::InvalidThreshold as i32 => {
+                Ok(RecoveryFailure::InvalidThreshold)
+            }
+            _ => Err(TryFromIntError(())),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::{convert::TryFrom, vec::Vec};
@@ -439,4 +497,15 @@ mod tests {
                    `UpdateKeyFailure`, or `max_valid_value_for_variant` in this test?"
         );
     }
+
+    #[test]
+    fn try_from_i32_for_recovery_failure() {
+        let max_valid_value_for_variant = RecoveryFailure::InvalidThreshold as i32;
+        assert_eq!(
+            Err(TryFromIntError(())),
+            RecoveryFailure::try_from(max_valid_value_for_variant + 1),
+            "Did you forget to update `RecoveryFailure::try_from` for a new variant of \
+                   `RecoveryFailure`, or `max_valid_value_for_variant` in this test?"
+        );
+    }
 }
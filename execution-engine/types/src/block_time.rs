@@ -1,6 +1,9 @@
 use alloc::vec::Vec;
 
-use crate::bytesrepr::{Error, FromBytes, ToBytes, U64_SERIALIZED_LENGTH};
+use crate::{
+    bytesrepr::{Error, FromBytes, ToBytes, U64_SERIALIZED_LENGTH},
+    CLType, CLTyped,
+};
 
 /// The number of bytes in a serialized [`BlockTime`].
 pub const BLOCKTIME_SERIALIZED_LENGTH: usize = U64_SERIALIZED_LENGTH;
@@ -34,6 +37,12 @@ impl ToBytes for BlockTime {
     }
 }
 
+impl CLTyped for BlockTime {
+    fn cl_type() -> CLType {
+        CLType::U64
+    }
+}
+
 impl FromBytes for BlockTime {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
         let (time, rem) = FromBytes::from_bytes(bytes)?;
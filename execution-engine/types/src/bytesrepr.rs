@@ -12,6 +12,9 @@ use core::mem::{size_of, MaybeUninit};
 
 use failure::Fail;
 
+#[cfg(feature = "derive")]
+pub use casperlabs_types_derive::{FromBytes, ToBytes};
+
 /// The number of bytes in a serialized [`i32`].
 pub const I32_SERIALIZED_LENGTH: usize = size_of::<i32>();
 /// The number of bytes in a serialized [`u8`].
@@ -449,7 +452,13 @@ macro_rules! impl_byte_array {
     }
 }
 
-impl_byte_array! { 4 5 8 32 }
+impl_byte_array! {
+     0  1  2  3  4  5  6  7  8  9
+    10 11 12 13 14 15 16 17 18 19
+    20 21 22 23 24 25 26 27 28 29
+    30 31 32
+    64 128 256 512
+}
 
 impl<K, V> ToBytes for BTreeMap<K, V>
 where
@@ -7,8 +7,8 @@ use alloc::{
 use core::mem;
 
 use crate::{
-    bytesrepr::{self, FromBytes, ToBytes},
-    Key, URef, U128, U256, U512,
+    bytesrepr::{self, FromBytes, ToBytes, U32_SERIALIZED_LENGTH, U8_SERIALIZED_LENGTH},
+    Decimal, Key, URef, I256, U128, U256, U512,
 };
 
 const CL_TYPE_TAG_BOOL: u8 = 0;
@@ -33,6 +33,11 @@ const CL_TYPE_TAG_TUPLE1: u8 = 18;
 const CL_TYPE_TAG_TUPLE2: u8 = 19;
 const CL_TYPE_TAG_TUPLE3: u8 = 20;
 const CL_TYPE_TAG_ANY: u8 = 21;
+const CL_TYPE_TAG_STRUCT: u8 = 22;
+const CL_TYPE_TAG_ENUM: u8 = 23;
+const CL_TYPE_TAG_I256: u8 = 24;
+const CL_TYPE_TAG_DECIMAL: u8 = 25;
+const CL_TYPE_TAG_BYTE_ARRAY: u8 = 26;
 
 /// CasperLabs types, i.e. types which can be stored and manipulated by smart contracts.
 ///
@@ -57,6 +62,10 @@ pub enum CLType {
     U256,
     /// [`U512`] large unsigned integer type.
     U512,
+    /// [`I256`] large signed integer type.
+    I256,
+    /// [`Decimal`] signed fixed-point decimal type.
+    Decimal,
     /// `()` primitive.
     Unit,
     /// `String` primitive.
@@ -71,6 +80,8 @@ pub enum CLType {
     List(Box<CLType>),
     /// Fixed-length list of a single `CLType` (comparable to a Rust array).
     FixedList(Box<CLType>, u32),
+    /// Fixed-length array of bytes (comparable to a Rust `[u8; N]`), serialized contiguously.
+    ByteArray(u32),
     /// `Result` with `Ok` and `Err` variants of `CLType`s.
     #[allow(missing_docs)] // generated docs are explicit enough.
     Result { ok: Box<CLType>, err: Box<CLType> },
@@ -88,6 +99,97 @@ pub enum CLType {
     Tuple3([Box<CLType>; 3]),
     /// Unspecified type.
     Any,
+    /// User-defined struct, serialized as the concatenation of its fields.
+    #[allow(missing_docs)] // generated docs are explicit enough.
+    Struct { name: String, fields: Vec<CLField> },
+    /// User-defined enum, serialized as the `u8` tag of the variant followed by its fields.
+    #[allow(missing_docs)] // generated docs are explicit enough.
+    Enum {
+        name: String,
+        variants: Vec<CLVariant>,
+    },
+}
+
+/// A named field of a [`CLType::Struct`] or of a variant of a [`CLType::Enum`].
+///
+/// Fields of tuple structs and tuple variants are named by their index, e.g. `"0"`.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct CLField {
+    /// The name of the field.
+    pub name: String,
+    /// The type of the field.
+    pub cl_type: CLType,
+}
+
+impl CLField {
+    /// Constructs a new `CLField`.
+    pub fn new<N: Into<String>>(name: N, cl_type: CLType) -> Self {
+        CLField {
+            name: name.into(),
+            cl_type,
+        }
+    }
+
+    fn serialized_len(&self) -> usize {
+        U32_SERIALIZED_LENGTH + self.name.len() + self.cl_type.serialized_len()
+    }
+
+    fn append_bytes(&self, stream: &mut Vec<u8>) {
+        stream.append(&mut self.name.to_bytes().unwrap());
+        self.cl_type.append_bytes(stream);
+    }
+}
+
+impl FromBytes for CLField {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (name, remainder) = String::from_bytes(bytes)?;
+        let (cl_type, remainder) = CLType::from_bytes(remainder)?;
+        Ok((CLField { name, cl_type }, remainder))
+    }
+}
+
+/// A variant of a [`CLType::Enum`].
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct CLVariant {
+    /// The tag identifying the variant in serialized values of the enum.
+    pub tag: u8,
+    /// The name of the variant.
+    pub name: String,
+    /// The fields of the variant, empty for a unit variant.
+    pub fields: Vec<CLField>,
+}
+
+impl CLVariant {
+    /// Constructs a new `CLVariant`.
+    pub fn new<N: Into<String>>(tag: u8, name: N, fields: Vec<CLField>) -> Self {
+        CLVariant {
+            tag,
+            name: name.into(),
+            fields,
+        }
+    }
+
+    fn serialized_len(&self) -> usize {
+        U8_SERIALIZED_LENGTH
+            + U32_SERIALIZED_LENGTH
+            + self.name.len()
+            + serialized_len_of_cl_fields(&self.fields)
+    }
+
+    fn append_bytes(&self, stream: &mut Vec<u8>) {
+        stream.push(self.tag);
+        stream.append(&mut self.name.to_bytes().unwrap());
+        append_cl_fields(&self.fields, stream);
+    }
+}
+
+impl FromBytes for CLVariant {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (tag, remainder) = u8::from_bytes(bytes)?;
+        let (name, remainder) = String::from_bytes(remainder)?;
+        let (fields, remainder) = parse_cl_list(remainder)?;
+        Ok((CLVariant { tag, name, fields }, remainder))
+    }
 }
 
 impl CLType {
@@ -104,6 +206,8 @@ impl CLType {
                 | CLType::U128
                 | CLType::U256
                 | CLType::U512
+                | CLType::I256
+                | CLType::Decimal
                 | CLType::Unit
                 | CLType::String
                 | CLType::Key
@@ -113,11 +217,24 @@ impl CLType {
                 CLType::FixedList(cl_type, list_len) => {
                     cl_type.serialized_len() + list_len.to_le_bytes().len()
                 }
+                CLType::ByteArray(_) => U32_SERIALIZED_LENGTH,
                 CLType::Result { ok, err } => ok.serialized_len() + err.serialized_len(),
                 CLType::Map { key, value } => key.serialized_len() + value.serialized_len(),
                 CLType::Tuple1(cl_type_array) => serialized_len_of_cl_tuple_type(cl_type_array),
                 CLType::Tuple2(cl_type_array) => serialized_len_of_cl_tuple_type(cl_type_array),
                 CLType::Tuple3(cl_type_array) => serialized_len_of_cl_tuple_type(cl_type_array),
+                CLType::Struct { name, fields } => {
+                    U32_SERIALIZED_LENGTH + name.len() + serialized_len_of_cl_fields(fields)
+                }
+                CLType::Enum { name, variants } => {
+                    U32_SERIALIZED_LENGTH
+                        + name.len()
+                        + U32_SERIALIZED_LENGTH
+                        + variants
+                            .iter()
+                            .map(CLVariant::serialized_len)
+                            .sum::<usize>()
+                }
             }
     }
 }
@@ -128,6 +245,67 @@ pub fn named_key_type() -> CLType {
 }
 
 impl CLType {
+    /// Returns `true` if a value of type `found` can be parsed as `self`.
+    ///
+    /// This is the case if the two types are equal, or if they differ only in that `found` holds a
+    /// `FixedList(U8, n)` where `self` holds a `ByteArray(n)`.  Byte arrays were stored as fixed
+    /// lists of `U8` before `ByteArray` was introduced, and both have the same serialized values.
+    pub(crate) fn accepts(&self, found: &CLType) -> bool {
+        match (self, found) {
+            (CLType::ByteArray(len), CLType::FixedList(inner, found_len)) => {
+                **inner == CLType::U8 && len == found_len
+            }
+            (CLType::Option(inner), CLType::Option(found_inner))
+            | (CLType::List(inner), CLType::List(found_inner)) => inner.accepts(found_inner),
+            (CLType::FixedList(inner, len), CLType::FixedList(found_inner, found_len)) => {
+                len == found_len && inner.accepts(found_inner)
+            }
+            (
+                CLType::Result { ok, err },
+                CLType::Result {
+                    ok: found_ok,
+                    err: found_err,
+                },
+            ) => ok.accepts(found_ok) && err.accepts(found_err),
+            (
+                CLType::Map { key, value },
+                CLType::Map {
+                    key: found_key,
+                    value: found_value,
+                },
+            ) => key.accepts(found_key) && value.accepts(found_value),
+            (CLType::Tuple1(types), CLType::Tuple1(found_types)) => all_accept(types, found_types),
+            (CLType::Tuple2(types), CLType::Tuple2(found_types)) => all_accept(types, found_types),
+            (CLType::Tuple3(types), CLType::Tuple3(found_types)) => all_accept(types, found_types),
+            (
+                CLType::Struct { name, fields },
+                CLType::Struct {
+                    name: found_name,
+                    fields: found_fields,
+                },
+            ) => name == found_name && fields_accept(fields, found_fields),
+            (
+                CLType::Enum { name, variants },
+                CLType::Enum {
+                    name: found_name,
+                    variants: found_variants,
+                },
+            ) => {
+                name == found_name
+                    && variants.len() == found_variants.len()
+                    && variants
+                        .iter()
+                        .zip(found_variants)
+                        .all(|(variant, found_variant)| {
+                            variant.tag == found_variant.tag
+                                && variant.name == found_variant.name
+                                && fields_accept(&variant.fields, &found_variant.fields)
+                        })
+            }
+            _ => self == found,
+        }
+    }
+
     pub(crate) fn append_bytes(&self, stream: &mut Vec<u8>) {
         match self {
             CLType::Bool => stream.push(CL_TYPE_TAG_BOOL),
@@ -139,6 +317,8 @@ impl CLType {
             CLType::U128 => stream.push(CL_TYPE_TAG_U128),
             CLType::U256 => stream.push(CL_TYPE_TAG_U256),
             CLType::U512 => stream.push(CL_TYPE_TAG_U512),
+            CLType::I256 => stream.push(CL_TYPE_TAG_I256),
+            CLType::Decimal => stream.push(CL_TYPE_TAG_DECIMAL),
             CLType::Unit => stream.push(CL_TYPE_TAG_UNIT),
             CLType::String => stream.push(CL_TYPE_TAG_STRING),
             CLType::Key => stream.push(CL_TYPE_TAG_KEY),
@@ -156,6 +336,10 @@ impl CLType {
                 cl_type.append_bytes(stream);
                 stream.append(&mut len.to_bytes().unwrap());
             }
+            CLType::ByteArray(len) => {
+                stream.push(CL_TYPE_TAG_BYTE_ARRAY);
+                stream.append(&mut len.to_bytes().unwrap());
+            }
             CLType::Result { ok, err } => {
                 stream.push(CL_TYPE_TAG_RESULT);
                 ok.append_bytes(stream);
@@ -176,6 +360,19 @@ impl CLType {
                 serialize_cl_tuple_type(CL_TYPE_TAG_TUPLE3, cl_type_array, stream)
             }
             CLType::Any => stream.push(CL_TYPE_TAG_ANY),
+            CLType::Struct { name, fields } => {
+                stream.push(CL_TYPE_TAG_STRUCT);
+                stream.append(&mut name.to_bytes().unwrap());
+                append_cl_fields(fields, stream);
+            }
+            CLType::Enum { name, variants } => {
+                stream.push(CL_TYPE_TAG_ENUM);
+                stream.append(&mut name.to_bytes().unwrap());
+                stream.append(&mut (variants.len() as u32).to_bytes().unwrap());
+                for variant in variants {
+                    variant.append_bytes(stream);
+                }
+            }
         }
     }
 }
@@ -194,6 +391,8 @@ impl FromBytes for CLType {
             CL_TYPE_TAG_U128 => Ok((CLType::U128, remainder)),
             CL_TYPE_TAG_U256 => Ok((CLType::U256, remainder)),
             CL_TYPE_TAG_U512 => Ok((CLType::U512, remainder)),
+            CL_TYPE_TAG_I256 => Ok((CLType::I256, remainder)),
+            CL_TYPE_TAG_DECIMAL => Ok((CLType::Decimal, remainder)),
             CL_TYPE_TAG_UNIT => Ok((CLType::Unit, remainder)),
             CL_TYPE_TAG_STRING => Ok((CLType::String, remainder)),
             CL_TYPE_TAG_KEY => Ok((CLType::Key, remainder)),
@@ -214,6 +413,10 @@ impl FromBytes for CLType {
                 let cl_type = CLType::FixedList(Box::new(inner_type), len);
                 Ok((cl_type, remainder))
             }
+            CL_TYPE_TAG_BYTE_ARRAY => {
+                let (len, remainder) = u32::from_bytes(remainder)?;
+                Ok((CLType::ByteArray(len), remainder))
+            }
             CL_TYPE_TAG_RESULT => {
                 let (ok_type, remainder) = CLType::from_bytes(remainder)?;
                 let (err_type, remainder) = CLType::from_bytes(remainder)?;
@@ -255,11 +458,35 @@ impl FromBytes for CLType {
                 Ok((cl_type, remainder))
             }
             CL_TYPE_TAG_ANY => Ok((CLType::Any, remainder)),
+            CL_TYPE_TAG_STRUCT => {
+                let (name, remainder) = String::from_bytes(remainder)?;
+                let (fields, remainder) = parse_cl_list(remainder)?;
+                Ok((CLType::Struct { name, fields }, remainder))
+            }
+            CL_TYPE_TAG_ENUM => {
+                let (name, remainder) = String::from_bytes(remainder)?;
+                let (variants, remainder) = parse_cl_list(remainder)?;
+                Ok((CLType::Enum { name, variants }, remainder))
+            }
             _ => Err(bytesrepr::Error::Formatting),
         }
     }
 }
 
+fn all_accept(types: &[Box<CLType>], found_types: &[Box<CLType>]) -> bool {
+    types
+        .iter()
+        .zip(found_types)
+        .all(|(cl_type, found_type)| cl_type.accepts(found_type))
+}
+
+fn fields_accept(fields: &[CLField], found_fields: &[CLField]) -> bool {
+    fields.len() == found_fields.len()
+        && fields.iter().zip(found_fields).all(|(field, found_field)| {
+            field.name == found_field.name && field.cl_type.accepts(&found_field.cl_type)
+        })
+}
+
 fn serialize_cl_tuple_type<'a, T: IntoIterator<Item = &'a Box<CLType>>>(
     tag: u8,
     cl_type_array: T,
@@ -294,6 +521,30 @@ fn serialized_len_of_cl_tuple_type<'a, T: IntoIterator<Item = &'a Box<CLType>>>(
         .sum()
 }
 
+fn append_cl_fields(fields: &[CLField], stream: &mut Vec<u8>) {
+    stream.append(&mut (fields.len() as u32).to_bytes().unwrap());
+    for field in fields {
+        field.append_bytes(stream);
+    }
+}
+
+/// Parses a `u32` count followed by that many elements.
+fn parse_cl_list<T: FromBytes>(bytes: &[u8]) -> Result<(Vec<T>, &[u8]), bytesrepr::Error> {
+    let (count, mut bytes) = u32::from_bytes(bytes)?;
+    // Don't trust `count` for the initial capacity, as it hasn't been validated yet.
+    let mut result = Vec::new();
+    for _ in 0..count {
+        let (element, remainder) = T::from_bytes(bytes)?;
+        result.push(element);
+        bytes = remainder;
+    }
+    Ok((result, bytes))
+}
+
+fn serialized_len_of_cl_fields(fields: &[CLField]) -> usize {
+    U32_SERIALIZED_LENGTH + fields.iter().map(CLField::serialized_len).sum::<usize>()
+}
+
 /// A type which can be described as a [`CLType`].
 pub trait CLTyped {
     /// The `CLType` of `Self`.
@@ -354,6 +605,18 @@ impl CLTyped for U512 {
     }
 }
 
+impl CLTyped for I256 {
+    fn cl_type() -> CLType {
+        CLType::I256
+    }
+}
+
+impl CLTyped for Decimal {
+    fn cl_type() -> CLType {
+        CLType::Decimal
+    }
+}
+
 impl CLTyped for () {
     fn cl_type() -> CLType {
         CLType::Unit
@@ -400,10 +663,16 @@ macro_rules! impl_cl_typed_for_array {
     ($($N:literal)+) => {
         $(
             impl<T: CLTyped> CLTyped for [T; $N] {
-                fn cl_type() -> CLType {
+                default fn cl_type() -> CLType {
                     CLType::FixedList(Box::new(T::cl_type()), $N as u32)
                 }
             }
+
+            impl CLTyped for [u8; $N] {
+                fn cl_type() -> CLType {
+                    CLType::ByteArray($N as u32)
+                }
+            }
         )+
     }
 }
@@ -522,6 +791,18 @@ mod tests {
         round_trip(&U512::one());
     }
 
+    #[test]
+    fn i256_should_work() {
+        round_trip(&I256::from(-1));
+        round_trip(&I256::MAX);
+    }
+
+    #[test]
+    fn decimal_should_work() {
+        round_trip(&Decimal::from_raw(I256::from(-1)));
+        round_trip(&Decimal::MIN);
+    }
+
     #[test]
     fn unit_should_work() {
         round_trip(&());
@@ -582,6 +863,60 @@ mod tests {
         }
     }
 
+    #[test]
+    #[allow(clippy::cognitive_complexity)]
+    fn byte_array_should_work() {
+        macro_rules! test_byte_array {
+            ($($N:literal)+) => {
+                $(
+                    let mut array = [0u8; $N];
+                    for i in 0..$N {
+                        array[i] = i as u8;
+                    }
+                    assert_eq!(<[u8; $N]>::cl_type(), CLType::ByteArray($N));
+                    let cl_value = CLValue::from_t(array).unwrap();
+                    assert_eq!(cl_value.serialized_len(), 1 + 4 + 4 + $N);
+                    let parsed_cl_value: CLValue =
+                        bytesrepr::deserialize(cl_value.to_bytes().unwrap()).unwrap();
+                    assert_eq!(cl_value, parsed_cl_value);
+                    let parsed_value: [u8; $N] = CLValue::into_t(cl_value).unwrap();
+                    assert_eq!(&array[..], &parsed_value[..]);
+                )+
+            }
+        }
+
+        test_byte_array! {
+              0  1  2  3  4  5  6  7  8  9
+             10 11 12 13 14 15 16 17 18 19
+             20 21 22 23 24 25 26 27 28 29
+             30 31 32
+             64 128 256 512
+        }
+    }
+
+    #[test]
+    fn byte_array_should_accept_legacy_fixed_list_of_u8() {
+        let legacy_type = CLType::FixedList(Box::new(CLType::U8), 32);
+        let hash = [7u8; 32];
+        let legacy_value = CLValue::from_components(legacy_type.clone(), hash.to_bytes().unwrap());
+        assert_eq!(legacy_value.into_t::<[u8; 32]>().unwrap(), hash);
+
+        let legacy_value = CLValue::from_components(
+            CLType::Option(Box::new(legacy_type)),
+            Some(hash).to_bytes().unwrap(),
+        );
+        assert_eq!(
+            legacy_value.into_t::<Option<[u8; 32]>>().unwrap(),
+            Some(hash)
+        );
+
+        let wrong_len = CLType::FixedList(Box::new(CLType::U8), 31);
+        assert!(!CLType::ByteArray(32).accepts(&wrong_len));
+        let wrong_inner = CLType::FixedList(Box::new(CLType::I32), 32);
+        assert!(!CLType::ByteArray(32).accepts(&wrong_inner));
+        assert!(!CLType::FixedList(Box::new(CLType::U8), 32).accepts(&CLType::ByteArray(32)));
+    }
+
     #[test]
     fn large_array_of_cl_type_should_work() {
         macro_rules! test_large_array {
@@ -651,6 +986,95 @@ mod tests {
         round_trip(&x);
     }
 
+    #[test]
+    fn struct_should_work() {
+        #[derive(PartialEq, Debug, Clone)]
+        struct Record {
+            name: String,
+            amount: U512,
+        }
+
+        impl CLTyped for Record {
+            fn cl_type() -> CLType {
+                CLType::Struct {
+                    name: String::from("Record"),
+                    fields: vec![
+                        CLField::new("name", CLType::String),
+                        CLField::new("amount", CLType::U512),
+                    ],
+                }
+            }
+        }
+
+        impl ToBytes for Record {
+            fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+                (self.name.clone(), self.amount).into_bytes()
+            }
+        }
+
+        impl FromBytes for Record {
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+                let ((name, amount), remainder) = <(String, U512)>::from_bytes(bytes)?;
+                Ok((Record { name, amount }, remainder))
+            }
+        }
+
+        round_trip(&Record {
+            name: String::from("abc"),
+            amount: U512::from(7),
+        });
+
+        let mismatched = CLValue::from_t((String::from("abc"), U512::from(7))).unwrap();
+        assert!(CLValue::into_t::<Record>(mismatched).is_err());
+    }
+
+    #[test]
+    fn enum_should_work() {
+        #[derive(PartialEq, Debug, Clone)]
+        enum Choice {
+            Nothing,
+            Something(Key),
+        }
+
+        impl CLTyped for Choice {
+            fn cl_type() -> CLType {
+                CLType::Enum {
+                    name: String::from("Choice"),
+                    variants: vec![
+                        CLVariant::new(0, "Nothing", vec![]),
+                        CLVariant::new(1, "Something", vec![CLField::new("0", CLType::Key)]),
+                    ],
+                }
+            }
+        }
+
+        impl ToBytes for Choice {
+            fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+                match self {
+                    Choice::Nothing => 0u8.to_bytes(),
+                    Choice::Something(key) => (1u8, *key).into_bytes(),
+                }
+            }
+        }
+
+        impl FromBytes for Choice {
+            fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+                let (tag, remainder) = u8::from_bytes(bytes)?;
+                match tag {
+                    0 => Ok((Choice::Nothing, remainder)),
+                    1 => {
+                        let (key, remainder) = Key::from_bytes(remainder)?;
+                        Ok((Choice::Something(key), remainder))
+                    }
+                    _ => Err(bytesrepr::Error::Formatting),
+                }
+            }
+        }
+
+        round_trip(&Choice::Nothing);
+        round_trip(&Choice::Something(Key::Hash([1; 32])));
+    }
+
     #[test]
     fn any_should_work() {
         #[derive(PartialEq, Debug, Clone)]
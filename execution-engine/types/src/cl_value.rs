@@ -50,7 +50,7 @@ impl CLValue {
     pub fn into_t<T: CLTyped + FromBytes>(self) -> Result<T, CLValueError> {
         let expected = T::cl_type();
 
-        if self.cl_type == expected {
+        if expected.accepts(&self.cl_type) {
             bytesrepr::deserialize(self.bytes).map_err(CLValueError::Serialization)
         } else {
             Err(CLValueError::Type(CLTypeMismatch {
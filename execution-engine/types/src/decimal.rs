@@ -0,0 +1,288 @@
+use alloc::vec::Vec;
+use core::{
+    fmt::{self, Debug, Display, Formatter},
+    str::FromStr,
+};
+
+use num_traits::AsPrimitive;
+
+use crate::{
+    bytesrepr::{Error, FromBytes, ToBytes},
+    int::{self, NumberParseError},
+    I256, U256, U512,
+};
+
+/// A signed fixed-point decimal number with [`Decimal::SCALE`] fractional digits.
+///
+/// A `Decimal` is held as an [`I256`] of its value multiplied by `10^SCALE`, and is serialized
+/// identically to that `I256`.  Arithmetic is checked, i.e. operations return `None` rather than
+/// wrapping on overflow, and results which can't be represented exactly are rounded towards zero.
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Decimal(I256);
+
+impl Decimal {
+    /// The number of fractional decimal digits.
+    pub const SCALE: u32 = 18;
+
+    /// The largest value which can be represented.
+    pub const MAX: Decimal = Decimal(I256::MAX);
+
+    /// The smallest value which can be represented.
+    pub const MIN: Decimal = Decimal(I256::MIN);
+
+    /// Constructs a `Decimal` from its raw value, i.e. its value multiplied by `10^SCALE`.
+    pub fn from_raw(raw: I256) -> Self {
+        Decimal(raw)
+    }
+
+    /// Returns the raw value of `self`, i.e. its value multiplied by `10^SCALE`.
+    pub fn raw(self) -> I256 {
+        self.0
+    }
+
+    /// Constructs a `Decimal` with the given integral value, returning `None` if it is out of
+    /// range.
+    pub fn from_integer(value: I256) -> Option<Self> {
+        value.checked_mul(Decimal::one().0).map(Decimal)
+    }
+
+    /// Returns zero.
+    pub fn zero() -> Self {
+        Decimal(I256::zero())
+    }
+
+    /// Returns one.
+    pub fn one() -> Self {
+        Decimal(I256::from(scale_factor()))
+    }
+
+    /// Returns `true` if `self` is less than zero.
+    pub fn is_negative(&self) -> bool {
+        self.0.is_negative()
+    }
+
+    /// Checked negation.  Returns `None` if `self` is [`Decimal::MIN`].
+    pub fn checked_neg(self) -> Option<Self> {
+        self.0.checked_neg().map(Decimal)
+    }
+
+    /// Checked addition.  Returns `None` if overflow occurred.
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.0.checked_add(other.0).map(Decimal)
+    }
+
+    /// Wrapping (two's complement) addition.
+    pub fn wrapping_add(self, other: Self) -> Self {
+        Decimal(self.0.wrapping_add(other.0))
+    }
+
+    /// Checked subtraction.  Returns `None` if overflow occurred.
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.0.checked_sub(other.0).map(Decimal)
+    }
+
+    /// Checked multiplication, rounding towards zero.  Returns `None` if overflow occurred.
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        let product = widen(self.0.unsigned_abs()) * widen(other.0.unsigned_abs());
+        let magnitude = narrow(product / widen(U256::from(scale_factor())))?;
+        I256::from_sign_and_magnitude(self.is_negative() != other.is_negative(), magnitude)
+            .map(Decimal)
+    }
+
+    /// Checked division, rounding towards zero.  Returns `None` if `other` is zero or if overflow
+    /// occurred.
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        if other.0.is_zero() {
+            return None;
+        }
+        let dividend = widen(self.0.unsigned_abs()) * widen(U256::from(scale_factor()));
+        let magnitude = narrow(dividend / widen(other.0.unsigned_abs()))?;
+        I256::from_sign_and_magnitude(self.is_negative() != other.is_negative(), magnitude)
+            .map(Decimal)
+    }
+}
+
+/// Returns `10^SCALE`.
+fn scale_factor() -> u64 {
+    10_u64.pow(Decimal::SCALE)
+}
+
+fn widen(value: U256) -> U512 {
+    value.as_()
+}
+
+fn narrow(value: U512) -> Option<U256> {
+    if value.0[4..].iter().any(|word| *word != 0) {
+        None
+    } else {
+        Some(value.as_())
+    }
+}
+
+impl Display for Decimal {
+    /// Formats `self` in decimal notation without trailing fractional zeros, e.g. `"-1.25"`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let magnitude = self.0.unsigned_abs();
+        let factor = U256::from(scale_factor());
+        if self.is_negative() {
+            write!(f, "-")?;
+        }
+        write!(f, "{}", magnitude / factor)?;
+
+        let mut fraction = (magnitude % factor).low_u64();
+        if fraction == 0 {
+            return Ok(());
+        }
+        let mut width = Decimal::SCALE as usize;
+        while fraction % 10 == 0 {
+            fraction /= 10;
+            width -= 1;
+        }
+        write!(f, ".{:0width$}", fraction, width = width)
+    }
+}
+
+impl Debug for Decimal {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl FromStr for Decimal {
+    type Err = NumberParseError;
+
+    /// Parses a decimal of the form `"-123.456"`, where the sign and fractional part are optional.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (is_negative, unsigned) = int::split_sign(value);
+        let (integer, fraction) = match unsigned.find('.') {
+            Some(index) => (&unsigned[..index], &unsigned[index + 1..]),
+            None => (unsigned, "0"),
+        };
+        if fraction.len() > Decimal::SCALE as usize {
+            return Err(NumberParseError::TooManyFractionalDigits);
+        }
+
+        let integer = int::parse_digits(integer)?;
+        let fraction = int::parse_digits(fraction)?
+            * U256::from(10_u64.pow(Decimal::SCALE - fraction.len() as u32));
+        let magnitude = integer
+            .checked_mul(U256::from(scale_factor()))
+            .and_then(|integer| integer.checked_add(fraction))
+            .ok_or(NumberParseError::OutOfRange)?;
+        I256::from_sign_and_magnitude(is_negative, magnitude)
+            .map(Decimal)
+            .ok_or(NumberParseError::OutOfRange)
+    }
+}
+
+impl ToBytes for Decimal {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        self.0.to_bytes()
+    }
+}
+
+impl FromBytes for Decimal {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let (raw, remainder) = I256::from_bytes(bytes)?;
+        Ok((Decimal(raw), remainder))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::string::ToString;
+
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::{bytesrepr, gens};
+
+    fn decimal(value: &str) -> Decimal {
+        value.parse().expect("should parse")
+    }
+
+    #[test]
+    fn should_format_without_trailing_zeros() {
+        assert_eq!(Decimal::zero().to_string(), "0");
+        assert_eq!(Decimal::one().to_string(), "1");
+        assert_eq!(decimal("-1.250").to_string(), "-1.25");
+        assert_eq!(
+            decimal("0.000000000000000001").to_string(),
+            "0.000000000000000001"
+        );
+        assert_eq!(decimal("-0.5").to_string(), "-0.5");
+        assert_eq!(
+            Decimal::from_integer(I256::from(-42)).map(|value| value.to_string()),
+            Some("-42".to_string())
+        );
+    }
+
+    #[test]
+    fn should_fail_to_parse_invalid_strings() {
+        assert_eq!("".parse::<Decimal>(), Err(NumberParseError::InvalidFormat));
+        assert_eq!(
+            "1.".parse::<Decimal>(),
+            Err(NumberParseError::InvalidFormat)
+        );
+        assert_eq!(
+            ".1".parse::<Decimal>(),
+            Err(NumberParseError::InvalidFormat)
+        );
+        assert_eq!(
+            "0.0000000000000000001".parse::<Decimal>(),
+            Err(NumberParseError::TooManyFractionalDigits)
+        );
+        assert_eq!(
+            "1.-1".parse::<Decimal>(),
+            Err(NumberParseError::FromDecStr(
+                uint::FromDecStrErr::InvalidCharacter
+            ))
+        );
+        let too_large = format!("{}", I256::MAX);
+        assert_eq!(
+            too_large.parse::<Decimal>(),
+            Err(NumberParseError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn checked_arithmetic_should_work() {
+        assert_eq!(
+            decimal("1.5").checked_add(decimal("-2.25")),
+            Some(decimal("-0.75"))
+        );
+        assert_eq!(
+            decimal("1.5").checked_sub(decimal("2.25")),
+            Some(decimal("-0.75"))
+        );
+        assert_eq!(
+            decimal("-1.5").checked_mul(decimal("0.5")),
+            Some(decimal("-0.75"))
+        );
+        assert_eq!(
+            decimal("1").checked_div(decimal("-3")),
+            Some(decimal("-0.333333333333333333"))
+        );
+        assert_eq!(decimal("2").checked_div(decimal("0.5")), Some(decimal("4")));
+        assert_eq!(decimal("1").checked_div(Decimal::zero()), None);
+        assert_eq!(
+            Decimal::MAX.checked_add(Decimal::from_raw(I256::one())),
+            None
+        );
+        assert_eq!(Decimal::MAX.checked_mul(decimal("2")), None);
+        assert_eq!(Decimal::MAX.checked_div(decimal("0.5")), None);
+        assert_eq!(Decimal::MIN.checked_mul(Decimal::one()), Some(Decimal::MIN));
+    }
+
+    proptest! {
+        #[test]
+        fn serialization_round_trip(value in gens::decimal_arb()) {
+            bytesrepr::test_serialization_roundtrip(&value);
+        }
+
+        #[test]
+        fn string_round_trip(value in gens::decimal_arb()) {
+            prop_assert_eq!(value.to_string().parse(), Ok(value));
+        }
+    }
+}
@@ -14,7 +14,9 @@ use proptest::{
 
 use crate::{
     account::{PublicKey, Weight},
-    AccessRights, CLType, CLValue, Key, Phase, ProtocolVersion, SemVer, URef, U128, U256, U512,
+    bytesrepr::ToBytes,
+    AccessRights, CLField, CLType, CLValue, CLVariant, Decimal, Key, NamedArg, Phase,
+    ProtocolVersion, SemVer, URef, I256, U128, U256, U512,
 };
 
 pub fn u8_slice_32() -> impl Strategy<Value = [u8; 32]> {
@@ -95,6 +97,16 @@ pub fn u512_arb() -> impl Strategy<Value = U512> {
     vec(any::<u8>(), 0..64).prop_map(|b| U512::from_little_endian(b.as_slice()))
 }
 
+pub fn i256_arb() -> impl Strategy<Value = I256> {
+    (any::<bool>(), u256_arb()).prop_filter_map("magnitude out of range", |(sign, magnitude)| {
+        I256::from_sign_and_magnitude(sign, magnitude)
+    })
+}
+
+pub fn decimal_arb() -> impl Strategy<Value = Decimal> {
+    i256_arb().prop_map(Decimal::from_raw)
+}
+
 pub fn cl_value_arb() -> impl Strategy<Value = CLValue> {
     // If compiler brings you here it most probably means you've added a variant to `CLType` enum
     // but forgot to add generator for it.
@@ -110,6 +122,8 @@ pub fn cl_value_arb() -> impl Strategy<Value = CLValue> {
             | CLType::U128
             | CLType::U256
             | CLType::U512
+            | CLType::I256
+            | CLType::Decimal
             | CLType::Unit
             | CLType::String
             | CLType::Key
@@ -117,12 +131,15 @@ pub fn cl_value_arb() -> impl Strategy<Value = CLValue> {
             | CLType::Option(_)
             | CLType::List(_)
             | CLType::FixedList(..)
+            | CLType::ByteArray(_)
             | CLType::Result { .. }
             | CLType::Map { .. }
             | CLType::Tuple1(_)
             | CLType::Tuple2(_)
             | CLType::Tuple3(_)
-            | CLType::Any => (),
+            | CLType::Any
+            | CLType::Struct { .. }
+            | CLType::Enum { .. } => (),
         }
     };
 
@@ -137,12 +154,15 @@ pub fn cl_value_arb() -> impl Strategy<Value = CLValue> {
         u128_arb().prop_map(|x| CLValue::from_t(x).expect("should create CLValue")),
         u256_arb().prop_map(|x| CLValue::from_t(x).expect("should create CLValue")),
         u512_arb().prop_map(|x| CLValue::from_t(x).expect("should create CLValue")),
+        i256_arb().prop_map(|x| CLValue::from_t(x).expect("should create CLValue")),
+        decimal_arb().prop_map(|x| CLValue::from_t(x).expect("should create CLValue")),
         key_arb().prop_map(|x| CLValue::from_t(x).expect("should create CLValue")),
         uref_arb().prop_map(|x| CLValue::from_t(x).expect("should create CLValue")),
         ".*".prop_map(|x: String| CLValue::from_t(x).expect("should create CLValue")),
         option::of(any::<u64>()).prop_map(|x| CLValue::from_t(x).expect("should create CLValue")),
         vec(uref_arb(), 0..100).prop_map(|x| CLValue::from_t(x).expect("should create CLValue")),
         [any::<u64>(); 32].prop_map(|x| CLValue::from_t(x).expect("should create CLValue")),
+        u8_slice_32().prop_map(|x| CLValue::from_t(x).expect("should create CLValue")),
         result::maybe_err(key_arb(), ".*")
             .prop_map(|x| CLValue::from_t(x).expect("should create CLValue")),
         btree_map(".*", u512_arb(), 0..100)
@@ -152,9 +172,36 @@ pub fn cl_value_arb() -> impl Strategy<Value = CLValue> {
             .prop_map(|x| CLValue::from_t(x).expect("should create CLValue")),
         (any::<bool>(), any::<i32>(), any::<i64>())
             .prop_map(|x| CLValue::from_t(x).expect("should create CLValue")),
+        (".*", any::<u64>()).prop_map(|x: (String, u64)| {
+            let cl_type = CLType::Struct {
+                name: String::from("Struct"),
+                fields: vec![
+                    CLField::new("name", CLType::String),
+                    CLField::new("value", CLType::U64),
+                ],
+            };
+            let bytes = x.into_bytes().expect("should serialize");
+            CLValue::from_components(cl_type, bytes)
+        }),
+        option::of(key_arb()).prop_map(|x| {
+            let cl_type = CLType::Enum {
+                name: String::from("Enum"),
+                variants: vec![
+                    CLVariant::new(0, "Empty", vec![]),
+                    CLVariant::new(1, "Full", vec![CLField::new("0", CLType::Key)]),
+                ],
+            };
+            // Encoded identically to an `Option`.
+            let bytes = x.into_bytes().expect("should serialize");
+            CLValue::from_components(cl_type, bytes)
+        }),
     ]
 }
 
+pub fn named_arg_arb() -> impl Strategy<Value = NamedArg> {
+    ("\\PC*", cl_value_arb()).prop_map(|(name, value)| NamedArg::new(name, value))
+}
+
 pub fn result_arb() -> impl Strategy<Value = Result<u32, u32>> {
     result::maybe_ok(any::<u32>(), any::<u32>())
 }
@@ -0,0 +1,401 @@
+use alloc::vec::Vec;
+use core::{
+    cmp::Ordering,
+    fmt::{self, Debug, Display, Formatter},
+    str::FromStr,
+};
+
+use crate::{
+    bytesrepr::{Error, FromBytes, ToBytes},
+    U256,
+};
+
+const SIGN_BIT: usize = 255;
+
+/// Error type for parsing an [`I256`] or a [`Decimal`](crate::Decimal) from a string.
+#[derive(Debug, PartialEq, Eq)]
+pub enum NumberParseError {
+    /// Contains the parsing error from the `uint` crate for the digits of the number.
+    FromDecStr(uint::FromDecStrErr),
+    /// The string was empty or otherwise not of the expected form, e.g. `"-"` or `"1."`.
+    InvalidFormat,
+    /// The number is outside the range of the type being parsed.
+    OutOfRange,
+    /// A decimal had more than [`Decimal::SCALE`](crate::Decimal::SCALE) fractional digits.
+    TooManyFractionalDigits,
+}
+
+/// A signed 256-bit integer.
+///
+/// Arithmetic is checked, i.e. operations return `None` rather than wrapping on overflow.
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct I256(U256);
+
+impl I256 {
+    /// The largest value which can be represented, i.e. `2^255 - 1`.
+    pub const MAX: I256 = I256(U256([
+        u64::max_value(),
+        u64::max_value(),
+        u64::max_value(),
+        i64::max_value() as u64,
+    ]));
+
+    /// The smallest value which can be represented, i.e. `-2^255`.
+    pub const MIN: I256 = I256(U256([0, 0, 0, 1 << 63]));
+
+    /// Returns zero.
+    pub fn zero() -> Self {
+        I256(U256::zero())
+    }
+
+    /// Returns one.
+    pub fn one() -> Self {
+        I256(U256::one())
+    }
+
+    /// Constructs an `I256` from its sign and magnitude, returning `None` if the result is out of
+    /// range.
+    pub fn from_sign_and_magnitude(is_negative: bool, magnitude: U256) -> Option<Self> {
+        if !is_negative {
+            if magnitude.bit(SIGN_BIT) {
+                None
+            } else {
+                Some(I256(magnitude))
+            }
+        } else if magnitude > I256::MIN.0 {
+            None
+        } else {
+            Some(I256(negate(magnitude)))
+        }
+    }
+
+    /// Returns `true` if `self` is zero.
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    /// Returns `true` if `self` is less than zero.
+    pub fn is_negative(&self) -> bool {
+        self.0.bit(SIGN_BIT)
+    }
+
+    /// Returns the absolute value of `self` as an unsigned integer, which can't overflow.
+    pub fn unsigned_abs(&self) -> U256 {
+        if self.is_negative() {
+            negate(self.0)
+        } else {
+            self.0
+        }
+    }
+
+    /// Checked negation.  Returns `None` if `self` is [`I256::MIN`].
+    pub fn checked_neg(self) -> Option<Self> {
+        if self == I256::MIN {
+            None
+        } else {
+            Some(I256(negate(self.0)))
+        }
+    }
+
+    /// Checked addition.  Returns `None` if overflow occurred.
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        let result = I256(self.0.overflowing_add(other.0).0);
+        if self.is_negative() == other.is_negative() && result.is_negative() != self.is_negative() {
+            None
+        } else {
+            Some(result)
+        }
+    }
+
+    /// Wrapping (two's complement) addition.
+    pub fn wrapping_add(self, other: Self) -> Self {
+        I256(self.0.overflowing_add(other.0).0)
+    }
+
+    /// Checked subtraction.  Returns `None` if overflow occurred.
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        let result = I256(self.0.overflowing_sub(other.0).0);
+        if self.is_negative() != other.is_negative() && result.is_negative() != self.is_negative() {
+            None
+        } else {
+            Some(result)
+        }
+    }
+
+    /// Checked multiplication.  Returns `None` if overflow occurred.
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        let magnitude = self.unsigned_abs().checked_mul(other.unsigned_abs())?;
+        I256::from_sign_and_magnitude(self.is_negative() != other.is_negative(), magnitude)
+    }
+
+    /// Checked division, rounding towards zero.  Returns `None` if `other` is zero or if overflow
+    /// occurred.
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        if other.is_zero() {
+            return None;
+        }
+        let magnitude = self.unsigned_abs() / other.unsigned_abs();
+        I256::from_sign_and_magnitude(self.is_negative() != other.is_negative(), magnitude)
+    }
+
+    /// Checked remainder, taking the sign of `self`.  Returns `None` if `other` is zero.
+    pub fn checked_rem(self, other: Self) -> Option<Self> {
+        if other.is_zero() {
+            return None;
+        }
+        let magnitude = self.unsigned_abs() % other.unsigned_abs();
+        I256::from_sign_and_magnitude(self.is_negative(), magnitude)
+    }
+
+    /// Maps `self` to an unsigned value via zigzag encoding, so that values of small magnitude map
+    /// to small unsigned values regardless of their sign.
+    fn to_zigzag(self) -> U256 {
+        if self.is_negative() {
+            (!self.0 << 1) | U256::one()
+        } else {
+            self.0 << 1
+        }
+    }
+
+    fn from_zigzag(value: U256) -> Self {
+        if value.bit(0) {
+            I256(!(value >> 1))
+        } else {
+            I256(value >> 1)
+        }
+    }
+}
+
+/// Returns the two's complement negation of `value`.
+fn negate(value: U256) -> U256 {
+    (!value).overflowing_add(U256::one()).0
+}
+
+/// Splits an optional leading `'-'` from `value`, returning whether it was present.
+pub(crate) fn split_sign(value: &str) -> (bool, &str) {
+    if value.starts_with('-') {
+        (true, &value[1..])
+    } else {
+        (false, value)
+    }
+}
+
+/// Parses a non-empty string of decimal digits.
+pub(crate) fn parse_digits(digits: &str) -> Result<U256, NumberParseError> {
+    if digits.is_empty() {
+        return Err(NumberParseError::InvalidFormat);
+    }
+    U256::from_dec_str(digits).map_err(NumberParseError::FromDecStr)
+}
+
+impl From<i64> for I256 {
+    fn from(value: i64) -> Self {
+        // Sign-extend into the upper words.
+        let fill = if value < 0 { u64::max_value() } else { 0 };
+        I256(U256([value as u64, fill, fill, fill]))
+    }
+}
+
+impl From<i32> for I256 {
+    fn from(value: i32) -> Self {
+        I256::from(i64::from(value))
+    }
+}
+
+impl From<u64> for I256 {
+    fn from(value: u64) -> Self {
+        I256(U256::from(value))
+    }
+}
+
+impl Ord for I256 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.is_negative(), other.is_negative()) {
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            // With equal signs, two's complement representations order the same as their values.
+            _ => self.0.cmp(&other.0),
+        }
+    }
+}
+
+impl PartialOrd for I256 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for I256 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if self.is_negative() {
+            write!(f, "-")?;
+        }
+        write!(f, "{}", self.unsigned_abs())
+    }
+}
+
+impl Debug for I256 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl FromStr for I256 {
+    type Err = NumberParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (is_negative, digits) = split_sign(value);
+        let magnitude = parse_digits(digits)?;
+        I256::from_sign_and_magnitude(is_negative, magnitude).ok_or(NumberParseError::OutOfRange)
+    }
+}
+
+impl ToBytes for I256 {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        self.to_zigzag().to_bytes()
+    }
+}
+
+impl FromBytes for I256 {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let (zigzag, remainder) = U256::from_bytes(bytes)?;
+        Ok((I256::from_zigzag(zigzag), remainder))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::string::ToString;
+
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::{bytesrepr, gens};
+
+    #[test]
+    fn should_convert_from_primitives() {
+        assert_eq!(I256::from(0_i64), I256::zero());
+        assert_eq!(I256::from(1_i32), I256::one());
+        assert_eq!(I256::from(-1_i64).checked_neg(), Some(I256::one()));
+        assert_eq!(
+            I256::from(i64::min_value()).to_string(),
+            "-9223372036854775808"
+        );
+        assert_eq!(
+            I256::from(u64::max_value()).to_string(),
+            "18446744073709551615"
+        );
+    }
+
+    #[test]
+    fn checked_arithmetic_should_detect_overflow() {
+        let one = I256::one();
+        let minus_one = I256::from(-1);
+
+        assert_eq!(I256::MAX.checked_add(one), None);
+        assert_eq!(I256::MIN.checked_add(minus_one), None);
+        assert_eq!(I256::MIN.checked_sub(one), None);
+        assert_eq!(I256::MAX.checked_sub(minus_one), None);
+        assert_eq!(I256::MIN.checked_neg(), None);
+        assert_eq!(I256::MIN.checked_mul(minus_one), None);
+        assert_eq!(I256::MIN.checked_div(minus_one), None);
+        assert_eq!(one.checked_div(I256::zero()), None);
+        assert_eq!(one.checked_rem(I256::zero()), None);
+
+        assert_eq!(
+            I256::MAX
+                .checked_add(minus_one)
+                .and_then(|x| x.checked_add(one)),
+            Some(I256::MAX)
+        );
+        assert_eq!(
+            I256::MIN
+                .checked_sub(minus_one)
+                .and_then(|x| x.checked_sub(one)),
+            Some(I256::MIN)
+        );
+        assert_eq!(
+            I256::MAX.checked_neg().and_then(|x| x.checked_sub(one)),
+            Some(I256::MIN)
+        );
+    }
+
+    #[test]
+    fn wrapping_add_should_wrap_around() {
+        let one = I256::one();
+        let minus_one = I256::from(-1);
+
+        assert_eq!(I256::MAX.wrapping_add(one), I256::MIN);
+        assert_eq!(I256::MIN.wrapping_add(minus_one), I256::MAX);
+        assert_eq!(I256::from(-5).wrapping_add(one), I256::from(-4));
+    }
+
+    #[test]
+    fn checked_arithmetic_should_follow_sign_rules() {
+        let seven = I256::from(7);
+        let minus_seven = I256::from(-7);
+        let two = I256::from(2);
+        let minus_two = I256::from(-2);
+
+        assert_eq!(seven.checked_add(minus_two), Some(I256::from(5)));
+        assert_eq!(minus_seven.checked_sub(minus_two), Some(I256::from(-5)));
+        assert_eq!(minus_seven.checked_mul(minus_two), Some(I256::from(14)));
+        assert_eq!(seven.checked_mul(minus_two), Some(I256::from(-14)));
+        assert_eq!(minus_seven.checked_div(two), Some(I256::from(-3)));
+        assert_eq!(seven.checked_div(minus_two), Some(I256::from(-3)));
+        assert_eq!(minus_seven.checked_rem(two), Some(I256::from(-1)));
+        assert_eq!(seven.checked_rem(minus_two), Some(I256::one()));
+    }
+
+    #[test]
+    fn should_order_by_value() {
+        assert!(I256::MIN < I256::from(-1));
+        assert!(I256::from(-2) < I256::from(-1));
+        assert!(I256::from(-1) < I256::zero());
+        assert!(I256::zero() < I256::one());
+        assert!(I256::one() < I256::MAX);
+    }
+
+    #[test]
+    fn should_fail_to_parse_invalid_strings() {
+        assert_eq!("".parse::<I256>(), Err(NumberParseError::InvalidFormat));
+        assert_eq!("-".parse::<I256>(), Err(NumberParseError::InvalidFormat));
+        assert_eq!(
+            "1a".parse::<I256>(),
+            Err(NumberParseError::FromDecStr(
+                uint::FromDecStrErr::InvalidCharacter
+            ))
+        );
+        let too_large = format!("{}", I256::MAX.unsigned_abs() + U256::one());
+        assert_eq!(too_large.parse::<I256>(), Err(NumberParseError::OutOfRange));
+        assert_eq!(format!("-{}", too_large).parse::<I256>(), Ok(I256::MIN));
+    }
+
+    #[test]
+    fn should_serialize_small_magnitudes_compactly() {
+        assert_eq!(I256::zero().to_bytes(), Ok(vec![0]));
+        assert_eq!(I256::from(-1).to_bytes(), Ok(vec![1, 1]));
+        assert_eq!(I256::one().to_bytes(), Ok(vec![1, 2]));
+        assert_eq!(I256::MIN.to_bytes().map(|bytes| bytes.len()), Ok(33));
+    }
+
+    proptest! {
+        #[test]
+        fn serialization_round_trip(value in gens::i256_arb()) {
+            bytesrepr::test_serialization_roundtrip(&value);
+        }
+
+        #[test]
+        fn string_round_trip(value in gens::i256_arb()) {
+            prop_assert_eq!(value.to_string().parse(), Ok(value));
+        }
+
+        #[test]
+        fn add_then_sub_round_trip(a in gens::i256_arb(), b in gens::i256_arb()) {
+            if let Some(sum) = a.checked_add(b) {
+                prop_assert_eq!(sum.checked_sub(b), Some(a));
+                prop_assert_eq!(a.wrapping_add(b), sum);
+            }
+        }
+    }
+}
@@ -18,6 +18,10 @@ const HASH_ID: u8 = 1;
 const UREF_ID: u8 = 2;
 const LOCAL_ID: u8 = 3;
 
+const ACCOUNT_FORMATTED_STRING_PREFIX: &str = "account-ed25519-";
+const HASH_FORMATTED_STRING_PREFIX: &str = "hash-";
+const LOCAL_FORMATTED_STRING_PREFIX: &str = "local-";
+
 /// The number of bytes in a Blake2b hash
 pub const BLAKE2B_DIGEST_LENGTH: usize = 32;
 /// The upper bound of bytes in a serialized [`Key::Account`].
@@ -135,12 +139,60 @@ impl Key {
     /// Returns a human-readable version of `self`, with the inner bytes encoded to Base16.
     pub fn as_string(&self) -> String {
         match self {
-            Key::Account(PublicKey::Ed25519(addr)) => {
-                format!("account-ed25519-{}", base16::encode_lower(&addr.value()))
-            }
-            Key::Hash(addr) => format!("hash-{}", base16::encode_lower(addr)),
+            Key::Account(PublicKey::Ed25519(addr)) => format!(
+                "{}{}",
+                ACCOUNT_FORMATTED_STRING_PREFIX,
+                base16::encode_lower(&addr.value())
+            ),
+            Key::Hash(addr) => format!(
+                "{}{}",
+                HASH_FORMATTED_STRING_PREFIX,
+                base16::encode_lower(addr)
+            ),
             Key::URef(uref) => uref.as_string(),
-            Key::Local { hash, .. } => format!("local-{}", base16::encode_lower(hash)),
+            Key::Local { hash, .. } => format!(
+                "{}{}",
+                LOCAL_FORMATTED_STRING_PREFIX,
+                base16::encode_lower(hash)
+            ),
+        }
+    }
+
+    /// Returns a human-readable version of `self` which can be parsed by
+    /// [`Key::from_formatted_str`].
+    ///
+    /// This only differs from [`Key::as_string`] for [`Key::Local`], where the seed is included as
+    /// `local-<seed>-<hash>`.
+    pub fn to_formatted_string(&self) -> String {
+        match self {
+            Key::Local { seed, hash } => format!(
+                "{}{}-{}",
+                LOCAL_FORMATTED_STRING_PREFIX,
+                base16::encode_lower(seed),
+                base16::encode_lower(hash)
+            ),
+            _ => self.as_string(),
+        }
+    }
+
+    /// Parses a `Key` from the format produced by [`Key::to_formatted_string`].
+    ///
+    /// Returns `None` if `input` doesn't start with one of the prefixes `account-ed25519-`,
+    /// `hash-`, `uref-` or `local-`, or if the remainder isn't correctly formatted.
+    pub fn from_formatted_str(input: &str) -> Option<Key> {
+        if input.starts_with(ACCOUNT_FORMATTED_STRING_PREFIX) {
+            let addr = decode_hex_only(&input[ACCOUNT_FORMATTED_STRING_PREFIX.len()..])?;
+            Some(Key::Account(PublicKey::ed25519_from(addr)))
+        } else if input.starts_with(HASH_FORMATTED_STRING_PREFIX) {
+            decode_hex_only(&input[HASH_FORMATTED_STRING_PREFIX.len()..]).map(Key::Hash)
+        } else if input.starts_with(LOCAL_FORMATTED_STRING_PREFIX) {
+            let remainder = &input[LOCAL_FORMATTED_STRING_PREFIX.len()..];
+            let mut parts = remainder.splitn(2, '-');
+            let seed = decode_hex_only(parts.next()?)?;
+            let hash = decode_hex_only(parts.next()?)?;
+            Some(Key::Local { seed, hash })
+        } else {
+            URef::from_formatted_str(input).map(Key::URef)
         }
     }
 
@@ -231,6 +283,14 @@ fn drop_hex_prefix(s: &str) -> &str {
     }
 }
 
+/// Like [`decode_from_hex`], but doesn't accept a "0x" prefix.
+fn decode_hex_only(input: &str) -> Option<[u8; KEY_HASH_LENGTH]> {
+    if input.starts_with("0x") {
+        return None;
+    }
+    decode_from_hex(input)
+}
+
 /// Tries to decode `input` as a 32-byte array.  `input` may be prefixed with "0x".  Returns `None`
 /// if `input` cannot be parsed as hex, or if it does not parse to exactly 32 bytes.
 fn decode_from_hex(input: &str) -> Option<[u8; KEY_HASH_LENGTH]> {
@@ -455,6 +515,11 @@ mod tests {
 
     proptest! {
 
+        #[test]
+        fn formatted_string_round_trip(key in crate::gens::key_arb()) {
+            assert_eq!(Key::from_formatted_str(&key.to_formatted_string()), Some(key));
+        }
+
         #[test]
         fn should_fail_parse_small_base16_to_key(base16_addr in base16_str_arb(32)) {
             assert!(Key::parse_hash(&base16_addr).is_none());
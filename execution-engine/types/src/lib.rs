@@ -4,6 +4,11 @@
 //!
 //! By default, the library is `no_std`, however you can enable full `std` functionality by enabling
 //! the crate's `std` feature.
+//!
+//! # Derive macros
+//!
+//! Enabling the crate's `derive` feature provides derive macros for [`bytesrepr::ToBytes`],
+//! [`bytesrepr::FromBytes`] and [`CLTyped`], exported alongside the traits themselves.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![feature(specialization, try_reserve)]
@@ -28,9 +33,12 @@ pub mod bytesrepr;
 mod cl_type;
 mod cl_value;
 mod contract_ref;
+mod decimal;
 #[cfg(any(feature = "gens", test))]
 pub mod gens;
+mod int;
 mod key;
+mod named_arg;
 mod phase;
 mod protocol_version;
 mod semver;
@@ -45,14 +53,19 @@ pub use access_rights::{AccessRights, ACCESS_RIGHTS_SERIALIZED_LENGTH};
 #[doc(inline)]
 pub use api_error::ApiError;
 pub use block_time::{BlockTime, BLOCKTIME_SERIALIZED_LENGTH};
-pub use cl_type::{named_key_type, CLType, CLTyped};
+pub use cl_type::{named_key_type, CLField, CLType, CLTyped, CLVariant};
+#[cfg(feature = "derive")]
+pub use casperlabs_types_derive::CLTyped;
 pub use cl_value::{CLTypeMismatch, CLValue, CLValueError};
 pub use contract_ref::ContractRef;
+pub use decimal::Decimal;
 #[doc(inline)]
 pub use key::{
     Key, BLAKE2B_DIGEST_LENGTH, KEY_HASH_LENGTH, KEY_LOCAL_LENGTH, KEY_LOCAL_SEED_LENGTH,
     KEY_UREF_SERIALIZED_LENGTH,
 };
+pub use int::{NumberParseError, I256};
+pub use named_arg::NamedArg;
 pub use phase::{Phase, PHASE_SERIALIZED_LENGTH};
 pub use protocol_version::{ProtocolVersion, VersionCheckResult};
 pub use semver::SemVer;
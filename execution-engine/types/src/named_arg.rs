@@ -0,0 +1,65 @@
+use alloc::{string::String, vec::Vec};
+
+use crate::{
+    bytesrepr::{Error, FromBytes, ToBytes},
+    CLValue,
+};
+
+/// A runtime argument identified by name rather than by its position in the argument list.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct NamedArg {
+    name: String,
+    value: CLValue,
+}
+
+impl NamedArg {
+    /// Constructs a new `NamedArg`.
+    pub fn new(name: String, value: CLValue) -> Self {
+        NamedArg { name, value }
+    }
+
+    /// Returns the name of the argument.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the value of the argument.
+    pub fn cl_value(&self) -> &CLValue {
+        &self.value
+    }
+
+    /// Consumes `self`, returning its name and value.
+    pub fn destructure(self) -> (String, CLValue) {
+        (self.name, self.value)
+    }
+}
+
+impl ToBytes for NamedArg {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut result = self.name.to_bytes()?;
+        result.append(&mut self.value.to_bytes()?);
+        Ok(result)
+    }
+}
+
+impl FromBytes for NamedArg {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let (name, remainder) = String::from_bytes(bytes)?;
+        let (value, remainder) = CLValue::from_bytes(remainder)?;
+        Ok((NamedArg::new(name, value), remainder))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use crate::{bytesrepr, gens};
+
+    proptest! {
+        #[test]
+        fn serialization_round_trip(named_arg in gens::named_arg_arb()) {
+            bytesrepr::test_serialization_roundtrip(&named_arg);
+        }
+    }
+}
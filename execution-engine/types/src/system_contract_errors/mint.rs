@@ -38,6 +38,12 @@ pub enum Error {
     /// Purse not found while trying to get balance.
     #[fail(display = "Purse not found")]
     PurseNotFound = 7,
+    /// The given URef is not the mint authority, or lacks the rights to use it.
+    #[fail(display = "Invalid mint authority")]
+    InvalidAuthority = 8,
+    /// Minting or burning tokens would overflow or underflow the total supply.
+    #[fail(display = "Supply overflow")]
+    SupplyOverflow = 9,
 }
 
 impl From<PurseError> for Error {
@@ -79,6 +85,8 @@ impl TryFrom<u8> for Error {
             d if d == Error::InvalidNonEmptyPurseCreation as u8 => {
                 Ok(Error::InvalidNonEmptyPurseCreation)
             }
+            d if d == Error::InvalidAuthority as u8 => Ok(Error::InvalidAuthority),
+            d if d == Error::SupplyOverflow as u8 => Ok(Error::SupplyOverflow),
             _ => Err(TryFromU8ForError(())),
         }
     }
@@ -74,6 +74,39 @@ pub enum Error {
     /// PoS contract's "set_refund_purse" method can only be called by the payment code of a
     /// deploy, but was called by the session code.
     SetRefundPurseCalledOutsidePayment,
+    /// Internal error: rewards were already distributed for the given era or a later one.
+    EraAlreadyRewarded,
+    /// Internal error: an inflation or commission rate exceeded one million millionths.
+    InvalidRewardRate,
+    /// Internal error: the PoS contract's mint authority wasn't found.
+    MintAuthorityNotFound,
+    /// Internal error: failed to mint an era's inflation into the PoS contract's rewards purse.
+    FailedToMintRewards,
+    /// Internal error: couldn't retrieve the balance for the PoS contract's rewards purse.
+    RewardsPurseBalanceNotFound,
+    /// Internal error: while distributing rewards, failed to pay a validator (the transfer from
+    /// the PoS contract's rewards purse to the validator's account or the bonding purse failed).
+    FailedTransferFromRewardsPurse,
+    /// The given account has not delegated any stake to the given validator.
+    NotDelegated,
+    /// The given validator has been jailed and may not bond again before their sentence ends.
+    ValidatorJailed,
+    /// Internal error: a slashing rate exceeded one million millionths.
+    InvalidSlashRate,
+    /// Internal error: the PoS contract's treasury purse wasn't found.
+    TreasuryPurseNotFound,
+    /// Internal error: the PoS contract's treasury purse key was the wrong type.
+    TreasuryPurseKeyUnexpectedType,
+    /// Internal error: while slashing, failed to move the slashed motes from the PoS contract's
+    /// bonding purse to its treasury purse.
+    FailedTransferToTreasuryPurse,
+    /// Internal error: one of the PoS contract's state urefs (e.g. its queues or delegations)
+    /// wasn't found.
+    StateKeyNotFound,
+    /// Internal error: one of the PoS contract's state keys was the wrong type.
+    StateKeyUnexpectedType,
+    /// Internal error: failed to serialize or deserialize one of the PoS contract's state values.
+    StateSerializationFailed,
 }
 
 impl CLTyped for Error {
@@ -122,4 +155,11 @@ impl PurseLookupError {
             PurseLookupError::KeyUnexpectedType => Error::RewardsPurseKeyUnexpectedType,
         }
     }
+
+    pub fn treasury(err: PurseLookupError) -> Error {
+        match err {
+            PurseLookupError::KeyNotFound => Error::TreasuryPurseNotFound,
+            PurseLookupError::KeyUnexpectedType => Error::TreasuryPurseKeyUnexpectedType,
+        }
+    }
 }
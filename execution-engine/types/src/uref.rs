@@ -18,6 +18,8 @@ pub const UREF_ADDR_LENGTH: usize = 32;
 pub const UREF_SERIALIZED_LENGTH: usize =
     UREF_ADDR_LENGTH + OPTION_TAG_SERIALIZED_LENGTH + ACCESS_RIGHTS_SERIALIZED_LENGTH;
 
+const UREF_FORMATTED_STRING_PREFIX: &str = "uref-";
+
 /// Represents an unforgeable reference, containing an address in the network's global storage and
 /// the [`AccessRights`] of the reference.
 ///
@@ -113,11 +115,39 @@ impl URef {
         // Access rights is represented as octal, which means that max value of u8 can
         // be represented as maximum of 3 octal digits.
         format!(
-            "uref-{}-{:03o}",
+            "{}{}-{:03o}",
+            UREF_FORMATTED_STRING_PREFIX,
             base16::encode_lower(&self.addr()),
             access_rights_bits
         )
     }
+
+    /// Parses a `URef` from the format produced by [`URef::as_string`].
+    ///
+    /// Returns `None` if `input` is not of the form `uref-<64 hex digits>-<3 octal digits>`, or if
+    /// the octal digits don't represent valid access rights.
+    pub fn from_formatted_str(input: &str) -> Option<URef> {
+        if !input.starts_with(UREF_FORMATTED_STRING_PREFIX) {
+            return None;
+        }
+        let remainder = &input[UREF_FORMATTED_STRING_PREFIX.len()..];
+        let (addr, access_rights) = remainder.split_at(remainder.rfind('-')?);
+        let addr_bytes = base16::decode(addr).ok()?;
+        if addr_bytes.len() != UREF_ADDR_LENGTH {
+            return None;
+        }
+        let mut address = [0u8; UREF_ADDR_LENGTH];
+        address.copy_from_slice(&addr_bytes);
+        let access_rights_digits = &access_rights[1..];
+        if access_rights_digits.len() != 3 {
+            return None;
+        }
+        let access_rights_bits = u8::from_str_radix(access_rights_digits, 8).ok()?;
+        if access_rights_bits == 0 {
+            return Some(URef(address, None));
+        }
+        AccessRights::from_bits(access_rights_bits).map(|rights| URef::new(address, rights))
+    }
 }
 
 impl Display for URef {
@@ -225,4 +255,19 @@ mod tests {
             "uref-0000000000000000000000000000000000000000000000000000000000000000-000"
         );
     }
+
+    #[test]
+    fn uref_from_formatted_str() {
+        let uref = URef::new([7u8; 32], AccessRights::READ_ADD_WRITE);
+        assert_eq!(URef::from_formatted_str(&uref.as_string()), Some(uref));
+
+        let uref = uref.remove_access_rights();
+        assert_eq!(URef::from_formatted_str(&uref.as_string()), Some(uref));
+
+        let addr = "07".repeat(32);
+        assert!(URef::from_formatted_str(&format!("uref-{}-010", addr)).is_none());
+        assert!(URef::from_formatted_str(&format!("uref-{}-07", addr)).is_none());
+        assert!(URef::from_formatted_str(&format!("uref-{}07-007", addr)).is_none());
+        assert!(URef::from_formatted_str(&format!("hash-{}-007", addr)).is_none());
+    }
 }
@@ -37,14 +37,27 @@ import io.netty.handler.ssl.ApplicationProtocolConfig.Protocol
       protocolVersion: ProtocolVersion
   ): F[Either[Throwable, UpgradeResult]]
 
-  /** Executes a sequence of deploys, returning the results in the same order as the inputs. */
+  /** Executes a sequence of deploys, returning the results in the same order as the inputs.
+    * `rank` is the main rank of the block, against which the EE checks the protocol version.
+    */
   def exec(
       prestate: ByteString,
       blocktime: Long,
+      rank: Long,
       deploys: Seq[DeployItem],
       protocolVersion: ProtocolVersion
   ): F[Either[Throwable, Seq[DeployResult]]]
 
+  /** Runs the scheduled calls which are due at `blocktime`, returning their results in the order
+    * their effects have to be committed. A block runs them once, ahead of its deploys.
+    */
+  def execScheduledCalls(
+      prestate: ByteString,
+      blocktime: Long,
+      rank: Long,
+      protocolVersion: ProtocolVersion
+  ): F[Either[Throwable, Seq[DeployResult]]]
+
   def commit(
       prestate: ByteString,
       effects: Seq[TransformEntry],
@@ -98,7 +111,7 @@ class GrpcExecutionEngineService[F[_]: Defer: Concurrent: Log: TaskLift: Metrics
   ): F[Either[SmartContractEngineError, Seq[DeployResult]]] =
     sendMessage(request, _.execute) {
       _.result match {
-        case ExecuteResponse.Result.Success(ExecResult(deployResults)) =>
+        case ExecuteResponse.Result.Success(ExecResult(deployResults, _)) =>
           Right(deployResults) //TODO: Capture errors better than just as a string
         case ExecuteResponse.Result.Empty =>
           Left(new SmartContractEngineError("empty response"))
@@ -129,11 +142,18 @@ class GrpcExecutionEngineService[F[_]: Defer: Concurrent: Log: TaskLift: Metrics
   override def exec(
       prestate: ByteString,
       blocktime: Long,
+      rank: Long,
       deploys: Seq[DeployItem],
       protocolVersion: ProtocolVersion
   ): F[Either[Throwable, Seq[DeployResult]]] = Metrics[F].timer("eeExec") {
     val baseExecRequest =
-      ExecuteRequest(prestate, blocktime, protocolVersion = Some(protocolVersion))
+      ExecuteRequest(
+        prestate,
+        blocktime,
+        protocolVersion = Some(protocolVersion),
+        rank = rank,
+        hasRank = true
+      )
     // Build batches limited by the size of message sent to EE, targeting the level of
     // parallelism the EE is supposed to be configured with.
     val batches =
@@ -147,6 +167,36 @@ class GrpcExecutionEngineService[F[_]: Defer: Concurrent: Log: TaskLift: Metrics
     } yield result
   }
 
+  override def execScheduledCalls(
+      prestate: ByteString,
+      blocktime: Long,
+      rank: Long,
+      protocolVersion: ProtocolVersion
+  ): F[Either[Throwable, Seq[DeployResult]]] = Metrics[F].timer("eeExecScheduledCalls") {
+    val request = ExecuteRequest(
+      prestate,
+      blocktime,
+      protocolVersion = Some(protocolVersion),
+      rank = rank,
+      runScheduledCalls = true,
+      hasRank = true
+    )
+    sendMessage(request, _.execute) {
+      _.result match {
+        case ExecuteResponse.Result.Success(ExecResult(_, scheduledCallResults)) =>
+          Right(scheduledCallResults)
+        case ExecuteResponse.Result.Empty =>
+          Left(new SmartContractEngineError("empty response"))
+        case ExecuteResponse.Result.MissingParent(RootNotFound(missing)) =>
+          Left(
+            new SmartContractEngineError(
+              s"Missing states: ${Base16.encode(missing.toByteArray)}"
+            )
+          )
+      }
+    }
+  }
+
   override def runGenesis(
       genesisConfig: GenesisConfig
   ): F[Either[Throwable, GenesisResult]] =
@@ -86,6 +86,16 @@ object FromBytes {
         }
     }
 
+  // Signed 256-bit integers are zigzag encoded as an unsigned `bigInt`, mapping
+  // 0, -1, 1, -2, ... to 0, 1, 2, 3, ...
+  val signedBigInt: Deserializer[BigInt] =
+    bigInt.map(zigzag => if (zigzag.testBit(0)) -(zigzag >> 1) - 1 else zigzag >> 1)
+
+  // Decimals are held as a `signedBigInt` of their value times 10^DECIMAL_SCALE.
+  val DECIMAL_SCALE = 18
+
+  val decimal: Deserializer[BigDecimal] = signedBigInt.map(raw => BigDecimal(raw, DECIMAL_SCALE))
+
   val unit: Deserializer[Unit] = id
 
   val bytes: Deserializer[Array[Byte]] =
@@ -17,6 +17,8 @@ object CLType {
   case object U128                                      extends CLType
   case object U256                                      extends CLType
   case object U512                                      extends CLType
+  case object I256                                      extends CLType
+  case object Decimal                                   extends CLType
   case object Unit                                      extends CLType
   case object String                                    extends CLType
   case object Key                                       extends CLType
@@ -24,6 +26,7 @@ object CLType {
   case class Option(t: CLType)                          extends CLType
   case class List(t: CLType)                            extends CLType
   case class FixedList(t: CLType, length: Int)          extends CLType
+  case class ByteArray(length: Int)                     extends CLType
   case class Result(ok: CLType, err: CLType)            extends CLType
   case class Map(key: CLType, value: CLType)            extends CLType
   case class Tuple1(t: CLType)                          extends CLType
@@ -31,40 +34,70 @@ object CLType {
   case class Tuple3(t1: CLType, t2: CLType, t3: CLType) extends CLType
   case object Any                                       extends CLType
 
+  case class Struct(name: java.lang.String, fields: Seq[Field])   extends CLType
+  case class Enum(name: java.lang.String, variants: Seq[Variant]) extends CLType
+
+  /** A named field of a `Struct` or of a `Variant`. Fields of tuple structs and tuple variants
+    * are named by their index, e.g. "0".
+    */
+  case class Field(name: java.lang.String, clType: CLType)
+
+  /** A variant of an `Enum`, identified in serialized values by its `tag`. */
+  case class Variant(tag: Byte, name: java.lang.String, fields: Seq[Field])
+
   // Type representing the list of things that need to be appended to the
-  // serialized CLType (see `toBytesTailRec` below). The `Left` case represents the
-  // length of a `FixedList`, while the `Right` case represents the inner type of
-  // something like `List`, `Tuple2`, etc.
-  private type LoopState = immutable.List[Either[Int, CLType]]
+  // serialized CLType (see `toBytesTailRec` below). The `Left` case represents
+  // bytes to append as they are (e.g. the length of a `FixedList` or the name of a
+  // `Struct`), while the `Right` case represents the inner type of something like
+  // `List`, `Tuple2`, etc.
+  private type LoopState = immutable.List[Either[Array[Byte], CLType]]
+
+  private def fieldsState(fields: Seq[Field]): LoopState =
+    fields.toList.flatMap(field => Left(ToBytes.toBytes(field.name)) :: Right(field.clType) :: Nil)
+
+  private def variantsState(variants: Seq[Variant]): LoopState =
+    variants.toList.flatMap { variant =>
+      val header =
+        variant.tag +: (ToBytes.toBytes(variant.name) ++ ToBytes.toBytes(variant.fields.size))
+      Left(header) :: fieldsState(variant.fields)
+    }
 
   @tailrec
   private def toBytesTailRec(state: LoopState, acc: IndexedSeq[Byte]): Array[Byte] = state match {
     case Nil => acc.toArray
 
-    case Left(n) :: tail => toBytesTailRec(tail, acc ++ ToBytes.toBytes(n))
+    case Left(bytes) :: tail => toBytesTailRec(tail, acc ++ bytes)
 
     case Right(t) :: tail =>
       t match {
-        case Bool   => toBytesTailRec(tail, acc :+ CL_TYPE_TAG_BOOL)
-        case I32    => toBytesTailRec(tail, acc :+ CL_TYPE_TAG_I32)
-        case I64    => toBytesTailRec(tail, acc :+ CL_TYPE_TAG_I64)
-        case U8     => toBytesTailRec(tail, acc :+ CL_TYPE_TAG_U8)
-        case U32    => toBytesTailRec(tail, acc :+ CL_TYPE_TAG_U32)
-        case U64    => toBytesTailRec(tail, acc :+ CL_TYPE_TAG_U64)
-        case U128   => toBytesTailRec(tail, acc :+ CL_TYPE_TAG_U128)
-        case U256   => toBytesTailRec(tail, acc :+ CL_TYPE_TAG_U256)
-        case U512   => toBytesTailRec(tail, acc :+ CL_TYPE_TAG_U512)
-        case Unit   => toBytesTailRec(tail, acc :+ CL_TYPE_TAG_UNIT)
-        case String => toBytesTailRec(tail, acc :+ CL_TYPE_TAG_STRING)
-        case Key    => toBytesTailRec(tail, acc :+ CL_TYPE_TAG_KEY)
-        case URef   => toBytesTailRec(tail, acc :+ CL_TYPE_TAG_UREF)
+        case Bool    => toBytesTailRec(tail, acc :+ CL_TYPE_TAG_BOOL)
+        case I32     => toBytesTailRec(tail, acc :+ CL_TYPE_TAG_I32)
+        case I64     => toBytesTailRec(tail, acc :+ CL_TYPE_TAG_I64)
+        case U8      => toBytesTailRec(tail, acc :+ CL_TYPE_TAG_U8)
+        case U32     => toBytesTailRec(tail, acc :+ CL_TYPE_TAG_U32)
+        case U64     => toBytesTailRec(tail, acc :+ CL_TYPE_TAG_U64)
+        case U128    => toBytesTailRec(tail, acc :+ CL_TYPE_TAG_U128)
+        case U256    => toBytesTailRec(tail, acc :+ CL_TYPE_TAG_U256)
+        case U512    => toBytesTailRec(tail, acc :+ CL_TYPE_TAG_U512)
+        case I256    => toBytesTailRec(tail, acc :+ CL_TYPE_TAG_I256)
+        case Decimal => toBytesTailRec(tail, acc :+ CL_TYPE_TAG_DECIMAL)
+        case Unit    => toBytesTailRec(tail, acc :+ CL_TYPE_TAG_UNIT)
+        case String  => toBytesTailRec(tail, acc :+ CL_TYPE_TAG_STRING)
+        case Key     => toBytesTailRec(tail, acc :+ CL_TYPE_TAG_KEY)
+        case URef    => toBytesTailRec(tail, acc :+ CL_TYPE_TAG_UREF)
 
         case Option(inner) => toBytesTailRec(Right(inner) :: tail, acc :+ CL_TYPE_TAG_OPTION)
 
         case List(inner) => toBytesTailRec(Right(inner) :: tail, acc :+ CL_TYPE_TAG_LIST)
 
         case FixedList(inner, n) =>
-          toBytesTailRec(Right(inner) :: Left(n) :: tail, acc :+ CL_TYPE_TAG_FIXED_LIST)
+          toBytesTailRec(
+            Right(inner) :: Left(ToBytes.toBytes(n)) :: tail,
+            acc :+ CL_TYPE_TAG_FIXED_LIST
+          )
+
+        case ByteArray(n) =>
+          toBytesTailRec(tail, (acc :+ CL_TYPE_TAG_BYTE_ARRAY) ++ ToBytes.toBytes(n))
 
         case Result(ok, err) =>
           toBytesTailRec(Right(ok) :: Right(err) :: tail, acc :+ CL_TYPE_TAG_RESULT)
@@ -81,6 +114,14 @@ object CLType {
           toBytesTailRec(Right(t1) :: Right(t2) :: Right(t3) :: tail, acc :+ CL_TYPE_TAG_TUPLE3)
 
         case Any => toBytesTailRec(tail, acc :+ CL_TYPE_TAG_ANY)
+
+        case Struct(name, fields) =>
+          val header = ToBytes.toBytes(name) ++ ToBytes.toBytes(fields.size)
+          toBytesTailRec(Left(header) :: fieldsState(fields) ::: tail, acc :+ CL_TYPE_TAG_STRUCT)
+
+        case Enum(name, variants) =>
+          val header = ToBytes.toBytes(name) ++ ToBytes.toBytes(variants.size)
+          toBytesTailRec(Left(header) :: variantsState(variants) ::: tail, acc :+ CL_TYPE_TAG_ENUM)
       }
   }
 
@@ -90,21 +131,23 @@ object CLType {
 
   val deserializer: FromBytes.Deserializer[CLType] =
     FromBytes.byte.flatMap {
-      case tag if tag == CL_TYPE_TAG_BOOL   => FromBytes.pure(Bool)
-      case tag if tag == CL_TYPE_TAG_I32    => FromBytes.pure(I32)
-      case tag if tag == CL_TYPE_TAG_I64    => FromBytes.pure(I64)
-      case tag if tag == CL_TYPE_TAG_U8     => FromBytes.pure(U8)
-      case tag if tag == CL_TYPE_TAG_U32    => FromBytes.pure(U32)
-      case tag if tag == CL_TYPE_TAG_U64    => FromBytes.pure(U64)
-      case tag if tag == CL_TYPE_TAG_U128   => FromBytes.pure(U128)
-      case tag if tag == CL_TYPE_TAG_U256   => FromBytes.pure(U256)
-      case tag if tag == CL_TYPE_TAG_U512   => FromBytes.pure(U512)
-      case tag if tag == CL_TYPE_TAG_UNIT   => FromBytes.pure(Unit)
-      case tag if tag == CL_TYPE_TAG_STRING => FromBytes.pure(String)
-      case tag if tag == CL_TYPE_TAG_KEY    => FromBytes.pure(Key)
-      case tag if tag == CL_TYPE_TAG_UREF   => FromBytes.pure(URef)
-      case tag if tag == CL_TYPE_TAG_OPTION => deserializer.map(inner => Option(inner))
-      case tag if tag == CL_TYPE_TAG_LIST   => deserializer.map(inner => List(inner))
+      case tag if tag == CL_TYPE_TAG_BOOL    => FromBytes.pure(Bool)
+      case tag if tag == CL_TYPE_TAG_I32     => FromBytes.pure(I32)
+      case tag if tag == CL_TYPE_TAG_I64     => FromBytes.pure(I64)
+      case tag if tag == CL_TYPE_TAG_U8      => FromBytes.pure(U8)
+      case tag if tag == CL_TYPE_TAG_U32     => FromBytes.pure(U32)
+      case tag if tag == CL_TYPE_TAG_U64     => FromBytes.pure(U64)
+      case tag if tag == CL_TYPE_TAG_U128    => FromBytes.pure(U128)
+      case tag if tag == CL_TYPE_TAG_U256    => FromBytes.pure(U256)
+      case tag if tag == CL_TYPE_TAG_U512    => FromBytes.pure(U512)
+      case tag if tag == CL_TYPE_TAG_I256    => FromBytes.pure(I256)
+      case tag if tag == CL_TYPE_TAG_DECIMAL => FromBytes.pure(Decimal)
+      case tag if tag == CL_TYPE_TAG_UNIT    => FromBytes.pure(Unit)
+      case tag if tag == CL_TYPE_TAG_STRING  => FromBytes.pure(String)
+      case tag if tag == CL_TYPE_TAG_KEY     => FromBytes.pure(Key)
+      case tag if tag == CL_TYPE_TAG_UREF    => FromBytes.pure(URef)
+      case tag if tag == CL_TYPE_TAG_OPTION  => deserializer.map(inner => Option(inner))
+      case tag if tag == CL_TYPE_TAG_LIST    => deserializer.map(inner => List(inner))
 
       case tag if tag == CL_TYPE_TAG_FIXED_LIST =>
         for {
@@ -112,6 +155,8 @@ object CLType {
           length <- FromBytes.int
         } yield FixedList(inner, length)
 
+      case tag if tag == CL_TYPE_TAG_BYTE_ARRAY => FromBytes.int.map(length => ByteArray(length))
+
       case tag if tag == CL_TYPE_TAG_RESULT =>
         for {
           ok  <- deserializer
@@ -141,9 +186,34 @@ object CLType {
 
       case tag if tag == CL_TYPE_TAG_ANY => FromBytes.pure(Any)
 
+      case tag if tag == CL_TYPE_TAG_STRUCT =>
+        for {
+          name   <- FromBytes.string
+          fields <- FromBytes.seq(fieldDeserializer)
+        } yield Struct(name, fields)
+
+      case tag if tag == CL_TYPE_TAG_ENUM =>
+        for {
+          name     <- FromBytes.string
+          variants <- FromBytes.seq(variantDeserializer)
+        } yield Enum(name, variants)
+
       case other => FromBytes.raise(FromBytes.Error.InvalidVariantTag(other, "CLType"))
     }
 
+  private val fieldDeserializer: FromBytes.Deserializer[Field] =
+    for {
+      name   <- FromBytes.string
+      clType <- deserializer
+    } yield Field(name, clType)
+
+  private val variantDeserializer: FromBytes.Deserializer[Variant] =
+    for {
+      tag    <- FromBytes.byte
+      name   <- FromBytes.string
+      fields <- FromBytes.seq(fieldDeserializer)
+    } yield Variant(tag, name, fields)
+
   val CL_TYPE_TAG_BOOL: Byte       = 0
   val CL_TYPE_TAG_I32: Byte        = 1
   val CL_TYPE_TAG_I64: Byte        = 2
@@ -166,4 +236,9 @@ object CLType {
   val CL_TYPE_TAG_TUPLE2: Byte     = 19
   val CL_TYPE_TAG_TUPLE3: Byte     = 20
   val CL_TYPE_TAG_ANY: Byte        = 21
+  val CL_TYPE_TAG_STRUCT: Byte     = 22
+  val CL_TYPE_TAG_ENUM: Byte       = 23
+  val CL_TYPE_TAG_I256: Byte       = 24
+  val CL_TYPE_TAG_DECIMAL: Byte    = 25
+  val CL_TYPE_TAG_BYTE_ARRAY: Byte = 26
 }
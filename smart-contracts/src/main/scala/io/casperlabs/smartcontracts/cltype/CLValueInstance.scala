@@ -39,6 +39,10 @@ object CLValueInstance {
     case CLType.U512 =>
       bigIntNN.map(nn => U512(nn))
 
+    case CLType.I256 => FromBytes.signedBigInt.map(i => I256(i))
+
+    case CLType.Decimal => FromBytes.decimal.map(d => Decimal(d))
+
     case CLType.Unit => FromBytes.unit.map(_ => Unit)
 
     case CLType.String => FromBytes.string.map(s => String(s))
@@ -62,6 +66,11 @@ object CLValueInstance {
         .fixedSeq(deserializer(inner), n)
         .flatMap(instances => lift(FixedList(instances, inner, n)))
 
+    case CLType.ByteArray(n) =>
+      FromBytes
+        .take(n)
+        .flatMap(view => lift(ByteArray(view.toArray.toIndexedSeq, n)))
+
     case CLType.Result(ok, err) =>
       FromBytes
         .either(deserializer(err), deserializer(ok))
@@ -100,6 +109,20 @@ object CLValueInstance {
 
     case CLType.Any =>
       FromBytes.raise(FromBytes.Error.FormatException("Cannot instantiate CLType.Any"))
+
+    case CLType.Struct(name, fields) =>
+      fieldsDeserializer(fields).flatMap(instances => lift(Struct(instances, name, fields)))
+
+    case CLType.Enum(name, variants) =>
+      FromBytes.byte.flatMap { tag =>
+        variants.find(_.tag == tag) match {
+          case None => FromBytes.raise(FromBytes.Error.InvalidVariantTag(tag, name))
+
+          case Some(variant) =>
+            fieldsDeserializer(variant.fields)
+              .flatMap(instances => lift(Enum(tag, instances, name, variants)))
+        }
+      }
   }
 
   case class Bool(value: Boolean) extends CLValueInstance {
@@ -138,6 +161,14 @@ object CLValueInstance {
     override val clType: CLType = CLType.U512
   }
 
+  case class I256(value: BigInt) extends CLValueInstance {
+    override val clType: CLType = CLType.I256
+  }
+
+  case class Decimal(value: BigDecimal) extends CLValueInstance {
+    override val clType: CLType = CLType.Decimal
+  }
+
   case object Unit extends CLValueInstance { override val clType: CLType = CLType.Unit }
 
   case class String(value: java.lang.String) extends CLValueInstance {
@@ -201,6 +232,17 @@ object CLValueInstance {
       }
   }
 
+  case class ByteArray private (value: IndexedSeq[Byte], length: Int) extends CLValueInstance {
+    override val clType: CLType = CLType.ByteArray(length)
+  }
+  object ByteArray {
+    def apply(value: IndexedSeq[Byte], length: Int): Either[Error.InvalidLength, ByteArray] = {
+      val n = value.size
+      if (n != length) Left(Error.InvalidLength(valueLength = n, typeLength = length))
+      else Right(new ByteArray(value, length))
+    }
+  }
+
   case class Result private (
       value: Either[CLValueInstance, CLValueInstance],
       ok: CLType,
@@ -256,6 +298,46 @@ object CLValueInstance {
     override val clType: CLType = CLType.Tuple3(_1.clType, _2.clType, _3.clType)
   }
 
+  case class Struct private (
+      value: Seq[CLValueInstance],
+      name: java.lang.String,
+      fields: Seq[CLType.Field]
+  ) extends CLValueInstance {
+    override val clType: CLType = CLType.Struct(name, fields)
+  }
+  object Struct {
+    def apply(
+        value: Seq[CLValueInstance],
+        name: java.lang.String,
+        fields: Seq[CLType.Field]
+    ): Either[Error, Struct] =
+      Error.detectFieldErrors(value, fields).map(_ => new Struct(value, name, fields))
+  }
+
+  case class Enum private (
+      tag: Byte,
+      value: Seq[CLValueInstance],
+      name: java.lang.String,
+      variants: Seq[CLType.Variant]
+  ) extends CLValueInstance {
+    override val clType: CLType = CLType.Enum(name, variants)
+  }
+  object Enum {
+    def apply(
+        tag: Byte,
+        value: Seq[CLValueInstance],
+        name: java.lang.String,
+        variants: Seq[CLType.Variant]
+    ): Either[Error, Enum] =
+      variants.find(_.tag == tag) match {
+        case None => Left(Error.UnknownVariant(tag))
+        case Some(variant) =>
+          Error
+            .detectFieldErrors(value, variant.fields)
+            .map(_ => new Enum(tag, value, name, variants))
+      }
+  }
+
   sealed trait Error
   object Error {
     case class TypeMismatch(valueType: CLType, targetType: CLType) extends Error
@@ -266,6 +348,21 @@ object CLValueInstance {
     }
 
     case class InvalidLength(valueLength: Int, typeLength: Int) extends Error
+
+    case class UnknownVariant(tag: Byte) extends Error
+
+    def detectFieldErrors(
+        value: Seq[CLValueInstance],
+        fields: Seq[CLType.Field]
+    ): Either[Error, Unit] =
+      if (value.size != fields.size)
+        Left(InvalidLength(valueLength = value.size, typeLength = fields.size))
+      else
+        value.zip(fields).find { case (v, field) => v.clType != field.clType } match {
+          case None => Right(())
+          case Some((badValue, field)) =>
+            Left(TypeMismatch(valueType = badValue.clType, targetType = field.clType))
+        }
   }
 
   private def lift[T <: CLValueInstance, E <: Error](
@@ -280,6 +377,19 @@ object CLValueInstance {
       case Right(result) => FromBytes.pure(result)
     }
 
+  // Deserializes the values of a struct's or an enum variant's fields, in order.
+  private def fieldsDeserializer(
+      fields: Seq[CLType.Field]
+  ): FromBytes.Deserializer[Seq[CLValueInstance]] =
+    fields
+      .foldLeft(FromBytes.pure(IndexedSeq.empty[CLValueInstance])) {
+        case (acc, field) =>
+          acc.flatMap { instances =>
+            deserializer(field.clType).map(instance => instances :+ instance)
+          }
+      }
+      .map(_.toSeq)
+
   private val bigIntNN: FromBytes.Deserializer[BigInt Refined NonNegative] =
     FromBytes.bigInt.flatMap { i =>
       refineV[NonNegative](i) match {
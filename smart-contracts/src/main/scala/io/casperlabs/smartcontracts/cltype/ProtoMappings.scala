@@ -166,6 +166,45 @@ object ProtoMappings {
         }
         .leftMap(err => Error.FromBytesError(err))
 
+    case CLType.I256 =>
+      FromBytes
+        .deserialize(FromBytes.signedBigInt, v.value.toArray)
+        .map { i =>
+          state.Value(
+            state.Value.Value.BigInt(
+              state.BigInt(value = i.toString, bitWidth = 256, signed = true)
+            )
+          )
+        }
+        .leftMap(err => Error.FromBytesError(err))
+
+    case CLType.Decimal =>
+      FromBytes
+        .deserialize(FromBytes.decimal, v.value.toArray)
+        .map { d =>
+          // Plain notation without trailing zeros, as the EE prints decimals.
+          val value = d.bigDecimal.stripTrailingZeros.toPlainString
+          state.Value(
+            state.Value.Value.BigInt(
+              state.BigInt(
+                value = value,
+                bitWidth = 256,
+                signed = true,
+                scale = FromBytes.DECIMAL_SCALE
+              )
+            )
+          )
+        }
+        .leftMap(err => Error.FromBytesError(err))
+
+    case CLType.ByteArray(n) =>
+      FromBytes
+        .deserialize(FromBytes.take(n), v.value.toArray)
+        .map { view =>
+          state.Value(state.Value.Value.BytesValue(ByteString.copyFrom(view.toArray)))
+        }
+        .leftMap(err => Error.FromBytesError(err))
+
     case CLType.Key =>
       FromBytes
         .deserialize(Key.deserializer, v.value.toArray)
@@ -206,6 +245,9 @@ object ProtoMappings {
       Left(Error.NoRepresentation(s"CLType.Tuple2($t1, $t2)", "state.Value"))
     case CLType.Tuple3(_, _, _) => Left(Error.NoRepresentation("CLType.Tuple3", "state.Value"))
     case CLType.Any             => Left(Error.NoRepresentation("CLType.Any", "state.Value"))
+    case CLType.Struct(name, _) =>
+      Left(Error.NoRepresentation(s"CLType.Struct($name)", "state.Value"))
+    case CLType.Enum(name, _) => Left(Error.NoRepresentation(s"CLType.Enum($name)", "state.Value"))
   }
 
   def fromProto(rights: state.Key.URef.AccessRights): Either[Error, Option[AccessRights]] =
@@ -270,6 +312,28 @@ object ProtoMappings {
       val value  = CLValue(clType, bytes)
       Right(value)
 
+    case Deploy.Arg.Value.Value.BigInt(x) if x.signed =>
+      if (x.bitWidth != 256) Left(Error.InvalidBitWidth(x.bitWidth))
+      else
+        x.scale match {
+          case 0 =>
+            Try(BigInt(x.value)).toOption.filter(isI256) match {
+              case None        => Left(Error.InvalidBigIntValue(x.value))
+              case Some(value) => Right(signed(CLType.I256, value))
+            }
+
+          case FromBytes.DECIMAL_SCALE =>
+            Try(BigDecimal(x.value).bigDecimal.movePointRight(FromBytes.DECIMAL_SCALE))
+              .flatMap(raw => Try(BigInt(raw.toBigIntegerExact)))
+              .toOption
+              .filter(isI256) match {
+              case None      => Left(Error.InvalidBigIntValue(x.value))
+              case Some(raw) => Right(signed(CLType.Decimal, raw))
+            }
+
+          case other => Left(Error.InvalidScale(other))
+        }
+
     case Deploy.Arg.Value.Value.BigInt(x) =>
       Try(BigInt(x.value)) match {
         case Failure(_) => Left(Error.InvalidBigIntValue(x.value))
@@ -306,6 +370,16 @@ object ProtoMappings {
       CLValue(CLType.Option(value.clType), bytesrepr.Constants.Option.SOME_TAG +: value.value)
   }
 
+  private val I256Bound = BigInt(2).pow(255)
+
+  private def isI256(value: BigInt): Boolean = -I256Bound <= value && value < I256Bound
+
+  // Signed values are zigzag encoded, the inverse of `FromBytes.signedBigInt`.
+  private def signed(clType: CLType, value: BigInt): CLValue = {
+    val zigzag = if (value < 0) ((-value - 1) << 1) + 1 else value << 1
+    CLValue(clType, ToBytes[BigInt].toBytes(zigzag))
+  }
+
   private def toByteArray32(bytes: ByteString): Either[Error, ByteArray32] =
     ByteArray32(bytes.toByteArray) match {
       case None          => Left(Error.Expected32Bytes(foundLength = bytes.size))
@@ -329,6 +403,7 @@ object ProtoMappings {
 
     case class InvalidBigIntValue(value: String) extends Error
     case class InvalidBitWidth(bitWidth: Int)    extends Error
+    case class InvalidScale(scale: Int)          extends Error
 
     case class UnrecognizedAccessRights(enumValue: Int) extends Error
   }
@@ -31,7 +31,7 @@ class CLTypeSerializationTest extends FlatSpec with PropertyChecks {
 
 object CLTypeSerializationTest extends Matchers {
 
-  def genCLType: Gen[CLType] = Gen.choose(0, 21).flatMap {
+  def genCLType: Gen[CLType] = Gen.choose(0, 26).flatMap {
     case 0  => Gen.const(CLType.Bool)
     case 1  => Gen.const(CLType.I32)
     case 2  => Gen.const(CLType.I64)
@@ -84,10 +84,40 @@ object CLTypeSerializationTest extends Matchers {
 
     case 21 => Gen.const(CLType.Any)
 
-    // this should never happen since we generate from 0 to 21
+    case 22 =>
+      for {
+        name   <- Gen.alphaStr
+        fields <- genFields
+      } yield CLType.Struct(name, fields)
+
+    case 23 =>
+      for {
+        name     <- Gen.alphaStr
+        n        <- Gen.choose(0, 2)
+        variants <- Gen.listOfN(n, genVariant)
+      } yield CLType.Enum(name, variants)
+
+    case 24 => Gen.const(CLType.I256)
+    case 25 => Gen.const(CLType.Decimal)
+    case 26 => Gen.choose(1, 100).map(n => CLType.ByteArray(n))
+
+    // this should never happen since we generate from 0 to 26
     case _ => Gen.fail
   }
 
+  def genFields: Gen[Seq[CLType.Field]] =
+    for {
+      n      <- Gen.choose(0, 2)
+      fields <- Gen.listOfN(n, Gen.zip(Gen.alphaStr, genCLType))
+    } yield fields.map { case (name, t) => CLType.Field(name, t) }
+
+  def genVariant: Gen[CLType.Variant] =
+    for {
+      tag    <- Gen.choose(Byte.MinValue, Byte.MaxValue)
+      name   <- Gen.alphaStr
+      fields <- genFields
+    } yield CLType.Variant(tag, name, fields)
+
   implicit val arbCLType: Arbitrary[CLType] = Arbitrary(genCLType)
 
   def nested(base: CLType, n: Int)(nest: CLType => CLType): CLType = (1 to n).foldLeft(base) {
@@ -5,7 +5,7 @@ import cats.implicits._
 import eu.timepit.refined._
 import eu.timepit.refined.api.Refined
 import eu.timepit.refined.numeric._
-import io.casperlabs.smartcontracts.bytesrepr.ToBytes
+import io.casperlabs.smartcontracts.bytesrepr.{FromBytes, ToBytes}
 import org.scalatest.{FlatSpec, Matchers}
 import org.scalatest.prop.PropertyChecks
 import scala.annotation.tailrec
@@ -109,6 +109,18 @@ class CLValueInstanceTest extends FlatSpec with Matchers with PropertyChecks {
     }
   }
 
+  it should "instantiate CLType.I256 properly" in forAll { (i: Long) =>
+    instantiateTest[BigInt](zigzag(i), CLType.I256, _ => CLValueInstance.I256(BigInt(i)))
+  }
+
+  it should "instantiate CLType.Decimal properly" in forAll { (i: Long) =>
+    instantiateTest[BigInt](
+      zigzag(i),
+      CLType.Decimal,
+      _ => CLValueInstance.Decimal(BigDecimal(BigInt(i), 18))
+    )
+  }
+
   it should "instantiate CLType.Unit properly" in {
     instantiateTest[Unit]((), CLType.Unit, _ => CLValueInstance.Unit)
   }
@@ -156,6 +168,15 @@ class CLValueInstanceTest extends FlatSpec with Matchers with PropertyChecks {
     )(Functor[List], toBytesFixedList[String])
   }
 
+  it should "instantiate CLType.ByteArray properly" in forAll { (bytes: List[Byte]) =>
+    val n = bytes.size
+    instantiateTest[List[Byte]](
+      bytes,
+      CLType.ByteArray(n),
+      x => CLValueInstance.ByteArray(x.toIndexedSeq, n).right.get
+    )(toBytesFixedList[Byte])
+  }
+
   it should "instantiate CLType.Result properly" in forAll { (e: Either[String, URef]) =>
     instantiateTest[Either[String, URef]](
       e,
@@ -218,6 +239,53 @@ class CLValueInstanceTest extends FlatSpec with Matchers with PropertyChecks {
       )
   }
 
+  it should "instantiate CLType.Struct properly" in forAll { (t: (String, Long)) =>
+    val fields = Seq(CLType.Field("name", CLType.String), CLType.Field("amount", CLType.U64))
+    instantiateTest[(String, Long)](
+      t,
+      CLType.Struct("Named", fields),
+      x =>
+        CLValueInstance
+          .Struct(Seq(CLValueInstance.String(x._1), CLValueInstance.U64(x._2)), "Named", fields)
+          .right
+          .get
+    )
+  }
+
+  it should "instantiate CLType.Enum properly" in forAll { (o: Option[Long]) =>
+    instantiateTest[Option[Long]](
+      o,
+      optionEnum,
+      {
+        case None => CLValueInstance.Enum(0, Nil, "Option", optionEnum.variants).right.get
+        case Some(x) =>
+          CLValueInstance
+            .Enum(1, Seq(CLValueInstance.U64(x)), "Option", optionEnum.variants)
+            .right
+            .get
+      }
+    )
+  }
+
+  it should "not instantiate CLType.Enum with an unknown variant" in {
+    CLValueInstance.from(CLValue(optionEnum, Vector(2.toByte))) shouldBe Left(
+      FromBytes.Error.InvalidVariantTag(2, "Option")
+    )
+  }
+
+  // An enum with the same serialized values as an `Option[Long]`.
+  private val optionEnum = CLType.Enum(
+    "Option",
+    Seq(
+      CLType.Variant(0, "None", Nil),
+      CLType.Variant(1, "Some", Seq(CLType.Field("0", CLType.U64)))
+    )
+  )
+
+  // `I256` values are zigzag encoded, see `FromBytes.signedBigInt`.
+  private def zigzag(i: Long): BigInt =
+    if (i < 0) ((-BigInt(i) - 1) << 1) + 1 else BigInt(i) << 1
+
   private def instantiateTest[T: ToBytes](t: T, clType: CLType, instance: T => CLValueInstance) = {
     val clValue    = CLValue.from(t, clType)
     val clInstance = CLValueInstance.from(clValue)